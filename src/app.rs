@@ -1,3 +1,4 @@
+pub mod alerts;
 pub mod data;
 pub mod filter;
 pub mod layout_manager;
@@ -5,6 +6,7 @@ pub mod states;
 
 use std::time::Instant;
 
+use alerts::Alerts;
 use concat_string::concat_string;
 use data::*;
 use filter::*;
@@ -30,6 +32,22 @@ pub enum AxisScaling {
     #[default]
     Log,
     Linear,
+    /// Linear, with the maximum set to the link capacity of the network interfaces.
+    Link,
+}
+
+/// Which packet series are charted below the network graph.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct PacketGraphSeries {
+    pub packets: bool,
+    pub errors: bool,
+    pub drops: bool,
+}
+
+impl PacketGraphSeries {
+    pub fn any(&self) -> bool {
+        self.packets || self.errors || self.drops
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -61,6 +79,7 @@ pub struct AppConfigFields {
     pub temperature_type: TemperatureType,
     pub use_dot: bool,
     pub cpu_left_legend: bool,
+    pub temperature_left_legend: bool,
     pub show_average_cpu: bool, // TODO: Unify this in CPU options
     pub use_current_cpu_total: bool,
     pub unnormalized_cpu: bool,
@@ -84,15 +103,27 @@ pub struct AppConfigFields {
     pub is_read_only: bool,
     #[cfg(target_os = "linux")]
     pub hide_k_threads: bool,
+    #[cfg(target_os = "linux")]
+    pub group_cpus_by_numa: bool,
+    #[cfg(target_os = "linux")]
+    pub get_process_numa_node: bool,
     #[cfg(feature = "zfs")]
     pub free_arc: bool,
     pub memory_legend_position: Option<LegendPosition>,
+    pub disk_io_legend_position: Option<LegendPosition>,
+    pub tcp_legend_position: Option<LegendPosition>,
+    pub power_legend_position: Option<LegendPosition>,
+    pub gpu_legend_position: Option<LegendPosition>,
     // TODO: Remove these, move network details state-side.
     pub network_unit_type: DataUnit,
     pub network_legend_position: Option<LegendPosition>,
     pub network_scale_type: AxisScaling,
     pub network_use_binary_prefix: bool,
+    pub network_saturation_threshold: u8,
+    pub network_packet_series: PacketGraphSeries,
+    pub network_error_threshold: u32,
     pub retention_ms: u64,
+    pub disk_trend_window_ms: u64,
     pub dedicated_average_row: bool,
     pub default_tree_collapse: bool,
     pub text_width_mode: TextWidthMode,
@@ -105,6 +136,7 @@ pub struct AppConfigFields {
 pub struct DataFilters {
     pub disk_filter: Option<Filter>,
     pub mount_filter: Option<Filter>,
+    pub fs_type_filter: Option<Filter>,
     pub temp_filter: Option<Filter>,
     pub net_filter: Option<Filter>,
 }
@@ -126,6 +158,7 @@ pub struct App {
     pub current_widget: BottomWidget,
     pub used_widgets: UsedWidgets,
     pub filters: DataFilters,
+    pub alerts: Alerts,
 }
 
 impl App {
@@ -133,7 +166,7 @@ impl App {
     pub fn new(
         app_config_fields: AppConfigFields, states: AppWidgetStates,
         widget_map: HashMap<u64, BottomWidget>, current_widget: BottomWidget,
-        used_widgets: UsedWidgets, filters: DataFilters, is_expanded: bool,
+        used_widgets: UsedWidgets, filters: DataFilters, alerts: Alerts, is_expanded: bool,
     ) -> Self {
         Self {
             awaiting_second_char: false,
@@ -152,6 +185,14 @@ impl App {
             current_widget,
             used_widgets,
             filters,
+            alerts,
+        }
+    }
+
+    /// Evaluate the alert rules against the latest data, notifying for any that just fired.
+    pub fn update_alerts(&mut self) {
+        for (rule, value) in self.alerts.update(self.data_store.latest_data()) {
+            rule.notify(value);
         }
     }
 
@@ -174,9 +215,21 @@ impl App {
             }
         }
 
+        let temp_graph_state = &mut self.states.temp_graph_state;
+        for temp_graph in temp_graph_state.widget_states.values_mut() {
+            if temp_graph.force_update_data {
+                temp_graph.set_legend_data(&temp_graph_state.graph_sensors, data_source);
+            }
+        }
+
         for cpu in self.states.cpu_state.widget_states.values_mut() {
             if cpu.force_update_data {
-                cpu.set_legend_data(&data_source.cpu_harvest);
+                #[cfg(target_os = "linux")]
+                let power = &data_source.cpu_power_harvest;
+                #[cfg(not(target_os = "linux"))]
+                let power = &[];
+
+                cpu.set_legend_data(&data_source.cpu_harvest, power);
             }
         }
 
@@ -185,6 +238,12 @@ impl App {
                 disk.set_table_data(data_source);
             }
         }
+
+        for net_table in self.states.net_table_state.widget_states.values_mut() {
+            if net_table.force_update_data {
+                net_table.set_table_data(data_source, &self.states.net_state.graph_interfaces);
+            }
+        }
     }
 
     pub fn reset(&mut self) {
@@ -304,6 +363,26 @@ impl App {
         self.is_in_dialog()
     }
 
+    /// Whether the legend of the graph with `graph_id` was left out when last drawn, or `None`
+    /// if `legend_type` isn't a graph legend.
+    fn is_legend_hidden(&self, legend_type: &BottomWidgetType, graph_id: u64) -> Option<bool> {
+        match legend_type {
+            BottomWidgetType::CpuLegend => self
+                .states
+                .cpu_state
+                .widget_states
+                .get(&graph_id)
+                .map(|state| state.is_legend_hidden),
+            BottomWidgetType::TempGraphLegend => self
+                .states
+                .temp_graph_state
+                .widget_states
+                .get(&graph_id)
+                .map(|state| state.is_legend_hidden),
+            _ => None,
+        }
+    }
+
     pub fn on_tab(&mut self) {
         // Allow usage whilst only in processes
 
@@ -688,6 +767,16 @@ impl App {
                         }
                     }
                 }
+                BottomWidgetType::Gpu => {
+                    if let Some(gpu_widget_state) = self
+                        .states
+                        .gpu_state
+                        .widget_states
+                        .get_mut(&self.current_widget.widget_id)
+                    {
+                        gpu_widget_state.metric = gpu_widget_state.metric.prev();
+                    }
+                }
                 BottomWidgetType::Battery => {
                     #[cfg(feature = "battery")]
                     if self.data_store.get_data().battery_harvest.len() > 1 {
@@ -738,6 +827,16 @@ impl App {
                         }
                     }
                 }
+                BottomWidgetType::Gpu => {
+                    if let Some(gpu_widget_state) = self
+                        .states
+                        .gpu_state
+                        .widget_states
+                        .get_mut(&self.current_widget.widget_id)
+                    {
+                        gpu_widget_state.metric = gpu_widget_state.metric.next();
+                    }
+                }
                 BottomWidgetType::Battery => {
                     #[cfg(feature = "battery")]
                     {
@@ -774,6 +873,12 @@ impl App {
                 {
                     proc_widget_state.toggle_current_tree_branch_entry();
                 }
+            } else if self.current_widget.widget_type == BottomWidgetType::NetTable {
+                self.toggle_graphed_interface();
+            } else if self.current_widget.widget_type == BottomWidgetType::Disk {
+                self.toggle_graphed_disk();
+            } else if self.current_widget.widget_type == BottomWidgetType::Temp {
+                self.toggle_graphed_sensor();
             }
         } else if self.process_kill_dialog.is_open() {
             // Either select the current option,
@@ -781,6 +886,83 @@ impl App {
         }
     }
 
+    /// Toggles whether the currently selected interface in a network table is plotted in
+    /// the network graph.
+    fn toggle_graphed_interface(&mut self) {
+        let Some(name) = self
+            .states
+            .net_table_state
+            .get_widget_state(self.current_widget.widget_id)
+            .and_then(|net_table_widget_state| net_table_widget_state.table.current_item())
+            .map(|interface| interface.name.clone())
+        else {
+            return;
+        };
+
+        let graph_interfaces = &mut self.states.net_state.graph_interfaces;
+        if let Some(index) = graph_interfaces.iter().position(|n| *n == name) {
+            graph_interfaces.remove(index);
+        } else {
+            graph_interfaces.push(name);
+        }
+
+        // The cached graph heights don't account for the newly plotted interface.
+        for net_widget_state in self.states.net_state.widget_states.values_mut() {
+            net_widget_state.height_cache = None;
+        }
+
+        for net_table in self.states.net_table_state.widget_states.values_mut() {
+            net_table.force_data_update();
+        }
+    }
+
+    /// Toggles whether the currently selected disk in a disk table is plotted in the disk
+    /// I/O graph.
+    fn toggle_graphed_disk(&mut self) {
+        let Some(name) = self
+            .states
+            .disk_state
+            .get_widget_state(self.current_widget.widget_id)
+            .and_then(|disk_widget_state| disk_widget_state.table.current_item())
+            .map(|disk| disk.name.clone())
+        else {
+            return;
+        };
+
+        let graph_disks = &mut self.states.disk_io_state.graph_disks;
+        if let Some(index) = graph_disks.iter().position(|n| *n == name) {
+            graph_disks.remove(index);
+        } else {
+            graph_disks.push(name);
+        }
+    }
+
+    /// Toggles whether the currently selected sensor in a temperature table is plotted in the
+    /// temperature graph.
+    fn toggle_graphed_sensor(&mut self) {
+        let Some(name) = self
+            .states
+            .temp_state
+            .get_widget_state(self.current_widget.widget_id)
+            .and_then(|temp_widget_state| temp_widget_state.table.current_item())
+            .map(|temp| temp.sensor.clone())
+        else {
+            return;
+        };
+
+        let graph_sensors = &mut self.states.temp_graph_state.graph_sensors;
+        if let Some(index) = graph_sensors.iter().position(|n| *n == name) {
+            graph_sensors.remove(index);
+        } else {
+            graph_sensors.push(name);
+        }
+
+        // The graph legends list the plotted sensors, so they need to follow along.
+        for temp_graph in self.states.temp_graph_state.widget_states.values_mut() {
+            temp_graph.force_data_update();
+        }
+    }
+
     pub fn on_page_up(&mut self) {
         if self.process_kill_dialog.is_open() {
             self.process_kill_dialog.on_page_up();
@@ -1048,10 +1230,14 @@ impl App {
             self.handle_char(caught_char);
         } else if self.help_dialog_state.is_showing_help {
             match caught_char {
-                '1' | '2' | '3' | '4' | '5' | '6' | '7' | '8' | '9' => {
+                '0' | '1' | '2' | '3' | '4' | '5' | '6' | '7' | '8' | '9' => {
                     let potential_index = caught_char.to_digit(10);
                     if let Some(potential_index) = potential_index {
-                        let potential_index = potential_index as usize;
+                        // There's no 10 key, so 0 goes to the tenth section.
+                        let potential_index = match potential_index {
+                            0 => 10,
+                            index => index as usize,
+                        };
                         if (potential_index) < self.help_dialog_state.index_shortcuts.len() {
                             self.help_scroll_to_or_max(
                                 self.help_dialog_state.index_shortcuts[potential_index],
@@ -1309,6 +1495,15 @@ impl App {
                     disk.set_index(4);
                 }
             }
+            'T' => {
+                if let Some(disk) = self
+                    .states
+                    .disk_state
+                    .get_mut_widget_state(self.current_widget.widget_id)
+                {
+                    disk.toggle_tree();
+                }
+            }
             '+' => self.on_plus(),
             '-' => self.on_minus(),
             '=' => self.reset_zoom(),
@@ -1528,14 +1723,13 @@ impl App {
                                         WidgetDirection::Down => new_widget.down_neighbour,
                                     };
                                     match &new_widget.widget_type {
-                                        BottomWidgetType::CpuLegend => {
-                                            if let Some(cpu_widget_state) = self
-                                                .states
-                                                .cpu_state
-                                                .widget_states
-                                                .get(&(new_widget_id - *offset))
-                                            {
-                                                if cpu_widget_state.is_legend_hidden {
+                                        BottomWidgetType::CpuLegend
+                                        | BottomWidgetType::TempGraphLegend => {
+                                            if let Some(is_legend_hidden) = self.is_legend_hidden(
+                                                &new_widget.widget_type,
+                                                new_widget_id - *offset,
+                                            ) {
+                                                if is_legend_hidden {
                                                     if let Some(next_neighbour_id) =
                                                         option_next_neighbour_id
                                                     {
@@ -1611,14 +1805,13 @@ impl App {
                                 } else {
                                     // Reflect
                                     match &new_widget.widget_type {
-                                        BottomWidgetType::CpuLegend => {
-                                            if let Some(cpu_widget_state) = self
-                                                .states
-                                                .cpu_state
-                                                .widget_states
-                                                .get(&(new_widget_id - *offset))
-                                            {
-                                                if cpu_widget_state.is_legend_hidden {
+                                        BottomWidgetType::CpuLegend
+                                        | BottomWidgetType::TempGraphLegend => {
+                                            if let Some(is_legend_hidden) = self.is_legend_hidden(
+                                                &new_widget.widget_type,
+                                                new_widget_id - *offset,
+                                            ) {
+                                                if is_legend_hidden {
                                                     if let Some(parent_cpu_widget) = self
                                                         .widget_map
                                                         .get(&(new_widget_id - *offset))
@@ -1691,16 +1884,12 @@ impl App {
                     if let Some((parent_direction, offset)) = &self.current_widget.parent_reflector
                     {
                         match &self.current_widget.widget_type {
-                            BottomWidgetType::CpuLegend => {
-                                if let Some(cpu_widget_state) = self
-                                    .states
-                                    .cpu_state
-                                    .widget_states
-                                    .get(&(self.current_widget.widget_id - *offset))
-                                {
-                                    if cpu_widget_state.is_legend_hidden {
-                                        reflection_dir = Some(parent_direction.clone());
-                                    }
+                            BottomWidgetType::CpuLegend | BottomWidgetType::TempGraphLegend => {
+                                if let Some(true) = self.is_legend_hidden(
+                                    &self.current_widget.widget_type,
+                                    self.current_widget.widget_id - *offset,
+                                ) {
+                                    reflection_dir = Some(parent_direction.clone());
                                 }
                             }
                             BottomWidgetType::ProcSearch | BottomWidgetType::ProcSort => {
@@ -1778,8 +1967,42 @@ impl App {
         }
     }
 
+    /// Moves between an expanded temperature graph and its legend, if they're in `direction`
+    /// of each other.
+    fn handle_expanded_temp_graph_movement(&mut self, direction: WidgetDirection) {
+        let is_left = matches!(direction, WidgetDirection::Left);
+        let left_legend = self.app_config_fields.temperature_left_legend;
+        let should_move = match self.current_widget.widget_type {
+            BottomWidgetType::TempGraph => {
+                is_left == left_legend
+                    && self.is_legend_hidden(
+                        &BottomWidgetType::TempGraphLegend,
+                        self.current_widget.widget_id,
+                    ) == Some(false)
+            }
+            BottomWidgetType::TempGraphLegend => is_left != left_legend,
+            _ => false,
+        };
+
+        if should_move {
+            let new_widget_id = if is_left {
+                self.current_widget.left_neighbour
+            } else {
+                self.current_widget.right_neighbour
+            };
+
+            if let Some(new_widget) = new_widget_id.and_then(|id| self.widget_map.get(&id)) {
+                self.current_widget = new_widget.clone();
+            }
+        }
+    }
+
     fn handle_left_expanded_movement(&mut self) {
-        if let BottomWidgetType::Proc = self.current_widget.widget_type {
+        if let BottomWidgetType::TempGraph | BottomWidgetType::TempGraphLegend =
+            self.current_widget.widget_type
+        {
+            self.handle_expanded_temp_graph_movement(WidgetDirection::Left);
+        } else if let BottomWidgetType::Proc = self.current_widget.widget_type {
             if let Some(new_widget_id) = self.current_widget.left_neighbour {
                 if let Some(proc_widget_state) = self
                     .states
@@ -1825,7 +2048,11 @@ impl App {
     }
 
     fn handle_right_expanded_movement(&mut self) {
-        if let BottomWidgetType::ProcSort = self.current_widget.widget_type {
+        if let BottomWidgetType::TempGraph | BottomWidgetType::TempGraphLegend =
+            self.current_widget.widget_type
+        {
+            self.handle_expanded_temp_graph_movement(WidgetDirection::Right);
+        } else if let BottomWidgetType::ProcSort = self.current_widget.widget_type {
             if let Some(new_widget_id) = self.current_widget.right_neighbour {
                 if let Some(proc_sort_widget) = self.widget_map.get(&new_widget_id) {
                     self.current_widget = proc_sort_widget.clone();
@@ -1900,6 +2127,15 @@ impl App {
                         disk_widget_state.table.scroll_to_first();
                     }
                 }
+                BottomWidgetType::NetTable => {
+                    if let Some(net_table_widget_state) = self
+                        .states
+                        .net_table_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        net_table_widget_state.table.scroll_to_first();
+                    }
+                }
                 BottomWidgetType::CpuLegend => {
                     if let Some(cpu_widget_state) = self
                         .states
@@ -1909,6 +2145,15 @@ impl App {
                         cpu_widget_state.table.scroll_to_first();
                     }
                 }
+                BottomWidgetType::TempGraphLegend => {
+                    if let Some(temp_graph_widget_state) = self
+                        .states
+                        .temp_graph_state
+                        .get_mut_widget_state(self.current_widget.widget_id - 1)
+                    {
+                        temp_graph_widget_state.table.scroll_to_first();
+                    }
+                }
 
                 _ => {}
             }
@@ -1961,13 +2206,39 @@ impl App {
                         }
                     }
                 }
+                BottomWidgetType::NetTable => {
+                    if let Some(net_table_widget_state) = self
+                        .states
+                        .net_table_state
+                        .get_mut_widget_state(self.current_widget.widget_id)
+                    {
+                        if !self
+                            .data_store
+                            .get_data()
+                            .network_harvest
+                            .interfaces
+                            .is_empty()
+                        {
+                            net_table_widget_state.table.scroll_to_last();
+                        }
+                    }
+                }
                 BottomWidgetType::CpuLegend => {
                     if let Some(cpu_widget_state) = self
                         .states
                         .cpu_state
                         .get_mut_widget_state(self.current_widget.widget_id - 1)
                     {
-                        cpu_widget_state.table.scroll_to_last();
+                        cpu_widget_state.scroll_legend_to_last();
+                    }
+                }
+                BottomWidgetType::TempGraphLegend => {
+                    if let Some(temp_graph_widget_state) = self
+                        .states
+                        .temp_graph_state
+                        .get_mut_widget_state(self.current_widget.widget_id - 1)
+                    {
+                        temp_graph_widget_state.table.scroll_to_last();
                     }
                 }
                 _ => {}
@@ -1998,7 +2269,9 @@ impl App {
                 BottomWidgetType::ProcSort => self.change_process_sort_position(amount),
                 BottomWidgetType::Temp => self.change_temp_position(amount),
                 BottomWidgetType::Disk => self.change_disk_position(amount),
+                BottomWidgetType::NetTable => self.change_net_table_position(amount),
                 BottomWidgetType::CpuLegend => self.change_cpu_legend_position(amount),
+                BottomWidgetType::TempGraphLegend => self.change_temp_graph_legend_position(amount),
                 _ => {}
             }
         }
@@ -2023,7 +2296,19 @@ impl App {
             .widget_states
             .get_mut(&(self.current_widget.widget_id - 1))
         {
-            cpu_widget_state.table.increment_position(num_to_change_by);
+            cpu_widget_state.increment_legend_position(num_to_change_by);
+        }
+    }
+
+    fn change_temp_graph_legend_position(&mut self, num_to_change_by: i64) {
+        if let Some(temp_graph_widget_state) = self
+            .states
+            .temp_graph_state
+            .get_mut_widget_state(self.current_widget.widget_id - 1)
+        {
+            temp_graph_widget_state
+                .table
+                .increment_position(num_to_change_by);
         }
     }
 
@@ -2062,6 +2347,19 @@ impl App {
         }
     }
 
+    fn change_net_table_position(&mut self, num_to_change_by: i64) {
+        if let Some(net_table_widget_state) = self
+            .states
+            .net_table_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            net_table_widget_state
+                .table
+                .increment_position(num_to_change_by);
+        }
+    }
+
     fn help_scroll_up(&mut self) {
         if self.help_dialog_state.scroll_state.current_scroll_index > 0 {
             self.help_dialog_state.scroll_state.current_scroll_index -= 1;
@@ -2192,6 +2490,194 @@ impl App {
                     }
                 }
             }
+            BottomWidgetType::MemPressure => {
+                if let Some(mem_pressure_widget_state) = self
+                    .states
+                    .mem_pressure_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = mem_pressure_widget_state
+                        .current_display_time
+                        .saturating_add(self.app_config_fields.time_interval);
+
+                    if new_time <= self.app_config_fields.retention_ms {
+                        mem_pressure_widget_state.current_display_time = new_time;
+                        if self.app_config_fields.autohide_time {
+                            mem_pressure_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if mem_pressure_widget_state.current_display_time
+                        != self.app_config_fields.retention_ms
+                    {
+                        mem_pressure_widget_state.current_display_time =
+                            self.app_config_fields.retention_ms;
+                        if self.app_config_fields.autohide_time {
+                            mem_pressure_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
+            BottomWidgetType::Tcp => {
+                if let Some(tcp_widget_state) = self
+                    .states
+                    .tcp_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = tcp_widget_state
+                        .current_display_time
+                        .saturating_add(self.app_config_fields.time_interval);
+
+                    if new_time <= self.app_config_fields.retention_ms {
+                        tcp_widget_state.current_display_time = new_time;
+                        if self.app_config_fields.autohide_time {
+                            tcp_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if tcp_widget_state.current_display_time
+                        != self.app_config_fields.retention_ms
+                    {
+                        tcp_widget_state.current_display_time = self.app_config_fields.retention_ms;
+                        if self.app_config_fields.autohide_time {
+                            tcp_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
+            BottomWidgetType::DiskIo => {
+                if let Some(disk_io_widget_state) = self
+                    .states
+                    .disk_io_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = disk_io_widget_state
+                        .current_display_time
+                        .saturating_add(self.app_config_fields.time_interval);
+
+                    if new_time <= self.app_config_fields.retention_ms {
+                        disk_io_widget_state.current_display_time = new_time;
+                        if self.app_config_fields.autohide_time {
+                            disk_io_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if disk_io_widget_state.current_display_time
+                        != self.app_config_fields.retention_ms
+                    {
+                        disk_io_widget_state.current_display_time =
+                            self.app_config_fields.retention_ms;
+                        if self.app_config_fields.autohide_time {
+                            disk_io_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
+            BottomWidgetType::Power => {
+                if let Some(power_widget_state) = self
+                    .states
+                    .power_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = power_widget_state
+                        .current_display_time
+                        .saturating_add(self.app_config_fields.time_interval);
+
+                    if new_time <= self.app_config_fields.retention_ms {
+                        power_widget_state.current_display_time = new_time;
+                        if self.app_config_fields.autohide_time {
+                            power_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if power_widget_state.current_display_time
+                        != self.app_config_fields.retention_ms
+                    {
+                        power_widget_state.current_display_time =
+                            self.app_config_fields.retention_ms;
+                        if self.app_config_fields.autohide_time {
+                            power_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
+            BottomWidgetType::Gpu => {
+                if let Some(gpu_widget_state) = self
+                    .states
+                    .gpu_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = gpu_widget_state
+                        .current_display_time
+                        .saturating_add(self.app_config_fields.time_interval);
+
+                    if new_time <= self.app_config_fields.retention_ms {
+                        gpu_widget_state.current_display_time = new_time;
+                        if self.app_config_fields.autohide_time {
+                            gpu_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if gpu_widget_state.current_display_time
+                        != self.app_config_fields.retention_ms
+                    {
+                        gpu_widget_state.current_display_time = self.app_config_fields.retention_ms;
+                        if self.app_config_fields.autohide_time {
+                            gpu_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
+            BottomWidgetType::Battery => {
+                #[cfg(feature = "battery")]
+                if let Some(battery_widget_state) = self
+                    .states
+                    .battery_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = battery_widget_state
+                        .current_display_time
+                        .saturating_add(self.app_config_fields.time_interval);
+
+                    if new_time <= self.app_config_fields.retention_ms {
+                        battery_widget_state.current_display_time = new_time;
+                        if self.app_config_fields.autohide_time {
+                            battery_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if battery_widget_state.current_display_time
+                        != self.app_config_fields.retention_ms
+                    {
+                        battery_widget_state.current_display_time =
+                            self.app_config_fields.retention_ms;
+                        if self.app_config_fields.autohide_time {
+                            battery_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
+            BottomWidgetType::TempGraph => {
+                if let Some(temp_graph_widget_state) = self
+                    .states
+                    .temp_graph_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = temp_graph_widget_state
+                        .current_display_time
+                        .saturating_add(self.app_config_fields.time_interval);
+
+                    if new_time <= self.app_config_fields.retention_ms {
+                        temp_graph_widget_state.current_display_time = new_time;
+                        if self.app_config_fields.autohide_time {
+                            temp_graph_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if temp_graph_widget_state.current_display_time
+                        != self.app_config_fields.retention_ms
+                    {
+                        temp_graph_widget_state.current_display_time =
+                            self.app_config_fields.retention_ms;
+                        if self.app_config_fields.autohide_time {
+                            temp_graph_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
             BottomWidgetType::Net => {
                 if let Some(net_widget_state) = self
                     .states
@@ -2272,6 +2758,178 @@ impl App {
                     }
                 }
             }
+            BottomWidgetType::MemPressure => {
+                if let Some(mem_pressure_widget_state) = self
+                    .states
+                    .mem_pressure_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = mem_pressure_widget_state
+                        .current_display_time
+                        .saturating_sub(self.app_config_fields.time_interval);
+
+                    if new_time >= STALE_MIN_MILLISECONDS {
+                        mem_pressure_widget_state.current_display_time = new_time;
+                        if self.app_config_fields.autohide_time {
+                            mem_pressure_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if mem_pressure_widget_state.current_display_time
+                        != STALE_MIN_MILLISECONDS
+                    {
+                        mem_pressure_widget_state.current_display_time = STALE_MIN_MILLISECONDS;
+                        if self.app_config_fields.autohide_time {
+                            mem_pressure_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
+            BottomWidgetType::Tcp => {
+                if let Some(tcp_widget_state) = self
+                    .states
+                    .tcp_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = tcp_widget_state
+                        .current_display_time
+                        .saturating_sub(self.app_config_fields.time_interval);
+
+                    if new_time >= STALE_MIN_MILLISECONDS {
+                        tcp_widget_state.current_display_time = new_time;
+                        if self.app_config_fields.autohide_time {
+                            tcp_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if tcp_widget_state.current_display_time != STALE_MIN_MILLISECONDS {
+                        tcp_widget_state.current_display_time = STALE_MIN_MILLISECONDS;
+                        if self.app_config_fields.autohide_time {
+                            tcp_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
+            BottomWidgetType::DiskIo => {
+                if let Some(disk_io_widget_state) = self
+                    .states
+                    .disk_io_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = disk_io_widget_state
+                        .current_display_time
+                        .saturating_sub(self.app_config_fields.time_interval);
+
+                    if new_time >= STALE_MIN_MILLISECONDS {
+                        disk_io_widget_state.current_display_time = new_time;
+                        if self.app_config_fields.autohide_time {
+                            disk_io_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if disk_io_widget_state.current_display_time != STALE_MIN_MILLISECONDS {
+                        disk_io_widget_state.current_display_time = STALE_MIN_MILLISECONDS;
+                        if self.app_config_fields.autohide_time {
+                            disk_io_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
+            BottomWidgetType::Power => {
+                if let Some(power_widget_state) = self
+                    .states
+                    .power_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = power_widget_state
+                        .current_display_time
+                        .saturating_sub(self.app_config_fields.time_interval);
+
+                    if new_time >= STALE_MIN_MILLISECONDS {
+                        power_widget_state.current_display_time = new_time;
+                        if self.app_config_fields.autohide_time {
+                            power_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if power_widget_state.current_display_time != STALE_MIN_MILLISECONDS {
+                        power_widget_state.current_display_time = STALE_MIN_MILLISECONDS;
+                        if self.app_config_fields.autohide_time {
+                            power_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
+            BottomWidgetType::Gpu => {
+                if let Some(gpu_widget_state) = self
+                    .states
+                    .gpu_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = gpu_widget_state
+                        .current_display_time
+                        .saturating_sub(self.app_config_fields.time_interval);
+
+                    if new_time >= STALE_MIN_MILLISECONDS {
+                        gpu_widget_state.current_display_time = new_time;
+                        if self.app_config_fields.autohide_time {
+                            gpu_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if gpu_widget_state.current_display_time != STALE_MIN_MILLISECONDS {
+                        gpu_widget_state.current_display_time = STALE_MIN_MILLISECONDS;
+                        if self.app_config_fields.autohide_time {
+                            gpu_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
+            BottomWidgetType::Battery => {
+                #[cfg(feature = "battery")]
+                if let Some(battery_widget_state) = self
+                    .states
+                    .battery_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = battery_widget_state
+                        .current_display_time
+                        .saturating_sub(self.app_config_fields.time_interval);
+
+                    if new_time >= STALE_MIN_MILLISECONDS {
+                        battery_widget_state.current_display_time = new_time;
+                        if self.app_config_fields.autohide_time {
+                            battery_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if battery_widget_state.current_display_time != STALE_MIN_MILLISECONDS {
+                        battery_widget_state.current_display_time = STALE_MIN_MILLISECONDS;
+                        if self.app_config_fields.autohide_time {
+                            battery_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
+            BottomWidgetType::TempGraph => {
+                if let Some(temp_graph_widget_state) = self
+                    .states
+                    .temp_graph_state
+                    .widget_states
+                    .get_mut(&self.current_widget.widget_id)
+                {
+                    let new_time = temp_graph_widget_state
+                        .current_display_time
+                        .saturating_sub(self.app_config_fields.time_interval);
+
+                    if new_time >= STALE_MIN_MILLISECONDS {
+                        temp_graph_widget_state.current_display_time = new_time;
+                        if self.app_config_fields.autohide_time {
+                            temp_graph_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    } else if temp_graph_widget_state.current_display_time != STALE_MIN_MILLISECONDS
+                    {
+                        temp_graph_widget_state.current_display_time = STALE_MIN_MILLISECONDS;
+                        if self.app_config_fields.autohide_time {
+                            temp_graph_widget_state.autohide_timer = Some(Instant::now());
+                        }
+                    }
+                }
+            }
             BottomWidgetType::Net => {
                 if let Some(net_widget_state) = self
                     .states
@@ -2328,6 +2986,107 @@ impl App {
         }
     }
 
+    fn reset_mem_pressure_zoom(&mut self) {
+        if let Some(mem_pressure_widget_state) = self
+            .states
+            .mem_pressure_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            mem_pressure_widget_state.current_display_time =
+                self.app_config_fields.default_time_value;
+            if self.app_config_fields.autohide_time {
+                mem_pressure_widget_state.autohide_timer = Some(Instant::now());
+            }
+        }
+    }
+
+    fn reset_tcp_zoom(&mut self) {
+        if let Some(tcp_widget_state) = self
+            .states
+            .tcp_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            tcp_widget_state.current_display_time = self.app_config_fields.default_time_value;
+            if self.app_config_fields.autohide_time {
+                tcp_widget_state.autohide_timer = Some(Instant::now());
+            }
+        }
+    }
+
+    fn reset_disk_io_zoom(&mut self) {
+        if let Some(disk_io_widget_state) = self
+            .states
+            .disk_io_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            disk_io_widget_state.current_display_time = self.app_config_fields.default_time_value;
+            if self.app_config_fields.autohide_time {
+                disk_io_widget_state.autohide_timer = Some(Instant::now());
+            }
+        }
+    }
+
+    fn reset_power_zoom(&mut self) {
+        if let Some(power_widget_state) = self
+            .states
+            .power_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            power_widget_state.current_display_time = self.app_config_fields.default_time_value;
+            if self.app_config_fields.autohide_time {
+                power_widget_state.autohide_timer = Some(Instant::now());
+            }
+        }
+    }
+
+    fn reset_gpu_zoom(&mut self) {
+        if let Some(gpu_widget_state) = self
+            .states
+            .gpu_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            gpu_widget_state.current_display_time = self.app_config_fields.default_time_value;
+            if self.app_config_fields.autohide_time {
+                gpu_widget_state.autohide_timer = Some(Instant::now());
+            }
+        }
+    }
+
+    #[cfg(feature = "battery")]
+    fn reset_battery_zoom(&mut self) {
+        if let Some(battery_widget_state) = self
+            .states
+            .battery_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            battery_widget_state.current_display_time = self.app_config_fields.default_time_value;
+            if self.app_config_fields.autohide_time {
+                battery_widget_state.autohide_timer = Some(Instant::now());
+            }
+        }
+    }
+
+    fn reset_temp_graph_zoom(&mut self) {
+        if let Some(temp_graph_widget_state) = self
+            .states
+            .temp_graph_state
+            .widget_states
+            .get_mut(&self.current_widget.widget_id)
+        {
+            temp_graph_widget_state.current_display_time =
+                self.app_config_fields.default_time_value;
+            if self.app_config_fields.autohide_time {
+                temp_graph_widget_state.autohide_timer = Some(Instant::now());
+            }
+        }
+    }
+
     fn reset_net_zoom(&mut self) {
         if let Some(net_widget_state) = self
             .states
@@ -2346,6 +3105,17 @@ impl App {
         match self.current_widget.widget_type {
             BottomWidgetType::Cpu => self.reset_cpu_zoom(),
             BottomWidgetType::Mem => self.reset_mem_zoom(),
+            BottomWidgetType::MemPressure => self.reset_mem_pressure_zoom(),
+            BottomWidgetType::Tcp => self.reset_tcp_zoom(),
+            BottomWidgetType::DiskIo => self.reset_disk_io_zoom(),
+            BottomWidgetType::Power => self.reset_power_zoom(),
+            BottomWidgetType::Gpu => self.reset_gpu_zoom(),
+            BottomWidgetType::Battery =>
+            {
+                #[cfg(feature = "battery")]
+                self.reset_battery_zoom()
+            }
+            BottomWidgetType::TempGraph => self.reset_temp_graph_zoom(),
             BottomWidgetType::Net => self.reset_net_zoom(),
             _ => {}
         }
@@ -2487,8 +3257,10 @@ impl App {
                     BottomWidgetType::Proc
                     | BottomWidgetType::ProcSort
                     | BottomWidgetType::CpuLegend
+                    | BottomWidgetType::TempGraphLegend
                     | BottomWidgetType::Temp
-                    | BottomWidgetType::Disk => {
+                    | BottomWidgetType::Disk
+                    | BottomWidgetType::NetTable => {
                         // Get our index...
                         let clicked_entry = y - *tlc_y;
                         let header_offset = self.header_offset(&self.current_widget);
@@ -2556,6 +3328,21 @@ impl App {
                                         }
                                     }
                                 }
+                                BottomWidgetType::TempGraphLegend => {
+                                    if let Some(temp_graph_widget_state) = self
+                                        .states
+                                        .temp_graph_state
+                                        .get_widget_state(self.current_widget.widget_id - 1)
+                                    {
+                                        if let Some(visual_index) =
+                                            temp_graph_widget_state.table.ratatui_selected()
+                                        {
+                                            self.change_temp_graph_legend_position(
+                                                offset_clicked_entry as i64 - visual_index as i64,
+                                            );
+                                        }
+                                    }
+                                }
                                 BottomWidgetType::Temp => {
                                     if let Some(temp_widget_state) = self
                                         .states
@@ -2586,6 +3373,21 @@ impl App {
                                         }
                                     }
                                 }
+                                BottomWidgetType::NetTable => {
+                                    if let Some(net_table_widget_state) = self
+                                        .states
+                                        .net_table_state
+                                        .get_widget_state(self.current_widget.widget_id)
+                                    {
+                                        if let Some(visual_index) =
+                                            net_table_widget_state.table.ratatui_selected()
+                                        {
+                                            self.change_net_table_position(
+                                                offset_clicked_entry as i64 - visual_index as i64,
+                                            );
+                                        }
+                                    }
+                                }
                                 _ => {}
                             }
                         } else {
@@ -2627,6 +3429,17 @@ impl App {
                                             }
                                         }
                                     }
+                                    BottomWidgetType::NetTable => {
+                                        if let Some(net_table) = self
+                                            .states
+                                            .net_table_state
+                                            .get_mut_widget_state(self.current_widget.widget_id)
+                                        {
+                                            if net_table.table.try_select_location(x, y).is_some() {
+                                                net_table.force_data_update();
+                                            }
+                                        }
+                                    }
                                     _ => (),
                                 }
                             }
@@ -0,0 +1,483 @@
+//! User-defined alerts, which fire when a metric crosses a threshold for long enough.
+
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use super::data::{SensorValue, StoredData, TypedTemperature};
+use crate::{
+    collection::cpu::CpuDataType, utils::data_units::get_unit_prefix, widgets::query::ProcessQuery,
+};
+
+/// What an alert watches.
+#[derive(Debug)]
+pub enum AlertMetric {
+    /// The average usage of all CPUs, as a percentage.
+    CpuAverage,
+
+    /// The usage of a single core, as a percentage.
+    Core(usize),
+
+    /// Used RAM, as a percentage.
+    MemPercent,
+
+    /// Used swap, as a percentage.
+    SwapPercent,
+
+    /// The reading of a sensor with the given name, or the hottest temperature sensor if none
+    /// is given.
+    Sensor(Option<String>),
+
+    /// The used space of a disk with the given mount point or name, as a percentage. If none is
+    /// given, the fullest disk is used.
+    DiskUsedPercent(Option<String>),
+
+    /// The receive rate of an interface, or of all interfaces, in bits per second.
+    NetRx(Option<String>),
+
+    /// The transmit rate of an interface, or of all interfaces, in bits per second.
+    NetTx(Option<String>),
+
+    /// The number of processes that match a search query.
+    ProcessCount {
+        query: ProcessQuery,
+        is_using_command: bool,
+    },
+}
+
+impl AlertMetric {
+    /// The name of the metric, as written in the config file.
+    pub fn config_name(&self) -> &'static str {
+        match self {
+            AlertMetric::CpuAverage => "cpu",
+            AlertMetric::Core(_) => "core",
+            AlertMetric::MemPercent => "mem",
+            AlertMetric::SwapPercent => "swap",
+            AlertMetric::Sensor(_) => "temp",
+            AlertMetric::DiskUsedPercent(_) => "disk",
+            AlertMetric::NetRx(_) => "rx",
+            AlertMetric::NetTx(_) => "tx",
+            AlertMetric::ProcessCount { .. } => "processes",
+        }
+    }
+
+    /// Returns the current value of the metric, or [`None`] if it isn't available.
+    fn value(&self, data: &StoredData) -> Option<f64> {
+        match self {
+            AlertMetric::CpuAverage => {
+                let avg = data
+                    .cpu_harvest
+                    .iter()
+                    .find(|cpu| matches!(cpu.data_type, CpuDataType::Avg));
+
+                match avg {
+                    Some(avg) => Some(avg.usage as f64),
+                    None => {
+                        let cores = data
+                            .cpu_harvest
+                            .iter()
+                            .filter(|cpu| matches!(cpu.data_type, CpuDataType::Cpu(_)))
+                            .map(|cpu| cpu.usage as f64)
+                            .collect::<Vec<_>>();
+
+                        (!cores.is_empty()).then(|| cores.iter().sum::<f64>() / cores.len() as f64)
+                    }
+                }
+            }
+            AlertMetric::Core(index) => data
+                .cpu_harvest
+                .iter()
+                .find(|cpu| matches!(cpu.data_type, CpuDataType::Cpu(i) if i == *index))
+                .map(|cpu| cpu.usage as f64),
+            AlertMetric::MemPercent => data.ram_harvest.as_ref().map(|mem| mem.percentage()),
+            AlertMetric::SwapPercent => data.swap_harvest.as_ref().map(|swap| swap.percentage()),
+            AlertMetric::Sensor(sensor) => data
+                .temp_data
+                .iter()
+                .filter(|temp| match sensor {
+                    Some(sensor) => temp.sensor.eq_ignore_ascii_case(sensor),
+                    None => matches!(temp.value, Some(SensorValue::Temperature(_))),
+                })
+                .filter_map(|temp| temp.value.as_ref().map(sensor_reading))
+                .reduce(f64::max),
+            AlertMetric::DiskUsedPercent(disk) => data
+                .disk_harvest
+                .iter()
+                .filter(|entry| match disk {
+                    Some(disk) => entry.mount_point == *disk || entry.name == *disk,
+                    None => true,
+                })
+                .filter_map(|entry| entry.used_percent())
+                .reduce(f64::max),
+            AlertMetric::NetRx(interface) => match interface {
+                Some(interface) => data
+                    .network_harvest
+                    .interfaces
+                    .iter()
+                    .find(|harvest| harvest.name == *interface)
+                    .map(|harvest| harvest.rx as f64),
+                None => Some(data.network_harvest.rx as f64),
+            },
+            AlertMetric::NetTx(interface) => match interface {
+                Some(interface) => data
+                    .network_harvest
+                    .interfaces
+                    .iter()
+                    .find(|harvest| harvest.name == *interface)
+                    .map(|harvest| harvest.tx as f64),
+                None => Some(data.network_harvest.tx as f64),
+            },
+            AlertMetric::ProcessCount {
+                query,
+                is_using_command,
+            } => Some(
+                data.process_data
+                    .process_harvest
+                    .values()
+                    .filter(|process| query.check(process, *is_using_command))
+                    .count() as f64,
+            ),
+        }
+    }
+
+    /// Formats a value of this metric for display.
+    fn format_value(&self, value: f64) -> String {
+        match self {
+            AlertMetric::NetRx(_) | AlertMetric::NetTx(_) => {
+                let (value, prefix) = get_unit_prefix(value as u64, false);
+                format!("{value:.1}{prefix}b/s")
+            }
+            AlertMetric::ProcessCount { .. } => format!("{value:.0}"),
+            AlertMetric::Sensor(_) => format!("{value:.1}"),
+            _ => format!("{value:.1}%"),
+        }
+    }
+}
+
+/// Returns the numeric value of a sensor reading, in the unit it is displayed in.
+fn sensor_reading(value: &SensorValue) -> f64 {
+    match value {
+        SensorValue::Temperature(
+            TypedTemperature::Celsius(val)
+            | TypedTemperature::Kelvin(val)
+            | TypedTemperature::Fahrenheit(val),
+        ) => *val as f64,
+        SensorValue::Fan(rpm) => *rpm as f64,
+        SensorValue::Voltage(val) | SensorValue::Power(val) | SensorValue::Current(val) => {
+            *val as f64
+        }
+    }
+}
+
+/// How a metric is compared against an alert's threshold.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AlertComparison {
+    #[default]
+    Greater,
+    GreaterOrEqual,
+    Less,
+    LessOrEqual,
+    Equal,
+}
+
+impl AlertComparison {
+    /// Whether `value` is on the alerting side of `threshold`.
+    fn holds(self, value: f64, threshold: f64) -> bool {
+        match self {
+            AlertComparison::Greater => value > threshold,
+            AlertComparison::GreaterOrEqual => value >= threshold,
+            AlertComparison::Less => value < threshold,
+            AlertComparison::LessOrEqual => value <= threshold,
+            AlertComparison::Equal => (value - threshold).abs() < f64::EPSILON,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            AlertComparison::Greater => ">",
+            AlertComparison::GreaterOrEqual => ">=",
+            AlertComparison::Less => "<",
+            AlertComparison::LessOrEqual => "<=",
+            AlertComparison::Equal => "=",
+        }
+    }
+}
+
+impl FromStr for AlertComparison {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            ">" => Ok(AlertComparison::Greater),
+            ">=" => Ok(AlertComparison::GreaterOrEqual),
+            "<" => Ok(AlertComparison::Less),
+            "<=" => Ok(AlertComparison::LessOrEqual),
+            "=" | "==" => Ok(AlertComparison::Equal),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A single alert rule.
+#[derive(Debug)]
+pub struct AlertRule {
+    pub name: String,
+    pub metric: AlertMetric,
+    pub target: Option<String>,
+    pub comparison: AlertComparison,
+    pub threshold: f64,
+
+    /// How long the comparison must hold before the alert fires.
+    pub duration: Duration,
+
+    /// Whether to ring the terminal bell when the alert fires.
+    pub bell: bool,
+
+    /// A shell command to run when the alert fires.
+    pub command: Option<String>,
+}
+
+impl AlertRule {
+    /// A description of the rule, used if it wasn't given a name.
+    pub fn describe(
+        metric: &str, target: Option<&str>, comparison: AlertComparison, threshold: &str,
+    ) -> String {
+        match target {
+            Some(target) => format!("{metric}({target}) {} {threshold}", comparison.as_str()),
+            None => format!("{metric} {} {threshold}", comparison.as_str()),
+        }
+    }
+
+    /// Rings the bell and runs the command of a rule that just fired, if configured.
+    pub fn notify(&self, value: f64) {
+        if self.bell {
+            let mut stdout = std::io::stdout();
+            let _ = stdout.write_all(b"\x07");
+            let _ = stdout.flush();
+        }
+
+        if let Some(command) = &self.command {
+            #[cfg(target_family = "windows")]
+            let mut cmd = {
+                let mut cmd = Command::new("cmd");
+                cmd.arg("/C").arg(command);
+                cmd
+            };
+            #[cfg(not(target_family = "windows"))]
+            let mut cmd = {
+                let mut cmd = Command::new("sh");
+                cmd.arg("-c").arg(command);
+                cmd
+            };
+
+            let spawned = cmd
+                .env("BTM_ALERT_NAME", &self.name)
+                .env("BTM_ALERT_METRIC", self.metric.config_name())
+                .env(
+                    "BTM_ALERT_TARGET",
+                    self.target.as_deref().unwrap_or_default(),
+                )
+                .env("BTM_ALERT_VALUE", value.to_string())
+                .env("BTM_ALERT_COMPARISON", self.comparison.as_str())
+                .env("BTM_ALERT_THRESHOLD", self.threshold.to_string())
+                .stdin(Stdio::null())
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .spawn();
+
+            // Reap the command in the background so it doesn't linger as a zombie.
+            if let Ok(mut child) = spawned {
+                std::thread::spawn(move || child.wait());
+            }
+        }
+    }
+}
+
+/// The evaluation state of a rule.
+#[derive(Debug, Default)]
+struct AlertState {
+    /// When the comparison started holding, if it currently holds.
+    breached_since: Option<Instant>,
+
+    /// Whether the alert is firing.
+    firing: bool,
+
+    /// The latest value of the metric.
+    value: f64,
+}
+
+/// All configured alert rules and their state.
+#[derive(Debug, Default)]
+pub struct Alerts {
+    rules: Vec<AlertRule>,
+    states: Vec<AlertState>,
+}
+
+impl Alerts {
+    pub fn new(rules: Vec<AlertRule>) -> Self {
+        let states = rules.iter().map(|_| AlertState::default()).collect();
+
+        Self { rules, states }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Evaluates every rule against the latest data, returning the rules that just started
+    /// firing along with the value that triggered them.
+    pub fn update(&mut self, data: &StoredData) -> Vec<(&AlertRule, f64)> {
+        let now = data.last_update_time;
+        let mut fired = vec![];
+
+        for (rule, state) in self.rules.iter().zip(self.states.iter_mut()) {
+            let value = rule.metric.value(data);
+
+            match value {
+                Some(value) if rule.comparison.holds(value, rule.threshold) => {
+                    state.value = value;
+                    let since = *state.breached_since.get_or_insert(now);
+
+                    if !state.firing && now.saturating_duration_since(since) >= rule.duration {
+                        state.firing = true;
+                        fired.push((rule, value));
+                    }
+                }
+                _ => {
+                    state.breached_since = None;
+                    state.firing = false;
+                }
+            }
+        }
+
+        fired
+    }
+
+    /// Returns a description of each firing alert and its current value.
+    pub fn firing(&self) -> impl Iterator<Item = String> + '_ {
+        self.rules
+            .iter()
+            .zip(self.states.iter())
+            .filter(|(_, state)| state.firing)
+            .map(|(rule, state)| {
+                format!("{} ({})", rule.name, rule.metric.format_value(state.value))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collection::cpu::CpuData;
+
+    fn rule(metric: AlertMetric, comparison: AlertComparison, threshold: f64) -> AlertRule {
+        AlertRule {
+            name: "test".to_string(),
+            metric,
+            target: None,
+            comparison,
+            threshold,
+            duration: Duration::ZERO,
+            bell: false,
+            command: None,
+        }
+    }
+
+    fn cpu_data(usage: &[f32]) -> StoredData {
+        StoredData {
+            cpu_harvest: usage
+                .iter()
+                .enumerate()
+                .map(|(i, usage)| CpuData {
+                    data_type: CpuDataType::Cpu(i),
+                    usage: *usage,
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn comparisons() {
+        assert!(AlertComparison::Greater.holds(2.0, 1.0));
+        assert!(!AlertComparison::Greater.holds(1.0, 1.0));
+        assert!(AlertComparison::GreaterOrEqual.holds(1.0, 1.0));
+        assert!(AlertComparison::Less.holds(0.5, 1.0));
+        assert!(!AlertComparison::LessOrEqual.holds(1.5, 1.0));
+        assert!(AlertComparison::Equal.holds(1.0, 1.0));
+
+        assert_eq!(">=".parse(), Ok(AlertComparison::GreaterOrEqual));
+        assert_eq!(" < ".parse(), Ok(AlertComparison::Less));
+        assert!("=>".parse::<AlertComparison>().is_err());
+    }
+
+    #[test]
+    fn cpu_values() {
+        let data = cpu_data(&[20.0, 60.0]);
+
+        assert_eq!(AlertMetric::CpuAverage.value(&data), Some(40.0));
+        assert_eq!(AlertMetric::Core(1).value(&data), Some(60.0));
+        assert_eq!(AlertMetric::Core(2).value(&data), None);
+        assert_eq!(AlertMetric::MemPercent.value(&data), None);
+    }
+
+    #[test]
+    fn fires_immediately_without_duration() {
+        let mut alerts = Alerts::new(vec![rule(
+            AlertMetric::Core(0),
+            AlertComparison::Greater,
+            50.0,
+        )]);
+
+        assert!(alerts.update(&cpu_data(&[40.0])).is_empty());
+        assert_eq!(alerts.firing().count(), 0);
+
+        let fired = alerts.update(&cpu_data(&[60.0]));
+        assert_eq!(fired.len(), 1);
+        assert_eq!(fired[0].1, 60.0);
+        assert_eq!(alerts.firing().collect::<Vec<_>>(), vec!["test (60.0%)"]);
+
+        // Only notify once while it stays firing.
+        assert!(alerts.update(&cpu_data(&[70.0])).is_empty());
+        assert_eq!(alerts.firing().count(), 1);
+
+        assert!(alerts.update(&cpu_data(&[30.0])).is_empty());
+        assert_eq!(alerts.firing().count(), 0);
+    }
+
+    #[test]
+    fn waits_for_duration() {
+        let mut alert = rule(AlertMetric::CpuAverage, AlertComparison::Greater, 50.0);
+        alert.duration = Duration::from_secs(10);
+        let mut alerts = Alerts::new(vec![alert]);
+
+        let start = Instant::now();
+        let at = |secs: u64, usage: f32| StoredData {
+            last_update_time: start + Duration::from_secs(secs),
+            ..cpu_data(&[usage])
+        };
+
+        assert!(alerts.update(&at(0, 90.0)).is_empty());
+        assert!(alerts.update(&at(5, 90.0)).is_empty());
+
+        // Dropping below the threshold resets the timer.
+        assert!(alerts.update(&at(6, 10.0)).is_empty());
+        assert!(alerts.update(&at(7, 90.0)).is_empty());
+        assert!(alerts.update(&at(16, 90.0)).is_empty());
+        assert_eq!(alerts.update(&at(17, 90.0)).len(), 1);
+    }
+
+    #[test]
+    fn missing_values_do_not_fire() {
+        let mut alerts = Alerts::new(vec![rule(
+            AlertMetric::SwapPercent,
+            AlertComparison::Less,
+            10.0,
+        )]);
+
+        assert!(alerts.update(&StoredData::default()).is_empty());
+    }
+}
@@ -0,0 +1,152 @@
+//! Tracking how the used space of each mount changes over time, to estimate when it'll fill up.
+
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+/// The used space of a mount over a window of time.
+#[derive(Clone, Debug, Default)]
+pub struct DiskUsageHistory {
+    samples: VecDeque<(Instant, u64)>,
+}
+
+impl DiskUsageHistory {
+    /// How much time the samples need to span before a trend is reported, so that a
+    /// couple of noisy samples don't result in a wild estimate.
+    const MIN_SPAN: Duration = Duration::from_secs(60);
+
+    /// Adds a sample, and drops any that are older than `window` relative to it.
+    pub fn push(&mut self, time: Instant, used_bytes: u64, window: Duration) {
+        self.samples.push_back((time, used_bytes));
+
+        while let Some((oldest, _)) = self.samples.front() {
+            if time.saturating_duration_since(*oldest) > window {
+                self.samples.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// The rate at which used space is growing, in bytes per second, using a least-squares
+    /// fit over the samples. Negative if space is being freed.
+    pub fn growth_rate(&self) -> Option<f64> {
+        let (first, _) = self.samples.front()?;
+        let (last, _) = self.samples.back()?;
+        if last.saturating_duration_since(*first) < Self::MIN_SPAN {
+            return None;
+        }
+
+        let points = self
+            .samples
+            .iter()
+            .map(|(time, used)| {
+                (
+                    time.saturating_duration_since(*first).as_secs_f64(),
+                    *used as f64,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let count = points.len() as f64;
+        let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / count;
+        let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / count;
+
+        let (covariance, variance) =
+            points
+                .iter()
+                .fold((0.0, 0.0), |(covariance, variance), (x, y)| {
+                    let dx = x - mean_x;
+                    (covariance + dx * (y - mean_y), variance + dx * dx)
+                });
+
+        if variance > 0.0 {
+            Some(covariance / variance)
+        } else {
+            None
+        }
+    }
+
+    /// Estimates how long until `remaining_bytes` more are used up at the current rate.
+    /// Returns [`None`] if usage isn't growing, or there isn't enough history yet.
+    pub fn time_to_full(&self, remaining_bytes: u64) -> Option<Duration> {
+        let rate = self.growth_rate()?;
+        if rate <= 0.0 {
+            return None;
+        }
+
+        Duration::try_from_secs_f64(remaining_bytes as f64 / rate).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: Duration = Duration::from_secs(600);
+
+    /// Builds a history from `(seconds, used bytes)` samples.
+    fn history(samples: &[(u64, u64)]) -> DiskUsageHistory {
+        let start = Instant::now();
+        let mut history = DiskUsageHistory::default();
+        for (secs, used) in samples {
+            history.push(start + Duration::from_secs(*secs), *used, WINDOW);
+        }
+
+        history
+    }
+
+    #[test]
+    fn test_steady_growth() {
+        // Growing by 1000 bytes a second.
+        let samples = (0..=120)
+            .map(|secs| (secs, 50_000 + secs * 1000))
+            .collect::<Vec<_>>();
+        let history = history(&samples);
+
+        let rate = history.growth_rate().unwrap();
+        assert!((rate - 1000.0).abs() < 1e-6);
+        assert_eq!(
+            history.time_to_full(3_600_000),
+            Some(Duration::from_secs(3600))
+        );
+    }
+
+    #[test]
+    fn test_noisy_growth() {
+        // Alternating above and below a line growing by 10 bytes a second.
+        let samples = (0..=100)
+            .map(|secs| {
+                let noise = if secs % 2 == 0 { 50 } else { 0 };
+                (secs, 1000 + secs * 10 + noise)
+            })
+            .collect::<Vec<_>>();
+
+        let rate = history(&samples).growth_rate().unwrap();
+        assert!((rate - 10.0).abs() < 0.1, "rate was {rate}");
+    }
+
+    #[test]
+    fn test_no_estimate() {
+        // Not enough history yet.
+        assert_eq!(history(&[]).time_to_full(1000), None);
+        assert_eq!(history(&[(0, 0), (30, 1000)]).time_to_full(1000), None);
+
+        // Shrinking or unchanged usage never fills up.
+        assert_eq!(history(&[(0, 2000), (60, 1000)]).time_to_full(1000), None);
+        assert_eq!(history(&[(0, 1000), (60, 1000)]).time_to_full(1000), None);
+    }
+
+    #[test]
+    fn test_window() {
+        // A burst of writes that's since stopped falls out of the window.
+        let mut samples = vec![(0, 0), (60, 1_000_000)];
+        samples.extend((700..=800).step_by(10).map(|secs| (secs, 1_000_000)));
+        let history = history(&samples);
+
+        assert_eq!(history.samples.len(), 11);
+        assert_eq!(history.growth_rate(), Some(0.0));
+        assert_eq!(history.time_to_full(1000), None);
+    }
+}
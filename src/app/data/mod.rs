@@ -1,8 +1,13 @@
 //! How we manage data internally.
 
 mod time_series;
+#[cfg(feature = "gpu")]
+pub use time_series::GpuSeries;
 pub use time_series::{TimeSeriesData, Values};
 
+mod disk_trend;
+pub use disk_trend::DiskUsageHistory;
+
 mod process;
 pub use process::ProcessData;
 
@@ -3,9 +3,24 @@ use std::{
     vec::Vec,
 };
 
-use super::{ProcessData, TimeSeriesData};
+use rustc_hash::FxHashMap as HashMap;
+#[cfg(all(feature = "zfs", target_os = "linux"))]
+use rustc_hash::FxHashSet as HashSet;
+
+use super::{DiskUsageHistory, ProcessData, SensorValue, TempLevel, TimeSeriesData};
 #[cfg(feature = "battery")]
 use crate::collection::batteries;
+#[cfg(feature = "gpu")]
+use crate::collection::gpu;
+#[cfg(all(feature = "zfs", target_os = "linux"))]
+use crate::collection::memory::arc::ArcDetail;
+#[cfg(target_os = "linux")]
+use crate::collection::memory::{
+    CompressedMemData,
+    vmstat::{OomVictim, VmStatHarvest},
+};
+#[cfg(target_os = "linux")]
+use crate::collection::network::tcp::TcpHarvest;
 use crate::{
     app::AppConfigFields,
     collection::{Data, cpu, disks, memory::MemData, network},
@@ -13,6 +28,31 @@ use crate::{
     widgets::{DiskWidgetData, TempWidgetData},
 };
 
+/// The most recent OOM kill(s), used to show a warning.
+#[cfg(target_os = "linux")]
+#[derive(Debug, Clone)]
+pub struct OomEvent {
+    /// When the OOM kill(s) were noticed.
+    pub time: Instant,
+
+    /// How many processes were killed.
+    pub kills: u64,
+
+    /// The latest victim, if known.
+    pub victim: Option<OomVictim>,
+}
+
+/// RAPL zones whose energy counters couldn't be read, used to show a warning.
+#[cfg(target_os = "linux")]
+#[derive(Debug, Clone)]
+pub struct RaplWarning {
+    /// When the zones were found.
+    pub time: Instant,
+
+    /// The names of the zones (e.g. `package-0`).
+    pub zones: Vec<String>,
+}
+
 /// A collection of data. This is where we dump data into.
 ///
 /// TODO: Maybe reduce visibility of internal data, make it only accessible through DataStore?
@@ -27,14 +67,50 @@ pub struct StoredData {
     pub cache_harvest: Option<MemData>,
     #[cfg(feature = "zfs")]
     pub arc_harvest: Option<MemData>,
+    #[cfg(all(feature = "zfs", target_os = "linux"))]
+    pub arc_detail_harvest: Option<ArcDetail>,
+    #[cfg(all(feature = "zfs", target_os = "linux"))]
+    pub zpool_harvest: Vec<disks::ZpoolHarvest>,
+    #[cfg(all(feature = "zfs", target_os = "linux"))]
+    pub prev_zpool_io: HashMap<String, (u64, u64)>,
+    #[cfg(all(feature = "zfs", target_os = "linux"))]
+    pub prev_dataset_io: HashMap<String, (u64, u64)>,
+    #[cfg(target_os = "linux")]
+    pub zram_harvest: Option<CompressedMemData>,
+    #[cfg(target_os = "linux")]
+    pub zswap_harvest: Option<CompressedMemData>,
+    #[cfg(target_os = "linux")]
+    pub vmstat_harvest: Option<VmStatHarvest>,
+    #[cfg(target_os = "linux")]
+    pub last_oom_event: Option<OomEvent>,
+    #[cfg(target_os = "linux")]
+    pub tcp_harvest: Option<TcpHarvest>,
+    #[cfg(target_os = "linux")]
+    pub cpu_power_harvest: Vec<cpu::CpuPower>,
+    #[cfg(target_os = "linux")]
+    pub rapl_warning: Option<RaplWarning>,
+    #[cfg(target_os = "linux")]
+    pub numa_mem_harvest: Vec<(usize, MemData)>,
     #[cfg(feature = "gpu")]
     pub gpu_harvest: Vec<(String, MemData)>,
+    #[cfg(feature = "gpu")]
+    pub gpu_stats_harvest: Vec<gpu::GpuStats>,
     pub cpu_harvest: cpu::CpuHarvest,
     pub load_avg_harvest: cpu::LoadAvgHarvest,
     pub process_data: ProcessData,
     /// TODO: (points_rework_v1) Might be a better way to do this without having to store here?
-    pub prev_io: Vec<(u64, u64)>,
+    pub prev_io: Vec<(u64, u64, Option<disks::IoDetails>)>,
     pub disk_harvest: Vec<DiskWidgetData>,
+    /// The used space of each mount over time, keyed by mount point.
+    pub disk_usage_history: HashMap<String, DiskUsageHistory>,
+    #[cfg(target_os = "linux")]
+    pub block_topology: disks::BlockTopology,
+    /// The read and write rates of every block device, including unmounted ones,
+    /// keyed by kernel name.
+    #[cfg(target_os = "linux")]
+    pub block_io_rates: HashMap<String, (u64, u64)>,
+    #[cfg(target_os = "linux")]
+    pub prev_block_io: HashMap<String, (u64, u64)>,
     pub temp_data: Vec<TempWidgetData>,
     #[cfg(feature = "battery")]
     pub battery_harvest: Vec<batteries::BatteryData>,
@@ -55,13 +131,46 @@ impl Default for StoredData {
             process_data: Default::default(),
             prev_io: Vec::default(),
             disk_harvest: Vec::default(),
+            disk_usage_history: HashMap::default(),
+            #[cfg(target_os = "linux")]
+            block_topology: disks::BlockTopology::default(),
+            #[cfg(target_os = "linux")]
+            block_io_rates: HashMap::default(),
+            #[cfg(target_os = "linux")]
+            prev_block_io: HashMap::default(),
             temp_data: Vec::default(),
             #[cfg(feature = "battery")]
             battery_harvest: Vec::default(),
             #[cfg(feature = "zfs")]
             arc_harvest: None,
+            #[cfg(all(feature = "zfs", target_os = "linux"))]
+            arc_detail_harvest: None,
+            #[cfg(all(feature = "zfs", target_os = "linux"))]
+            zpool_harvest: Vec::default(),
+            #[cfg(all(feature = "zfs", target_os = "linux"))]
+            prev_zpool_io: HashMap::default(),
+            #[cfg(all(feature = "zfs", target_os = "linux"))]
+            prev_dataset_io: HashMap::default(),
+            #[cfg(target_os = "linux")]
+            zram_harvest: None,
+            #[cfg(target_os = "linux")]
+            zswap_harvest: None,
+            #[cfg(target_os = "linux")]
+            vmstat_harvest: None,
+            #[cfg(target_os = "linux")]
+            last_oom_event: None,
+            #[cfg(target_os = "linux")]
+            tcp_harvest: None,
+            #[cfg(target_os = "linux")]
+            cpu_power_harvest: Vec::default(),
+            #[cfg(target_os = "linux")]
+            rapl_warning: None,
+            #[cfg(target_os = "linux")]
+            numa_mem_harvest: Vec::default(),
             #[cfg(feature = "gpu")]
             gpu_harvest: Vec::default(),
+            #[cfg(feature = "gpu")]
+            gpu_stats_harvest: Vec::default(),
         }
     }
 }
@@ -83,11 +192,38 @@ impl StoredData {
             if let Some(network) = &mut data.network {
                 network.rx /= 8;
                 network.tx /= 8;
+
+                for interface in &mut network.interfaces {
+                    interface.rx /= 8;
+                    interface.tx /= 8;
+                }
             }
         }
 
+        // Disk I/O rates are only known once the disk data is processed, so do that first.
+        let disks_updated = match (data.disks.take(), data.io.take()) {
+            (Some(disks), Some(io)) => {
+                self.eat_disks(
+                    disks,
+                    io,
+                    harvested_time,
+                    Duration::from_millis(settings.disk_trend_window_ms),
+                );
+
+                #[cfg(all(feature = "zfs", target_os = "linux"))]
+                if let Some(zpools) = data.zpools.take() {
+                    self.eat_zpools(zpools, harvested_time);
+                }
+
+                true
+            }
+            _ => false,
+        };
+
         if !settings.use_basic_mode {
             self.timeseries_data.add(&data);
+            self.timeseries_data
+                .add_disk_io(disks_updated.then_some(self.disk_harvest.as_slice()));
         }
 
         if let Some(network) = data.network {
@@ -107,9 +243,46 @@ impl StoredData {
             self.arc_harvest = data.arc;
         }
 
+        #[cfg(all(feature = "zfs", target_os = "linux"))]
+        {
+            self.arc_detail_harvest = data.arc_detail;
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            self.zram_harvest = data.zram;
+            self.zswap_harvest = data.zswap;
+
+            if let Some(vmstat) = &data.vmstat {
+                if vmstat.new_oom_kills > 0 {
+                    self.last_oom_event = Some(OomEvent {
+                        time: harvested_time,
+                        kills: vmstat.new_oom_kills,
+                        victim: vmstat.oom_victim.clone(),
+                    });
+                }
+            }
+            self.vmstat_harvest = data.vmstat;
+            self.tcp_harvest = data.tcp;
+            self.cpu_power_harvest = data.cpu_power.unwrap_or_default();
+            if let Some(zones) = data.rapl_unreadable {
+                self.rapl_warning = Some(RaplWarning {
+                    time: harvested_time,
+                    zones,
+                });
+            }
+            if let Some(block_topology) = data.block_topology {
+                self.block_topology = block_topology;
+            }
+            self.numa_mem_harvest = data.numa_memory.unwrap_or_default();
+        }
+
         #[cfg(feature = "gpu")]
-        if let Some(gpu) = data.gpu {
-            self.gpu_harvest = gpu;
+        {
+            if let Some(gpu) = data.gpu {
+                self.gpu_harvest = gpu;
+            }
+            self.gpu_stats_harvest = data.gpu_stats.unwrap_or_default();
         }
 
         if let Some(cpu) = data.cpu {
@@ -125,22 +298,30 @@ impl StoredData {
             .map(|sensors| {
                 sensors
                     .into_iter()
-                    .map(|temp| TempWidgetData {
-                        sensor: temp.name,
-                        temperature: temp
-                            .temperature
-                            .map(|c| settings.temperature_type.convert_temp_unit(c)),
+                    .map(|sensor| {
+                        let convert =
+                            |value| SensorValue::new(sensor.kind, value, settings.temperature_type);
+
+                        TempWidgetData {
+                            level: TempLevel::new(
+                                sensor.kind,
+                                sensor.value,
+                                sensor.max,
+                                sensor.crit,
+                                sensor.crit_alarm,
+                            ),
+                            kind: sensor.kind,
+                            value: sensor.value.map(convert),
+                            max: sensor.max.map(convert),
+                            crit: sensor.crit.map(convert),
+                            crit_alarm: sensor.crit_alarm,
+                            sensor: sensor.name,
+                        }
                     })
                     .collect()
             })
             .unwrap_or_default();
 
-        if let Some(disks) = data.disks {
-            if let Some(io) = data.io {
-                self.eat_disks(disks, io, harvested_time);
-            }
-        }
-
         if let Some(list_of_processes) = data.list_of_processes {
             self.process_data.ingest(list_of_processes);
         }
@@ -156,8 +337,128 @@ impl StoredData {
         self.last_update_time = harvested_time;
     }
 
+    /// Calculates the I/O rates of all block devices, rather than just the mounted ones.
+    #[cfg(target_os = "linux")]
+    fn eat_block_io(&mut self, io: &disks::IoHarvest, time_since_last_harvest: f64) {
+        self.block_io_rates.clear();
+
+        for (name, io_data) in io {
+            let Some(io_data) = io_data else {
+                continue;
+            };
+
+            if let Some((prev_read, prev_write)) = self.prev_block_io.get(name) {
+                self.block_io_rates.insert(
+                    name.clone(),
+                    (
+                        (io_data.read_bytes.saturating_sub(*prev_read) as f64
+                            / time_since_last_harvest)
+                            .round() as u64,
+                        (io_data.write_bytes.saturating_sub(*prev_write) as f64
+                            / time_since_last_harvest)
+                            .round() as u64,
+                    ),
+                );
+            }
+        }
+
+        self.prev_block_io = io
+            .iter()
+            .filter_map(|(name, io_data)| {
+                io_data
+                    .as_ref()
+                    .map(|io_data| (name.clone(), (io_data.read_bytes, io_data.write_bytes)))
+            })
+            .collect();
+    }
+
+    /// Adds a row for each ZFS pool to the disk data, showing its state and the
+    /// combined I/O of its datasets, followed by a row for each of its datasets.
+    ///
+    /// Mounted datasets already have a row with the same counters, so only the
+    /// other datasets (such as unmounted ones or zvols) get a new row.
+    #[cfg(all(feature = "zfs", target_os = "linux"))]
+    fn eat_zpools(&mut self, zpools: Vec<disks::ZpoolHarvest>, harvested_time: Instant) {
+        let time_since_last_harvest = harvested_time
+            .duration_since(self.last_update_time)
+            .as_secs_f64();
+
+        // Rates are only known once there is a previous reading to compare against.
+        let rates = |curr: (u64, u64), prev: Option<&(u64, u64)>| match prev {
+            Some((prev_read, prev_write)) => {
+                let rate = |curr: u64, prev: u64| {
+                    (curr.saturating_sub(prev) as f64 / time_since_last_harvest).round() as u64
+                };
+
+                (
+                    Some(rate(curr.0, *prev_read)),
+                    Some(rate(curr.1, *prev_write)),
+                )
+            }
+            None => (None, None),
+        };
+        let row = |name: &str, fs_type: &str, (read, write), pool_state| DiskWidgetData {
+            name: name.to_string(),
+            mount_point: String::default(),
+            free_bytes: None,
+            used_bytes: None,
+            total_bytes: None,
+            summed_total_bytes: None,
+            io_read_rate_bytes: read,
+            io_write_rate_bytes: write,
+            io_detail_rates: None,
+            fs_type: Some(fs_type.to_string()),
+            mount_options: None,
+            inodes_free: None,
+            inodes_used: None,
+            inodes_total: None,
+            btrfs: None,
+            eta_full: None,
+            tree_name: None,
+            pool_state,
+        };
+
+        let existing = self
+            .disk_harvest
+            .iter()
+            .map(|disk| disk.name.clone())
+            .collect::<HashSet<_>>();
+        let mut prev_zpool_io = HashMap::default();
+        let mut prev_dataset_io = HashMap::default();
+
+        for zpool in &zpools {
+            let io = (zpool.read_bytes, zpool.write_bytes);
+            let pool_rates = rates(io, self.prev_zpool_io.get(&zpool.name));
+            prev_zpool_io.insert(zpool.name.clone(), io);
+
+            self.disk_harvest.push(row(
+                &zpool.name,
+                "zpool",
+                pool_rates,
+                Some(zpool.state.clone()),
+            ));
+
+            for dataset in &zpool.datasets {
+                let io = (dataset.read_bytes, dataset.write_bytes);
+                let dataset_rates = rates(io, self.prev_dataset_io.get(&dataset.dataset_name));
+                prev_dataset_io.insert(dataset.dataset_name.clone(), io);
+
+                // The root dataset shares its pool's name, and its I/O is already in the pool's.
+                if dataset.dataset_name != zpool.name && !existing.contains(&dataset.dataset_name) {
+                    self.disk_harvest
+                        .push(row(&dataset.dataset_name, "zfs", dataset_rates, None));
+                }
+            }
+        }
+
+        self.prev_zpool_io = prev_zpool_io;
+        self.prev_dataset_io = prev_dataset_io;
+        self.zpool_harvest = zpools;
+    }
+
     fn eat_disks(
         &mut self, disks: Vec<disks::DiskHarvest>, io: disks::IoHarvest, harvested_time: Instant,
+        trend_window: Duration,
     ) {
         let time_since_last_harvest = harvested_time
             .duration_since(self.last_update_time)
@@ -165,9 +466,12 @@ impl StoredData {
 
         self.disk_harvest.clear();
 
+        #[cfg(target_os = "linux")]
+        self.eat_block_io(&io, time_since_last_harvest);
+
         let prev_io_diff = disks.len().saturating_sub(self.prev_io.len());
         self.prev_io.reserve(prev_io_diff);
-        self.prev_io.extend((0..prev_io_diff).map(|_| (0, 0)));
+        self.prev_io.extend((0..prev_io_diff).map(|_| (0, 0, None)));
 
         for (itx, device) in disks.into_iter().enumerate() {
             let Some(checked_name) = ({
@@ -228,6 +532,7 @@ impl StoredData {
             };
 
             let (mut io_read_rate_bytes, mut io_write_rate_bytes) = (None, None);
+            let mut io_detail_rates = None;
             if let Some(Some(io_device)) = io_device {
                 if let Some(prev_io) = self.prev_io.get_mut(itx) {
                     io_read_rate_bytes = Some(
@@ -242,7 +547,16 @@ impl StoredData {
                             .round() as u64,
                     );
 
-                    *prev_io = (io_device.read_bytes, io_device.write_bytes);
+                    if let (Some(details), Some(prev_details)) = (&io_device.details, &prev_io.2) {
+                        io_detail_rates =
+                            Some(details.rates(prev_details, time_since_last_harvest));
+                    }
+
+                    *prev_io = (
+                        io_device.read_bytes,
+                        io_device.write_bytes,
+                        io_device.details,
+                    );
                 }
             }
 
@@ -260,8 +574,36 @@ impl StoredData {
                 summed_total_bytes,
                 io_read_rate_bytes,
                 io_write_rate_bytes,
+                io_detail_rates,
+                fs_type: device.fs_type,
+                mount_options: device.mount_options,
+                inodes_free: device.inodes_free,
+                inodes_used: device.inodes_used,
+                inodes_total: device.inodes_total,
+                btrfs: device.btrfs,
+                eta_full: None,
+                tree_name: None,
+                pool_state: None,
             });
         }
+
+        // Track the used space of each mount to estimate when it'll fill up.
+        self.disk_usage_history.retain(|mount_point, _| {
+            self.disk_harvest
+                .iter()
+                .any(|disk| &disk.mount_point == mount_point)
+        });
+
+        for disk in &mut self.disk_harvest {
+            if let (Some(used), Some(total)) = (disk.used_bytes, disk.total_bytes) {
+                let history = self
+                    .disk_usage_history
+                    .entry(disk.mount_point.clone())
+                    .or_default();
+                history.push(harvested_time, used, trend_window);
+                disk.eta_full = history.time_to_full(total.saturating_sub(used));
+            }
+        }
     }
 }
 
@@ -306,6 +648,11 @@ impl DataStore {
         }
     }
 
+    /// Return a reference to the most recently collected data, even if frozen.
+    pub fn latest_data(&self) -> &StoredData {
+        &self.main
+    }
+
     /// Eat data.
     pub fn eat_data(&mut self, data: Box<Data>, settings: &AppConfigFields) {
         self.main.eat_data(data, settings);
@@ -322,3 +669,67 @@ impl DataStore {
         self.main = StoredData::default();
     }
 }
+
+#[cfg(all(test, feature = "zfs", target_os = "linux"))]
+mod test {
+    use std::time::{Duration, Instant};
+
+    use super::StoredData;
+    use crate::collection::disks::{ObjsetCounters, ZpoolHarvest};
+
+    #[test]
+    fn test_eat_zpools() {
+        let objset = |name: &str, read_bytes, write_bytes| ObjsetCounters {
+            dataset_name: name.to_string(),
+            read_bytes,
+            write_bytes,
+        };
+        let zpool = ZpoolHarvest {
+            name: "tank".to_string(),
+            state: "ONLINE".to_string(),
+            read_bytes: 5000,
+            write_bytes: 4000,
+            datasets: vec![
+                objset("tank", 1000, 1000),
+                objset("tank/vol", 3000, 2000),
+                objset("tank/new", 1000, 1000),
+            ],
+        };
+        let rates = |data: &StoredData, name: &str| {
+            let rows = data
+                .disk_harvest
+                .iter()
+                .filter(|disk| disk.name == name)
+                .collect::<Vec<_>>();
+            assert_eq!(rows.len(), 1, "expected exactly one row for {name}");
+
+            (rows[0].io_read_rate_bytes, rows[0].io_write_rate_bytes)
+        };
+
+        let start = Instant::now();
+        let mut data = StoredData {
+            last_update_time: start,
+            ..Default::default()
+        };
+        data.prev_zpool_io.insert("tank".to_string(), (1000, 2000));
+        data.prev_dataset_io
+            .insert("tank/vol".to_string(), (1000, 0));
+
+        data.eat_zpools(vec![zpool.clone()], start + Duration::from_secs(2));
+
+        // The root dataset isn't shown separately from its pool.
+        assert_eq!(data.disk_harvest.len(), 3);
+        assert_eq!(rates(&data, "tank"), (Some(2000), Some(1000)));
+        assert_eq!(rates(&data, "tank/vol"), (Some(1000), Some(1000)));
+        assert_eq!(rates(&data, "tank/new"), (None, None));
+        assert_eq!(data.prev_dataset_io["tank/new"], (1000, 1000));
+
+        // A dataset that already has a row, such as a mounted one, isn't added again.
+        data.disk_harvest.retain(|disk| disk.name == "tank/vol");
+        data.eat_zpools(vec![zpool], start + Duration::from_secs(2));
+
+        assert_eq!(data.disk_harvest.len(), 3);
+        assert_eq!(rates(&data, "tank"), (Some(0), Some(0)));
+        assert_eq!(rates(&data, "tank/new"), (Some(0), Some(0)));
+    }
+}
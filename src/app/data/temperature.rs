@@ -2,6 +2,8 @@
 
 use std::{fmt::Display, str::FromStr};
 
+use crate::collection::temperature::SensorKind;
+
 #[derive(Clone, Debug, Copy, PartialEq, Eq, Default)]
 pub enum TemperatureType {
     #[default]
@@ -57,10 +59,163 @@ impl Display for TypedTemperature {
     }
 }
 
+/// A sensor reading, ready for display. Temperatures are converted to the configured unit.
+#[derive(Debug, PartialEq, Clone, PartialOrd)]
+pub enum SensorValue {
+    Temperature(TypedTemperature),
+    Fan(u32),
+    Voltage(f32),
+    Power(f32),
+    Current(f32),
+}
+
+impl SensorValue {
+    /// Creates a reading of `kind` from a value in its base unit.
+    pub fn new(kind: SensorKind, value: f32, temperature_type: TemperatureType) -> Self {
+        match kind {
+            SensorKind::Temperature => {
+                SensorValue::Temperature(temperature_type.convert_temp_unit(value))
+            }
+            SensorKind::Fan => SensorValue::Fan(value.round() as u32),
+            SensorKind::Voltage => SensorValue::Voltage(value),
+            SensorKind::Power => SensorValue::Power(value),
+            SensorKind::Current => SensorValue::Current(value),
+        }
+    }
+}
+
+impl Display for SensorValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SensorValue::Temperature(temp) => write!(f, "{temp}"),
+            SensorValue::Fan(rpm) => write!(f, "{rpm} RPM"),
+            SensorValue::Voltage(volts) => write!(f, "{volts:.2}V"),
+            SensorValue::Power(watts) => write!(f, "{watts:.1}W"),
+            SensorValue::Current(amps) => write!(f, "{amps:.2}A"),
+        }
+    }
+}
+
+/// How close a sensor reading is to its thresholds.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TempLevel {
+    #[default]
+    Normal,
+
+    /// Within [`TempLevel::WARM_MARGIN`] of the next threshold. Only used for temperatures.
+    Warm,
+
+    /// At or above the max threshold.
+    Hot,
+
+    /// At or above the critical threshold, or the kernel reports a critical alarm.
+    Critical,
+}
+
+impl TempLevel {
+    /// How close, in degrees Celsius, a temperature must be to its next threshold to be
+    /// considered warm.
+    const WARM_MARGIN: f32 = 10.0;
+
+    /// Classifies a reading against its thresholds, all in the kind's base unit.
+    pub fn new(
+        kind: SensorKind, temp: Option<f32>, max: Option<f32>, crit: Option<f32>, crit_alarm: bool,
+    ) -> Self {
+        let Some(temp) = temp else {
+            return if crit_alarm {
+                TempLevel::Critical
+            } else {
+                TempLevel::Normal
+            };
+        };
+
+        if crit_alarm || crit.is_some_and(|crit| temp >= crit) {
+            TempLevel::Critical
+        } else if max.is_some_and(|max| temp >= max) {
+            TempLevel::Hot
+        } else if kind == SensorKind::Temperature
+            && max
+                .or(crit)
+                .is_some_and(|threshold| temp >= threshold - Self::WARM_MARGIN)
+        {
+            TempLevel::Warm
+        } else {
+            TempLevel::Normal
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    fn temp_levels() {
+        const TEMP: SensorKind = SensorKind::Temperature;
+
+        assert_eq!(
+            TempLevel::new(TEMP, Some(50.0), None, None, false),
+            TempLevel::Normal
+        );
+        assert_eq!(
+            TempLevel::new(TEMP, None, Some(80.0), Some(100.0), false),
+            TempLevel::Normal
+        );
+
+        let level = |temp| TempLevel::new(TEMP, Some(temp), Some(80.0), Some(100.0), false);
+        assert_eq!(level(69.0), TempLevel::Normal);
+        assert_eq!(level(70.0), TempLevel::Warm);
+        assert_eq!(level(80.0), TempLevel::Hot);
+        assert_eq!(level(99.0), TempLevel::Hot);
+        assert_eq!(level(100.0), TempLevel::Critical);
+
+        // Without a max threshold, being near the critical one is still warm.
+        assert_eq!(
+            TempLevel::new(TEMP, Some(89.0), None, Some(100.0), false),
+            TempLevel::Normal
+        );
+        assert_eq!(
+            TempLevel::new(TEMP, Some(95.0), None, Some(100.0), false),
+            TempLevel::Warm
+        );
+
+        // The kernel's alarm takes precedence.
+        assert_eq!(
+            TempLevel::new(TEMP, Some(50.0), Some(80.0), None, true),
+            TempLevel::Critical
+        );
+        assert_eq!(
+            TempLevel::new(TEMP, None, None, None, true),
+            TempLevel::Critical
+        );
+
+        // Other kinds of sensors don't warm up, and are only compared against their thresholds.
+        assert_eq!(
+            TempLevel::new(SensorKind::Voltage, Some(1.45), None, Some(1.5), false),
+            TempLevel::Normal
+        );
+        assert_eq!(
+            TempLevel::new(SensorKind::Voltage, Some(1.5), None, Some(1.5), false),
+            TempLevel::Critical
+        );
+    }
+
+    #[test]
+    fn sensor_values() {
+        let value = |kind, value| SensorValue::new(kind, value, TemperatureType::Celsius);
+
+        assert_eq!(value(SensorKind::Temperature, 41.2).to_string(), "42°C");
+        assert_eq!(value(SensorKind::Fan, 1199.6).to_string(), "1200 RPM");
+        assert_eq!(value(SensorKind::Voltage, 1.216).to_string(), "1.22V");
+        assert_eq!(value(SensorKind::Power, 45.5).to_string(), "45.5W");
+        assert_eq!(value(SensorKind::Current, 2.5).to_string(), "2.50A");
+
+        assert_eq!(
+            SensorValue::new(SensorKind::Temperature, 100.0, TemperatureType::Fahrenheit),
+            SensorValue::Temperature(TypedTemperature::Fahrenheit(212))
+        );
+    }
+
     #[test]
     fn temp_conversions() {
         const TEMP: f32 = 100.0;
@@ -6,11 +6,27 @@ use std::{
     vec::Vec,
 };
 
-#[cfg(feature = "gpu")]
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use timeless::data::ChunkedData;
 
-use crate::collection::Data;
+#[cfg(feature = "battery")]
+use crate::collection::batteries::BatteryData;
+#[cfg(feature = "gpu")]
+use crate::collection::gpu::GpuStats;
+#[cfg(target_os = "linux")]
+use crate::collection::{
+    cpu::{CpuPower, PowerDomain},
+    memory::vmstat::VmStatHarvest,
+    network::tcp::TcpHarvest,
+};
+use crate::{
+    collection::{
+        Data,
+        network::{InterfaceHarvest, PacketRates},
+        temperature::SensorKind,
+    },
+    widgets::DiskWidgetData,
+};
 
 /// Values corresponding to a time slice.
 pub type Values = ChunkedData<f64>;
@@ -35,6 +51,18 @@ pub struct TimeSeriesData {
     /// Network TX data.
     pub tx: Values,
 
+    /// Network packet, error and drop rates.
+    pub net_packets: PacketSeries,
+
+    /// Per-interface network data, keyed by interface name.
+    pub net_interfaces: HashMap<String, InterfaceSeries>,
+
+    /// Per-device disk I/O data in bytes per second, keyed by disk name.
+    pub disk_io: HashMap<String, DiskIoSeries>,
+
+    /// Per-sensor temperature data in Celsius, keyed by sensor name.
+    pub temperatures: HashMap<String, Values>,
+
     /// CPU data.
     pub cpu: Vec<Values>,
 
@@ -52,9 +80,412 @@ pub struct TimeSeriesData {
     /// Arc data.
     pub arc_mem: Values,
 
+    #[cfg(all(feature = "zfs", target_os = "linux"))]
+    /// ARC hit ratio data.
+    pub arc_hit: Values,
+
+    #[cfg(all(feature = "zfs", target_os = "linux"))]
+    /// L2ARC hit ratio data.
+    pub l2arc_hit: Values,
+
+    #[cfg(target_os = "linux")]
+    /// zram data.
+    pub zram_mem: Values,
+
+    #[cfg(target_os = "linux")]
+    /// zswap data.
+    pub zswap_mem: Values,
+
+    #[cfg(target_os = "linux")]
+    /// Memory pressure data.
+    pub vmstat: VmStatSeries,
+
+    #[cfg(target_os = "linux")]
+    /// TCP and UDP activity data.
+    pub tcp: TcpSeries,
+
+    #[cfg(target_os = "linux")]
+    /// CPU and DRAM power draw data.
+    pub cpu_power: PowerSeries,
+
+    #[cfg(target_os = "linux")]
+    /// Per-NUMA node memory data, keyed by node ID.
+    pub numa_mem: HashMap<usize, Values>,
+
     #[cfg(feature = "gpu")]
     /// GPU memory data.
     pub gpu_mem: HashMap<String, Values>,
+
+    #[cfg(feature = "gpu")]
+    /// Per-GPU activity data, keyed by device ID.
+    pub gpu_stats: HashMap<String, GpuSeries>,
+
+    #[cfg(feature = "battery")]
+    /// Per-battery data, keyed by battery ID.
+    pub batteries: HashMap<String, BatterySeries>,
+}
+
+/// Network packet, error and drop rates over time, per second.
+#[derive(Clone, Debug, Default)]
+pub struct PacketSeries {
+    pub rx_packets: Values,
+    pub tx_packets: Values,
+    pub errors: Values,
+    pub drops: Values,
+}
+
+impl PacketSeries {
+    fn push(&mut self, rates: &PacketRates) {
+        self.rx_packets.push(rates.rx_packets);
+        self.tx_packets.push(rates.tx_packets);
+        self.errors.push(rates.errors());
+        self.drops.push(rates.drops());
+    }
+
+    fn insert_break(&mut self) {
+        for values in self.all_mut() {
+            values.insert_break();
+        }
+    }
+
+    fn prune(&mut self, end: usize) {
+        for values in self.all_mut() {
+            let _ = values.prune_and_shrink_to_fit(end);
+        }
+    }
+
+    fn all_mut(&mut self) -> [&mut Values; 4] {
+        [
+            &mut self.rx_packets,
+            &mut self.tx_packets,
+            &mut self.errors,
+            &mut self.drops,
+        ]
+    }
+}
+
+/// Network RX and TX rates of a single interface over time.
+#[derive(Clone, Debug, Default)]
+pub struct InterfaceSeries {
+    pub rx: Values,
+    pub tx: Values,
+}
+
+impl InterfaceSeries {
+    /// Creates a series that's padded to line up with `padding` earlier data points.
+    fn with_padding(padding: usize) -> Self {
+        let mut series = Self::default();
+        for _ in 0..padding {
+            series.try_push(None);
+        }
+        series
+    }
+
+    fn try_push(&mut self, interface: Option<&InterfaceHarvest>) {
+        self.rx
+            .try_push(interface.map(|interface| interface.rx as f64));
+        self.tx
+            .try_push(interface.map(|interface| interface.tx as f64));
+    }
+}
+
+/// Disk read and write rates of a single device over time.
+#[derive(Clone, Debug, Default)]
+pub struct DiskIoSeries {
+    pub read: Values,
+    pub write: Values,
+}
+
+impl DiskIoSeries {
+    /// Creates a series that's padded to line up with `padding` earlier data points.
+    fn with_padding(padding: usize) -> Self {
+        let mut series = Self::default();
+        for _ in 0..padding {
+            series.try_push(None);
+        }
+        series
+    }
+
+    fn try_push(&mut self, disk: Option<&DiskWidgetData>) {
+        match disk.and_then(|disk| disk.io_read_rate_bytes.zip(disk.io_write_rate_bytes)) {
+            Some((read, write)) => {
+                self.read.push(read as f64);
+                self.write.push(write as f64);
+            }
+            None => {
+                self.read.try_push(None);
+                self.write.try_push(None);
+            }
+        }
+    }
+}
+
+/// Memory pressure rates over time, in events per second.
+#[cfg(target_os = "linux")]
+#[derive(Clone, Debug, Default)]
+pub struct VmStatSeries {
+    pub pgfault: Values,
+    pub pgmajfault: Values,
+    pub pswpin: Values,
+    pub pswpout: Values,
+    pub pgscan: Values,
+    pub pgsteal: Values,
+    pub oom_kill: Values,
+}
+
+#[cfg(target_os = "linux")]
+impl VmStatSeries {
+    fn push(&mut self, harvest: &VmStatHarvest) {
+        self.pgfault.push(harvest.pgfault);
+        self.pgmajfault.push(harvest.pgmajfault);
+        self.pswpin.push(harvest.pswpin);
+        self.pswpout.push(harvest.pswpout);
+        self.pgscan.push(harvest.pgscan);
+        self.pgsteal.push(harvest.pgsteal);
+        self.oom_kill.push(harvest.oom_kill);
+    }
+
+    fn insert_break(&mut self) {
+        for values in self.all_mut() {
+            values.insert_break();
+        }
+    }
+
+    fn prune(&mut self, end: usize) {
+        for values in self.all_mut() {
+            let _ = values.prune_and_shrink_to_fit(end);
+        }
+    }
+
+    fn all_mut(&mut self) -> [&mut Values; 7] {
+        [
+            &mut self.pgfault,
+            &mut self.pgmajfault,
+            &mut self.pswpin,
+            &mut self.pswpout,
+            &mut self.pgscan,
+            &mut self.pgsteal,
+            &mut self.oom_kill,
+        ]
+    }
+}
+
+/// TCP and UDP activity over time, in events per second.
+#[cfg(target_os = "linux")]
+#[derive(Clone, Debug, Default)]
+pub struct TcpSeries {
+    pub active_opens: Values,
+    pub passive_opens: Values,
+    pub retrans_segs: Values,
+    pub listen_overflows: Values,
+    pub udp_rcvbuf_errors: Values,
+}
+
+#[cfg(target_os = "linux")]
+impl TcpSeries {
+    fn push(&mut self, harvest: &TcpHarvest) {
+        self.active_opens.push(harvest.active_opens);
+        self.passive_opens.push(harvest.passive_opens);
+        self.retrans_segs.push(harvest.retrans_segs);
+        self.listen_overflows.push(harvest.listen_overflows);
+        self.udp_rcvbuf_errors.push(harvest.udp_rcvbuf_errors);
+    }
+
+    fn insert_break(&mut self) {
+        for values in self.all_mut() {
+            values.insert_break();
+        }
+    }
+
+    fn prune(&mut self, end: usize) {
+        for values in self.all_mut() {
+            let _ = values.prune_and_shrink_to_fit(end);
+        }
+    }
+
+    fn all_mut(&mut self) -> [&mut Values; 5] {
+        [
+            &mut self.active_opens,
+            &mut self.passive_opens,
+            &mut self.retrans_segs,
+            &mut self.listen_overflows,
+            &mut self.udp_rcvbuf_errors,
+        ]
+    }
+}
+
+/// GPU activity over time.
+#[cfg(feature = "gpu")]
+#[derive(Clone, Debug, Default)]
+pub struct GpuSeries {
+    /// Overall utilization, as a percentage.
+    pub utilization: Values,
+
+    /// Video encoder utilization, as a percentage.
+    pub encoder: Values,
+
+    /// Video decoder utilization, as a percentage.
+    pub decoder: Values,
+
+    /// Temperature, in Celsius.
+    pub temperature: Values,
+
+    /// Power draw, in watts.
+    pub power: Values,
+
+    /// Core clock, in MHz.
+    pub core_clock: Values,
+
+    /// Memory clock, in MHz.
+    pub memory_clock: Values,
+
+    /// Fan speed, as a percentage.
+    pub fan: Values,
+}
+
+#[cfg(feature = "gpu")]
+impl GpuSeries {
+    /// Creates a series that's padded to line up with `padding` earlier data points.
+    fn with_padding(padding: usize) -> Self {
+        let mut series = Self::default();
+        for _ in 0..padding {
+            series.try_push(None);
+        }
+        series
+    }
+
+    fn try_push(&mut self, gpu: Option<&GpuStats>) {
+        self.utilization
+            .try_push(gpu.and_then(|gpu| gpu.utilization));
+        self.encoder.try_push(gpu.and_then(|gpu| gpu.encoder));
+        self.decoder.try_push(gpu.and_then(|gpu| gpu.decoder));
+        self.temperature
+            .try_push(gpu.and_then(|gpu| gpu.temperature).map(f64::from));
+        self.power.try_push(gpu.and_then(|gpu| gpu.power));
+        self.core_clock
+            .try_push(gpu.and_then(|gpu| gpu.core_clock).map(f64::from));
+        self.memory_clock
+            .try_push(gpu.and_then(|gpu| gpu.memory_clock).map(f64::from));
+        self.fan.try_push(gpu.and_then(|gpu| gpu.fan));
+    }
+
+    fn all_mut(&mut self) -> [&mut Values; 8] {
+        [
+            &mut self.utilization,
+            &mut self.encoder,
+            &mut self.decoder,
+            &mut self.temperature,
+            &mut self.power,
+            &mut self.core_clock,
+            &mut self.memory_clock,
+            &mut self.fan,
+        ]
+    }
+}
+
+/// Battery charge and power draw over time.
+#[cfg(feature = "battery")]
+#[derive(Clone, Debug, Default)]
+pub struct BatterySeries {
+    /// Charge, as a percentage.
+    pub charge: Values,
+
+    /// Power draw, in watts.
+    pub power: Values,
+}
+
+#[cfg(feature = "battery")]
+impl BatterySeries {
+    /// Creates a series that's padded to line up with `padding` earlier data points.
+    fn with_padding(padding: usize) -> Self {
+        let mut series = Self::default();
+        for _ in 0..padding {
+            series.try_push(None);
+        }
+        series
+    }
+
+    fn try_push(&mut self, battery: Option<&BatteryData>) {
+        self.charge
+            .try_push(battery.map(|battery| battery.charge_percent));
+        self.power
+            .try_push(battery.map(|battery| battery.power_consumption));
+    }
+
+    fn prune(&mut self, end: usize) {
+        let _ = self.charge.prune_and_shrink_to_fit(end);
+        let _ = self.power.prune_and_shrink_to_fit(end);
+    }
+}
+
+/// CPU and DRAM power draw over time, in watts.
+#[cfg(target_os = "linux")]
+#[derive(Clone, Debug, Default)]
+pub struct PowerSeries {
+    pub package: Values,
+    pub core: Values,
+    pub uncore: Values,
+    pub dram: Values,
+}
+
+#[cfg(target_os = "linux")]
+impl PowerSeries {
+    fn push(&mut self, power: &[CpuPower]) {
+        for domain in [
+            PowerDomain::Package,
+            PowerDomain::Core,
+            PowerDomain::Uncore,
+            PowerDomain::Dram,
+        ] {
+            let values = self.get_mut(domain);
+            match power.iter().find(|power| power.domain == domain) {
+                Some(power) => values.push(power.watts),
+                None => values.try_push(None),
+            }
+        }
+    }
+
+    /// Unlike the other series, domains can show up partway through (e.g. as the first reading
+    /// is only a baseline), so gaps still need to advance the index to stay lined up with time.
+    fn insert_break(&mut self) {
+        for values in self.all_mut() {
+            values.try_push(None);
+        }
+    }
+
+    fn prune(&mut self, end: usize) {
+        for values in self.all_mut() {
+            let _ = values.prune_and_shrink_to_fit(end);
+        }
+    }
+
+    /// Returns the values of a domain.
+    pub fn get(&self, domain: PowerDomain) -> &Values {
+        match domain {
+            PowerDomain::Package => &self.package,
+            PowerDomain::Core => &self.core,
+            PowerDomain::Uncore => &self.uncore,
+            PowerDomain::Dram => &self.dram,
+        }
+    }
+
+    fn get_mut(&mut self, domain: PowerDomain) -> &mut Values {
+        match domain {
+            PowerDomain::Package => &mut self.package,
+            PowerDomain::Core => &mut self.core,
+            PowerDomain::Uncore => &mut self.uncore,
+            PowerDomain::Dram => &mut self.dram,
+        }
+    }
+
+    fn all_mut(&mut self) -> [&mut Values; 4] {
+        [
+            &mut self.package,
+            &mut self.core,
+            &mut self.uncore,
+            &mut self.dram,
+        ]
+    }
 }
 
 impl TimeSeriesData {
@@ -65,9 +496,77 @@ impl TimeSeriesData {
         if let Some(network) = &data.network {
             self.rx.push(network.rx as f64);
             self.tx.push(network.tx as f64);
+            self.net_packets.push(&network.packet_rates);
         } else {
             self.rx.insert_break();
             self.tx.insert_break();
+            self.net_packets.insert_break();
+        }
+
+        {
+            let interfaces = data
+                .network
+                .as_ref()
+                .map(|network| network.interfaces.as_slice())
+                .unwrap_or_default();
+
+            // The time for this data point has already been pushed, so an interface that's new
+            // (e.g. a VPN tunnel that was just brought up) needs to be padded to line up with it.
+            let padding = self.time.len() - 1;
+            for interface in interfaces {
+                self.net_interfaces
+                    .entry(interface.name.clone())
+                    .or_insert_with(|| InterfaceSeries::with_padding(padding));
+            }
+
+            for (name, series) in &mut self.net_interfaces {
+                series.try_push(interfaces.iter().find(|interface| interface.name == *name));
+            }
+        }
+
+        if let Some(sensors) = &data.temperature_sensors {
+            let mut not_visited = self
+                .temperatures
+                .keys()
+                .map(String::to_owned)
+                .collect::<HashSet<_>>();
+
+            // The time for this data point has already been pushed, so a sensor that's new
+            // needs to be padded to line up with it.
+            let padding = self.time.len() - 1;
+
+            for sensor in sensors
+                .iter()
+                .filter(|sensor| sensor.kind == SensorKind::Temperature)
+            {
+                // Only the first temperature sensor with a given name is recorded each update.
+                if !not_visited.remove(&sensor.name) && self.temperatures.contains_key(&sensor.name)
+                {
+                    continue;
+                }
+
+                let curr = self
+                    .temperatures
+                    .entry(sensor.name.clone())
+                    .or_insert_with(|| {
+                        let mut values = Values::default();
+                        for _ in 0..padding {
+                            values.try_push(None);
+                        }
+                        values
+                    });
+                curr.try_push(sensor.value.map(f64::from));
+            }
+
+            for nv in not_visited {
+                if let Some(entry) = self.temperatures.get_mut(&nv) {
+                    entry.try_push(None);
+                }
+            }
+        } else {
+            for t in self.temperatures.values_mut() {
+                t.try_push(None);
+            }
         }
 
         if let Some(cpu) = &data.cpu {
@@ -130,6 +629,78 @@ impl TimeSeriesData {
             }
         }
 
+        #[cfg(all(feature = "zfs", target_os = "linux"))]
+        {
+            let detail = data.arc_detail.as_ref();
+
+            if let Some(hit_percent) = detail.and_then(|detail| detail.hit_percent) {
+                self.arc_hit.push(hit_percent);
+            } else {
+                self.arc_hit.insert_break();
+            }
+
+            if let Some(l2_hit_percent) = detail.and_then(|detail| detail.l2_hit_percent) {
+                self.l2arc_hit.push(l2_hit_percent);
+            } else {
+                self.l2arc_hit.insert_break();
+            }
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            if let Some(zram) = &data.zram {
+                self.zram_mem.push(zram.percentage());
+            } else {
+                self.zram_mem.insert_break();
+            }
+
+            if let Some(zswap) = &data.zswap {
+                self.zswap_mem.push(zswap.percentage());
+            } else {
+                self.zswap_mem.insert_break();
+            }
+
+            if let Some(vmstat) = &data.vmstat {
+                self.vmstat.push(vmstat);
+            } else {
+                self.vmstat.insert_break();
+            }
+
+            if let Some(tcp) = &data.tcp {
+                self.tcp.push(tcp);
+            } else {
+                self.tcp.insert_break();
+            }
+
+            if let Some(power) = &data.cpu_power {
+                self.cpu_power.push(power);
+            } else {
+                self.cpu_power.insert_break();
+            }
+
+            if let Some(numa_memory) = &data.numa_memory {
+                let mut not_visited = self.numa_mem.keys().copied().collect::<HashSet<_>>();
+
+                for (node, new_data) in numa_memory {
+                    not_visited.remove(node);
+                    self.numa_mem
+                        .entry(*node)
+                        .or_default()
+                        .push(new_data.percentage());
+                }
+
+                for nv in not_visited {
+                    if let Some(entry) = self.numa_mem.get_mut(&nv) {
+                        entry.insert_break();
+                    }
+                }
+            } else {
+                for n in self.numa_mem.values_mut() {
+                    n.insert_break();
+                }
+            }
+        }
+
         #[cfg(feature = "gpu")]
         {
             if let Some(gpu) = &data.gpu {
@@ -165,6 +736,72 @@ impl TimeSeriesData {
                 }
             }
         }
+
+        #[cfg(feature = "gpu")]
+        {
+            let gpus = data.gpu_stats.as_deref().unwrap_or_default();
+
+            // The time for this data point has already been pushed, so a GPU that's new needs
+            // to be padded to line up with it.
+            let padding = self.time.len() - 1;
+            for gpu in gpus {
+                self.gpu_stats
+                    .entry(gpu.id.clone())
+                    .or_insert_with(|| GpuSeries::with_padding(padding));
+            }
+
+            for (id, series) in &mut self.gpu_stats {
+                series.try_push(gpus.iter().find(|gpu| gpu.id == *id));
+            }
+        }
+
+        #[cfg(feature = "battery")]
+        {
+            let batteries = data.list_of_batteries.as_deref().unwrap_or_default();
+
+            // The time for this data point has already been pushed, so a battery that's new
+            // needs to be padded to line up with it.
+            let padding = self.time.len() - 1;
+            for battery in batteries {
+                self.batteries
+                    .entry(battery.id.clone())
+                    .or_insert_with(|| BatterySeries::with_padding(padding));
+            }
+
+            for (id, series) in &mut self.batteries {
+                series.try_push(batteries.iter().find(|battery| battery.id == *id));
+            }
+        }
+    }
+
+    /// Add the disk I/O rates for the latest data point. This is separate from [`Self::add`],
+    /// as rates are only calculated once the disk data has been processed.
+    ///
+    /// Note that this must be called after [`Self::add`] for the same data point.
+    pub fn add_disk_io(&mut self, disks: Option<&[DiskWidgetData]>) {
+        let mut not_visited = self.disk_io.keys().cloned().collect::<HashSet<_>>();
+
+        // The time for this data point has already been pushed, so a disk that's new needs to
+        // be padded to line up with it.
+        let padding = self.time.len().saturating_sub(1);
+
+        for disk in disks.unwrap_or_default() {
+            // The same device can show up more than once (e.g. bind mounts), only record it once.
+            if !not_visited.remove(&disk.name) && self.disk_io.contains_key(&disk.name) {
+                continue;
+            }
+
+            self.disk_io
+                .entry(disk.name.clone())
+                .or_insert_with(|| DiskIoSeries::with_padding(padding))
+                .try_push(Some(disk));
+        }
+
+        for nv in not_visited {
+            if let Some(entry) = self.disk_io.get_mut(&nv) {
+                entry.try_push(None);
+            }
+        }
     }
 
     /// Prune any data older than the given duration.
@@ -197,6 +834,47 @@ impl TimeSeriesData {
 
         let _ = self.rx.prune_and_shrink_to_fit(end);
         let _ = self.tx.prune_and_shrink_to_fit(end);
+        self.net_packets.prune(end);
+
+        self.net_interfaces.retain(|_, interface| {
+            let _ = interface.rx.prune(end);
+            let _ = interface.tx.prune(end);
+
+            // Remove the entry if it is empty. We can always add it again later.
+            if interface.rx.no_elements() {
+                false
+            } else {
+                interface.rx.shrink_to_fit();
+                interface.tx.shrink_to_fit();
+                true
+            }
+        });
+
+        self.disk_io.retain(|_, disk| {
+            let _ = disk.read.prune(end);
+            let _ = disk.write.prune(end);
+
+            // Remove the entry if it is empty. We can always add it again later.
+            if disk.read.no_elements() {
+                false
+            } else {
+                disk.read.shrink_to_fit();
+                disk.write.shrink_to_fit();
+                true
+            }
+        });
+
+        self.temperatures.retain(|_, temperature| {
+            let _ = temperature.prune(end);
+
+            // Remove the entry if it is empty. We can always add it again later.
+            if temperature.no_elements() {
+                false
+            } else {
+                temperature.shrink_to_fit();
+                true
+            }
+        });
 
         for cpu in &mut self.cpu {
             let _ = cpu.prune_and_shrink_to_fit(end);
@@ -211,6 +889,68 @@ impl TimeSeriesData {
         #[cfg(feature = "zfs")]
         let _ = self.arc_mem.prune_and_shrink_to_fit(end);
 
+        #[cfg(all(feature = "zfs", target_os = "linux"))]
+        {
+            let _ = self.arc_hit.prune_and_shrink_to_fit(end);
+            let _ = self.l2arc_hit.prune_and_shrink_to_fit(end);
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            let _ = self.zram_mem.prune_and_shrink_to_fit(end);
+            let _ = self.zswap_mem.prune_and_shrink_to_fit(end);
+            self.vmstat.prune(end);
+            self.tcp.prune(end);
+            self.cpu_power.prune(end);
+
+            self.numa_mem.retain(|_, node| {
+                let _ = node.prune(end);
+
+                // Remove the entry if it is empty. We can always add it again later.
+                if node.no_elements() {
+                    false
+                } else {
+                    node.shrink_to_fit();
+                    true
+                }
+            });
+        }
+
+        #[cfg(feature = "battery")]
+        {
+            self.batteries.retain(|_, battery| {
+                battery.prune(end);
+
+                // Remove the entry if it is empty. We can always add it again later.
+                !battery.charge.no_elements()
+            });
+        }
+
+        #[cfg(feature = "gpu")]
+        {
+            let len = self.time.len();
+            self.gpu_stats.retain(|_, gpu| {
+                let mut has_elements = false;
+                for values in gpu.all_mut() {
+                    let _ = values.prune_and_shrink_to_fit(end);
+
+                    // Metrics that a GPU doesn't report have no readings and can't be pruned, so
+                    // start them over to stay lined up with time.
+                    if values.no_elements() {
+                        *values = Values::default();
+                        for _ in 0..len {
+                            values.try_push(None);
+                        }
+                    } else {
+                        has_elements = true;
+                    }
+                }
+
+                // Remove the entry if it is empty. We can always add it again later.
+                has_elements
+            });
+        }
+
         #[cfg(feature = "gpu")]
         {
             self.gpu_mem.retain(|_, gpu| {
@@ -227,3 +967,421 @@ impl TimeSeriesData {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collection::temperature::SensorData;
+
+    /// Builds the data for a point in time, `secs_ago` seconds before now.
+    fn data(secs_ago: u64) -> Data {
+        Data {
+            collection_time: Instant::now() - Duration::from_secs(secs_ago),
+            ..Default::default()
+        }
+    }
+
+    fn disk(name: &str, rates: Option<(u64, u64)>) -> DiskWidgetData {
+        DiskWidgetData {
+            name: name.to_string(),
+            mount_point: String::default(),
+            free_bytes: None,
+            used_bytes: None,
+            total_bytes: None,
+            summed_total_bytes: None,
+            io_read_rate_bytes: rates.map(|(read, _)| read),
+            io_write_rate_bytes: rates.map(|(_, write)| write),
+            io_detail_rates: None,
+            fs_type: None,
+            mount_options: None,
+            inodes_free: None,
+            inodes_used: None,
+            inodes_total: None,
+            btrfs: None,
+            eta_full: None,
+            tree_name: None,
+            pool_state: None,
+        }
+    }
+
+    fn sensor(name: &str, kind: SensorKind, value: Option<f32>) -> SensorData {
+        SensorData {
+            name: name.to_string(),
+            kind,
+            value,
+            ..Default::default()
+        }
+    }
+
+    /// Builds the data for a point in time with the given sensor readings.
+    fn sensor_data(secs_ago: u64, sensors: Vec<SensorData>) -> Data {
+        Data {
+            temperature_sensors: Some(sensors),
+            ..data(secs_ago)
+        }
+    }
+
+    #[cfg(feature = "battery")]
+    fn battery(id: &str, charge_percent: f64) -> BatteryData {
+        BatteryData {
+            id: id.to_string(),
+            charge_percent,
+            power_consumption: 10.0,
+            health_percent: 100.0,
+            state: crate::collection::batteries::BatteryState::Unknown,
+            energy_full: 0.0,
+            energy_full_design: 0.0,
+            voltage: 0.0,
+            cycle_count: None,
+            technology: None,
+            vendor: None,
+            model: None,
+        }
+    }
+
+    /// Builds the data for a point in time with the given batteries.
+    #[cfg(feature = "battery")]
+    fn battery_data(secs_ago: u64, batteries: Vec<BatteryData>) -> Data {
+        Data {
+            list_of_batteries: Some(batteries),
+            ..data(secs_ago)
+        }
+    }
+
+    /// Builds the data for a point in time with the given interfaces and their `(rx, tx)` rates.
+    fn network_data(secs_ago: u64, interfaces: &[(&str, u64, u64)]) -> Data {
+        use crate::collection::network::NetworkHarvest;
+
+        Data {
+            network: Some(NetworkHarvest {
+                interfaces: interfaces
+                    .iter()
+                    .map(|(name, rx, tx)| InterfaceHarvest {
+                        name: name.to_string(),
+                        rx: *rx,
+                        tx: *tx,
+                        ..Default::default()
+                    })
+                    .collect(),
+                ..Default::default()
+            }),
+            ..data(secs_ago)
+        }
+    }
+
+    /// Returns the values of a series alongside the index of the time they line up with.
+    fn indexed(values: &Values) -> Vec<(usize, f64)> {
+        values
+            .iter_with_index()
+            .map(|(index, value)| (index, *value))
+            .collect()
+    }
+
+    #[test]
+    fn test_temperature_sensors_come_and_go() {
+        use SensorKind::Temperature;
+
+        let mut series = TimeSeriesData::default();
+
+        series.add(&sensor_data(
+            3,
+            vec![sensor("cpu", Temperature, Some(40.0))],
+        ));
+
+        // A new sensor shows up.
+        series.add(&sensor_data(
+            2,
+            vec![
+                sensor("cpu", Temperature, Some(41.0)),
+                sensor("gpu", Temperature, Some(50.0)),
+            ],
+        ));
+
+        // The CPU sensor goes away.
+        series.add(&sensor_data(
+            1,
+            vec![sensor("gpu", Temperature, Some(51.0))],
+        ));
+
+        // No sensor data at all.
+        series.add(&data(0));
+
+        assert_eq!(
+            indexed(&series.temperatures["cpu"]),
+            vec![(0, 40.0), (1, 41.0)]
+        );
+        assert_eq!(
+            indexed(&series.temperatures["gpu"]),
+            vec![(1, 50.0), (2, 51.0)]
+        );
+
+        for values in series.temperatures.values() {
+            assert_eq!(values.length(), series.time.len());
+        }
+    }
+
+    #[test]
+    fn test_temperature_duplicate_sensor_names() {
+        use SensorKind::{Fan, Temperature};
+
+        let mut series = TimeSeriesData::default();
+
+        for secs_ago in [1, 0] {
+            series.add(&sensor_data(
+                secs_ago,
+                vec![
+                    // Sensors of other kinds are skipped, even if they share a name.
+                    sensor("nvme", Fan, Some(1200.0)),
+                    sensor("nvme", Temperature, Some(35.0)),
+                    // Only the first of the same name and kind is recorded.
+                    sensor("nvme", Temperature, Some(70.0)),
+                ],
+            ));
+        }
+
+        assert_eq!(series.temperatures.len(), 1);
+        assert_eq!(
+            indexed(&series.temperatures["nvme"]),
+            vec![(0, 35.0), (1, 35.0)]
+        );
+    }
+
+    #[test]
+    fn test_temperature_prune() {
+        use SensorKind::Temperature;
+
+        let mut series = TimeSeriesData::default();
+
+        series.add(&sensor_data(
+            30,
+            vec![
+                sensor("cpu", Temperature, Some(40.0)),
+                sensor("gpu", Temperature, Some(50.0)),
+            ],
+        ));
+        series.add(&sensor_data(
+            20,
+            vec![sensor("cpu", Temperature, Some(41.0))],
+        ));
+        series.add(&sensor_data(0, vec![sensor("cpu", Temperature, None)]));
+
+        series.prune(Duration::from_secs(25));
+
+        // The GPU sensor has no readings left, so it's removed.
+        assert_eq!(series.time.len(), 2);
+        assert!(!series.temperatures.contains_key("gpu"));
+        assert_eq!(indexed(&series.temperatures["cpu"]), vec![(0, 41.0)]);
+        assert_eq!(series.temperatures["cpu"].length(), series.time.len());
+
+        // A sensor that shows up again is lined up with the remaining data.
+        series.add(&sensor_data(
+            0,
+            vec![sensor("gpu", Temperature, Some(52.0))],
+        ));
+        assert_eq!(indexed(&series.temperatures["gpu"]), vec![(2, 52.0)]);
+    }
+
+    #[cfg(feature = "battery")]
+    #[test]
+    fn test_battery_series_follow_ids() {
+        let mut series = TimeSeriesData::default();
+
+        series.add(&battery_data(3, vec![battery("BAT0", 90.0)]));
+
+        // A new battery shows up, listed before the existing one.
+        series.add(&battery_data(
+            2,
+            vec![battery("BAT1", 50.0), battery("BAT0", 89.0)],
+        ));
+
+        // The first battery goes away, so the other one moves up the list.
+        series.add(&battery_data(1, vec![battery("BAT1", 51.0)]));
+
+        // No battery data at all.
+        series.add(&data(0));
+
+        let bat0 = &series.batteries["BAT0"];
+        assert_eq!(indexed(&bat0.charge), vec![(0, 90.0), (1, 89.0)]);
+        assert_eq!(indexed(&bat0.power), vec![(0, 10.0), (1, 10.0)]);
+
+        let bat1 = &series.batteries["BAT1"];
+        assert_eq!(indexed(&bat1.charge), vec![(1, 50.0), (2, 51.0)]);
+
+        for battery in series.batteries.values() {
+            assert_eq!(battery.charge.length(), series.time.len());
+            assert_eq!(battery.power.length(), series.time.len());
+        }
+    }
+
+    #[cfg(feature = "battery")]
+    #[test]
+    fn test_battery_prune() {
+        let mut series = TimeSeriesData::default();
+
+        series.add(&battery_data(
+            30,
+            vec![battery("BAT0", 90.0), battery("BAT1", 50.0)],
+        ));
+        series.add(&battery_data(20, vec![battery("BAT1", 49.0)]));
+        series.add(&battery_data(0, vec![battery("BAT1", 48.0)]));
+
+        series.prune(Duration::from_secs(25));
+
+        // BAT0 has no readings left, so it's removed, even though it was listed first.
+        assert_eq!(series.time.len(), 2);
+        assert!(!series.batteries.contains_key("BAT0"));
+
+        let bat1 = &series.batteries["BAT1"];
+        assert_eq!(indexed(&bat1.charge), vec![(0, 49.0), (1, 48.0)]);
+        assert_eq!(bat1.charge.length(), series.time.len());
+
+        // A battery that shows up again is lined up with the remaining data.
+        series.add(&battery_data(
+            0,
+            vec![battery("BAT0", 80.0), battery("BAT1", 47.0)],
+        ));
+        assert_eq!(indexed(&series.batteries["BAT0"].charge), vec![(2, 80.0)]);
+    }
+
+    #[test]
+    fn test_net_interface_new_interface_is_padded() {
+        let mut series = TimeSeriesData::default();
+
+        series.add(&network_data(3, &[("eth0", 1, 2)]));
+
+        // A VPN tunnel comes up.
+        series.add(&network_data(2, &[("eth0", 3, 4), ("tun0", 5, 6)]));
+
+        // The tunnel goes down again.
+        series.add(&network_data(1, &[("eth0", 7, 8)]));
+
+        // No network data at all.
+        series.add(&data(0));
+
+        let eth0 = &series.net_interfaces["eth0"];
+        assert_eq!(indexed(&eth0.rx), vec![(0, 1.0), (1, 3.0), (2, 7.0)]);
+        assert_eq!(indexed(&eth0.tx), vec![(0, 2.0), (1, 4.0), (2, 8.0)]);
+
+        let tun0 = &series.net_interfaces["tun0"];
+        assert_eq!(indexed(&tun0.rx), vec![(1, 5.0)]);
+        assert_eq!(indexed(&tun0.tx), vec![(1, 6.0)]);
+
+        for interface in series.net_interfaces.values() {
+            assert_eq!(interface.rx.length(), series.time.len());
+            assert_eq!(interface.tx.length(), series.time.len());
+        }
+    }
+
+    #[test]
+    fn test_net_interface_prune() {
+        let mut series = TimeSeriesData::default();
+
+        series.add(&network_data(30, &[("eth0", 1, 1), ("tun0", 2, 2)]));
+        series.add(&network_data(20, &[("eth0", 3, 3)]));
+        series.add(&network_data(0, &[("eth0", 4, 4)]));
+
+        series.prune(Duration::from_secs(25));
+
+        // tun0 has no readings left, so it's removed.
+        assert_eq!(series.time.len(), 2);
+        assert!(!series.net_interfaces.contains_key("tun0"));
+
+        let eth0 = &series.net_interfaces["eth0"];
+        assert_eq!(indexed(&eth0.rx), vec![(0, 3.0), (1, 4.0)]);
+        assert_eq!(eth0.rx.length(), series.time.len());
+
+        // An interface that shows up again is lined up with the remaining data.
+        series.add(&network_data(0, &[("eth0", 5, 5), ("tun0", 6, 6)]));
+        assert_eq!(indexed(&series.net_interfaces["tun0"].rx), vec![(2, 6.0)]);
+    }
+
+    #[test]
+    fn test_disk_io_new_disk_is_padded() {
+        let mut series = TimeSeriesData::default();
+
+        series.add(&data(2));
+        series.add_disk_io(Some(&[disk("sda", Some((1, 2)))]));
+
+        series.add(&data(1));
+        series.add_disk_io(Some(&[
+            disk("sda", Some((3, 4))),
+            disk("sdb", Some((5, 6))),
+        ]));
+
+        let sda = &series.disk_io["sda"];
+        assert_eq!(indexed(&sda.read), vec![(0, 1.0), (1, 3.0)]);
+        assert_eq!(indexed(&sda.write), vec![(0, 2.0), (1, 4.0)]);
+
+        let sdb = &series.disk_io["sdb"];
+        assert_eq!(indexed(&sdb.read), vec![(1, 5.0)]);
+        assert_eq!(indexed(&sdb.write), vec![(1, 6.0)]);
+        assert_eq!(sdb.read.length(), series.time.len());
+    }
+
+    #[test]
+    fn test_disk_io_missing_io_breaks() {
+        let mut series = TimeSeriesData::default();
+
+        series.add(&data(4));
+        series.add_disk_io(Some(&[
+            disk("sda", Some((1, 1))),
+            disk("sdb", Some((1, 1))),
+        ]));
+
+        // No rates for sda, and sdb is gone.
+        series.add(&data(3));
+        series.add_disk_io(Some(&[disk("sda", None)]));
+
+        // No disk data at all.
+        series.add(&data(2));
+        series.add_disk_io(None);
+
+        series.add(&data(1));
+        series.add_disk_io(Some(&[
+            disk("sda", Some((2, 2))),
+            disk("sda", Some((9, 9))),
+            disk("sdb", Some((3, 3))),
+        ]));
+
+        // Duplicate entries are only recorded once, and gaps still advance the series.
+        let sda = &series.disk_io["sda"];
+        assert_eq!(indexed(&sda.read), vec![(0, 1.0), (3, 2.0)]);
+        assert_eq!(indexed(&sda.write), vec![(0, 1.0), (3, 2.0)]);
+
+        let sdb = &series.disk_io["sdb"];
+        assert_eq!(indexed(&sdb.read), vec![(0, 1.0), (3, 3.0)]);
+
+        for disk in series.disk_io.values() {
+            assert_eq!(disk.read.length(), series.time.len());
+            assert_eq!(disk.write.length(), series.time.len());
+        }
+    }
+
+    #[test]
+    fn test_disk_io_prune() {
+        let mut series = TimeSeriesData::default();
+
+        series.add(&data(30));
+        series.add_disk_io(Some(&[
+            disk("sda", Some((1, 1))),
+            disk("sdb", Some((2, 2))),
+        ]));
+
+        series.add(&data(20));
+        series.add_disk_io(Some(&[disk("sda", Some((3, 3)))]));
+
+        series.add(&data(0));
+        series.add_disk_io(Some(&[disk("sda", Some((4, 4)))]));
+
+        series.prune(Duration::from_secs(25));
+
+        // sdb has no readings left, so it's removed.
+        assert_eq!(series.time.len(), 2);
+        assert!(!series.disk_io.contains_key("sdb"));
+
+        let sda = &series.disk_io["sda"];
+        assert_eq!(indexed(&sda.read), vec![(0, 3.0), (1, 4.0)]);
+        assert_eq!(indexed(&sda.write), vec![(0, 3.0), (1, 4.0)]);
+        assert_eq!(sda.read.length(), series.time.len());
+    }
+}
@@ -947,17 +947,31 @@ pub enum BottomWidgetType {
     BasicNet,
     BasicTables,
     Battery,
+    MemPressure,
+    NetTable,
+    Tcp,
+    DiskIo,
+    TempGraph,
+    TempGraphLegend,
+    Power,
+    Gpu,
 }
 
 impl BottomWidgetType {
     pub fn is_widget_table(&self) -> bool {
         use BottomWidgetType::*;
-        matches!(self, Disk | Proc | ProcSort | Temp | CpuLegend)
+        matches!(
+            self,
+            Disk | Proc | ProcSort | Temp | CpuLegend | NetTable | TempGraphLegend
+        )
     }
 
     pub fn is_widget_graph(&self) -> bool {
         use BottomWidgetType::*;
-        matches!(self, Cpu | Net | Mem)
+        matches!(
+            self,
+            Cpu | Net | Mem | MemPressure | Tcp | DiskIo | TempGraph | Power | Gpu
+        )
     }
 
     pub fn get_pretty_name(&self) -> &str {
@@ -1006,11 +1020,84 @@ impl BottomWidgetType {
                     "Battery"
                 }
             }
+            MemPressure => {
+                if is_japanese() {
+                    "メモリ負荷"
+                } else {
+                    "Memory Pressure"
+                }
+            }
+            NetTable => {
+                if is_japanese() {
+                    "ネットワーク一覧"
+                } else {
+                    "Network Table"
+                }
+            }
+            Tcp => "TCP",
+            DiskIo => {
+                if is_japanese() {
+                    "ディスクI/O"
+                } else {
+                    "Disk I/O"
+                }
+            }
+            TempGraph => {
+                if is_japanese() {
+                    "温度グラフ"
+                } else {
+                    "Temperature Graph"
+                }
+            }
+            Power => {
+                if is_japanese() {
+                    "消費電力"
+                } else {
+                    "Power"
+                }
+            }
+            Gpu => "GPU",
             _ => "",
         }
     }
 }
 
+/// Returns a table of the supported widget names, for use in error messages.
+fn supported_widget_names_table() -> String {
+    const WIDTH: usize = 26;
+
+    let names = [
+        "cpu",
+        "mem, memory",
+        #[cfg(target_os = "linux")]
+        "mem_pressure, vmstat",
+        "net, network",
+        "net_table, network_table",
+        #[cfg(target_os = "linux")]
+        "tcp",
+        #[cfg(target_os = "linux")]
+        "power",
+        #[cfg(feature = "gpu")]
+        "gpu",
+        "proc, process, processes",
+        "temp, temperature",
+        "disk",
+        "disk_io, io",
+        "temp_graph, temperature_graph",
+        #[cfg(feature = "battery")]
+        "batt, battery",
+        "empty",
+    ];
+
+    let separator = format!("+{}+", "-".repeat(WIDTH));
+    let mut table = separator.clone();
+    for name in names {
+        table.push_str(&format!("\n|{name:^WIDTH$}|\n{separator}"));
+    }
+
+    table
+}
+
 impl std::str::FromStr for BottomWidgetType {
     type Err = OptionError;
 
@@ -1020,64 +1107,31 @@ impl std::str::FromStr for BottomWidgetType {
             "cpu" => Ok(BottomWidgetType::Cpu),
             "mem" | "memory" => Ok(BottomWidgetType::Mem),
             "net" | "network" => Ok(BottomWidgetType::Net),
+            "net_table" | "network_table" => Ok(BottomWidgetType::NetTable),
             "proc" | "process" | "processes" => Ok(BottomWidgetType::Proc),
             "temp" | "temperature" => Ok(BottomWidgetType::Temp),
             "disk" => Ok(BottomWidgetType::Disk),
+            "disk_io" | "io" => Ok(BottomWidgetType::DiskIo),
+            "temp_graph" | "temperature_graph" => Ok(BottomWidgetType::TempGraph),
             "empty" => Ok(BottomWidgetType::Empty),
             #[cfg(feature = "battery")]
             "battery" | "batt" => Ok(BottomWidgetType::Battery),
-            _ => {
-                #[cfg(feature = "battery")]
-                {
-                    Err(OptionError::config(format!(
-                        "'{s}' is an invalid widget name.
-        
-Supported widget names:
-+--------------------------+
-|            cpu           |
-+--------------------------+
-|        mem, memory       |
-+--------------------------+
-|       net, network       |
-+--------------------------+
-| proc, process, processes |
-+--------------------------+
-|     temp, temperature    |
-+--------------------------+
-|           disk           |
-+--------------------------+
-|       batt, battery      |
-+--------------------------+
-|           empty          |
-+--------------------------+
-                ",
-                    )))
-                }
-                #[cfg(not(feature = "battery"))]
-                {
-                    Err(OptionError::config(format!(
-                        "'{s}' is an invalid widget name.
+            #[cfg(target_os = "linux")]
+            "mem_pressure" | "vmstat" => Ok(BottomWidgetType::MemPressure),
+            #[cfg(target_os = "linux")]
+            "tcp" => Ok(BottomWidgetType::Tcp),
+            #[cfg(target_os = "linux")]
+            "power" => Ok(BottomWidgetType::Power),
+            #[cfg(feature = "gpu")]
+            "gpu" => Ok(BottomWidgetType::Gpu),
+            _ => Err(OptionError::config(format!(
+                "'{s}' is an invalid widget name.
 
 Supported widget names:
-+--------------------------+
-|            cpu           |
-+--------------------------+
-|        mem, memory       |
-+--------------------------+
-|       net, network       |
-+--------------------------+
-| proc, process, processes |
-+--------------------------+
-|     temp, temperature    |
-+--------------------------+
-|           disk           |
-+--------------------------+
-|           empty          |
-+--------------------------+
+{}
                 ",
-                    )))
-                }
-            }
+                supported_widget_names_table()
+            ))),
         }
     }
 }
@@ -1093,4 +1147,8 @@ pub struct UsedWidgets {
     pub use_disk: bool,
     pub use_temp: bool,
     pub use_battery: bool,
+    pub use_vmstat: bool,
+    pub use_tcp: bool,
+    pub use_cpu_power: bool,
+    pub use_gpu_stats: bool,
 }
@@ -9,18 +9,27 @@ use crate::{
     constants,
     utils::text_width::{TextWidthMode, grapheme_display_width},
     widgets::{
-        BatteryWidgetState, CpuWidgetState, DiskTableWidget, MemWidgetState, NetWidgetState,
-        ProcWidgetState, TempWidgetState, query::ProcessQuery,
+        BatteryWidgetState, CpuWidgetState, DiskIoWidgetState, DiskTableWidget, GpuWidgetState,
+        MemPressureWidgetState, MemWidgetState, NetTableWidget, NetWidgetState, PowerWidgetState,
+        ProcWidgetState, TcpWidgetState, TempGraphWidgetState, TempWidgetState,
+        query::ProcessQuery,
     },
 };
 
 pub struct AppWidgetStates {
     pub cpu_state: CpuState,
     pub mem_state: MemState,
+    pub mem_pressure_state: MemPressureState,
+    pub tcp_state: TcpState,
     pub net_state: NetState,
+    pub net_table_state: NetTableState,
     pub proc_state: ProcState,
     pub temp_state: TempState,
+    pub temp_graph_state: TempGraphState,
+    pub power_state: PowerState,
+    pub gpu_state: GpuState,
     pub disk_state: DiskState,
+    pub disk_io_state: DiskIoState,
     pub battery_state: AppBatteryState,
     pub basic_table_widget_state: Option<BasicTableWidgetState>,
 }
@@ -260,11 +269,38 @@ impl ProcState {
 
 pub struct NetState {
     pub widget_states: HashMap<u64, NetWidgetState>,
+
+    /// Interfaces plotted as separate lines in the network graphs, in the order they were
+    /// selected. Shared by all network graph and table widgets.
+    pub graph_interfaces: Vec<String>,
 }
 
 impl NetState {
-    pub fn init(widget_states: HashMap<u64, NetWidgetState>) -> Self {
-        NetState { widget_states }
+    pub fn init(
+        widget_states: HashMap<u64, NetWidgetState>, graph_interfaces: Vec<String>,
+    ) -> Self {
+        NetState {
+            widget_states,
+            graph_interfaces,
+        }
+    }
+}
+
+pub struct NetTableState {
+    pub widget_states: HashMap<u64, NetTableWidget>,
+}
+
+impl NetTableState {
+    pub fn init(widget_states: HashMap<u64, NetTableWidget>) -> Self {
+        NetTableState { widget_states }
+    }
+
+    pub fn get_mut_widget_state(&mut self, widget_id: u64) -> Option<&mut NetTableWidget> {
+        self.widget_states.get_mut(&widget_id)
+    }
+
+    pub fn get_widget_state(&self, widget_id: u64) -> Option<&NetTableWidget> {
+        self.widget_states.get(&widget_id)
     }
 }
 
@@ -296,6 +332,26 @@ impl MemState {
     }
 }
 
+pub struct MemPressureState {
+    pub widget_states: HashMap<u64, MemPressureWidgetState>,
+}
+
+impl MemPressureState {
+    pub fn init(widget_states: HashMap<u64, MemPressureWidgetState>) -> Self {
+        MemPressureState { widget_states }
+    }
+}
+
+pub struct TcpState {
+    pub widget_states: HashMap<u64, TcpWidgetState>,
+}
+
+impl TcpState {
+    pub fn init(widget_states: HashMap<u64, TcpWidgetState>) -> Self {
+        TcpState { widget_states }
+    }
+}
+
 pub struct TempState {
     pub widget_states: HashMap<u64, TempWidgetState>,
 }
@@ -331,6 +387,69 @@ impl DiskState {
         self.widget_states.get(&widget_id)
     }
 }
+
+pub struct PowerState {
+    pub widget_states: HashMap<u64, PowerWidgetState>,
+}
+
+impl PowerState {
+    pub fn init(widget_states: HashMap<u64, PowerWidgetState>) -> Self {
+        PowerState { widget_states }
+    }
+}
+
+pub struct GpuState {
+    pub widget_states: HashMap<u64, GpuWidgetState>,
+}
+
+impl GpuState {
+    pub fn init(widget_states: HashMap<u64, GpuWidgetState>) -> Self {
+        GpuState { widget_states }
+    }
+}
+
+pub struct TempGraphState {
+    pub widget_states: HashMap<u64, TempGraphWidgetState>,
+
+    /// Sensors plotted in the temperature graphs, in the order they were selected. If empty,
+    /// all sensors are plotted. Shared by all temperature graph widgets.
+    pub graph_sensors: Vec<String>,
+}
+
+impl TempGraphState {
+    pub fn init(widget_states: HashMap<u64, TempGraphWidgetState>) -> Self {
+        TempGraphState {
+            widget_states,
+            graph_sensors: Vec::new(),
+        }
+    }
+
+    pub fn get_mut_widget_state(&mut self, widget_id: u64) -> Option<&mut TempGraphWidgetState> {
+        self.widget_states.get_mut(&widget_id)
+    }
+
+    pub fn get_widget_state(&self, widget_id: u64) -> Option<&TempGraphWidgetState> {
+        self.widget_states.get(&widget_id)
+    }
+}
+
+pub struct DiskIoState {
+    pub widget_states: HashMap<u64, DiskIoWidgetState>,
+
+    /// Disks plotted in the disk I/O graphs, in the order they were selected. If empty,
+    /// all disks are plotted. Shared by all disk I/O graph widgets.
+    pub graph_disks: Vec<String>,
+}
+
+impl DiskIoState {
+    pub fn init(widget_states: HashMap<u64, DiskIoWidgetState>) -> Self {
+        DiskIoState {
+            widget_states,
+            graph_disks: Vec::new(),
+        }
+    }
+}
+
 pub struct BasicTableWidgetState {
     // Since this is intended (currently) to only be used for ONE widget, that's
     // how it's going to be written.  If we want to allow for multiple of these,
@@ -16,16 +16,31 @@ use tui::{
     widgets::Paragraph,
 };
 
+#[cfg(all(feature = "zfs", target_os = "linux"))]
+use crate::localization::status_unhealthy_zpools;
+#[cfg(target_os = "linux")]
+use crate::localization::{status_oom_killed, status_rapl_unreadable};
 use crate::{
     app::{
         App,
         layout_manager::{BottomColRow, BottomLayout, BottomWidgetType},
     },
     constants::*,
-    localization::{environment_label, status_frozen},
+    localization::{
+        environment_label, status_alerts, status_btrfs_metadata_nearly_full, status_frozen,
+        status_network_errors,
+    },
     options::config::style::Styles,
 };
 
+/// How long to show a warning in the status line after an OOM kill.
+#[cfg(target_os = "linux")]
+const OOM_WARNING_DURATION: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// How long to show a warning in the status line about unreadable RAPL zones.
+#[cfg(target_os = "linux")]
+const RAPL_WARNING_DURATION: std::time::Duration = std::time::Duration::from_secs(30);
+
 /// Handles the canvas' state.
 pub struct Painter {
     pub styles: Styles,
@@ -64,10 +79,112 @@ impl Painter {
 
     fn status_line_text(&self, app_state: &App) -> Option<String> {
         let mut parts = Vec::with_capacity(2);
+
+        let firing = app_state.alerts.firing().collect::<Vec<_>>();
+        if !firing.is_empty() {
+            parts.push(format!("{}: {}", status_alerts(), firing.join(", ")));
+        }
+
         if app_state.data_store.is_frozen() {
             parts.push(status_frozen().to_string());
         }
 
+        #[cfg(target_os = "linux")]
+        if let Some(oom) = &app_state.data_store.get_data().last_oom_event {
+            if oom.time.elapsed() < OOM_WARNING_DURATION {
+                parts.push(match &oom.victim {
+                    Some(victim) => format!(
+                        "{} ({}): {} [{}]",
+                        status_oom_killed(),
+                        oom.kills,
+                        victim.name,
+                        victim.pid
+                    ),
+                    None => format!("{} ({})", status_oom_killed(), oom.kills),
+                });
+            }
+        }
+
+        #[cfg(target_os = "linux")]
+        if let Some(rapl) = &app_state.data_store.get_data().rapl_warning {
+            if rapl.time.elapsed() < RAPL_WARNING_DURATION {
+                parts.push(format!(
+                    "{}: {}",
+                    status_rapl_unreadable(),
+                    rapl.zones.join(", ")
+                ));
+            }
+        }
+
+        let error_threshold = app_state.app_config_fields.network_error_threshold;
+        if error_threshold > 0 {
+            let error_threshold = f64::from(error_threshold);
+            let noisy_interfaces = app_state
+                .data_store
+                .get_data()
+                .network_harvest
+                .interfaces
+                .iter()
+                .filter(|interface| {
+                    interface.packet_rates.errors() >= error_threshold
+                        || interface.packet_rates.drops() >= error_threshold
+                })
+                .map(|interface| {
+                    format!(
+                        "{} ({:.1} err/s, {:.1} drop/s)",
+                        interface.name,
+                        interface.packet_rates.errors(),
+                        interface.packet_rates.drops()
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            if !noisy_interfaces.is_empty() {
+                parts.push(format!(
+                    "{}: {}",
+                    status_network_errors(),
+                    noisy_interfaces.join(", ")
+                ));
+            }
+        }
+
+        let btrfs_nearly_full = app_state
+            .data_store
+            .get_data()
+            .disk_harvest
+            .iter()
+            .filter(|disk| disk.is_btrfs_metadata_nearly_full())
+            .map(|disk| disk.mount_point.as_str())
+            .collect::<Vec<_>>();
+
+        if !btrfs_nearly_full.is_empty() {
+            parts.push(format!(
+                "{}: {}",
+                status_btrfs_metadata_nearly_full(),
+                btrfs_nearly_full.join(", ")
+            ));
+        }
+
+        #[cfg(all(feature = "zfs", target_os = "linux"))]
+        {
+            let unhealthy_zpools = app_state
+                .data_store
+                .get_data()
+                .zpool_harvest
+                .iter()
+                .filter(|zpool| zpool.is_unhealthy())
+                .map(|zpool| format!("{} ({})", zpool.name, zpool.state))
+                .collect::<Vec<_>>();
+
+            if !unhealthy_zpools.is_empty() {
+                parts.push(format!(
+                    "{}: {}",
+                    status_unhealthy_zpools(),
+                    unhealthy_zpools.join(", ")
+                ));
+            }
+        }
+
         let wsl = app_state.app_config_fields.is_wsl;
         if app_state.app_config_fields.safe_terminal_mode || wsl {
             parts.push(format!(
@@ -266,6 +383,12 @@ impl Painter {
                         rect[0],
                         app_state.current_widget.widget_id,
                     ),
+                    NetTable => self.draw_net_table(
+                        f,
+                        app_state,
+                        rect[0],
+                        app_state.current_widget.widget_id,
+                    ),
                     Net => self.draw_network_graph(
                         f,
                         app_state,
@@ -288,6 +411,59 @@ impl Painter {
                         #[cfg(feature = "battery")]
                         self.draw_battery(f, app_state, rect[0], app_state.current_widget.widget_id)
                     }
+                    MemPressure =>
+                    {
+                        #[cfg(target_os = "linux")]
+                        self.draw_mem_pressure_graph(
+                            f,
+                            app_state,
+                            rect[0],
+                            app_state.current_widget.widget_id,
+                        )
+                    }
+                    Tcp =>
+                    {
+                        #[cfg(target_os = "linux")]
+                        self.draw_tcp(f, app_state, rect[0], app_state.current_widget.widget_id)
+                    }
+                    Power =>
+                    {
+                        #[cfg(target_os = "linux")]
+                        self.draw_power_graph(
+                            f,
+                            app_state,
+                            rect[0],
+                            app_state.current_widget.widget_id,
+                        )
+                    }
+                    Gpu =>
+                    {
+                        #[cfg(feature = "gpu")]
+                        self.draw_gpu_graph(
+                            f,
+                            app_state,
+                            rect[0],
+                            app_state.current_widget.widget_id,
+                        )
+                    }
+                    DiskIo => self.draw_disk_io_graph(
+                        f,
+                        app_state,
+                        rect[0],
+                        app_state.current_widget.widget_id,
+                    ),
+                    TempGraph => self.draw_temp_graph(
+                        f,
+                        app_state,
+                        rect[0],
+                        app_state.current_widget.widget_id,
+                    ),
+                    TempGraphLegend => self.draw_temp_graph(
+                        f,
+                        app_state,
+                        rect[0],
+                        app_state.current_widget.widget_id - 1,
+                    ),
                     _ => {}
                 }
             } else if app_state.app_config_fields.use_basic_mode {
@@ -330,6 +506,17 @@ impl Painter {
                     }
                 }
 
+                #[cfg(target_os = "linux")]
+                {
+                    if data.zram_harvest.is_some() {
+                        mem_rows += 1;
+                    }
+
+                    if data.zswap_harvest.is_some() {
+                        mem_rows += 1;
+                    }
+                }
+
                 #[cfg(feature = "gpu")]
                 {
                     mem_rows += data.gpu_harvest.len() as u16; // add row(s) for gpu
@@ -460,12 +647,35 @@ impl Painter {
                     Net => self.draw_network(f, app_state, *draw_loc, widget.widget_id),
                     Temp => self.draw_temp_table(f, app_state, *draw_loc, widget.widget_id),
                     Disk => self.draw_disk_table(f, app_state, *draw_loc, widget.widget_id),
+                    NetTable => self.draw_net_table(f, app_state, *draw_loc, widget.widget_id),
                     Proc => self.draw_process(f, app_state, *draw_loc, widget.widget_id),
                     Battery =>
                     {
                         #[cfg(feature = "battery")]
                         self.draw_battery(f, app_state, *draw_loc, widget.widget_id)
                     }
+                    MemPressure =>
+                    {
+                        #[cfg(target_os = "linux")]
+                        self.draw_mem_pressure_graph(f, app_state, *draw_loc, widget.widget_id)
+                    }
+                    Tcp =>
+                    {
+                        #[cfg(target_os = "linux")]
+                        self.draw_tcp(f, app_state, *draw_loc, widget.widget_id)
+                    }
+                    Power =>
+                    {
+                        #[cfg(target_os = "linux")]
+                        self.draw_power_graph(f, app_state, *draw_loc, widget.widget_id)
+                    }
+                    Gpu =>
+                    {
+                        #[cfg(feature = "gpu")]
+                        self.draw_gpu_graph(f, app_state, *draw_loc, widget.widget_id)
+                    }
+                    DiskIo => self.draw_disk_io_graph(f, app_state, *draw_loc, widget.widget_id),
+                    TempGraph => self.draw_temp_graph(f, app_state, *draw_loc, widget.widget_id),
                     _ => {}
                 }
             }
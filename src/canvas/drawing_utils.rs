@@ -5,6 +5,11 @@ use tui::{
     widgets::{Block, BorderType, Borders},
 };
 
+use crate::{
+    app::data::Values,
+    utils::{data_units::get_unit_prefix, general::saturating_log10},
+};
+
 pub const SIDE_BORDERS: Borders = Borders::LEFT.union(Borders::RIGHT);
 pub const AUTOHIDE_TIMEOUT_MILLISECONDS: u64 = 5000; // 5 seconds to autohide
 
@@ -52,6 +57,43 @@ pub fn dialog_block(border_type: BorderType) -> Block<'static> {
         .borders(Borders::all())
 }
 
+/// Returns the largest value in `values` that is newer than `left_bound`.
+pub fn visible_max(values: &Values, times: &[Instant], left_bound: Instant) -> f64 {
+    values
+        .iter_along_base(times)
+        .rev()
+        .take_while(|&(&time, _)| time >= left_bound)
+        .fold(0.0, |acc, (_, &v)| f64::max(acc, v))
+}
+
+/// Returns the upper bound of the y-axis (as a power of ten) and its labels,
+/// one per power of ten.
+pub fn log_axis(max_entry: f64) -> (f64, Vec<String>) {
+    let upper = saturating_log10(max_entry).ceil().max(1.0);
+    let labels = (0..=upper as u32)
+        .map(|power| {
+            if power == 0 {
+                format!("{:>4}", 0)
+            } else {
+                let (value, prefix) = get_unit_prefix(10_u64.pow(power), false);
+                format!("{:>4}", format!("{value}{prefix}"))
+            }
+        })
+        .collect();
+
+    (upper, labels)
+}
+
+/// Formats an events-per-second rate.
+pub fn rate_label(rate: f64) -> String {
+    let (value, prefix) = get_unit_prefix(rate.round() as u64, false);
+    if prefix.is_empty() {
+        format!("{value:.0}/s")
+    } else {
+        format!("{value:.1}{prefix}/s")
+    }
+}
+
 #[cfg(test)]
 mod test {
 
@@ -1,21 +1,48 @@
-use std::cmp::min;
+use std::{cmp::min, time::Duration};
 
 use tui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
+    symbols::Marker,
     text::{Line, Span},
     widgets::{Cell, Paragraph, Row, Table, Tabs},
 };
 
 use crate::{
     app::App,
-    canvas::{Painter, drawing_utils::widget_block},
+    canvas::{
+        Painter,
+        components::time_graph::{AxisBound, ChartScaling, GraphData, PercentTimeGraph, TimeGraph},
+        drawing_utils::{should_hide_x_label, visible_max, widget_block},
+    },
     collection::batteries::BatteryState,
     constants::*,
-    localization::{esc_to_go_back, is_japanese, title_battery},
+    localization::{
+        esc_to_go_back, is_japanese, title_battery, title_battery_charge, title_battery_rate,
+    },
     utils::text_width::display_width,
 };
 
+/// The minimum height needed below the battery details to draw the history graphs.
+const GRAPH_HEIGHT_LIMIT: u16 = 8;
+
+/// Returns the upper bound of the y-axis and its labels, for a maximum power draw in watts.
+fn rate_axis(max_entry: f64) -> (f64, Vec<String>) {
+    // Leave some headroom above the largest value, and avoid a zero-height axis.
+    let upper = if max_entry > 0.0 {
+        max_entry * 1.25
+    } else {
+        1.0
+    };
+
+    let labels = [0.0, upper / 2.0, upper]
+        .into_iter()
+        .map(|value| format!("{:>6}", format!("{value:.1}W")))
+        .collect();
+
+    (upper, labels)
+}
+
 /// Calculate how many bars are to be drawn within basic mode's components.
 fn calculate_basic_use_bars(use_percentage: f64, num_bars_available: usize) -> usize {
     min(
@@ -160,15 +187,14 @@ impl Painter {
                 battery_charge_rows.push(Row::new([
                     Cell::from(charge_label).style(self.styles.text_style)
                 ]));
-                battery_charge_rows.push(Row::new([Cell::from(bars).style(
-                    if charge_percent < 10.0 {
-                        self.styles.low_battery
-                    } else if charge_percent < 50.0 {
-                        self.styles.medium_battery
-                    } else {
-                        self.styles.high_battery
-                    },
-                )]));
+                let charge_style = if charge_percent < 10.0 {
+                    self.styles.low_battery
+                } else if charge_percent < 50.0 {
+                    self.styles.medium_battery
+                } else {
+                    self.styles.high_battery
+                };
+                battery_charge_rows.push(Row::new([Cell::from(bars).style(charge_style)]));
 
                 let mut battery_rows = Vec::with_capacity(3);
                 let watt_consumption = battery_details.watt_consumption();
@@ -200,6 +226,24 @@ impl Painter {
                     "To empty"
                 };
                 let health_label = if is_japanese() { "健全性" } else { "Health" };
+                let cycles_label = if is_japanese() {
+                    "サイクル数"
+                } else {
+                    "Cycles"
+                };
+                let capacity_label = if is_japanese() { "容量" } else { "Capacity" };
+                let voltage_label = if is_japanese() { "電圧" } else { "Voltage" };
+                let technology_label = if is_japanese() {
+                    "種類"
+                } else {
+                    "Technology"
+                };
+                let vendor_label = if is_japanese() {
+                    "メーカー"
+                } else {
+                    "Vendor"
+                };
+                let model_label = if is_japanese() { "モデル" } else { "Model" };
 
                 battery_rows.push(Row::new([""]).bottom_margin(table_gap + 1));
                 battery_rows
@@ -248,10 +292,46 @@ impl Painter {
 
                 battery_rows.push(Row::new([health_label, &health]).style(self.styles.text_style));
 
-                let header = if battery_harvest.len() > 1 {
-                    Row::new([""]).bottom_margin(table_gap)
+                // Not every battery reports these, so only show the ones that are.
+                let details = [
+                    (
+                        cycles_label,
+                        battery_details.cycle_count.map(|cycles| cycles.to_string()),
+                    ),
+                    (capacity_label, battery_details.capacity()),
+                    (voltage_label, battery_details.voltage()),
+                    (technology_label, battery_details.technology.clone()),
+                    (vendor_label, battery_details.vendor.clone()),
+                    (model_label, battery_details.model.clone()),
+                ];
+                for (label, value) in details {
+                    if let Some(value) = value {
+                        battery_rows.push(
+                            Row::new([label.to_string(), value]).style(self.styles.text_style),
+                        );
+                    }
+                }
+
+                let (header, header_height) = if battery_harvest.len() > 1 {
+                    (Row::new([""]).bottom_margin(table_gap), 1 + table_gap)
                 } else {
-                    Row::default()
+                    (Row::default(), 0)
+                };
+
+                // Borders, the charge bar, and the details below it.
+                let details_height =
+                    2 + header_height + 2 + table_gap + (battery_rows.len() as u16 - 1);
+                let (details_draw_loc, graph_draw_loc) = if !is_basic
+                    && margined_draw_loc.height >= details_height + GRAPH_HEIGHT_LIMIT
+                {
+                    let [details_draw_loc, graph_draw_loc] = Layout::default()
+                        .constraints([Constraint::Length(details_height), Constraint::Min(0)])
+                        .direction(Direction::Vertical)
+                        .areas(margined_draw_loc);
+
+                    (details_draw_loc, Some(graph_draw_loc))
+                } else {
+                    (margined_draw_loc, None)
                 };
 
                 // Draw bar
@@ -259,7 +339,7 @@ impl Painter {
                     Table::new(battery_charge_rows, [Constraint::Percentage(100)])
                         .block(block.clone())
                         .header(header.clone()),
-                    margined_draw_loc,
+                    details_draw_loc,
                 );
 
                 // Draw info
@@ -270,8 +350,101 @@ impl Painter {
                     )
                     .block(block)
                     .header(header),
-                    margined_draw_loc,
+                    details_draw_loc,
                 );
+
+                // Draw the charge and rate history
+                let data = app_state.data_store.get_data();
+                let series = data
+                    .battery_harvest
+                    .get(battery_widget_state.currently_selected_battery_index)
+                    .and_then(|battery| data.timeseries_data.batteries.get(&battery.id));
+                if let (Some(graph_draw_loc), Some(series)) = (graph_draw_loc, series) {
+                    let times = &data.timeseries_data.time;
+                    let hide_x_labels = should_hide_x_label(
+                        app_state.app_config_fields.hide_time,
+                        app_state.app_config_fields.autohide_time,
+                        &mut battery_widget_state.autohide_timer,
+                        graph_draw_loc,
+                    );
+                    let [charge_draw_loc, rate_draw_loc] = Layout::default()
+                        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                        .direction(Direction::Horizontal)
+                        .areas(graph_draw_loc);
+
+                    PercentTimeGraph {
+                        display_range: battery_widget_state.current_display_time,
+                        hide_x_labels,
+                        app_config_fields: &app_state.app_config_fields,
+                        current_widget: app_state.current_widget.widget_id,
+                        is_expanded: false,
+                        title: title_battery_charge().into(),
+                        styles: &self.styles,
+                        widget_id,
+                        legend_position: None,
+                        legend_constraints: None,
+                    }
+                    .build()
+                    .draw(
+                        f,
+                        charge_draw_loc,
+                        vec![
+                            GraphData::default()
+                                .time(times)
+                                .values(&series.charge)
+                                .style(charge_style),
+                        ],
+                    );
+
+                    let y_max = match times.last() {
+                        Some(last_time) => {
+                            let visible_duration =
+                                Duration::from_millis(battery_widget_state.current_display_time);
+                            let left_bound = last_time
+                                .checked_sub(visible_duration)
+                                .or_else(|| times.first().copied())
+                                .unwrap_or(*last_time);
+
+                            visible_max(&series.power, times, left_bound)
+                        }
+                        None => 0.0,
+                    };
+                    let (y_upper, y_labels) = rate_axis(y_max);
+
+                    TimeGraph {
+                        x_min: -(battery_widget_state.current_display_time as f64),
+                        hide_x_labels,
+                        y_bounds: AxisBound::Max(y_upper),
+                        y_labels: &(y_labels.into_iter().map(Into::into).collect::<Vec<_>>()),
+                        graph_style: self.styles.graph_style,
+                        border_style,
+                        border_type: self.styles.border_type,
+                        title: title_battery_rate().into(),
+                        is_selected,
+                        is_expanded: false,
+                        title_style: self.styles.widget_title_style,
+                        legend_position: None,
+                        legend_constraints: None,
+                        marker: if app_state.app_config_fields.use_dot {
+                            Marker::Dot
+                        } else {
+                            Marker::Braille
+                        },
+                        scaling: ChartScaling::Linear,
+                        text_width_mode: app_state.app_config_fields.text_width_mode,
+                        use_ascii: app_state.app_config_fields.safe_terminal_mode,
+                    }
+                    .draw(
+                        f,
+                        rate_draw_loc,
+                        vec![
+                            GraphData::default()
+                                .time(times)
+                                .values(&series.power)
+                                .style(self.styles.text_style),
+                        ],
+                    );
+                }
             } else {
                 let mut contents = vec![Line::default(); table_gap.into()];
 
@@ -156,13 +156,13 @@ impl Painter {
 
     #[inline]
     fn cpu_info(&self, data: &CpuData) -> (String, String, f32, tui::style::Style) {
-        let (outer, style) = match data.data_type {
-            CpuDataType::Avg => ("AVG".to_string(), self.styles.avg_cpu_colour),
-            CpuDataType::Cpu(index) => (
-                format!("{index:<3}",),
-                self.styles.cpu_colour_styles[index % self.styles.cpu_colour_styles.len()],
-            ),
+        let outer = match data.data_type {
+            CpuDataType::Avg => "AVG".to_string(),
+            CpuDataType::Cpu(index) => format!("{index:<3}",),
+            #[cfg(target_os = "linux")]
+            CpuDataType::Node(node) => format!("{:<3}", format!("N{node}")),
         };
+        let style = self.styles.cpu_style(data.data_type);
 
         let inner = format!("{:>3.0}%", data.usage.round());
         let ratio = data.usage / 100.0;
@@ -133,28 +133,24 @@ impl Painter {
                 .iter()
                 .enumerate()
                 .map(|(itx, values)| {
-                    let style = if show_avg_cpu && itx == 0 {
-                        self.styles.avg_cpu_colour
-                    } else {
-                        self.styles.cpu_colour_styles
-                            [(itx - show_avg_offset) % self.styles.cpu_colour_styles.len()]
+                    let style = match cpu_entries.get(itx) {
+                        Some(entry) => self.styles.cpu_style(entry.data_type),
+                        None if show_avg_cpu && itx == 0 => self.styles.avg_cpu_colour,
+                        None => self
+                            .styles
+                            .list_style(&self.styles.cpu_colour_styles, itx - show_avg_offset),
                     };
 
                     GraphData::default().style(style).time(time).values(values)
                 })
                 .rev()
                 .collect()
-        } else if let Some(CpuData { .. }) = cpu_entries.get(current_scroll_position - 1) {
+        } else if let Some(CpuData { data_type, .. }) = cpu_entries.get(current_scroll_position - 1)
+        {
             // We generally subtract one from current scroll position because of the all entry.
             // TODO: Do this a bit better (e.g. we can just do if let Some(_) = cpu_points.get())
 
-            let style = if show_avg_cpu && current_scroll_position == AVG_POSITION {
-                self.styles.avg_cpu_colour
-            } else {
-                let offset_position = current_scroll_position - 1;
-                self.styles.cpu_colour_styles
-                    [(offset_position - show_avg_offset) % self.styles.cpu_colour_styles.len()]
-            };
+            let style = self.styles.cpu_style(*data_type);
 
             vec![
                 GraphData::default()
@@ -0,0 +1,171 @@
+use std::time::Duration;
+
+use tui::{
+    Frame,
+    layout::{Constraint, Rect},
+    symbols::Marker,
+};
+
+use crate::{
+    app::App,
+    canvas::{
+        Painter,
+        components::time_graph::{AxisBound, ChartScaling, GraphData, TimeGraph},
+        drawing_utils::{should_hide_x_label, visible_max},
+    },
+    localization::{is_japanese, title_disk_io},
+    utils::{conversion::dec_bytes_per_second_string, data_units::get_decimal_bytes},
+};
+
+/// Returns the upper bound of the y-axis and its labels, for a maximum rate in bytes per second.
+fn disk_io_axis(max_entry: f64) -> (f64, Vec<String>) {
+    // Leave some headroom above the largest value, and avoid a zero-height axis.
+    let upper = if max_entry > 0.0 {
+        max_entry * 1.25
+    } else {
+        1.0
+    };
+
+    let labels = [0.0, upper / 2.0, upper]
+        .into_iter()
+        .map(|value| {
+            let (value, unit) = get_decimal_bytes(value as u64);
+            format!("{:>7}", format!("{value:.1}{unit}"))
+        })
+        .collect();
+
+    (upper, labels)
+}
+
+impl Painter {
+    pub fn draw_disk_io_graph(
+        &self, f: &mut Frame<'_>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        if let Some(widget_state) = app_state
+            .states
+            .disk_io_state
+            .widget_states
+            .get_mut(&widget_id)
+        {
+            let data = app_state.data_store.get_data();
+            let times = &data.timeseries_data.time;
+            let disk_io = &data.timeseries_data.disk_io;
+
+            // Plot the selected disks in the order they were selected, or every disk if none are.
+            let graph_disks = &app_state.states.disk_io_state.graph_disks;
+            let mut disks = if graph_disks.is_empty() {
+                let mut disks = disk_io.iter().collect::<Vec<_>>();
+                disks.sort_by_key(|(name, _)| *name);
+                disks
+            } else {
+                graph_disks
+                    .iter()
+                    .filter_map(|name| disk_io.get_key_value(name))
+                    .collect()
+            };
+            disks.dedup_by(|(a, _), (b, _)| a == b);
+
+            let border_style = self.get_border_style(widget_id, app_state.current_widget.widget_id);
+            let hide_x_labels = should_hide_x_label(
+                app_state.app_config_fields.hide_time,
+                app_state.app_config_fields.autohide_time,
+                &mut widget_state.autohide_timer,
+                draw_loc,
+            );
+
+            let y_max = match times.last() {
+                Some(last_time) => {
+                    let visible_duration = Duration::from_millis(widget_state.current_display_time);
+                    let left_bound = last_time
+                        .checked_sub(visible_duration)
+                        .or_else(|| times.first().copied())
+                        .unwrap_or(*last_time);
+
+                    disks
+                        .iter()
+                        .flat_map(|(_, series)| [&series.read, &series.write])
+                        .map(|values| visible_max(values, times, left_bound))
+                        .fold(0.0, f64::max)
+                }
+                None => 0.0,
+            };
+            let (y_upper, y_labels) = disk_io_axis(y_max);
+
+            let (read_name, write_name) = if is_japanese() {
+                ("読込", "書込")
+            } else {
+                ("R", "W")
+            };
+            let current_rates = |name: &str| {
+                data.disk_harvest
+                    .iter()
+                    .find(|disk| disk.name == name)
+                    .map(|disk| (disk.io_read_rate_bytes, disk.io_write_rate_bytes))
+                    .unwrap_or_default()
+            };
+            let rate_label = |rate: Option<u64>| match rate {
+                Some(rate) => dec_bytes_per_second_string(rate),
+                None if is_japanese() => "該当なし".to_string(),
+                None => "N/A".to_string(),
+            };
+
+            let graph_data = disks
+                .iter()
+                .enumerate()
+                .flat_map(|(index, (name, series))| {
+                    let (read, write) = current_rates(name);
+                    let (read_style, write_style) = self.styles.disk_io_style(index);
+
+                    [
+                        GraphData::default()
+                            .name(format!("{name} {read_name}: {}", rate_label(read)).into())
+                            .time(times)
+                            .values(&series.read)
+                            .style(read_style),
+                        GraphData::default()
+                            .name(format!("{name} {write_name}: {}", rate_label(write)).into())
+                            .time(times)
+                            .values(&series.write)
+                            .style(write_style),
+                    ]
+                })
+                .collect();
+
+            let marker = if app_state.app_config_fields.use_dot {
+                Marker::Dot
+            } else {
+                Marker::Braille
+            };
+
+            TimeGraph {
+                x_min: -(widget_state.current_display_time as f64),
+                hide_x_labels,
+                y_bounds: AxisBound::Max(y_upper),
+                y_labels: &(y_labels.into_iter().map(Into::into).collect::<Vec<_>>()),
+                graph_style: self.styles.graph_style,
+                border_style,
+                border_type: self.styles.border_type,
+                title: title_disk_io().into(),
+                is_selected: app_state.current_widget.widget_id == widget_id,
+                is_expanded: app_state.is_expanded,
+                title_style: self.styles.widget_title_style,
+                legend_position: app_state.app_config_fields.disk_io_legend_position,
+                legend_constraints: Some((Constraint::Ratio(3, 4), Constraint::Ratio(3, 4))),
+                marker,
+                scaling: ChartScaling::Linear,
+                text_width_mode: app_state.app_config_fields.text_width_mode,
+                use_ascii: app_state.app_config_fields.safe_terminal_mode,
+            }
+            .draw(f, draw_loc, graph_data);
+        }
+
+        if app_state.should_get_widget_bounds() {
+            // Update draw loc in widget map
+            if let Some(widget) = app_state.widget_map.get_mut(&widget_id) {
+                widget.top_left_corner = Some((draw_loc.x, draw_loc.y));
+                widget.bottom_right_corner =
+                    Some((draw_loc.x + draw_loc.width, draw_loc.y + draw_loc.height));
+            }
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use std::{borrow::Cow, time::Duration};
+
+use tui::{
+    Frame,
+    layout::{Constraint, Rect},
+    symbols::Marker,
+};
+
+use super::temperature_graph::temperature_axis;
+use crate::{
+    app::{
+        App,
+        data::{GpuSeries, Values},
+    },
+    canvas::{
+        Painter,
+        components::time_graph::{AxisBound, ChartScaling, GraphData, TimeGraph},
+        drawing_utils::{should_hide_x_label, visible_max},
+    },
+    collection::gpu::GpuStats,
+    localization::{is_japanese, title_gpu},
+    widgets::GpuMetric,
+};
+
+/// Returns the upper bound of the y-axis and its labels, scaled to the largest value.
+fn scaled_axis(max_entry: f64, unit: &str) -> (f64, Vec<String>) {
+    // Leave some headroom above the largest value, and avoid a zero-height axis.
+    let upper = if max_entry > 0.0 {
+        max_entry * 1.25
+    } else {
+        1.0
+    };
+
+    let labels = [0.0, upper / 2.0, upper]
+        .into_iter()
+        .map(|value| format!("{:>7}", format!("{value:.0}{unit}")))
+        .collect();
+
+    (upper, labels)
+}
+
+/// Returns the values to plot for a metric, along with a suffix to tell them apart if there is
+/// more than one.
+fn metric_values(series: &GpuSeries, metric: GpuMetric) -> Vec<(&Values, Option<&'static str>)> {
+    match metric {
+        GpuMetric::Utilization => vec![(&series.utilization, None)],
+        GpuMetric::Encoder => vec![(&series.encoder, None)],
+        GpuMetric::Decoder => vec![(&series.decoder, None)],
+        GpuMetric::Temperature => vec![(&series.temperature, None)],
+        GpuMetric::Power => vec![(&series.power, None)],
+        GpuMetric::Clocks => vec![
+            (&series.core_clock, Some("core")),
+            (&series.memory_clock, Some("mem")),
+        ],
+        GpuMetric::Fan => vec![(&series.fan, None)],
+    }
+}
+
+impl Painter {
+    pub fn draw_gpu_graph(
+        &self, f: &mut Frame<'_>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        if let Some(widget_state) = app_state.states.gpu_state.widget_states.get_mut(&widget_id) {
+            let data = app_state.data_store.get_data();
+            let times = &data.timeseries_data.time;
+            let metric = widget_state.metric;
+            let temperature_type = app_state.app_config_fields.temperature_type;
+
+            let mut devices = data.timeseries_data.gpu_stats.iter().collect::<Vec<_>>();
+            devices.sort_by_key(|(id, _)| *id);
+
+            let border_style = self.get_border_style(widget_id, app_state.current_widget.widget_id);
+            let hide_x_labels = should_hide_x_label(
+                app_state.app_config_fields.hide_time,
+                app_state.app_config_fields.autohide_time,
+                &mut widget_state.autohide_timer,
+                draw_loc,
+            );
+
+            let current = |id: &str| data.gpu_stats_harvest.iter().find(|gpu| gpu.id == id);
+            let reading = |gpu: Option<&GpuStats>, suffix: Option<&str>| -> Option<String> {
+                let gpu = gpu?;
+                Some(match metric {
+                    GpuMetric::Utilization => format!("{:.0}%", gpu.utilization?),
+                    GpuMetric::Encoder => format!("{:.0}%", gpu.encoder?),
+                    GpuMetric::Decoder => format!("{:.0}%", gpu.decoder?),
+                    GpuMetric::Temperature => temperature_type
+                        .convert_temp_unit(gpu.temperature?)
+                        .to_string(),
+                    GpuMetric::Power => match gpu.power_limit {
+                        Some(limit) => format!("{:.1}/{limit:.0}W", gpu.power?),
+                        None => format!("{:.1}W", gpu.power?),
+                    },
+                    GpuMetric::Clocks => match suffix {
+                        Some("mem") => format!("{}MHz", gpu.memory_clock?),
+                        _ => format!("{}MHz", gpu.core_clock?),
+                    },
+                    GpuMetric::Fan => format!("{:.0}%", gpu.fan?),
+                })
+            };
+
+            let lines = devices
+                .iter()
+                .flat_map(|(id, series)| {
+                    let gpu = current(id);
+                    let name = gpu.map_or(id.as_str(), |gpu| gpu.name.as_str());
+
+                    metric_values(series, metric)
+                        .into_iter()
+                        .map(move |(values, suffix)| {
+                            let name = match suffix {
+                                Some(suffix) => format!("{name} {suffix}"),
+                                None => name.to_string(),
+                            };
+                            let reading = reading(gpu, suffix).unwrap_or_else(|| {
+                                if is_japanese() {
+                                    "該当なし".to_string()
+                                } else {
+                                    "N/A".to_string()
+                                }
+                            });
+
+                            (format!("{name}: {reading}"), values)
+                        })
+                })
+                .collect::<Vec<_>>();
+
+            let y_max = match times.last() {
+                Some(last_time) => {
+                    let visible_duration = Duration::from_millis(widget_state.current_display_time);
+                    let left_bound = last_time
+                        .checked_sub(visible_duration)
+                        .or_else(|| times.first().copied())
+                        .unwrap_or(*last_time);
+
+                    lines
+                        .iter()
+                        .map(|(_, values)| visible_max(values, times, left_bound))
+                        .fold(0.0, f64::max)
+                }
+                None => 0.0,
+            };
+            let (y_bounds, y_labels): (AxisBound, Vec<Cow<'_, str>>) = match metric {
+                GpuMetric::Utilization
+                | GpuMetric::Encoder
+                | GpuMetric::Decoder
+                | GpuMetric::Fan => (AxisBound::Max(100.5), vec!["  0%".into(), "100%".into()]),
+                GpuMetric::Temperature => {
+                    let (upper, labels) = temperature_axis(y_max, temperature_type);
+                    (
+                        AxisBound::Max(upper),
+                        labels.into_iter().map(Into::into).collect(),
+                    )
+                }
+                GpuMetric::Power => {
+                    // Scale to the power limit if there is one, so the draw can be compared
+                    // against it.
+                    let limit = data
+                        .gpu_stats_harvest
+                        .iter()
+                        .filter_map(|gpu| gpu.power_limit)
+                        .fold(0.0, f64::max);
+                    let (upper, labels) = scaled_axis(y_max.max(limit / 1.25), "W");
+                    (
+                        AxisBound::Max(upper),
+                        labels.into_iter().map(Into::into).collect(),
+                    )
+                }
+                GpuMetric::Clocks => {
+                    let (upper, labels) = scaled_axis(y_max, "MHz");
+                    (
+                        AxisBound::Max(upper),
+                        labels.into_iter().map(Into::into).collect(),
+                    )
+                }
+            };
+
+            let graph_data = lines
+                .into_iter()
+                .enumerate()
+                .map(|(index, (name, values))| {
+                    GraphData::default()
+                        .name(name.into())
+                        .time(times)
+                        .values(values)
+                        .style(self.styles.list_style(&self.styles.gpu_colours, index))
+                })
+                .collect();
+
+            let marker = if app_state.app_config_fields.use_dot {
+                Marker::Dot
+            } else {
+                Marker::Braille
+            };
+
+            TimeGraph {
+                x_min: -(widget_state.current_display_time as f64),
+                hide_x_labels,
+                y_bounds,
+                y_labels: &y_labels,
+                graph_style: self.styles.graph_style,
+                border_style,
+                border_type: self.styles.border_type,
+                title: title_gpu(metric.name()).into(),
+                is_selected: app_state.current_widget.widget_id == widget_id,
+                is_expanded: app_state.is_expanded,
+                title_style: self.styles.widget_title_style,
+                legend_position: app_state.app_config_fields.gpu_legend_position,
+                legend_constraints: Some((Constraint::Ratio(3, 4), Constraint::Ratio(3, 4))),
+                marker,
+                scaling: ChartScaling::Linear,
+                text_width_mode: app_state.app_config_fields.text_width_mode,
+                use_ascii: app_state.app_config_fields.safe_terminal_mode,
+            }
+            .draw(f, draw_loc, graph_data);
+        }
+
+        if app_state.should_get_widget_bounds() {
+            // Update draw loc in widget map
+            if let Some(widget) = app_state.widget_map.get_mut(&widget_id) {
+                widget.top_left_corner = Some((draw_loc.x, draw_loc.y));
+                widget.bottom_right_corner =
+                    Some((draw_loc.x + draw_loc.width, draw_loc.y + draw_loc.height));
+            }
+        }
+    }
+}
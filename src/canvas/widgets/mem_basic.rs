@@ -5,6 +5,8 @@ use tui::{
     layout::{Constraint, Direction, Layout, Rect},
 };
 
+#[cfg(target_os = "linux")]
+use crate::collection::memory::CompressedMemData;
 use crate::{
     app::App,
     canvas::{Painter, components::pipe_gauge::PipeGauge, drawing_utils::widget_block},
@@ -40,6 +42,25 @@ fn memory_label(data: &MemData, is_percentage: bool) -> Cow<'static, str> {
     }
 }
 
+/// Convert compressed memory info into a label. If not showing a percentage,
+/// this shows the compressed size and compression ratio.
+#[cfg(target_os = "linux")]
+#[inline]
+fn compressed_label(data: &CompressedMemData, is_percentage: bool) -> Cow<'static, str> {
+    if is_percentage {
+        let percentage = data.percentage();
+        format!("{percentage:3.0}%").into()
+    } else {
+        let (unit, denominator) = get_binary_unit_and_denominator(data.compressed_bytes);
+        let compressed = data.compressed_bytes as f64 / denominator;
+
+        match data.compression_ratio() {
+            Some(ratio) => format!("{compressed:.1}{unit} ({ratio:.1}x)").into(),
+            None => format!("{compressed:.1}{unit}").into(),
+        }
+    }
+}
+
 impl Painter {
     pub fn draw_basic_memory(
         &self, f: &mut Frame<'_>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
@@ -135,6 +156,29 @@ impl Painter {
             }
         }
 
+        #[cfg(target_os = "linux")]
+        {
+            for (harvest, label, style) in [
+                (&data.zram_harvest, "ZRM", self.styles.zram_style),
+                (&data.zswap_harvest, "ZSW", self.styles.zswap_style),
+            ] {
+                if let Some(harvest) = harvest {
+                    draw_widgets.push(
+                        PipeGauge::default()
+                            .width_mode(app_state.app_config_fields.text_width_mode)
+                            .ratio(harvest.percentage() / 100.0)
+                            .start_label(label)
+                            .inner_label(compressed_label(
+                                harvest,
+                                app_state.basic_mode_use_percent,
+                            ))
+                            .label_style(style)
+                            .gauge_style(style),
+                    );
+                }
+            }
+        }
+
         #[cfg(feature = "gpu")]
         {
             let gpu_styles = &self.styles.gpu_colours;
@@ -6,6 +6,10 @@ use tui::{
     style::Style,
 };
 
+#[cfg(target_os = "linux")]
+use crate::collection::memory::CompressedMemData;
+#[cfg(all(feature = "zfs", target_os = "linux"))]
+use crate::utils::data_units::get_binary_bytes;
 use crate::{
     app::{App, data::Values},
     canvas::{
@@ -34,6 +38,78 @@ fn memory_legend_label(name: &str, data: Option<&MemData>) -> String {
     }
 }
 
+/// Convert compressed memory info into a label showing how much RAM it takes up,
+/// the original and compressed sizes, and the compression ratio.
+#[cfg(target_os = "linux")]
+#[inline]
+fn compressed_legend_label(name: &str, data: Option<&CompressedMemData>) -> String {
+    if let Some(data) = data {
+        let percentage = data.percentage();
+        let (orig_unit, orig_denominator) = get_binary_unit_and_denominator(data.original_bytes);
+        let (comp_unit, comp_denominator) = get_binary_unit_and_denominator(data.compressed_bytes);
+        let original = data.original_bytes as f64 / orig_denominator;
+        let compressed = data.compressed_bytes as f64 / comp_denominator;
+
+        match data.compression_ratio() {
+            Some(ratio) => format!(
+                "{name}:{percentage:3.0}%   {original:.1}{orig_unit}->{compressed:.1}{comp_unit} ({ratio:.1}x)"
+            ),
+            None => format!(
+                "{name}:{percentage:3.0}%   {original:.1}{orig_unit}->{compressed:.1}{comp_unit}"
+            ),
+        }
+    } else {
+        format!("{name}:   0%   0.0B->0.0B")
+    }
+}
+
+/// Get graph data for a compressed memory store.
+#[cfg(target_os = "linux")]
+#[inline]
+fn compressed_graph_data<'a>(
+    out: &mut Vec<GraphData<'a>>, name: &str, last_harvest: Option<&'a CompressedMemData>,
+    time: &'a [Instant], values: &'a Values, style: Style,
+) {
+    if !values.no_elements() {
+        let label = compressed_legend_label(name, last_harvest).into();
+
+        out.push(
+            GraphData::default()
+                .name(label)
+                .time(time)
+                .values(values)
+                .style(style),
+        );
+    }
+}
+
+/// Get graph data for an ARC or L2ARC hit ratio, with an optional size shown after the ratio.
+#[cfg(all(feature = "zfs", target_os = "linux"))]
+#[inline]
+fn hit_ratio_graph_data<'a>(
+    out: &mut Vec<GraphData<'a>>, name: &str, hit_percent: Option<f64>, size: Option<u64>,
+    time: &'a [Instant], values: &'a Values, style: Style,
+) {
+    if !values.no_elements() {
+        let percentage = hit_percent.unwrap_or(0.0);
+        let label = match size {
+            Some(size) => {
+                let (size, unit) = get_binary_bytes(size);
+                format!("{name}:{percentage:3.0}%   {size:.1}{unit}")
+            }
+            None => format!("{name}:{percentage:3.0}%"),
+        };
+
+        out.push(
+            GraphData::default()
+                .name(label.into())
+                .time(time)
+                .values(values)
+                .style(style),
+        );
+    }
+}
+
 /// Get graph data.
 #[inline]
 fn graph_data<'a>(
@@ -80,6 +156,16 @@ impl Painter {
                         size += 1; // add capacity for ARC
                     }
                 }
+                #[cfg(target_os = "linux")]
+                {
+                    if data.zram_harvest.is_some() {
+                        size += 1; // add capacity for zram
+                    }
+                    if data.zswap_harvest.is_some() {
+                        size += 1; // add capacity for zswap
+                    }
+                    size += data.numa_mem_harvest.len(); // add row(s) for NUMA nodes
+                }
                 #[cfg(feature = "gpu")]
                 {
                     size += data.gpu_harvest.len(); // add row(s) for gpu
@@ -132,6 +218,75 @@ impl Painter {
                     );
                 }
 
+                #[cfg(all(feature = "zfs", target_os = "linux"))]
+                {
+                    let detail = data.arc_detail_harvest.as_ref();
+
+                    hit_ratio_graph_data(
+                        &mut points,
+                        "ARC HIT",
+                        detail.and_then(|detail| detail.hit_percent),
+                        None,
+                        time,
+                        &timeseries.arc_hit,
+                        self.styles.arc_hit_style,
+                    );
+
+                    // Only show the L2ARC if there is a cache device holding data.
+                    if let Some(detail) = detail.filter(|detail| detail.l2_size > 0) {
+                        hit_ratio_graph_data(
+                            &mut points,
+                            "L2ARC",
+                            detail.l2_hit_percent,
+                            Some(detail.l2_size),
+                            time,
+                            &timeseries.l2arc_hit,
+                            self.styles.l2arc_style,
+                        );
+                    }
+                }
+
+                #[cfg(target_os = "linux")]
+                {
+                    compressed_graph_data(
+                        &mut points,
+                        "ZRAM",
+                        data.zram_harvest.as_ref(),
+                        time,
+                        &timeseries.zram_mem,
+                        self.styles.zram_style,
+                    );
+
+                    compressed_graph_data(
+                        &mut points,
+                        "ZSWAP",
+                        data.zswap_harvest.as_ref(),
+                        time,
+                        &timeseries.zswap_mem,
+                        self.styles.zswap_style,
+                    );
+
+                    let numa_styles = &self.styles.numa_colours;
+                    for (node, harvest) in &data.numa_mem_harvest {
+                        if let Some(node_data) = timeseries.numa_mem.get(node) {
+                            let style = if numa_styles.is_empty() {
+                                Style::default()
+                            } else {
+                                numa_styles[node % numa_styles.len()]
+                            };
+
+                            graph_data(
+                                &mut points,
+                                &format!("NODE{node}"),
+                                Some(harvest),
+                                time,
+                                node_data,
+                                style,
+                            );
+                        }
+                    }
+                }
+
                 #[cfg(feature = "gpu")]
                 {
                     let mut colour_index = 0;
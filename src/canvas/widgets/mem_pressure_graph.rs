@@ -0,0 +1,144 @@
+use std::time::Duration;
+
+use tui::{
+    Frame,
+    layout::{Constraint, Rect},
+    symbols::Marker,
+};
+
+use crate::{
+    app::App,
+    canvas::{
+        Painter,
+        components::time_graph::{AxisBound, ChartScaling, GraphData, TimeGraph},
+        drawing_utils::{log_axis, rate_label, should_hide_x_label, visible_max},
+    },
+    localization::{is_japanese, title_memory_pressure},
+};
+
+impl Painter {
+    pub fn draw_mem_pressure_graph(
+        &self, f: &mut Frame<'_>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        if let Some(widget_state) = app_state
+            .states
+            .mem_pressure_state
+            .widget_states
+            .get_mut(&widget_id)
+        {
+            let data = app_state.data_store.get_data();
+            let times = &data.timeseries_data.time;
+            let series = &data.timeseries_data.vmstat;
+            let latest = data.vmstat_harvest.clone().unwrap_or_default();
+
+            let border_style = self.get_border_style(widget_id, app_state.current_widget.widget_id);
+            let hide_x_labels = should_hide_x_label(
+                app_state.app_config_fields.hide_time,
+                app_state.app_config_fields.autohide_time,
+                &mut widget_state.autohide_timer,
+                draw_loc,
+            );
+
+            let names = if is_japanese() {
+                [
+                    "フォールト",
+                    "メジャー",
+                    "スワップイン",
+                    "スワップアウト",
+                    "スキャン",
+                    "回収",
+                    "OOMキル",
+                ]
+            } else {
+                [
+                    "Faults",
+                    "Major",
+                    "Swap in",
+                    "Swap out",
+                    "Scanned",
+                    "Stolen",
+                    "OOM kills",
+                ]
+            };
+            let entries = [
+                (latest.pgfault, &series.pgfault),
+                (latest.pgmajfault, &series.pgmajfault),
+                (latest.pswpin, &series.pswpin),
+                (latest.pswpout, &series.pswpout),
+                (latest.pgscan, &series.pgscan),
+                (latest.pgsteal, &series.pgsteal),
+                (latest.oom_kill, &series.oom_kill),
+            ];
+
+            let y_max = match times.last() {
+                Some(last_time) => {
+                    let visible_duration = Duration::from_millis(widget_state.current_display_time);
+                    let left_bound = last_time
+                        .checked_sub(visible_duration)
+                        .or_else(|| times.first().copied())
+                        .unwrap_or(*last_time);
+
+                    entries
+                        .iter()
+                        .map(|(_, values)| visible_max(values, times, left_bound))
+                        .fold(0.0, f64::max)
+                }
+                None => 0.0,
+            };
+            let (y_upper, y_labels) = log_axis(y_max);
+
+            let graph_data = names
+                .iter()
+                .zip(entries.iter())
+                .enumerate()
+                .map(|(index, (name, (rate, values)))| {
+                    let style = self
+                        .styles
+                        .list_style(&self.styles.mem_pressure_colours, index);
+
+                    GraphData::default()
+                        .name(format!("{name}: {}", rate_label(*rate)).into())
+                        .time(times)
+                        .values(values)
+                        .style(style)
+                })
+                .collect();
+
+            let marker = if app_state.app_config_fields.use_dot {
+                Marker::Dot
+            } else {
+                Marker::Braille
+            };
+
+            TimeGraph {
+                x_min: -(widget_state.current_display_time as f64),
+                hide_x_labels,
+                y_bounds: AxisBound::Max(y_upper),
+                y_labels: &(y_labels.into_iter().map(Into::into).collect::<Vec<_>>()),
+                graph_style: self.styles.graph_style,
+                border_style,
+                border_type: self.styles.border_type,
+                title: title_memory_pressure().into(),
+                is_selected: app_state.current_widget.widget_id == widget_id,
+                is_expanded: app_state.is_expanded,
+                title_style: self.styles.widget_title_style,
+                legend_position: app_state.app_config_fields.memory_legend_position,
+                legend_constraints: Some((Constraint::Ratio(3, 4), Constraint::Ratio(3, 4))),
+                marker,
+                scaling: ChartScaling::Log10,
+                text_width_mode: app_state.app_config_fields.text_width_mode,
+                use_ascii: app_state.app_config_fields.safe_terminal_mode,
+            }
+            .draw(f, draw_loc, graph_data);
+        }
+
+        if app_state.should_get_widget_bounds() {
+            // Update draw loc in widget map
+            if let Some(widget) = app_state.widget_map.get_mut(&widget_id) {
+                widget.top_left_corner = Some((draw_loc.x, draw_loc.y));
+                widget.bottom_right_corner =
+                    Some((draw_loc.x + draw_loc.width, draw_loc.y + draw_loc.height));
+            }
+        }
+    }
+}
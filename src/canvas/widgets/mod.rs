@@ -1,12 +1,27 @@
 pub mod cpu_basic;
 pub mod cpu_graph;
+pub mod disk_io_graph;
 pub mod disk_table;
 pub mod mem_basic;
 pub mod mem_graph;
+pub mod net_table;
 pub mod network_basic;
 pub mod network_graph;
 pub mod process_table;
+pub mod temperature_graph;
 pub mod temperature_table;
 
 #[cfg(feature = "battery")]
 pub mod battery_display;
+
+#[cfg(feature = "gpu")]
+pub mod gpu_graph;
+
+#[cfg(target_os = "linux")]
+pub mod mem_pressure_graph;
+
+#[cfg(target_os = "linux")]
+pub mod power_graph;
+
+#[cfg(target_os = "linux")]
+pub mod tcp_graph;
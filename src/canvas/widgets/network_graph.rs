@@ -3,6 +3,7 @@ use std::time::Duration;
 use tui::{
     Frame,
     layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
     symbols::Marker,
     text::Text,
     widgets::{Block, Borders, Row, Table},
@@ -13,9 +14,9 @@ use crate::{
     canvas::{
         Painter,
         components::time_graph::{AxisBound, ChartScaling, GraphData, TimeGraph},
-        drawing_utils::should_hide_x_label,
+        drawing_utils::{log_axis, rate_label, should_hide_x_label, visible_max},
     },
-    localization::{is_japanese, title_network},
+    localization::{is_japanese, title_network, title_network_packets},
     utils::{
         data_units::*,
         general::{saturating_log2, saturating_log10},
@@ -40,21 +41,34 @@ impl Painter {
     pub fn draw_network(
         &self, f: &mut Frame<'_>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
     ) {
+        let graph_loc = if app_state.app_config_fields.network_packet_series.any() {
+            let packet_chunk = Layout::default()
+                .direction(Direction::Vertical)
+                .margin(0)
+                .constraints([Constraint::Ratio(2, 3), Constraint::Ratio(1, 3)])
+                .split(draw_loc);
+
+            self.draw_packet_graph(f, app_state, packet_chunk[1], widget_id);
+            packet_chunk[0]
+        } else {
+            draw_loc
+        };
+
         if app_state.app_config_fields.use_old_network_legend {
             const LEGEND_HEIGHT: u16 = 4;
             let network_chunk = Layout::default()
                 .direction(Direction::Vertical)
                 .margin(0)
                 .constraints([
-                    Constraint::Length(draw_loc.height.saturating_sub(LEGEND_HEIGHT)),
+                    Constraint::Length(graph_loc.height.saturating_sub(LEGEND_HEIGHT)),
                     Constraint::Length(LEGEND_HEIGHT),
                 ])
-                .split(draw_loc);
+                .split(graph_loc);
 
             self.draw_network_graph(f, app_state, network_chunk[0], widget_id, true);
             self.draw_network_labels(f, app_state, network_chunk[1], widget_id);
         } else {
-            self.draw_network_graph(f, app_state, draw_loc, widget_id, false);
+            self.draw_network_graph(f, app_state, graph_loc, widget_id, false);
         }
 
         if app_state.should_get_widget_bounds() {
@@ -81,6 +95,20 @@ impl Painter {
             let rx_points = &(shared_data.timeseries_data.rx);
             let tx_points = &(shared_data.timeseries_data.tx);
             let times = &(shared_data.timeseries_data.time);
+            let graph_interfaces = app_state
+                .states
+                .net_state
+                .graph_interfaces
+                .iter()
+                .enumerate()
+                .filter_map(|(index, name)| {
+                    shared_data
+                        .timeseries_data
+                        .net_interfaces
+                        .get(name)
+                        .map(|series| (index, name, series))
+                })
+                .collect::<Vec<_>>();
             let time_start = -(network_widget_state.current_display_time as f64);
 
             let border_style = self.get_border_style(widget_id, app_state.current_widget.widget_id);
@@ -124,25 +152,20 @@ impl Painter {
                             (0.0, visible_left_bound, visible_left_bound)
                         });
 
-                    for (&time, &v) in rx_points
-                        .iter_along_base(times)
-                        .rev()
-                        .take_while(|&(&time, _)| time >= oldest_to_check)
-                    {
-                        if v > biggest {
-                            biggest = v;
-                            biggest_time = time;
-                        }
-                    }
-
-                    for (&time, &v) in tx_points
-                        .iter_along_base(times)
-                        .rev()
-                        .take_while(|&(&time, _)| time >= oldest_to_check)
-                    {
-                        if v > biggest {
-                            biggest = v;
-                            biggest_time = time;
+                    let interface_points = graph_interfaces
+                        .iter()
+                        .flat_map(|(_, _, series)| [&series.rx, &series.tx]);
+
+                    for points in [rx_points, tx_points].into_iter().chain(interface_points) {
+                        for (&time, &v) in points
+                            .iter_along_base(times)
+                            .rev()
+                            .take_while(|&(&time, _)| time >= oldest_to_check)
+                        {
+                            if v > biggest {
+                                biggest = v;
+                                biggest_time = time;
+                            }
                         }
                     }
 
@@ -157,8 +180,20 @@ impl Painter {
                     0.0
                 }
             };
-            let (adjusted_y_max, y_labels) =
-                adjust_network_data_point(y_max, &app_state.app_config_fields);
+            let link_capacity =
+                match app_state.app_config_fields.network_scale_type {
+                    AxisScaling::Link => network_latest_data.link_speed.map(|link_speed| {
+                        match app_state.app_config_fields.network_unit_type {
+                            DataUnit::Byte => link_speed as f64 / 8.0,
+                            DataUnit::Bit => link_speed as f64,
+                        }
+                    }),
+                    _ => None,
+                };
+            let (adjusted_y_max, y_labels) = match link_capacity {
+                Some(link_capacity) => (link_capacity, link_capacity_labels()),
+                None => adjust_network_data_point(y_max, &app_state.app_config_fields),
+            };
             let y_bounds = AxisBound::Max(adjusted_y_max);
 
             let legend_constraints = if full_screen {
@@ -180,6 +215,16 @@ impl Painter {
             let tx = get_unit_prefix(network_latest_data.tx, use_binary_prefix);
             let total_rx = convert_bits(network_latest_data.total_rx, use_binary_prefix);
             let total_tx = convert_bits(network_latest_data.total_tx, use_binary_prefix);
+            let saturation_threshold =
+                f64::from(app_state.app_config_fields.network_saturation_threshold);
+            let link_style = |style: Style, usage: Option<f64>| match usage {
+                Some(usage) if usage >= saturation_threshold => self.styles.saturated_link_style,
+                _ => style,
+            };
+            let rx_style = link_style(self.styles.rx_style, network_latest_data.rx_percent);
+            let tx_style = link_style(self.styles.tx_style, network_latest_data.tx_percent);
+            let rx_usage = link_usage_label(network_latest_data.rx_percent);
+            let tx_usage = link_usage_label(network_latest_data.tx_percent);
             let (rx_name, tx_name, total_rx_name, total_tx_name, all_name) = if is_japanese() {
                 ("受信", "送信", "累積受信", "累積送信", "累積")
             } else {
@@ -188,9 +233,11 @@ impl Painter {
 
             // TODO: This behaviour is pretty weird, we should probably just make it so if you use old network legend
             // you don't do whatever this is...
-            let graph_data = if app_state.app_config_fields.use_old_network_legend && !full_screen {
-                let rx_label = format!("{rx_name}: {:.1}{}{}", rx.0, rx.1, unit);
-                let tx_label = format!("{tx_name}: {:.1}{}{}", tx.0, tx.1, unit);
+            let mut graph_data = if app_state.app_config_fields.use_old_network_legend
+                && !full_screen
+            {
+                let rx_label = format!("{rx_name}: {:.1}{}{}{rx_usage}", rx.0, rx.1, unit);
+                let tx_label = format!("{tx_name}: {:.1}{}{}{tx_usage}", tx.0, tx.1, unit);
                 let total_rx_label = format!("{total_rx_name}: {:.1}{}", total_rx.0, total_rx.1);
                 let total_tx_label = format!("{total_tx_name}: {:.1}{}", total_tx.0, total_tx.1);
 
@@ -199,12 +246,12 @@ impl Painter {
                         .name(rx_label.into())
                         .time(times)
                         .values(rx_points)
-                        .style(self.styles.rx_style),
+                        .style(rx_style),
                     GraphData::default()
                         .name(tx_label.into())
                         .time(times)
                         .values(tx_points)
-                        .style(self.styles.tx_style),
+                        .style(tx_style),
                     GraphData::default()
                         .style(self.styles.total_rx_style)
                         .name(total_rx_label.into()),
@@ -224,21 +271,69 @@ impl Painter {
                 vec![
                     GraphData::default()
                         .name(
-                            format!("{rx_name}: {rx_padded}  {all_name}: {total_rx_label}").into(),
+                            format!(
+                                "{rx_name}: {rx_padded}{rx_usage}  {all_name}: {total_rx_label}"
+                            )
+                            .into(),
                         )
                         .time(times)
                         .values(rx_points)
-                        .style(self.styles.rx_style),
+                        .style(rx_style),
                     GraphData::default()
                         .name(
-                            format!("{tx_name}: {tx_padded}  {all_name}: {total_tx_label}").into(),
+                            format!(
+                                "{tx_name}: {tx_padded}{tx_usage}  {all_name}: {total_tx_label}"
+                            )
+                            .into(),
                         )
                         .time(times)
                         .values(tx_points)
-                        .style(self.styles.tx_style),
+                        .style(tx_style),
                 ]
             };
 
+            for (index, name, series) in &graph_interfaces {
+                let (rx_style, tx_style) = self.styles.net_interface_style(*index);
+                let interface = network_latest_data
+                    .interfaces
+                    .iter()
+                    .find(|interface| interface.name == **name);
+                let (rx, tx) = interface
+                    .map(|interface| {
+                        (
+                            get_unit_prefix(interface.rx, use_binary_prefix),
+                            get_unit_prefix(interface.tx, use_binary_prefix),
+                        )
+                    })
+                    .unwrap_or(((0.0, ""), (0.0, "")));
+                let (rx_percent, tx_percent) = interface
+                    .map(|interface| (interface.rx_percent, interface.tx_percent))
+                    .unwrap_or_default();
+                let rx_usage = link_usage_label(rx_percent);
+                let tx_usage = link_usage_label(tx_percent);
+
+                graph_data.push(
+                    GraphData::default()
+                        .name(
+                            format!("{name} {rx_name}: {:.1}{}{}{rx_usage}", rx.0, rx.1, unit)
+                                .into(),
+                        )
+                        .time(times)
+                        .values(&series.rx)
+                        .style(link_style(rx_style, rx_percent)),
+                );
+                graph_data.push(
+                    GraphData::default()
+                        .name(
+                            format!("{name} {tx_name}: {:.1}{}{}{tx_usage}", tx.0, tx.1, unit)
+                                .into(),
+                        )
+                        .time(times)
+                        .values(&series.tx)
+                        .style(link_style(tx_style, tx_percent)),
+                );
+            }
+
             let marker = if app_state.app_config_fields.use_dot {
                 Marker::Dot
             } else {
@@ -254,7 +349,7 @@ impl Painter {
                         ChartScaling::Log10
                     }
                 }
-                AxisScaling::Linear => ChartScaling::Linear,
+                AxisScaling::Linear | AxisScaling::Link => ChartScaling::Linear,
             };
 
             TimeGraph {
@@ -280,6 +375,120 @@ impl Painter {
         }
     }
 
+    /// Draws the enabled packet, error and drop series below the network graph.
+    fn draw_packet_graph(
+        &self, f: &mut Frame<'_>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        if let Some(network_widget_state) =
+            app_state.states.net_state.widget_states.get_mut(&widget_id)
+        {
+            let shared_data = app_state.data_store.get_data();
+            let rates = &shared_data.network_harvest.packet_rates;
+            let series = &shared_data.timeseries_data.net_packets;
+            let times = &shared_data.timeseries_data.time;
+            let enabled = app_state.app_config_fields.network_packet_series;
+
+            let border_style = self.get_border_style(widget_id, app_state.current_widget.widget_id);
+            let hide_x_labels = should_hide_x_label(
+                app_state.app_config_fields.hide_time,
+                app_state.app_config_fields.autohide_time,
+                &mut network_widget_state.autohide_timer,
+                draw_loc,
+            );
+
+            let names = if is_japanese() {
+                ["受信パケット", "送信パケット", "エラー", "ドロップ"]
+            } else {
+                ["RX pkts", "TX pkts", "Errors", "Drops"]
+            };
+            let entries = [
+                (
+                    enabled.packets,
+                    rates.rx_packets,
+                    &series.rx_packets,
+                    self.styles.rx_style,
+                ),
+                (
+                    enabled.packets,
+                    rates.tx_packets,
+                    &series.tx_packets,
+                    self.styles.tx_style,
+                ),
+                (
+                    enabled.errors,
+                    rates.errors(),
+                    &series.errors,
+                    self.styles.net_errors_style,
+                ),
+                (
+                    enabled.drops,
+                    rates.drops(),
+                    &series.drops,
+                    self.styles.net_drops_style,
+                ),
+            ];
+
+            let y_max = match times.last() {
+                Some(last_time) => {
+                    let visible_duration =
+                        Duration::from_millis(network_widget_state.current_display_time);
+                    let left_bound = last_time
+                        .checked_sub(visible_duration)
+                        .or_else(|| times.first().copied())
+                        .unwrap_or(*last_time);
+
+                    entries
+                        .iter()
+                        .filter(|(shown, ..)| *shown)
+                        .map(|(_, _, values, _)| visible_max(values, times, left_bound))
+                        .fold(0.0, f64::max)
+                }
+                None => 0.0,
+            };
+            let (y_upper, y_labels) = log_axis(y_max);
+
+            let graph_data = names
+                .iter()
+                .zip(entries.iter())
+                .filter(|(_, (shown, ..))| *shown)
+                .map(|(name, (_, rate, values, style))| {
+                    GraphData::default()
+                        .name(format!("{name}: {}", rate_label(*rate)).into())
+                        .time(times)
+                        .values(values)
+                        .style(*style)
+                })
+                .collect();
+
+            let marker = if app_state.app_config_fields.use_dot {
+                Marker::Dot
+            } else {
+                Marker::Braille
+            };
+
+            TimeGraph {
+                x_min: -(network_widget_state.current_display_time as f64),
+                hide_x_labels,
+                y_bounds: AxisBound::Max(y_upper),
+                y_labels: &(y_labels.into_iter().map(Into::into).collect::<Vec<_>>()),
+                graph_style: self.styles.graph_style,
+                border_style,
+                border_type: self.styles.border_type,
+                title: title_network_packets().into(),
+                is_selected: app_state.current_widget.widget_id == widget_id,
+                is_expanded: app_state.is_expanded,
+                title_style: self.styles.widget_title_style,
+                legend_position: app_state.app_config_fields.network_legend_position,
+                legend_constraints: Some((Constraint::Ratio(3, 4), Constraint::Ratio(3, 4))),
+                marker,
+                scaling: ChartScaling::Log10,
+                text_width_mode: app_state.app_config_fields.text_width_mode,
+                use_ascii: app_state.app_config_fields.safe_terminal_mode,
+            }
+            .draw(f, draw_loc, graph_data);
+        }
+    }
+
     fn draw_network_labels(
         &self, f: &mut Frame<'_>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
     ) {
@@ -361,6 +570,22 @@ fn check_network_height_cache(
     None
 }
 
+/// Formats a link usage percentage to append to a legend entry, if the link speed is known.
+fn link_usage_label(usage: Option<f64>) -> String {
+    match usage {
+        Some(usage) => format!(" {usage:>3.0}%"),
+        None => String::new(),
+    }
+}
+
+/// Returns the labels used when the y-axis is scaled to link capacity.
+fn link_capacity_labels() -> Vec<String> {
+    ["0%", "25%", "50%", "75%", "100%"]
+        .into_iter()
+        .map(|s| format!("{s:>5}"))
+        .collect()
+}
+
 /// Returns the required labels.
 ///
 /// TODO: This is _really_ ugly... also there might be a bug with certain heights and too many labels.
@@ -403,7 +628,7 @@ fn adjust_network_data_point(max_entry: f64, config: &AppConfigFields) -> (f64,
     };
 
     match scale_type {
-        AxisScaling::Linear => {
+        AxisScaling::Linear | AxisScaling::Link => {
             let (k_limit, m_limit, g_limit, t_limit) = if use_binary_prefix {
                 (
                     KIBI_LIMIT_F64,
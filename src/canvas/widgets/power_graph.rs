@@ -0,0 +1,149 @@
+use std::time::Duration;
+
+use tui::{
+    Frame,
+    layout::{Constraint, Rect},
+    symbols::Marker,
+};
+
+use crate::{
+    app::App,
+    canvas::{
+        Painter,
+        components::time_graph::{AxisBound, ChartScaling, GraphData, TimeGraph},
+        drawing_utils::{should_hide_x_label, visible_max},
+    },
+    collection::cpu::PowerDomain,
+    localization::{is_japanese, title_power},
+};
+
+const DOMAINS: [PowerDomain; 4] = [
+    PowerDomain::Package,
+    PowerDomain::Core,
+    PowerDomain::Uncore,
+    PowerDomain::Dram,
+];
+
+/// Returns the upper bound of the y-axis and its labels, for a maximum power draw in watts.
+fn power_axis(max_entry: f64) -> (f64, Vec<String>) {
+    // Leave some headroom above the largest value, and avoid a zero-height axis.
+    let upper = if max_entry > 0.0 {
+        max_entry * 1.25
+    } else {
+        1.0
+    };
+
+    let labels = [0.0, upper / 2.0, upper]
+        .into_iter()
+        .map(|value| format!("{:>6}", format!("{value:.1}W")))
+        .collect();
+
+    (upper, labels)
+}
+
+impl Painter {
+    pub fn draw_power_graph(
+        &self, f: &mut Frame<'_>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        if let Some(widget_state) = app_state
+            .states
+            .power_state
+            .widget_states
+            .get_mut(&widget_id)
+        {
+            let data = app_state.data_store.get_data();
+            let times = &data.timeseries_data.time;
+            let power = &data.timeseries_data.cpu_power;
+
+            // Not every CPU reports every domain (e.g. uncore or DRAM), so skip any without data.
+            // Each domain keeps its colour regardless.
+            let domains = DOMAINS
+                .into_iter()
+                .enumerate()
+                .filter(|(_, domain)| !power.get(*domain).no_elements())
+                .collect::<Vec<_>>();
+
+            let border_style = self.get_border_style(widget_id, app_state.current_widget.widget_id);
+            let hide_x_labels = should_hide_x_label(
+                app_state.app_config_fields.hide_time,
+                app_state.app_config_fields.autohide_time,
+                &mut widget_state.autohide_timer,
+                draw_loc,
+            );
+
+            let y_max = match times.last() {
+                Some(last_time) => {
+                    let visible_duration = Duration::from_millis(widget_state.current_display_time);
+                    let left_bound = last_time
+                        .checked_sub(visible_duration)
+                        .or_else(|| times.first().copied())
+                        .unwrap_or(*last_time);
+
+                    domains
+                        .iter()
+                        .map(|(_, domain)| visible_max(power.get(*domain), times, left_bound))
+                        .fold(0.0, f64::max)
+                }
+                None => 0.0,
+            };
+            let (y_upper, y_labels) = power_axis(y_max);
+
+            let current_watts = |domain: PowerDomain| match data
+                .cpu_power_harvest
+                .iter()
+                .find(|power| power.domain == domain)
+            {
+                Some(power) => format!("{:.1}W", power.watts),
+                None if is_japanese() => "該当なし".to_string(),
+                None => "N/A".to_string(),
+            };
+
+            let graph_data = domains
+                .iter()
+                .map(|(index, domain)| {
+                    GraphData::default()
+                        .name(format!("{}: {}", domain.short_name(), current_watts(*domain)).into())
+                        .time(times)
+                        .values(power.get(*domain))
+                        .style(self.styles.list_style(&self.styles.power_colours, *index))
+                })
+                .collect();
+
+            let marker = if app_state.app_config_fields.use_dot {
+                Marker::Dot
+            } else {
+                Marker::Braille
+            };
+
+            TimeGraph {
+                x_min: -(widget_state.current_display_time as f64),
+                hide_x_labels,
+                y_bounds: AxisBound::Max(y_upper),
+                y_labels: &(y_labels.into_iter().map(Into::into).collect::<Vec<_>>()),
+                graph_style: self.styles.graph_style,
+                border_style,
+                border_type: self.styles.border_type,
+                title: title_power().into(),
+                is_selected: app_state.current_widget.widget_id == widget_id,
+                is_expanded: app_state.is_expanded,
+                title_style: self.styles.widget_title_style,
+                legend_position: app_state.app_config_fields.power_legend_position,
+                legend_constraints: Some((Constraint::Ratio(3, 4), Constraint::Ratio(3, 4))),
+                marker,
+                scaling: ChartScaling::Linear,
+                text_width_mode: app_state.app_config_fields.text_width_mode,
+                use_ascii: app_state.app_config_fields.safe_terminal_mode,
+            }
+            .draw(f, draw_loc, graph_data);
+        }
+
+        if app_state.should_get_widget_bounds() {
+            // Update draw loc in widget map
+            if let Some(widget) = app_state.widget_map.get_mut(&widget_id) {
+                widget.top_left_corner = Some((draw_loc.x, draw_loc.y));
+                widget.bottom_right_corner =
+                    Some((draw_loc.x + draw_loc.width, draw_loc.y + draw_loc.height));
+            }
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use std::time::Duration;
+
+use tui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    symbols::Marker,
+    text::Text,
+    widgets::{Block, Borders, Row, Table},
+};
+
+use crate::{
+    app::App,
+    canvas::{
+        Painter,
+        components::time_graph::{AxisBound, ChartScaling, GraphData, TimeGraph},
+        drawing_utils::{log_axis, rate_label, should_hide_x_label, visible_max},
+    },
+    localization::{is_japanese, title_tcp},
+};
+
+/// The connection states shown above the graph, along with the states they count.
+/// `FIN_WAIT1` and `FIN_WAIT2` are combined to save space.
+const SHOWN_STATES: [(&str, &[&str]); 8] = [
+    ("ESTAB", &["ESTABLISHED"]),
+    ("SYN_SENT", &["SYN_SENT"]),
+    ("SYN_RECV", &["SYN_RECV"]),
+    ("FIN_WAIT", &["FIN_WAIT1", "FIN_WAIT2"]),
+    ("TIME_WAIT", &["TIME_WAIT"]),
+    ("CLOSE_WAIT", &["CLOSE_WAIT"]),
+    ("LAST_ACK", &["LAST_ACK"]),
+    ("LISTEN", &["LISTEN"]),
+];
+
+impl Painter {
+    pub fn draw_tcp(&self, f: &mut Frame<'_>, app_state: &mut App, draw_loc: Rect, widget_id: u64) {
+        const STATES_HEIGHT: u16 = 4;
+
+        // Only show the connection states if there's still some room left for the graph.
+        if draw_loc.height >= STATES_HEIGHT * 3 {
+            let tcp_chunk = Layout::default()
+                .direction(Direction::Vertical)
+                .margin(0)
+                .constraints([
+                    Constraint::Length(STATES_HEIGHT),
+                    Constraint::Length(draw_loc.height.saturating_sub(STATES_HEIGHT)),
+                ])
+                .split(draw_loc);
+
+            self.draw_tcp_states(f, app_state, tcp_chunk[0], widget_id);
+            self.draw_tcp_graph(f, app_state, tcp_chunk[1], widget_id);
+        } else {
+            self.draw_tcp_graph(f, app_state, draw_loc, widget_id);
+        }
+
+        if app_state.should_get_widget_bounds() {
+            // Update draw loc in widget map
+            if let Some(widget) = app_state.widget_map.get_mut(&widget_id) {
+                widget.top_left_corner = Some((draw_loc.x, draw_loc.y));
+                widget.bottom_right_corner =
+                    Some((draw_loc.x + draw_loc.width, draw_loc.y + draw_loc.height));
+            }
+        }
+    }
+
+    fn draw_tcp_states(
+        &self, f: &mut Frame<'_>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        let states = app_state
+            .data_store
+            .get_data()
+            .tcp_harvest
+            .as_ref()
+            .map(|tcp| tcp.states)
+            .unwrap_or_default();
+
+        let counts = SHOWN_STATES.iter().map(|(name, included)| {
+            let count: u64 = included.iter().map(|state| states.get(state)).sum();
+
+            // Sockets piling up in CLOSE_WAIT usually means an application isn't closing them.
+            let style = if *name == "CLOSE_WAIT" && count > 0 {
+                self.styles.net_errors_style
+            } else {
+                self.styles.text_style
+            };
+
+            Text::styled(count.to_string(), style)
+        });
+
+        f.render_widget(
+            Table::new(
+                vec![Row::new(counts)],
+                &((std::iter::repeat_n(
+                    draw_loc.width.saturating_sub(2) / SHOWN_STATES.len() as u16,
+                    SHOWN_STATES.len(),
+                ))
+                .map(Constraint::Length)
+                .collect::<Vec<_>>()),
+            )
+            .header(
+                Row::new(SHOWN_STATES.iter().map(|(name, _)| *name))
+                    .style(self.styles.table_header_style),
+            )
+            .block(Block::default().borders(Borders::ALL).border_style(
+                if app_state.current_widget.widget_id == widget_id {
+                    self.styles.highlighted_border_style
+                } else {
+                    self.styles.border_style
+                },
+            ))
+            .style(self.styles.text_style),
+            draw_loc,
+        );
+    }
+
+    fn draw_tcp_graph(
+        &self, f: &mut Frame<'_>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        if let Some(widget_state) = app_state.states.tcp_state.widget_states.get_mut(&widget_id) {
+            let data = app_state.data_store.get_data();
+            let times = &data.timeseries_data.time;
+            let series = &data.timeseries_data.tcp;
+            let latest = data.tcp_harvest.clone().unwrap_or_default();
+
+            let border_style = self.get_border_style(widget_id, app_state.current_widget.widget_id);
+            let hide_x_labels = should_hide_x_label(
+                app_state.app_config_fields.hide_time,
+                app_state.app_config_fields.autohide_time,
+                &mut widget_state.autohide_timer,
+                draw_loc,
+            );
+
+            let names = if is_japanese() {
+                [
+                    "能動オープン",
+                    "受動オープン",
+                    "再送",
+                    "Listenあふれ",
+                    "UDP受信バッファエラー",
+                ]
+            } else {
+                [
+                    "Active opens",
+                    "Passive opens",
+                    "Retransmits",
+                    "Listen overflows",
+                    "UDP rcvbuf errors",
+                ]
+            };
+            // Each entry is its latest rate, its series, and optionally a percentage to show
+            // alongside the rate. Retransmits are more meaningful relative to how much is being
+            // sent, so they also show their share of sent segments.
+            let entries = [
+                (latest.active_opens, &series.active_opens, None),
+                (latest.passive_opens, &series.passive_opens, None),
+                (
+                    latest.retrans_segs,
+                    &series.retrans_segs,
+                    Some(latest.retrans_percent),
+                ),
+                (latest.listen_overflows, &series.listen_overflows, None),
+                (latest.udp_rcvbuf_errors, &series.udp_rcvbuf_errors, None),
+            ];
+
+            let y_max = match times.last() {
+                Some(last_time) => {
+                    let visible_duration = Duration::from_millis(widget_state.current_display_time);
+                    let left_bound = last_time
+                        .checked_sub(visible_duration)
+                        .or_else(|| times.first().copied())
+                        .unwrap_or(*last_time);
+
+                    entries
+                        .iter()
+                        .map(|(_, values, _)| visible_max(values, times, left_bound))
+                        .fold(0.0, f64::max)
+                }
+                None => 0.0,
+            };
+            let (y_upper, y_labels) = log_axis(y_max);
+
+            let graph_data = names
+                .iter()
+                .zip(entries.iter())
+                .enumerate()
+                .map(|(index, (name, (rate, values, percent)))| {
+                    let style = self.styles.list_style(&self.styles.tcp_colours, index);
+
+                    let label = match percent {
+                        Some(percent) => {
+                            format!("{name}: {} ({percent:.1}%)", rate_label(*rate))
+                        }
+                        None => format!("{name}: {}", rate_label(*rate)),
+                    };
+
+                    GraphData::default()
+                        .name(label.into())
+                        .time(times)
+                        .values(values)
+                        .style(style)
+                })
+                .collect();
+
+            let marker = if app_state.app_config_fields.use_dot {
+                Marker::Dot
+            } else {
+                Marker::Braille
+            };
+
+            let title = if is_japanese() {
+                format!("{}(確立 {}) ", title_tcp(), latest.established)
+            } else {
+                format!("{}({} established) ", title_tcp(), latest.established)
+            };
+
+            TimeGraph {
+                x_min: -(widget_state.current_display_time as f64),
+                hide_x_labels,
+                y_bounds: AxisBound::Max(y_upper),
+                y_labels: &(y_labels.into_iter().map(Into::into).collect::<Vec<_>>()),
+                graph_style: self.styles.graph_style,
+                border_style,
+                border_type: self.styles.border_type,
+                title: title.into(),
+                is_selected: app_state.current_widget.widget_id == widget_id,
+                is_expanded: app_state.is_expanded,
+                title_style: self.styles.widget_title_style,
+                legend_position: app_state.app_config_fields.tcp_legend_position,
+                legend_constraints: Some((Constraint::Ratio(3, 4), Constraint::Ratio(3, 4))),
+                marker,
+                scaling: ChartScaling::Log10,
+                text_width_mode: app_state.app_config_fields.text_width_mode,
+                use_ascii: app_state.app_config_fields.safe_terminal_mode,
+            }
+            .draw(f, draw_loc, graph_data);
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use std::time::Duration;
+
+use tui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    symbols::Marker,
+};
+
+use crate::{
+    app::{App, data::TemperatureType, layout_manager::WidgetDirection},
+    canvas::{
+        Painter,
+        components::{
+            data_table::{DrawInfo, SelectionState},
+            time_graph::{AxisBound, ChartScaling, GraphData, TimeGraph},
+        },
+        drawing_utils::{should_hide_x_label, visible_max},
+    },
+    localization::title_temperature_graph,
+    widgets::plotted_sensors,
+};
+
+/// Returns the upper bound of the y-axis and its labels, for a maximum temperature in Celsius.
+/// Values are plotted in Celsius, so only the labels need to be converted.
+pub(super) fn temperature_axis(
+    max_entry: f64, temperature_type: TemperatureType,
+) -> (f64, Vec<String>) {
+    // Round up to the next multiple of ten with some headroom, so the axis doesn't jump around
+    // with every small change.
+    let upper = ((max_entry * 1.1) / 10.0).ceil().max(1.0) * 10.0;
+
+    let labels = [0.0, upper / 2.0, upper]
+        .into_iter()
+        .map(|value| {
+            format!(
+                "{:>6}",
+                temperature_type.convert_temp_unit(value as f32).to_string()
+            )
+        })
+        .collect();
+
+    (upper, labels)
+}
+
+impl Painter {
+    pub fn draw_temp_graph(
+        &self, f: &mut Frame<'_>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        let legend_width = (draw_loc.width as f64 * 0.25) as u16;
+
+        if legend_width < 10 {
+            // Skip drawing legend
+            if app_state.current_widget.widget_id == (widget_id + 1) {
+                if app_state.app_config_fields.temperature_left_legend {
+                    app_state.move_widget_selection(&WidgetDirection::Right);
+                } else {
+                    app_state.move_widget_selection(&WidgetDirection::Left);
+                }
+            }
+            self.draw_temp_graph_chart(f, app_state, draw_loc, widget_id);
+            if let Some(widget_state) = app_state
+                .states
+                .temp_graph_state
+                .widget_states
+                .get_mut(&widget_id)
+            {
+                widget_state.is_legend_hidden = true;
+            }
+
+            // Update draw loc in widget map
+            if app_state.should_get_widget_bounds() {
+                if let Some(bottom_widget) = app_state.widget_map.get_mut(&widget_id) {
+                    bottom_widget.top_left_corner = Some((draw_loc.x, draw_loc.y));
+                    bottom_widget.bottom_right_corner =
+                        Some((draw_loc.x + draw_loc.width, draw_loc.y + draw_loc.height));
+                }
+            }
+        } else {
+            let graph_width = draw_loc.width - legend_width;
+            let (graph_index, legend_index, constraints) =
+                if app_state.app_config_fields.temperature_left_legend {
+                    (
+                        1,
+                        0,
+                        [
+                            Constraint::Length(legend_width),
+                            Constraint::Length(graph_width),
+                        ],
+                    )
+                } else {
+                    (
+                        0,
+                        1,
+                        [
+                            Constraint::Length(graph_width),
+                            Constraint::Length(legend_width),
+                        ],
+                    )
+                };
+
+            let partitioned_draw_loc = Layout::default()
+                .margin(0)
+                .direction(Direction::Horizontal)
+                .constraints(constraints)
+                .split(draw_loc);
+
+            self.draw_temp_graph_chart(f, app_state, partitioned_draw_loc[graph_index], widget_id);
+            self.draw_temp_graph_legend(
+                f,
+                app_state,
+                partitioned_draw_loc[legend_index],
+                widget_id + 1,
+            );
+
+            if app_state.should_get_widget_bounds() {
+                // Update draw loc in widget map
+                for (id, loc) in [
+                    (widget_id, partitioned_draw_loc[graph_index]),
+                    (widget_id + 1, partitioned_draw_loc[legend_index]),
+                ] {
+                    if let Some(widget) = app_state.widget_map.get_mut(&id) {
+                        widget.top_left_corner = Some((loc.x, loc.y));
+                        widget.bottom_right_corner = Some((loc.x + loc.width, loc.y + loc.height));
+                    }
+                }
+            }
+        }
+    }
+
+    fn draw_temp_graph_chart(
+        &self, f: &mut Frame<'_>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        if let Some(widget_state) = app_state
+            .states
+            .temp_graph_state
+            .widget_states
+            .get_mut(&widget_id)
+        {
+            let data = app_state.data_store.get_data();
+            let times = &data.timeseries_data.time;
+
+            let sensors = plotted_sensors(
+                &app_state.states.temp_graph_state.graph_sensors,
+                &data.timeseries_data.temperatures,
+            );
+
+            // The first legend entry plots every sensor, and the rest plot just their own. Either
+            // way, each sensor keeps its colour.
+            let current_index = widget_state.table.state.current_index;
+            let shown = sensors
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| current_index == 0 || *index + 1 == current_index)
+                .collect::<Vec<_>>();
+
+            let border_style = self.get_border_style(widget_id, app_state.current_widget.widget_id);
+            let hide_x_labels = should_hide_x_label(
+                app_state.app_config_fields.hide_time,
+                app_state.app_config_fields.autohide_time,
+                &mut widget_state.autohide_timer,
+                draw_loc,
+            );
+
+            let y_max = match times.last() {
+                Some(last_time) => {
+                    let visible_duration = Duration::from_millis(widget_state.current_display_time);
+                    let left_bound = last_time
+                        .checked_sub(visible_duration)
+                        .or_else(|| times.first().copied())
+                        .unwrap_or(*last_time);
+
+                    shown
+                        .iter()
+                        .map(|(_, (_, values))| visible_max(values, times, left_bound))
+                        .fold(0.0, f64::max)
+                }
+                None => 0.0,
+            };
+            let (y_upper, y_labels) =
+                temperature_axis(y_max, app_state.app_config_fields.temperature_type);
+
+            let graph_data = shown
+                .into_iter()
+                .map(|(index, (_, values))| {
+                    GraphData::default().time(times).values(values).style(
+                        self.styles
+                            .list_style(&self.styles.temp_graph_colours, index),
+                    )
+                })
+                .collect();
+
+            let marker = if app_state.app_config_fields.use_dot {
+                Marker::Dot
+            } else {
+                Marker::Braille
+            };
+
+            TimeGraph {
+                x_min: -(widget_state.current_display_time as f64),
+                hide_x_labels,
+                y_bounds: AxisBound::Max(y_upper),
+                y_labels: &(y_labels.into_iter().map(Into::into).collect::<Vec<_>>()),
+                graph_style: self.styles.graph_style,
+                border_style,
+                border_type: self.styles.border_type,
+                title: title_temperature_graph().into(),
+                is_selected: app_state.current_widget.widget_id == widget_id,
+                is_expanded: app_state.is_expanded,
+                title_style: self.styles.widget_title_style,
+                legend_position: None,
+                legend_constraints: None,
+                marker,
+                scaling: ChartScaling::Linear,
+                text_width_mode: app_state.app_config_fields.text_width_mode,
+                use_ascii: app_state.app_config_fields.safe_terminal_mode,
+            }
+            .draw(f, draw_loc, graph_data);
+        }
+    }
+
+    fn draw_temp_graph_legend(
+        &self, f: &mut Frame<'_>, app_state: &mut App, draw_loc: Rect, widget_id: u64,
+    ) {
+        let recalculate_column_widths = app_state.should_get_widget_bounds();
+        if let Some(widget_state) = app_state
+            .states
+            .temp_graph_state
+            .widget_states
+            .get_mut(&(widget_id - 1))
+        {
+            widget_state.is_legend_hidden = false;
+
+            let is_on_widget = widget_id == app_state.current_widget.widget_id;
+
+            let draw_info = DrawInfo {
+                loc: draw_loc,
+                force_redraw: app_state.is_force_redraw,
+                recalculate_column_widths,
+                selection_state: SelectionState::new(app_state.is_expanded, is_on_widget),
+                text_width_mode: app_state.app_config_fields.text_width_mode,
+            };
+
+            widget_state.table.draw(
+                f,
+                &draw_info,
+                app_state.widget_map.get_mut(&widget_id),
+                self,
+            );
+        }
+    }
+}
@@ -8,6 +8,12 @@ pub mod nvidia;
 #[cfg(all(target_os = "linux", feature = "gpu"))]
 pub mod amd;
 
+#[cfg(all(target_os = "linux", feature = "gpu"))]
+pub mod drm;
+
+#[cfg(feature = "gpu")]
+pub mod gpu;
+
 #[cfg(target_os = "linux")]
 mod linux {
     pub mod utils;
@@ -20,6 +26,8 @@ pub mod disks;
 pub mod error;
 pub mod memory;
 pub mod network;
+#[cfg(target_os = "linux")]
+pub mod numa;
 pub mod processes;
 pub mod temperature;
 
@@ -29,6 +37,7 @@ use std::time::{Duration, Instant};
 use nohash::IntMap;
 #[cfg(any(not(target_os = "windows"), feature = "gpu"))]
 use processes::Pid;
+use rustc_hash::FxHashMap as HashMap;
 #[cfg(feature = "battery")]
 use starship_battery::{Battery, Manager};
 
@@ -45,7 +54,7 @@ pub struct Data {
     #[cfg(not(target_os = "windows"))]
     pub cache: Option<memory::MemData>,
     pub swap: Option<memory::MemData>,
-    pub temperature_sensors: Option<Vec<temperature::TempSensorData>>,
+    pub temperature_sensors: Option<Vec<temperature::SensorData>>,
     pub network: Option<network::NetworkHarvest>,
     pub list_of_processes: Option<Vec<processes::ProcessHarvest>>,
     pub disks: Option<Vec<disks::DiskHarvest>>,
@@ -54,8 +63,31 @@ pub struct Data {
     pub list_of_batteries: Option<Vec<batteries::BatteryData>>,
     #[cfg(feature = "zfs")]
     pub arc: Option<memory::MemData>,
+    #[cfg(all(feature = "zfs", target_os = "linux"))]
+    pub arc_detail: Option<memory::arc::ArcDetail>,
+    #[cfg(all(feature = "zfs", target_os = "linux"))]
+    pub zpools: Option<Vec<disks::ZpoolHarvest>>,
+    #[cfg(target_os = "linux")]
+    pub zram: Option<memory::CompressedMemData>,
+    #[cfg(target_os = "linux")]
+    pub zswap: Option<memory::CompressedMemData>,
+    #[cfg(target_os = "linux")]
+    pub vmstat: Option<memory::vmstat::VmStatHarvest>,
+    #[cfg(target_os = "linux")]
+    pub tcp: Option<network::tcp::TcpHarvest>,
+    #[cfg(target_os = "linux")]
+    pub cpu_power: Option<Vec<cpu::CpuPower>>,
+    /// RAPL zones that couldn't be read. Only reported once.
+    #[cfg(target_os = "linux")]
+    pub rapl_unreadable: Option<Vec<String>>,
+    #[cfg(target_os = "linux")]
+    pub block_topology: Option<disks::BlockTopology>,
+    #[cfg(target_os = "linux")]
+    pub numa_memory: Option<Vec<(usize, memory::MemData)>>,
     #[cfg(feature = "gpu")]
     pub gpu: Option<Vec<(String, memory::MemData)>>,
+    #[cfg(feature = "gpu")]
+    pub gpu_stats: Option<Vec<gpu::GpuStats>>,
 }
 
 impl Default for Data {
@@ -77,8 +109,30 @@ impl Default for Data {
             list_of_batteries: None,
             #[cfg(feature = "zfs")]
             arc: None,
+            #[cfg(all(feature = "zfs", target_os = "linux"))]
+            arc_detail: None,
+            #[cfg(all(feature = "zfs", target_os = "linux"))]
+            zpools: None,
+            #[cfg(target_os = "linux")]
+            zram: None,
+            #[cfg(target_os = "linux")]
+            zswap: None,
+            #[cfg(target_os = "linux")]
+            vmstat: None,
+            #[cfg(target_os = "linux")]
+            tcp: None,
+            #[cfg(target_os = "linux")]
+            cpu_power: None,
+            #[cfg(target_os = "linux")]
+            rapl_unreadable: None,
+            #[cfg(target_os = "linux")]
+            block_topology: None,
+            #[cfg(target_os = "linux")]
+            numa_memory: None,
             #[cfg(feature = "gpu")]
             gpu: None,
+            #[cfg(feature = "gpu")]
+            gpu_stats: None,
         }
     }
 }
@@ -101,9 +155,25 @@ impl Data {
         {
             self.arc = None;
         }
+        #[cfg(all(feature = "zfs", target_os = "linux"))]
+        {
+            self.arc_detail = None;
+            self.zpools = None;
+        }
+        #[cfg(target_os = "linux")]
+        {
+            self.zram = None;
+            self.zswap = None;
+            self.vmstat = None;
+            self.tcp = None;
+            self.cpu_power = None;
+            self.block_topology = None;
+            self.numa_memory = None;
+        }
         #[cfg(feature = "gpu")]
         {
             self.gpu = None;
+            self.gpu_stats = None;
         }
     }
 }
@@ -156,6 +226,7 @@ pub struct DataCollector {
 
     total_rx: u64,
     total_tx: u64,
+    prev_interface_counters: HashMap<String, network::PrevInterfaceCounters>,
 
     unnormalized_cpu: bool,
     use_current_cpu_total: bool,
@@ -171,6 +242,26 @@ pub struct DataCollector {
     prev_idle: f64,
     #[cfg(target_os = "linux")]
     prev_non_idle: f64,
+    #[cfg(target_os = "linux")]
+    prev_vmstat: Option<memory::vmstat::VmStatCounters>,
+    #[cfg(target_os = "linux")]
+    prev_tcp: Option<network::tcp::TcpCounters>,
+    #[cfg(all(feature = "zfs", target_os = "linux"))]
+    prev_arc: Option<memory::arc::ArcCounters>,
+    /// The RAPL zones to read, found on the first update.
+    #[cfg(target_os = "linux")]
+    rapl_zones: Option<Vec<cpu::rapl::RaplZone>>,
+    /// RAPL zones that couldn't be read, and haven't been reported yet.
+    #[cfg(target_os = "linux")]
+    rapl_unreadable: Vec<String>,
+    #[cfg(target_os = "linux")]
+    prev_rapl_energy: Option<Vec<Option<u64>>>,
+    #[cfg(target_os = "linux")]
+    numa_nodes: Vec<numa::NumaNode>,
+    #[cfg(target_os = "linux")]
+    group_cpus_by_numa: bool,
+    #[cfg(target_os = "linux")]
+    get_process_numa_node: bool,
 
     #[cfg(feature = "battery")]
     battery_manager: Option<Manager>,
@@ -181,7 +272,7 @@ pub struct DataCollector {
     user_table: processes::UserTable,
 
     #[cfg(feature = "gpu")]
-    gpu_pids: Option<Vec<IntMap<Pid, (u64, u32)>>>,
+    gpu_pids: Option<Vec<IntMap<Pid, gpu::GpuProcUsage>>>,
     #[cfg(feature = "gpu")]
     gpus_total_mem: Option<u64>,
     #[cfg(feature = "zfs")]
@@ -205,12 +296,31 @@ impl DataCollector {
             prev_idle: 0_f64,
             #[cfg(target_os = "linux")]
             prev_non_idle: 0_f64,
+            #[cfg(target_os = "linux")]
+            prev_vmstat: None,
+            #[cfg(target_os = "linux")]
+            prev_tcp: None,
+            #[cfg(all(feature = "zfs", target_os = "linux"))]
+            prev_arc: None,
+            #[cfg(target_os = "linux")]
+            rapl_zones: None,
+            #[cfg(target_os = "linux")]
+            rapl_unreadable: Vec::new(),
+            #[cfg(target_os = "linux")]
+            prev_rapl_energy: None,
+            #[cfg(target_os = "linux")]
+            numa_nodes: Vec::new(),
+            #[cfg(target_os = "linux")]
+            group_cpus_by_numa: false,
+            #[cfg(target_os = "linux")]
+            get_process_numa_node: false,
             use_current_cpu_total: false,
             unnormalized_cpu: false,
             get_process_threads: false,
             last_collection_time,
             total_rx: 0,
             total_tx: 0,
+            prev_interface_counters: HashMap::default(),
             show_average_cpu: false,
             widgets_to_harvest: UsedWidgets::default(),
             #[cfg(feature = "battery")]
@@ -271,6 +381,16 @@ impl DataCollector {
         self.get_process_threads = get_process_threads;
     }
 
+    #[cfg(target_os = "linux")]
+    pub fn set_group_cpus_by_numa(&mut self, group_cpus_by_numa: bool) {
+        self.group_cpus_by_numa = group_cpus_by_numa;
+    }
+
+    #[cfg(target_os = "linux")]
+    pub fn set_get_process_numa_node(&mut self, get_process_numa_node: bool) {
+        self.get_process_numa_node = get_process_numa_node;
+    }
+
     #[cfg(feature = "zfs")]
     pub fn set_free_arc_mem(&mut self, free_mem: bool) {
         self.free_arc_mem = free_mem;
@@ -355,8 +475,18 @@ impl DataCollector {
 
         self.refresh_sysinfo_data();
 
+        // The NUMA topology only changes with CPU or memory hotplug, so no need to check it often.
+        #[cfg(target_os = "linux")]
+        if self.should_run_less_routine_tasks {
+            self.numa_nodes = numa::get_numa_nodes();
+        }
+
         self.update_cpu_usage();
+        #[cfg(target_os = "linux")]
+        self.update_cpu_power();
         self.update_memory_usage();
+        #[cfg(target_os = "linux")]
+        self.update_vmstat();
         self.update_temps();
 
         #[cfg(feature = "battery")]
@@ -367,6 +497,8 @@ impl DataCollector {
 
         self.update_processes();
         self.update_network_usage();
+        #[cfg(target_os = "linux")]
+        self.update_tcp();
         self.update_disks();
 
         // Make sure to run this to refresh the setting.
@@ -383,7 +515,8 @@ impl DataCollector {
     fn update_gpus(&mut self) {
         if self.widgets_to_harvest.use_gpu {
             let mut local_gpu: Vec<(String, memory::MemData)> = Vec::new();
-            let mut local_gpu_pids: Vec<IntMap<Pid, (u64, u32)>> = Vec::new();
+            let mut local_gpu_stats: Vec<gpu::GpuStats> = Vec::new();
+            let mut local_gpu_pids: Vec<IntMap<Pid, gpu::GpuProcUsage>> = Vec::new();
             let mut local_gpu_total_mem: u64 = 0;
 
             #[cfg(feature = "nvidia")]
@@ -400,6 +533,9 @@ impl DataCollector {
                 if let Some(mut mem) = data.memory {
                     local_gpu.append(&mut mem);
                 }
+                if let Some(mut stats) = data.stats {
+                    local_gpu_stats.append(&mut stats);
+                }
                 if let Some(mut proc) = data.procs {
                     local_gpu_pids.append(&mut proc.1);
                     local_gpu_total_mem += proc.0;
@@ -407,12 +543,20 @@ impl DataCollector {
             }
 
             #[cfg(target_os = "linux")]
-            if let Some(data) =
-                amd::get_amd_vecs(&self.widgets_to_harvest, self.last_collection_time)
-            {
+            if let Some(data) = amd::get_amd_vecs(&self.widgets_to_harvest) {
                 if let Some(mut mem) = data.memory {
                     local_gpu.append(&mut mem);
                 }
+                if let Some(mut stats) = data.stats {
+                    local_gpu_stats.append(&mut stats);
+                }
+                local_gpu_total_mem += data.total_mem;
+            }
+
+            #[cfg(target_os = "linux")]
+            if let Some(data) =
+                drm::get_drm_vecs(&self.widgets_to_harvest, self.last_collection_time)
+            {
                 if let Some(mut proc) = data.procs {
                     local_gpu_pids.append(&mut proc.1);
                     local_gpu_total_mem += proc.0;
@@ -420,6 +564,7 @@ impl DataCollector {
             }
 
             self.data.gpu = (!local_gpu.is_empty()).then_some(local_gpu);
+            self.data.gpu_stats = (!local_gpu_stats.is_empty()).then_some(local_gpu_stats);
             self.gpu_pids = (!local_gpu_pids.is_empty()).then_some(local_gpu_pids);
             self.gpus_total_mem = (local_gpu_total_mem > 0).then_some(local_gpu_total_mem);
         }
@@ -430,6 +575,15 @@ impl DataCollector {
         if self.widgets_to_harvest.use_cpu {
             self.data.cpu = cpu::get_cpu_data_list(&self.sys.system, self.show_average_cpu).ok();
 
+            #[cfg(target_os = "linux")]
+            if self.group_cpus_by_numa && self.numa_nodes.len() > 1 {
+                self.data.cpu = self
+                    .data
+                    .cpu
+                    .take()
+                    .map(|cpus| numa::group_cpus_by_node(cpus, &self.numa_nodes));
+            }
+
             #[cfg(unix)]
             {
                 self.data.load_avg = Some(cpu::get_load_avg());
@@ -437,6 +591,37 @@ impl DataCollector {
         }
     }
 
+    /// Gets CPU and DRAM power draw from RAPL energy counters.
+    #[cfg(target_os = "linux")]
+    #[inline]
+    fn update_cpu_power(&mut self) {
+        if self.widgets_to_harvest.use_cpu_power {
+            if self.rapl_zones.is_none() {
+                let (zones, unreadable) = cpu::rapl::get_rapl_zones();
+                self.rapl_zones = Some(zones);
+                self.rapl_unreadable = unreadable;
+            }
+
+            let zones = self.rapl_zones.as_deref().unwrap_or_default();
+            let energy = cpu::rapl::read_energy(zones);
+
+            if let Some(prev) = &self.prev_rapl_energy {
+                let elapsed = self
+                    .data
+                    .collection_time
+                    .duration_since(self.last_collection_time);
+                self.data.cpu_power = Some(cpu::rapl::power(zones, prev, &energy, elapsed));
+
+                // The first update is thrown away, so report these along with the first readings.
+                if !self.rapl_unreadable.is_empty() {
+                    self.data.rapl_unreadable = Some(std::mem::take(&mut self.rapl_unreadable));
+                }
+            }
+
+            self.prev_rapl_energy = Some(energy);
+        }
+    }
+
     #[inline]
     fn update_processes(&mut self) {
         if self.widgets_to_harvest.use_proc {
@@ -503,6 +688,15 @@ impl DataCollector {
 
                     self.data.arc = Some(arc.0);
                 }
+
+                #[cfg(target_os = "linux")]
+                if let Some(counters) = memory::arc::get_arc_counters() {
+                    if let Some(prev) = &self.prev_arc {
+                        self.data.arc_detail = Some(counters.detail(prev));
+                    }
+
+                    self.prev_arc = Some(counters);
+                }
             }
 
             #[cfg(not(target_os = "windows"))]
@@ -511,6 +705,46 @@ impl DataCollector {
             }
 
             self.data.swap = memory::get_swap_usage(&self.sys.system);
+
+            #[cfg(target_os = "linux")]
+            {
+                let total_memory = self.total_memory();
+                self.data.zram = memory::zram::get_zram_usage(total_memory);
+                self.data.zswap = memory::zswap::get_zswap_usage(total_memory);
+
+                // There's no point showing per-node usage if there's only one node.
+                if self.numa_nodes.len() > 1 {
+                    self.data.numa_memory = Some(numa::get_numa_memory(&self.numa_nodes));
+                }
+            }
+        }
+    }
+
+    /// Gets memory pressure rates from `/proc/vmstat`, as well as the latest OOM
+    /// victim if there were any new OOM kills since the last update.
+    #[cfg(target_os = "linux")]
+    #[inline]
+    fn update_vmstat(&mut self) {
+        if self.widgets_to_harvest.use_vmstat {
+            let Some(counters) = memory::vmstat::get_vmstat_counters() else {
+                return;
+            };
+
+            if let Some(prev) = &self.prev_vmstat {
+                let elapsed = self
+                    .data
+                    .collection_time
+                    .duration_since(self.last_collection_time);
+                let mut harvest = counters.rates(prev, elapsed);
+
+                if harvest.new_oom_kills > 0 {
+                    harvest.oom_victim = memory::vmstat::get_latest_oom_victim();
+                }
+
+                self.data.vmstat = Some(harvest);
+            }
+
+            self.prev_vmstat = Some(counters);
         }
     }
 
@@ -522,6 +756,7 @@ impl DataCollector {
                 self.last_collection_time,
                 &mut self.total_rx,
                 &mut self.total_tx,
+                &mut self.prev_interface_counters,
                 self.data.collection_time,
                 &self.filters.net_filter,
             );
@@ -532,6 +767,29 @@ impl DataCollector {
         }
     }
 
+    /// Gets TCP and UDP rates from `/proc/net/snmp` and `/proc/net/netstat`, as well
+    /// as the number of TCP connections in each state.
+    #[cfg(target_os = "linux")]
+    #[inline]
+    fn update_tcp(&mut self) {
+        if self.widgets_to_harvest.use_tcp {
+            let Some(counters) = network::tcp::get_tcp_counters() else {
+                return;
+            };
+
+            if let Some(prev) = &self.prev_tcp {
+                let elapsed = self
+                    .data
+                    .collection_time
+                    .duration_since(self.last_collection_time);
+
+                self.data.tcp = Some(counters.rates(prev, elapsed, network::tcp::get_tcp_states()));
+            }
+
+            self.prev_tcp = Some(counters);
+        }
+    }
+
     /// Update battery information.
     ///
     /// If the battery manager is not initialized, it will attempt to initialize it if at least one battery is found.
@@ -592,6 +850,36 @@ impl DataCollector {
         if self.widgets_to_harvest.use_disk {
             self.data.disks = disks::get_disk_usage(self).ok();
             self.data.io = disks::get_io_usage().ok();
+
+            #[cfg(target_os = "linux")]
+            {
+                self.data.block_topology = disks::get_block_topology().ok();
+            }
+
+            #[cfg(all(feature = "zfs", target_os = "linux"))]
+            {
+                // Pools and datasets have no mount point of their own here.
+                let keep = |name: &str, fs_type: &str| {
+                    disks::keep_disk_entry(
+                        name,
+                        "",
+                        &self.filters.disk_filter,
+                        &self.filters.mount_filter,
+                    ) && disks::keep_fs_type(Some(fs_type), &self.filters.fs_type_filter)
+                };
+
+                let zpools = disks::get_zpools()
+                    .into_iter()
+                    .filter(|zpool| keep(&zpool.name, "zpool"))
+                    .map(|mut zpool| {
+                        zpool
+                            .datasets
+                            .retain(|dataset| keep(&dataset.dataset_name, "zfs"));
+                        zpool
+                    })
+                    .collect();
+                self.data.zpools = Some(zpools);
+            }
         }
     }
 
@@ -629,6 +917,7 @@ mod tests {
         let mut collector = DataCollector::new(DataFilters {
             disk_filter: None,
             mount_filter: None,
+            fs_type_filter: None,
             temp_filter: None,
             net_filter: None,
         });
@@ -4,23 +4,20 @@ use std::{
     fs::{self, read_to_string},
     num::NonZeroU64,
     path::{Path, PathBuf},
-    sync::{LazyLock, Mutex},
-    time::{Duration, Instant},
 };
 
-use nohash::IntMap;
-use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
-
 use super::linux::utils::is_device_awake;
 use crate::{
     app::layout_manager::UsedWidgets,
-    collection::{memory::MemData, processes::Pid},
+    collection::{gpu::GpuStats, memory::MemData},
 };
 
 // TODO: May be able to clean up some of these, Option<Vec> for example is a bit redundant.
 pub struct AmdGpuData {
     pub memory: Option<Vec<(String, MemData)>>,
-    pub procs: Option<(u64, Vec<IntMap<Pid, (u64, u32)>>)>,
+    pub stats: Option<Vec<GpuStats>>,
+    /// The total VRAM across all devices, which process memory usage is measured against.
+    pub total_mem: u64,
 }
 
 pub struct AmdGpuMemory {
@@ -28,23 +25,6 @@ pub struct AmdGpuMemory {
     pub used: u64,
 }
 
-#[derive(Debug, Clone, Default, Eq, PartialEq)]
-pub struct AmdGpuProc {
-    pub vram_usage: u64,
-    pub gfx_usage: u64,
-    pub dma_usage: u64,
-    pub enc_usage: u64,
-    pub dec_usage: u64,
-    pub uvd_usage: u64,
-    pub vcn_usage: u64,
-    pub vpe_usage: u64,
-    pub compute_usage: u64,
-}
-
-// needs previous state for usage calculation
-static PROC_DATA: LazyLock<Mutex<HashMap<PathBuf, IntMap<Pid, AmdGpuProc>>>> =
-    LazyLock::new(|| Mutex::new(HashMap::default()));
-
 fn get_amd_devs() -> Option<Vec<PathBuf>> {
     let mut devices = Vec::new();
 
@@ -149,190 +129,79 @@ fn get_amd_vram(device_path: &Path) -> Option<AmdGpuMemory> {
     })
 }
 
-// from amdgpu_top: https://github.com/Umio-Yasuno/amdgpu_top/blob/c961cf6625c4b6d63fda7f03348323048563c584/crates/libamdgpu_top/src/stat/fdinfo/proc_info.rs#L114
-fn diff_usage(pre: u64, cur: u64, interval: &Duration) -> u64 {
-    use std::ops::Mul;
-
-    let diff_ns = if pre == 0 || cur < pre {
-        return 0;
-    } else {
-        cur.saturating_sub(pre) as u128
-    };
-
-    diff_ns
-        .mul(100)
-        .checked_div(interval.as_nanos())
-        .unwrap_or(0) as u64
+fn read_value(path: &Path) -> Option<f64> {
+    read_to_string(path).ok()?.trim().parse().ok()
 }
 
-// from amdgpu_top: https://github.com/Umio-Yasuno/amdgpu_top/blob/c961cf6625c4b6d63fda7f03348323048563c584/crates/libamdgpu_top/src/stat/fdinfo/proc_info.rs#L13-L27
-fn get_amdgpu_pid_fds(pid: Pid, device_path: Vec<PathBuf>) -> Option<Vec<u32>> {
-    let Ok(fd_list) = fs::read_dir(format!("/proc/{pid}/fd/")) else {
-        return None;
-    };
-
-    let valid_fds: Vec<u32> = fd_list
-        .filter_map(|fd_link| {
-            let dir_entry = fd_link.map(|fd_link| fd_link.path()).ok()?;
-            let link = fs::read_link(&dir_entry).ok()?;
-
-            // e.g. "/dev/dri/renderD128" or "/dev/dri/card0"
-            if device_path.iter().any(|path| link.starts_with(path)) {
-                dir_entry.file_name()?.to_str()?.parse::<u32>().ok()
-            } else {
-                None
-            }
-        })
-        .collect();
-
-    if valid_fds.is_empty() {
-        None
-    } else {
-        Some(valid_fds)
-    }
+/// Returns the current clock in MHz from a `pp_dpm_*` file, which lists the available clock
+/// levels with the current one marked by a `*` (e.g. `1: 1800Mhz *`).
+fn parse_dpm_clock(text: &str) -> Option<u32> {
+    let line = text.lines().find(|line| line.trim_end().ends_with('*'))?;
+    let (_, level) = line.split_once(':')?;
+    let level = level
+        .trim()
+        .trim_end_matches('*')
+        .trim()
+        .to_ascii_lowercase();
+
+    level.strip_suffix("mhz")?.trim().parse().ok()
 }
 
-fn get_amdgpu_drm(device_path: &Path) -> Option<Vec<PathBuf>> {
-    let mut drm_devices = Vec::new();
-    let drm_root = device_path.join("drm");
-
-    let Ok(drm_paths) = fs::read_dir(drm_root) else {
-        return None;
+/// Returns the activity of an AMD GPU from its sysfs directory (e.g.
+/// `/sys/module/amdgpu/drivers/pci:amdgpu/0000:03:00.0`).
+fn get_amd_stats(device_path: &Path, name: String) -> GpuStats {
+    let hwmon = fs::read_dir(device_path.join("hwmon"))
+        .ok()
+        .and_then(|entries| entries.flatten().map(|entry| entry.path()).min());
+    let hwmon_value = |file: &str| {
+        hwmon
+            .as_ref()
+            .and_then(|hwmon| read_value(&hwmon.join(file)))
     };
-
-    for drm_dir in drm_paths {
-        let Ok(drm_dir) = drm_dir else {
-            continue;
-        };
-
-        // attempt to get the device renderer name
-        let drm_name = drm_dir.file_name();
-        let Some(drm_name) = drm_name.to_str() else {
-            continue;
-        };
-
-        // construct driver device path if valid
-        if !drm_name.starts_with("card") && !drm_name.starts_with("render") {
-            continue;
-        }
-
-        drm_devices.push(PathBuf::from(format!("/dev/dri/{drm_name}")));
-    }
-
-    if drm_devices.is_empty() {
-        None
-    } else {
-        Some(drm_devices)
-    }
-}
-
-fn get_amd_fdinfo(device_path: &Path) -> Option<IntMap<Pid, AmdGpuProc>> {
-    let mut fdinfo = IntMap::default();
-
-    let drm_paths = get_amdgpu_drm(device_path)?;
-
-    let Ok(proc_dir) = fs::read_dir("/proc") else {
-        return None;
+    let dpm_clock = |file: &str| {
+        read_to_string(device_path.join(file))
+            .ok()
+            .and_then(|text| parse_dpm_clock(&text))
     };
 
-    let pids: Vec<Pid> = proc_dir
-        .filter_map(|dir_entry| {
-            // check if pid is valid
-            let dir_entry = dir_entry.ok()?;
-            let metadata = dir_entry.metadata().ok()?;
-
-            if !metadata.is_dir() {
-                return None;
-            }
-
-            let pid = dir_entry.file_name().to_str()?.parse::<Pid>().ok()?;
-
-            // skip init process
-            if pid == 1 {
-                return None;
-            }
-
-            Some(pid)
-        })
-        .collect();
-
-    for pid in pids {
-        // collect file descriptors that point to our device renderers
-        let Some(fds) = get_amdgpu_pid_fds(pid, drm_paths.clone()) else {
-            continue;
-        };
-
-        let mut usage: AmdGpuProc = Default::default();
-
-        let mut observed_ids: HashSet<usize> = HashSet::default();
-
-        for fd in fds {
-            let fdinfo_path = format!("/proc/{pid}/fdinfo/{fd}");
-            let Ok(fdinfo_data) = read_to_string(fdinfo_path) else {
-                continue;
-            };
-
-            let mut fdinfo_lines = fdinfo_data
-                .lines()
-                .skip_while(|l| !l.starts_with("drm-client-id"));
-            if let Some(id) = fdinfo_lines.next().and_then(|fdinfo_line| {
-                const LEN: usize = "drm-client-id:\t".len();
-                fdinfo_line.get(LEN..)?.parse().ok()
-            }) {
-                if !observed_ids.insert(id) {
-                    continue;
-                }
-            } else {
-                continue;
-            }
-
-            for fdinfo_line in fdinfo_lines {
-                let Some(fdinfo_separator_index) = fdinfo_line.find(':') else {
-                    continue;
-                };
-
-                let (fdinfo_keyword, mut fdinfo_value) =
-                    fdinfo_line.split_at(fdinfo_separator_index);
-                fdinfo_value = &fdinfo_value[1..];
-
-                fdinfo_value = fdinfo_value.trim();
-                if let Some(fdinfo_value_space_index) = fdinfo_value.find(' ') {
-                    fdinfo_value = &fdinfo_value[..fdinfo_value_space_index];
-                };
-
-                let Ok(fdinfo_value_num) = fdinfo_value.parse::<u64>() else {
-                    continue;
-                };
-
-                match fdinfo_keyword {
-                    "drm-engine-gfx" => usage.gfx_usage += fdinfo_value_num,
-                    "drm-engine-dma" => usage.dma_usage += fdinfo_value_num,
-                    "drm-engine-dec" => usage.dec_usage += fdinfo_value_num,
-                    "drm-engine-enc" => usage.enc_usage += fdinfo_value_num,
-                    "drm-engine-enc_1" => usage.uvd_usage += fdinfo_value_num,
-                    "drm-engine-jpeg" => usage.vcn_usage += fdinfo_value_num,
-                    "drm-engine-vpe" => usage.vpe_usage += fdinfo_value_num,
-                    "drm-engine-compute" => usage.compute_usage += fdinfo_value_num,
-                    "drm-memory-vram" => usage.vram_usage += fdinfo_value_num << 10, // KiB -> B
-                    _ => {}
-                };
-            }
-        }
+    // Prefer the fan's PWM duty cycle, and fall back to its speed against its maximum.
+    let fan = match hwmon_value("pwm1") {
+        Some(pwm) => Some(pwm / hwmon_value("pwm1_max").unwrap_or(255.0) * 100.0),
+        None => hwmon_value("fan1_input")
+            .zip(hwmon_value("fan1_max"))
+            .filter(|(_, max)| *max > 0.0)
+            .map(|(rpm, max)| rpm / max * 100.0),
+    };
 
-        if usage != Default::default() {
-            fdinfo.insert(pid, usage);
-        }
+    GpuStats {
+        id: device_path
+            .file_name()
+            .and_then(|id| id.to_str())
+            .map_or_else(|| name.clone(), str::to_string),
+        utilization: read_value(&device_path.join("gpu_busy_percent")),
+        // These aren't exposed through sysfs.
+        encoder: None,
+        decoder: None,
+        // Temperatures are reported in millidegrees, and power in microwatts.
+        temperature: hwmon_value("temp1_input").map(|temp| (temp / 1000.0) as f32),
+        power: hwmon_value("power1_average")
+            .or_else(|| hwmon_value("power1_input"))
+            .map(|power| power / 1_000_000.0),
+        power_limit: hwmon_value("power1_cap").map(|power| power / 1_000_000.0),
+        core_clock: dpm_clock("pp_dpm_sclk"),
+        memory_clock: dpm_clock("pp_dpm_mclk"),
+        fan: fan.map(|fan| fan.min(100.0)),
+        name,
     }
-
-    Some(fdinfo)
 }
 
-pub fn get_amd_vecs(widgets_to_harvest: &UsedWidgets, prev_time: Instant) -> Option<AmdGpuData> {
+/// Returns the memory and activity of AMD GPUs. Per-process usage is read from DRM fdinfo, see
+/// [`super::drm`].
+pub fn get_amd_vecs(widgets_to_harvest: &UsedWidgets) -> Option<AmdGpuData> {
     let device_path_list = get_amd_devs()?;
-    let interval = Instant::now().duration_since(prev_time);
     let num_gpu = device_path_list.len();
     let mut mem_vec = Vec::with_capacity(num_gpu);
-    let mut proc_vec = Vec::with_capacity(num_gpu);
+    let mut stats_vec = Vec::with_capacity(num_gpu);
     let mut total_mem = 0;
 
     for device_path in device_path_list {
@@ -355,60 +224,79 @@ pub fn get_amd_vecs(widgets_to_harvest: &UsedWidgets, prev_time: Instant) -> Opt
             total_mem += mem.total
         }
 
-        if widgets_to_harvest.use_proc {
-            if let Some(procs) = get_amd_fdinfo(&device_path) {
-                let mut proc_info = PROC_DATA.lock().expect("mutex is poisoned");
-                let prev_fdinfo = proc_info.entry(device_path).or_default();
-
-                let mut procs_map = IntMap::default();
-                for (proc_pid, proc_usage) in procs {
-                    if let Some(prev_usage) = prev_fdinfo.get_mut(&proc_pid) {
-                        // calculate deltas
-                        let gfx_usage =
-                            diff_usage(prev_usage.gfx_usage, proc_usage.gfx_usage, &interval);
-                        let dma_usage =
-                            diff_usage(prev_usage.dma_usage, proc_usage.dma_usage, &interval);
-                        let enc_usage =
-                            diff_usage(prev_usage.enc_usage, proc_usage.enc_usage, &interval);
-                        let dec_usage =
-                            diff_usage(prev_usage.dec_usage, proc_usage.dec_usage, &interval);
-                        let uvd_usage =
-                            diff_usage(prev_usage.uvd_usage, proc_usage.uvd_usage, &interval);
-                        let vcn_usage =
-                            diff_usage(prev_usage.vcn_usage, proc_usage.vcn_usage, &interval);
-                        let vpe_usage =
-                            diff_usage(prev_usage.vpe_usage, proc_usage.vpe_usage, &interval);
-
-                        // combined usage
-                        let gpu_util_wide = gfx_usage
-                            + dma_usage
-                            + enc_usage
-                            + dec_usage
-                            + uvd_usage
-                            + vcn_usage
-                            + vpe_usage;
-
-                        let gpu_util: u32 = gpu_util_wide.try_into().unwrap_or(0);
-
-                        if gpu_util > 0 || proc_usage.vram_usage > 0 {
-                            procs_map.insert(proc_pid, (proc_usage.vram_usage, gpu_util));
-                        }
-
-                        *prev_usage = proc_usage;
-                    } else {
-                        prev_fdinfo.insert(proc_pid, proc_usage);
-                    }
-                }
-
-                if !procs_map.is_empty() {
-                    proc_vec.push(procs_map);
-                }
-            }
+        if widgets_to_harvest.use_gpu_stats {
+            stats_vec.push(get_amd_stats(&device_path, device_name.clone()));
         }
     }
 
     Some(AmdGpuData {
         memory: (!mem_vec.is_empty()).then_some(mem_vec),
-        procs: (!proc_vec.is_empty()).then_some((total_mem, proc_vec)),
+        stats: (!stats_vec.is_empty()).then_some(stats_vec),
+        total_mem,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_dpm_clock() {
+        assert_eq!(
+            parse_dpm_clock("0: 500Mhz \n1: 1800Mhz *\n2: 2100Mhz \n"),
+            Some(1800)
+        );
+        assert_eq!(parse_dpm_clock("0: 96Mhz *\n1: 1000Mhz \n"), Some(96));
+        assert_eq!(parse_dpm_clock("0: 500Mhz \n1: 1800Mhz \n"), None);
+        assert_eq!(parse_dpm_clock(""), None);
+    }
+
+    #[test]
+    fn test_get_amd_stats() {
+        let dir = tempfile::tempdir().unwrap();
+        let device = dir.path().join("0000:03:00.0");
+        let hwmon = device.join("hwmon").join("hwmon4");
+        fs::create_dir_all(&hwmon).unwrap();
+
+        let files = [
+            (device.join("gpu_busy_percent"), "37\n"),
+            (device.join("pp_dpm_sclk"), "0: 500Mhz \n1: 2304Mhz *\n"),
+            (
+                device.join("pp_dpm_mclk"),
+                "0: 96Mhz \n1: 456Mhz \n2: 1250Mhz *\n",
+            ),
+            (hwmon.join("temp1_input"), "54000\n"),
+            (hwmon.join("power1_average"), "123000000\n"),
+            (hwmon.join("power1_cap"), "250000000\n"),
+            (hwmon.join("pwm1"), "102\n"),
+            (hwmon.join("pwm1_max"), "255\n"),
+        ];
+        for (path, contents) in files {
+            fs::write(path, contents).unwrap();
+        }
+
+        let stats = get_amd_stats(&device, "Radeon".to_string());
+        assert_eq!(stats.id, "0000:03:00.0");
+        assert_eq!(stats.name, "Radeon");
+        assert_eq!(stats.utilization, Some(37.0));
+        assert_eq!(stats.encoder, None);
+        assert_eq!(stats.decoder, None);
+        assert_eq!(stats.temperature, Some(54.0));
+        assert_eq!(stats.power, Some(123.0));
+        assert_eq!(stats.power_limit, Some(250.0));
+        assert_eq!(stats.core_clock, Some(2304));
+        assert_eq!(stats.memory_clock, Some(1250));
+        assert_eq!(stats.fan, Some(40.0));
+
+        // Older cards only report the current power draw, and the fan speed in RPM.
+        fs::remove_file(hwmon.join("power1_average")).unwrap();
+        fs::remove_file(hwmon.join("pwm1")).unwrap();
+        fs::write(hwmon.join("power1_input"), "50000000\n").unwrap();
+        fs::write(hwmon.join("fan1_input"), "1500\n").unwrap();
+        fs::write(hwmon.join("fan1_max"), "3000\n").unwrap();
+
+        let stats = get_amd_stats(&device, "Radeon".to_string());
+        assert_eq!(stats.power, Some(50.0));
+        assert_eq!(stats.fan, Some(50.0));
+    }
+}
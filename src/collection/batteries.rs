@@ -11,8 +11,10 @@
 //! For more information, refer to the [starship_battery](https://github.com/starship/rust-battery) repo/docs.
 
 use starship_battery::{
-    Battery, Manager, State,
-    units::{power::watt, ratio::percent, time::second},
+    Battery, Manager, State, Technology,
+    units::{
+        electric_potential::volt, energy::watt_hour, power::watt, ratio::percent, time::second,
+    },
 };
 
 /// Battery state.
@@ -46,6 +48,8 @@ impl BatteryState {
 
 #[derive(Debug, Clone)]
 pub struct BatteryData {
+    /// An identifier that stays the same across refreshes.
+    pub id: String,
     /// Current charge percent.
     pub charge_percent: f64,
     /// Power consumption, in watts.
@@ -54,6 +58,20 @@ pub struct BatteryData {
     pub health_percent: f64,
     /// The current battery "state" (e.g. is it full, charging, etc.).
     pub state: BatteryState,
+    /// Energy when full, in watt-hours.
+    pub energy_full: f64,
+    /// Energy when full as designed, in watt-hours.
+    pub energy_full_design: f64,
+    /// Current voltage, in volts.
+    pub voltage: f64,
+    /// Number of charge cycles, if reported.
+    pub cycle_count: Option<u32>,
+    /// Battery chemistry (e.g. lithium-ion), if known.
+    pub technology: Option<String>,
+    /// Battery vendor, if reported.
+    pub vendor: Option<String>,
+    /// Battery model, if reported.
+    pub model: Option<String>,
 }
 
 impl BatteryData {
@@ -64,14 +82,26 @@ impl BatteryData {
     pub fn health(&self) -> String {
         format!("{:.2}%", self.health_percent)
     }
+
+    /// The current full capacity against the designed one, if the latter is reported.
+    pub fn capacity(&self) -> Option<String> {
+        (self.energy_full_design > 0.0)
+            .then(|| format!("{:.1}/{:.1}Wh", self.energy_full, self.energy_full_design))
+    }
+
+    pub fn voltage(&self) -> Option<String> {
+        (self.voltage > 0.0).then(|| format!("{:.2}V", self.voltage))
+    }
 }
 
 pub fn refresh_batteries(manager: &Manager, batteries: &mut [Battery]) -> Vec<BatteryData> {
     batteries
         .iter_mut()
-        .filter_map(|battery| {
+        .enumerate()
+        .filter_map(|(index, battery)| {
             if manager.refresh(battery).is_ok() {
                 Some(BatteryData {
+                    id: battery_id(battery, index),
                     charge_percent: f64::from(battery.state_of_charge().get::<percent>()),
                     power_consumption: f64::from(battery.energy_rate().get::<watt>()),
                     health_percent: f64::from(battery.state_of_health().get::<percent>()),
@@ -92,6 +122,16 @@ pub fn refresh_batteries(manager: &Manager, batteries: &mut [Battery]) -> Vec<Ba
                         State::Empty => BatteryState::Empty,
                         State::Full => BatteryState::Full,
                     },
+                    energy_full: f64::from(battery.energy_full().get::<watt_hour>()),
+                    energy_full_design: f64::from(battery.energy_full_design().get::<watt_hour>()),
+                    voltage: f64::from(battery.voltage().get::<volt>()),
+                    cycle_count: battery.cycle_count(),
+                    technology: match battery.technology() {
+                        Technology::Unknown => None,
+                        technology => Some(technology.to_string()),
+                    },
+                    vendor: non_empty(battery.vendor()),
+                    model: non_empty(battery.model()),
                 })
             } else {
                 None
@@ -99,3 +139,28 @@ pub fn refresh_batteries(manager: &Manager, batteries: &mut [Battery]) -> Vec<Ba
         })
         .collect::<Vec<_>>()
 }
+
+/// Returns the vendor, model and serial number of a battery if it reports a serial number, or
+/// its position in the list of batteries otherwise.
+fn battery_id(battery: &Battery, index: usize) -> String {
+    match non_empty(battery.serial_number()) {
+        Some(serial) => [
+            non_empty(battery.vendor()),
+            non_empty(battery.model()),
+            Some(serial),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" "),
+        None => format!("#{index}"),
+    }
+}
+
+/// Some drivers report empty strings rather than nothing at all.
+fn non_empty(value: Option<&str>) -> Option<String> {
+    value
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
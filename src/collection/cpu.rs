@@ -1,14 +1,20 @@
-//! Data collection for CPU usage and load average.
+//! Data collection for CPU usage, load average and power draw.
 
 pub mod sysinfo;
 pub use self::sysinfo::*;
 
+#[cfg(target_os = "linux")]
+pub mod rapl;
+
 pub type LoadAvgHarvest = [f32; 3];
 
 #[derive(Debug, Clone, Copy)]
 pub enum CpuDataType {
     Avg,
     Cpu(usize),
+    /// The average usage of the CPUs in a NUMA node.
+    #[cfg(target_os = "linux")]
+    Node(usize),
 }
 
 #[derive(Debug, Clone)]
@@ -18,3 +24,35 @@ pub struct CpuData {
 }
 
 pub type CpuHarvest = Vec<CpuData>;
+
+/// What a power reading measures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PowerDomain {
+    /// The whole CPU package, including the cores and uncore.
+    Package,
+    /// Just the cores.
+    Core,
+    /// The parts of the package outside the cores, usually an integrated GPU.
+    Uncore,
+    /// Memory attached to the CPU.
+    Dram,
+}
+
+impl PowerDomain {
+    /// A short name for the domain, as used in legends.
+    pub fn short_name(&self) -> &'static str {
+        match self {
+            PowerDomain::Package => "PKG",
+            PowerDomain::Core => "CORE",
+            PowerDomain::Uncore => "UNCORE",
+            PowerDomain::Dram => "DRAM",
+        }
+    }
+}
+
+/// The power draw of a domain, summed over all sockets.
+#[derive(Debug, Clone, Copy)]
+pub struct CpuPower {
+    pub domain: PowerDomain,
+    pub watts: f64,
+}
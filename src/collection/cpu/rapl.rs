@@ -0,0 +1,226 @@
+//! CPU and DRAM power draw from RAPL (Running Average Power Limit) energy counters, based on
+//! `/sys/class/powercap`. Linux-only.
+//!
+//! Both Intel and AMD CPUs expose these as `intel-rapl:*` zones. Each zone has a cumulative
+//! energy counter in microjoules that wraps around at `max_energy_range_uj`, so we keep the
+//! previous reading around and report the average power in between.
+
+use std::{
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use super::{CpuPower, PowerDomain};
+
+const POWERCAP: &str = "/sys/class/powercap";
+
+/// A RAPL zone with a readable energy counter.
+#[derive(Debug, Clone)]
+pub(crate) struct RaplZone {
+    domain: PowerDomain,
+    energy_path: PathBuf,
+    max_energy_range_uj: u64,
+}
+
+/// Returns the domain a zone measures, from its `name` (e.g. `package-0` or `dram`).
+fn parse_domain(name: &str) -> Option<PowerDomain> {
+    match name {
+        "core" => Some(PowerDomain::Core),
+        "uncore" => Some(PowerDomain::Uncore),
+        "dram" => Some(PowerDomain::Dram),
+        name if name.starts_with("package-") => Some(PowerDomain::Package),
+        _ => None,
+    }
+}
+
+fn read_u64(path: &Path) -> std::io::Result<u64> {
+    fs::read_to_string(path)?
+        .trim()
+        .parse()
+        .map_err(|err| std::io::Error::new(ErrorKind::InvalidData, err))
+}
+
+/// Finds the RAPL zones in `powercap`. Also returns the names of zones whose energy counter
+/// can't be read due to permissions, which is the default for non-root users on newer kernels.
+pub(crate) fn read_rapl_zones(powercap: &Path) -> (Vec<RaplZone>, Vec<String>) {
+    let mut zones = vec![];
+    let mut unreadable = vec![];
+
+    let Ok(entries) = fs::read_dir(powercap) else {
+        return (zones, unreadable);
+    };
+
+    let mut entries = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .collect::<Vec<_>>();
+    entries.sort();
+
+    for zone_dir in entries {
+        // `intel-rapl-mmio` zones are the same package domains through a different interface,
+        // so skip them to avoid counting the same energy twice.
+        let is_rapl = zone_dir
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with("intel-rapl:"));
+        if !is_rapl {
+            continue;
+        }
+
+        let Ok(name) = fs::read_to_string(zone_dir.join("name")) else {
+            continue;
+        };
+        let name = name.trim();
+        let Some(domain) = parse_domain(name) else {
+            continue;
+        };
+
+        let energy_path = zone_dir.join("energy_uj");
+        match read_u64(&energy_path) {
+            Ok(_) => {
+                if let Ok(max_energy_range_uj) = read_u64(&zone_dir.join("max_energy_range_uj")) {
+                    zones.push(RaplZone {
+                        domain,
+                        energy_path,
+                        max_energy_range_uj,
+                    });
+                }
+            }
+            Err(err) if err.kind() == ErrorKind::PermissionDenied => {
+                unreadable.push(name.to_string());
+            }
+            Err(_) => {}
+        }
+    }
+
+    (zones, unreadable)
+}
+
+/// Finds the RAPL zones on this system, and the names of any that can't be read.
+pub(crate) fn get_rapl_zones() -> (Vec<RaplZone>, Vec<String>) {
+    read_rapl_zones(Path::new(POWERCAP))
+}
+
+/// Reads the energy counter of each zone, in microjoules.
+pub(crate) fn read_energy(zones: &[RaplZone]) -> Vec<Option<u64>> {
+    zones
+        .iter()
+        .map(|zone| read_u64(&zone.energy_path).ok())
+        .collect()
+}
+
+/// The energy used between two readings of a counter that wraps around after `max_range`.
+fn energy_delta(prev: u64, curr: u64, max_range: u64) -> u64 {
+    if curr >= prev {
+        curr - prev
+    } else {
+        max_range.saturating_sub(prev) + curr
+    }
+}
+
+/// Calculates the power draw of each domain between two readings, summing up zones of the same
+/// domain (e.g. the packages of a multi-socket system). Domains without any readings are left
+/// out.
+pub(crate) fn power(
+    zones: &[RaplZone], prev: &[Option<u64>], curr: &[Option<u64>], elapsed: Duration,
+) -> Vec<CpuPower> {
+    let elapsed = elapsed.as_secs_f64();
+    if elapsed <= 0.0 {
+        return vec![];
+    }
+
+    let mut power: Vec<CpuPower> = vec![];
+    for ((zone, prev), curr) in zones.iter().zip(prev).zip(curr) {
+        let (Some(prev), Some(curr)) = (prev, curr) else {
+            continue;
+        };
+
+        let watts = energy_delta(*prev, *curr, zone.max_energy_range_uj) as f64 / elapsed / 1e6;
+        match power.iter_mut().find(|power| power.domain == zone.domain) {
+            Some(power) => power.watts += watts,
+            None => power.push(CpuPower {
+                domain: zone.domain,
+                watts,
+            }),
+        }
+    }
+
+    power.sort_by_key(|power| power.domain);
+    power
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_zone(powercap: &Path, zone: &str, name: &str, energy: u64) {
+        let zone_dir = powercap.join(zone);
+        fs::create_dir_all(&zone_dir).unwrap();
+        fs::write(zone_dir.join("name"), format!("{name}\n")).unwrap();
+        fs::write(zone_dir.join("energy_uj"), format!("{energy}\n")).unwrap();
+        fs::write(zone_dir.join("max_energy_range_uj"), "262143328850\n").unwrap();
+    }
+
+    #[test]
+    fn test_energy_delta() {
+        assert_eq!(energy_delta(1_000, 3_500, 10_000), 2_500);
+        assert_eq!(energy_delta(9_000, 500, 10_000), 1_500);
+        assert_eq!(energy_delta(500, 500, 10_000), 0);
+    }
+
+    #[test]
+    fn test_read_rapl_zones() {
+        let powercap = tempfile::tempdir().unwrap();
+        let path = powercap.path();
+
+        write_zone(path, "intel-rapl:0", "package-0", 1_000_000);
+        write_zone(path, "intel-rapl:0:0", "core", 600_000);
+        write_zone(path, "intel-rapl:0:1", "uncore", 100_000);
+        write_zone(path, "intel-rapl:0:2", "dram", 200_000);
+        write_zone(path, "intel-rapl:1", "package-1", 262_142_328_850);
+        write_zone(path, "intel-rapl:2", "psys", 5_000_000);
+        write_zone(path, "intel-rapl-mmio:0", "package-0", 1_000_000);
+
+        let (zones, unreadable) = read_rapl_zones(path);
+        assert!(unreadable.is_empty());
+
+        let domains = zones.iter().map(|zone| zone.domain).collect::<Vec<_>>();
+        assert_eq!(
+            domains,
+            vec![
+                PowerDomain::Package,
+                PowerDomain::Core,
+                PowerDomain::Uncore,
+                PowerDomain::Dram,
+                PowerDomain::Package,
+            ]
+        );
+
+        let prev = read_energy(&zones);
+        assert_eq!(prev[0], Some(1_000_000));
+
+        // Half a second later, with the second package's counter having wrapped around.
+        write_zone(path, "intel-rapl:0", "package-0", 11_000_000);
+        write_zone(path, "intel-rapl:0:0", "core", 5_600_000);
+        write_zone(path, "intel-rapl:0:2", "dram", 1_200_000);
+        write_zone(path, "intel-rapl:1", "package-1", 9_000_000);
+        fs::remove_file(path.join("intel-rapl:0:1").join("energy_uj")).unwrap();
+
+        let curr = read_energy(&zones);
+        let power = power(&zones, &prev, &curr, Duration::from_millis(500));
+        let watts = power
+            .iter()
+            .map(|power| (power.domain, power.watts.round()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            watts,
+            vec![
+                (PowerDomain::Package, 40.0),
+                (PowerDomain::Core, 10.0),
+                (PowerDomain::Dram, 2.0),
+            ]
+        );
+    }
+}
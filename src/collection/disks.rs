@@ -17,7 +17,11 @@ cfg_if! {
         mod unix;
         #[cfg(feature = "zfs")]
         mod zfs_io_counters;
+        #[cfg(feature = "zfs")]
+        mod zpools;
         pub(crate) use self::unix::*;
+        #[cfg(feature = "zfs")]
+        pub use self::zpools::*;
     } else if #[cfg(target_os = "macos")] {
         mod unix;
         pub(crate) use self::unix::*;
@@ -45,16 +49,155 @@ pub struct DiskHarvest {
     pub free_space: Option<u64>,
     pub used_space: Option<u64>,
     pub total_space: Option<u64>,
+
+    /// The filesystem type (e.g. `ext4`), if known.
+    pub fs_type: Option<String>,
+
+    /// The mount options (e.g. `rw,noatime`), if known. Some platforms only
+    /// report whether the mount is read-only (`ro`) or not (`rw`).
+    pub mount_options: Option<String>,
+
+    pub inodes_free: Option<u64>,
+    pub inodes_used: Option<u64>,
+    pub inodes_total: Option<u64>,
+
+    /// How a btrfs filesystem's space is split between chunk types. Currently Linux-only.
+    pub btrfs: Option<BtrfsAllocation>,
 }
 
 #[derive(Clone, Debug)]
 pub struct IoData {
     pub read_bytes: u64,
     pub write_bytes: u64,
+
+    /// Operation counts and timings, if the platform reports them. Currently Linux-only.
+    pub details: Option<IoDetails>,
+}
+
+/// Cumulative operation counts and timings for a device, as found in
+/// `/proc/diskstats`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct IoDetails {
+    /// Reads completed.
+    pub reads: u64,
+
+    /// Writes completed.
+    pub writes: u64,
+
+    /// Time spent on reads, in milliseconds.
+    pub read_time_ms: u64,
+
+    /// Time spent on writes, in milliseconds.
+    pub write_time_ms: u64,
+
+    /// Requests currently in flight. Unlike the other fields, this is not cumulative.
+    pub in_flight: u64,
+
+    /// Time the device had at least one request in flight, in milliseconds.
+    pub io_ticks_ms: u64,
+}
+
+/// Rates derived from two [`IoDetails`] readings, similar to `iostat -x`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct IoDetailRates {
+    /// Reads completed per second.
+    pub read_ops: f64,
+
+    /// Writes completed per second.
+    pub write_ops: f64,
+
+    /// The average time a read or write took, including time spent queued, in milliseconds.
+    pub await_ms: f64,
+
+    /// The percentage of time the device was busy.
+    pub util_percent: f64,
+}
+
+impl IoDetails {
+    /// Calculates the rates between a previous reading and this one, `elapsed_secs` apart.
+    pub fn rates(&self, prev: &IoDetails, elapsed_secs: f64) -> IoDetailRates {
+        if elapsed_secs <= 0.0 {
+            return IoDetailRates::default();
+        }
+
+        let reads = self.reads.saturating_sub(prev.reads);
+        let writes = self.writes.saturating_sub(prev.writes);
+        let ops = reads + writes;
+        let io_time_ms = self.read_time_ms.saturating_sub(prev.read_time_ms)
+            + self.write_time_ms.saturating_sub(prev.write_time_ms);
+        let busy_ms = self.io_ticks_ms.saturating_sub(prev.io_ticks_ms);
+
+        IoDetailRates {
+            read_ops: reads as f64 / elapsed_secs,
+            write_ops: writes as f64 / elapsed_secs,
+            await_ms: if ops > 0 {
+                io_time_ms as f64 / ops as f64
+            } else {
+                0.0
+            },
+            util_percent: (busy_ms as f64 / (elapsed_secs * 1000.0) * 100.0).min(100.0),
+        }
+    }
 }
 
 pub type IoHarvest = HashMap<String, Option<IoData>>;
 
+/// How much space is allocated to, and used within, one type of btrfs chunk.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BtrfsChunkUsage {
+    /// Space allocated to chunks of this type.
+    pub total_bytes: u64,
+
+    /// Space used within the allocated chunks.
+    pub used_bytes: u64,
+}
+
+impl BtrfsChunkUsage {
+    pub fn used_percent(&self) -> Option<f64> {
+        if self.total_bytes > 0 {
+            Some(self.used_bytes as f64 / self.total_bytes as f64 * 100.0)
+        } else {
+            None
+        }
+    }
+}
+
+/// The allocation of a btrfs filesystem, as found in `/sys/fs/btrfs/<uuid>/allocation`.
+///
+/// btrfs allocates space for data and metadata separately, in chunks. A filesystem can run out
+/// of metadata space, and fail writes, while `statvfs` still reports plenty of free space.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BtrfsAllocation {
+    pub data: BtrfsChunkUsage,
+    pub metadata: BtrfsChunkUsage,
+    pub system: BtrfsChunkUsage,
+
+    /// Raw device space not yet allocated to any chunk, if known.
+    pub unallocated_bytes: Option<u64>,
+}
+
+impl BtrfsAllocation {
+    /// The metadata usage, in percent, past which metadata is considered nearly exhausted.
+    const METADATA_NEARLY_FULL_PERCENT: f64 = 90.0;
+
+    /// The largest size of a metadata chunk, as allocated for filesystems over 50GiB.
+    const METADATA_CHUNK_BYTES: u64 = 1 << 30;
+
+    /// Whether the allocated metadata chunks are nearly full, and there isn't enough
+    /// unallocated space left to be sure another metadata chunk can be allocated.
+    pub fn is_metadata_nearly_full(&self) -> bool {
+        let nearly_full = self
+            .metadata
+            .used_percent()
+            .is_some_and(|percent| percent >= Self::METADATA_NEARLY_FULL_PERCENT);
+        let can_grow = self
+            .unallocated_bytes
+            .is_some_and(|unallocated| unallocated >= Self::METADATA_CHUNK_BYTES);
+
+        nearly_full && !can_grow
+    }
+}
+
 cfg_if! {
     if #[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))] {
         mod io_counters;
@@ -73,6 +216,7 @@ cfg_if! {
                     Some(IoData {
                         read_bytes: io.read_bytes(),
                         write_bytes: io.write_bytes(),
+                        details: io.details(),
                     }),
                 );
             }
@@ -127,11 +271,23 @@ pub fn keep_disk_entry(
     }
 }
 
+/// Whether to keep the current disk entry given the filesystem type filter.
+/// Unlike the name and mount filters, this is always applied on its own, and
+/// entries with an unknown filesystem type are kept.
+pub fn keep_fs_type(fs_type: Option<&str>, fs_type_filter: &Option<Filter>) -> bool {
+    match fs_type {
+        Some(fs_type) => Filter::optional_should_keep(fs_type_filter, fs_type),
+        None => true,
+    }
+}
+
 #[cfg(test)]
 mod test {
     use regex::Regex;
 
-    use super::keep_disk_entry;
+    use super::{
+        BtrfsAllocation, BtrfsChunkUsage, IoDetailRates, IoDetails, keep_disk_entry, keep_fs_type,
+    };
     use crate::app::filter::Filter;
 
     fn run_filter(disk_filter: &Option<Filter>, mount_filter: &Option<Filter>) -> Vec<usize> {
@@ -177,4 +333,93 @@ mod test {
         assert_eq!(run_filter(&disk_ignore, &mount_keep), vec![0, 3, 4]);
         assert_eq!(run_filter(&disk_keep, &mount_keep), vec![0, 1, 2, 4]);
     }
+
+    #[test]
+    fn test_keeping_fs_type() {
+        let ignore = Some(Filter::new(
+            true,
+            vec![Regex::new("^(tmpfs|squashfs)$").unwrap()],
+        ));
+        let keep = Some(Filter::new(false, vec![Regex::new("^ext4$").unwrap()]));
+
+        assert!(keep_fs_type(Some("squashfs"), &None));
+
+        assert!(!keep_fs_type(Some("squashfs"), &ignore));
+        assert!(keep_fs_type(Some("ext4"), &ignore));
+
+        assert!(keep_fs_type(Some("ext4"), &keep));
+        assert!(!keep_fs_type(Some("xfs"), &keep));
+
+        // Unknown filesystem types are always kept.
+        assert!(keep_fs_type(None, &ignore));
+        assert!(keep_fs_type(None, &keep));
+    }
+
+    #[test]
+    fn test_btrfs_metadata_nearly_full() {
+        const GIB: u64 = 1 << 30;
+
+        let allocation = |used: u64, unallocated: Option<u64>| BtrfsAllocation {
+            metadata: BtrfsChunkUsage {
+                total_bytes: 2 * GIB,
+                used_bytes: used,
+            },
+            unallocated_bytes: unallocated,
+            ..Default::default()
+        };
+
+        assert!(!allocation(GIB, Some(0)).is_metadata_nearly_full());
+        assert!(allocation(2 * GIB - GIB / 20, Some(0)).is_metadata_nearly_full());
+        assert!(allocation(2 * GIB - GIB / 20, None).is_metadata_nearly_full());
+
+        // Still fine if another metadata chunk can be allocated.
+        assert!(!allocation(2 * GIB - GIB / 20, Some(10 * GIB)).is_metadata_nearly_full());
+
+        // Nothing allocated yet.
+        assert!(!BtrfsAllocation::default().is_metadata_nearly_full());
+    }
+
+    #[test]
+    fn test_io_detail_rates() {
+        let prev = IoDetails {
+            reads: 1000,
+            writes: 500,
+            read_time_ms: 2000,
+            write_time_ms: 4000,
+            in_flight: 0,
+            io_ticks_ms: 10_000,
+        };
+        let curr = IoDetails {
+            reads: 1200,
+            writes: 800,
+            read_time_ms: 2400,
+            write_time_ms: 5100,
+            in_flight: 2,
+            io_ticks_ms: 11_500,
+        };
+
+        assert_eq!(
+            curr.rates(&prev, 2.0),
+            IoDetailRates {
+                read_ops: 100.0,
+                write_ops: 150.0,
+                await_ms: 3.0,
+                util_percent: 75.0,
+            }
+        );
+
+        // Utilization can't go over 100%, even if the kernel's timing is a bit off.
+        let busy = IoDetails {
+            io_ticks_ms: 13_000,
+            ..curr
+        };
+        assert_eq!(busy.rates(&prev, 2.0).util_percent, 100.0);
+
+        // No I/O means no latency, rather than dividing by zero.
+        let idle = curr.rates(&curr, 1.0);
+        assert_eq!(idle.await_ms, 0.0);
+        assert_eq!(idle.util_percent, 0.0);
+
+        assert_eq!(curr.rates(&prev, 0.0), IoDetailRates::default());
+    }
 }
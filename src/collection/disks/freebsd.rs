@@ -5,7 +5,7 @@ use std::io;
 use rustc_hash::FxHashMap as HashMap;
 use serde::Deserialize;
 
-use super::{DiskHarvest, IoHarvest, keep_disk_entry};
+use super::{DiskHarvest, IoHarvest, keep_disk_entry, keep_fs_type};
 use crate::collection::{DataCollector, deserialize_xo, disks::IoData, error::CollectionResult};
 
 #[derive(Deserialize, Debug, Default)]
@@ -22,6 +22,8 @@ struct FileSystem {
     used_blocks: u64,
     available_blocks: u64,
     mounted_on: String,
+    #[serde(default, rename = "type")]
+    fs_type: Option<String>,
 }
 
 pub fn get_io_usage() -> CollectionResult<IoHarvest> {
@@ -48,6 +50,7 @@ pub fn get_io_usage() -> CollectionResult<IoHarvest> {
                     Some(IoData {
                         read_bytes: io.read_bytes(),
                         write_bytes: io.write_bytes(),
+                        details: io.details(),
                     }),
                 );
             }
@@ -59,16 +62,25 @@ pub fn get_io_usage() -> CollectionResult<IoHarvest> {
 pub fn get_disk_usage(collector: &DataCollector) -> CollectionResult<Vec<DiskHarvest>> {
     let disk_filter = &collector.filters.disk_filter;
     let mount_filter = &collector.filters.mount_filter;
+    let fs_type_filter = &collector.filters.fs_type_filter;
     let vec_disks: Vec<DiskHarvest> = get_disk_info().map(|storage_system_information| {
         storage_system_information
             .filesystem
             .into_iter()
             .filter_map(|disk| {
-                if keep_disk_entry(&disk.name, &disk.mounted_on, disk_filter, mount_filter) {
+                if keep_disk_entry(&disk.name, &disk.mounted_on, disk_filter, mount_filter)
+                    && keep_fs_type(disk.fs_type.as_deref(), fs_type_filter)
+                {
                     Some(DiskHarvest {
                         free_space: Some(disk.available_blocks * 1024),
                         used_space: Some(disk.used_blocks * 1024),
                         total_space: Some(disk.total_blocks * 1024),
+                        fs_type: disk.fs_type,
+                        mount_options: None,
+                        inodes_free: None,
+                        inodes_used: None,
+                        inodes_total: None,
+                        btrfs: None,
                         mount_point: disk.mounted_on,
                         name: disk.name,
                     })
@@ -85,7 +97,7 @@ pub fn get_disk_usage(collector: &DataCollector) -> CollectionResult<Vec<DiskHar
 fn get_disk_info() -> io::Result<StorageSystemInformation> {
     // TODO: Ideally we don't have to shell out to a new program.
     let output = std::process::Command::new("df")
-        .args(["--libxo", "json", "-k", "-t", "ufs,msdosfs,zfs"])
+        .args(["--libxo", "json", "-k", "-T", "-t", "ufs,msdosfs,zfs"])
         .output()?;
     deserialize_xo("storage-system-information", &output.stdout)
 }
@@ -1,12 +1,13 @@
 //! Fallback disk info using sysinfo.
 
-use super::{DiskHarvest, keep_disk_entry};
+use super::{DiskHarvest, keep_disk_entry, keep_fs_type};
 use crate::collection::DataCollector;
 
 pub(crate) fn get_disk_usage(collector: &DataCollector) -> anyhow::Result<Vec<DiskHarvest>> {
     let disks = &collector.sys.disks;
     let disk_filter = &collector.filters.disk_filter;
     let mount_filter = &collector.filters.mount_filter;
+    let fs_type_filter = &collector.filters.fs_type_filter;
 
     Ok(disks
         .iter()
@@ -30,7 +31,11 @@ pub(crate) fn get_disk_usage(collector: &DataCollector) -> anyhow::Result<Vec<Di
                 .into_string()
                 .unwrap_or_else(|_| "Mount Unavailable".to_string());
 
-            if keep_disk_entry(&name, &mount_point, disk_filter, mount_filter) {
+            let fs_type = disk.file_system().to_string_lossy().to_string();
+
+            if keep_disk_entry(&name, &mount_point, disk_filter, mount_filter)
+                && keep_fs_type(Some(&fs_type), fs_type_filter)
+            {
                 let free_space = disk.available_space();
                 let total_space = disk.total_space();
                 let used_space = total_space - free_space;
@@ -41,6 +46,12 @@ pub(crate) fn get_disk_usage(collector: &DataCollector) -> anyhow::Result<Vec<Di
                     free_space: Some(free_space),
                     used_space: Some(used_space),
                     total_space: Some(total_space),
+                    fs_type: Some(fs_type),
+                    mount_options: Some(if disk.is_read_only() { "ro" } else { "rw" }.to_string()),
+                    inodes_free: None,
+                    inodes_used: None,
+                    inodes_total: None,
+                    btrfs: None,
                 })
             } else {
                 None
@@ -24,18 +24,23 @@ cfg_if::cfg_if! {
 use file_systems::*;
 use usage::*;
 
-use super::{DiskHarvest, keep_disk_entry};
+use super::{DiskHarvest, keep_disk_entry, keep_fs_type};
 use crate::collection::DataCollector;
 
 /// Returns the disk usage of the mounted (and for now, physical) disks.
 pub fn get_disk_usage(collector: &DataCollector) -> anyhow::Result<Vec<DiskHarvest>> {
     let disk_filter = &collector.filters.disk_filter;
     let mount_filter = &collector.filters.mount_filter;
+    let fs_type_filter = &collector.filters.fs_type_filter;
     let mut vec_disks: Vec<DiskHarvest> = Vec::new();
 
+    #[cfg(target_os = "linux")]
+    let btrfs_allocations = get_btrfs_allocations();
+
     for partition in physical_partitions()? {
         let name = partition.get_device_name();
         let mount_point = partition.mount_point().to_string_lossy().to_string();
+        let fs_type = partition.fs_type().as_str().to_string();
 
         // Precedence ordering in the case where name and mount filters disagree,
         // "allow" takes precedence over "deny".
@@ -49,16 +54,53 @@ pub fn get_disk_usage(collector: &DataCollector) -> anyhow::Result<Vec<DiskHarve
         //    entry.
         // 3. Anything else is allowed.
 
-        if keep_disk_entry(&name, &mount_point, disk_filter, mount_filter) {
+        if keep_disk_entry(&name, &mount_point, disk_filter, mount_filter)
+            && keep_fs_type(Some(&fs_type), fs_type_filter)
+        {
+            let mount_options = partition.options().map(str::to_string);
+
             // The usage line can fail in some cases (for example, if you use Void Linux +
             // LUKS, see https://github.com/ClementTsang/bottom/issues/419 for details).
             if let Ok(usage) = partition.usage() {
                 let total = usage.total();
 
+                // Filesystems that allocate inodes dynamically report no inodes at all.
+                let inodes_total = usage.inodes_total();
+                let (inodes_free, inodes_used, inodes_total) = if inodes_total > 0 {
+                    let inodes_free = usage.inodes_free();
+                    (
+                        Some(inodes_free),
+                        Some(inodes_total.saturating_sub(inodes_free)),
+                        Some(inodes_total),
+                    )
+                } else {
+                    (None, None, None)
+                };
+
+                let read_only = if usage.is_read_only() { "ro" } else { "rw" };
+
+                #[cfg(target_os = "linux")]
+                let btrfs = if fs_type == "btrfs" {
+                    name.rsplit('/')
+                        .next()
+                        .and_then(|device| btrfs_allocations.get(device))
+                        .copied()
+                } else {
+                    None
+                };
+                #[cfg(not(target_os = "linux"))]
+                let btrfs = None;
+
                 vec_disks.push(DiskHarvest {
                     free_space: Some(usage.free()),
                     used_space: Some(total - usage.available()),
                     total_space: Some(total),
+                    fs_type: Some(fs_type),
+                    mount_options: mount_options.or_else(|| Some(read_only.to_string())),
+                    inodes_free,
+                    inodes_used,
+                    inodes_total,
+                    btrfs,
                     mount_point,
                     name,
                 });
@@ -67,6 +109,12 @@ pub fn get_disk_usage(collector: &DataCollector) -> anyhow::Result<Vec<DiskHarve
                     free_space: None,
                     used_space: None,
                     total_space: None,
+                    fs_type: Some(fs_type),
+                    mount_options,
+                    inodes_free: None,
+                    inodes_used: None,
+                    inodes_total: None,
+                    btrfs: None,
                     mount_point,
                     name,
                 });
@@ -88,7 +88,6 @@ impl FileSystem {
         matches!(self, FileSystem::Other(..))
     }
 
-    #[expect(dead_code)]
     #[inline]
     /// Returns a string literal identifying this filesystem.
     pub fn as_str(&self) -> &str {
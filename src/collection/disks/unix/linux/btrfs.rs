@@ -0,0 +1,164 @@
+//! btrfs chunk allocation, based on `/sys/fs/btrfs`.
+
+use std::{fs, path::Path};
+
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::collection::disks::{BtrfsAllocation, BtrfsChunkUsage};
+
+const SYS_FS_BTRFS: &str = "/sys/fs/btrfs";
+
+/// Reads a single number from a sysfs file.
+fn read_u64(path: &Path) -> Option<u64> {
+    fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+/// Reads the usage of one chunk type, along with how much raw device space it takes up.
+/// The raw space can be larger than the allocated space, depending on the profile (e.g. `DUP`).
+fn read_chunk_usage(chunk_dir: &Path) -> Option<(BtrfsChunkUsage, Option<u64>)> {
+    let usage = BtrfsChunkUsage {
+        total_bytes: read_u64(&chunk_dir.join("total_bytes"))?,
+        used_bytes: read_u64(&chunk_dir.join("bytes_used"))?,
+    };
+
+    Some((usage, read_u64(&chunk_dir.join("disk_total"))))
+}
+
+/// Reads the allocation of a filesystem from its `/sys/fs/btrfs/<uuid>` directory.
+pub(crate) fn read_btrfs_allocation(fs_dir: &Path) -> Option<BtrfsAllocation> {
+    let allocation_dir = fs_dir.join("allocation");
+    let (data, data_disk) = read_chunk_usage(&allocation_dir.join("data"))?;
+    let (metadata, metadata_disk) = read_chunk_usage(&allocation_dir.join("metadata"))?;
+    let (system, system_disk) = read_chunk_usage(&allocation_dir.join("system"))?;
+
+    // Device sizes are in 512-byte sectors, regardless of the device's actual sector size.
+    let device_bytes = fs::read_dir(fs_dir.join("devices"))
+        .ok()?
+        .flatten()
+        .map(|entry| read_u64(&entry.path().join("size")).map(|sectors| sectors * 512))
+        .sum::<Option<u64>>();
+
+    let unallocated_bytes = match (device_bytes, data_disk, metadata_disk, system_disk) {
+        (Some(device), Some(data), Some(metadata), Some(system)) => {
+            Some(device.saturating_sub(data + metadata + system))
+        }
+        _ => None,
+    };
+
+    Some(BtrfsAllocation {
+        data,
+        metadata,
+        system,
+        unallocated_bytes,
+    })
+}
+
+/// Returns the allocation of every btrfs filesystem in `sys_fs_btrfs`, keyed by the kernel
+/// name (e.g. `sda2` or `dm-0`) of each device it spans.
+pub(crate) fn read_btrfs_allocations(sys_fs_btrfs: &Path) -> HashMap<String, BtrfsAllocation> {
+    let mut allocations = HashMap::default();
+
+    let Ok(entries) = fs::read_dir(sys_fs_btrfs) else {
+        return allocations;
+    };
+
+    for fs_dir in entries.flatten().map(|entry| entry.path()) {
+        // Skip non-filesystem entries like `features`.
+        let Some(allocation) = read_btrfs_allocation(&fs_dir) else {
+            continue;
+        };
+
+        let Ok(devices) = fs::read_dir(fs_dir.join("devices")) else {
+            continue;
+        };
+
+        for device in devices.flatten() {
+            allocations.insert(device.file_name().to_string_lossy().to_string(), allocation);
+        }
+    }
+
+    allocations
+}
+
+/// Returns the allocation of every mounted btrfs filesystem, keyed by device kernel name.
+pub fn get_btrfs_allocations() -> HashMap<String, BtrfsAllocation> {
+    read_btrfs_allocations(Path::new(SYS_FS_BTRFS))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GIB: u64 = 1 << 30;
+
+    fn write_chunk(dir: &Path, chunk: &str, total: u64, used: u64, disk_total: u64) {
+        let chunk_dir = dir.join("allocation").join(chunk);
+        fs::create_dir_all(&chunk_dir).unwrap();
+        fs::write(chunk_dir.join("total_bytes"), format!("{total}\n")).unwrap();
+        fs::write(chunk_dir.join("bytes_used"), format!("{used}\n")).unwrap();
+        fs::write(chunk_dir.join("disk_total"), format!("{disk_total}\n")).unwrap();
+    }
+
+    fn write_device(dir: &Path, name: &str, bytes: u64) {
+        let device_dir = dir.join("devices").join(name);
+        fs::create_dir_all(&device_dir).unwrap();
+        fs::write(device_dir.join("size"), format!("{}\n", bytes / 512)).unwrap();
+    }
+
+    #[test]
+    fn test_read_btrfs_allocations() {
+        let sys_fs_btrfs = tempfile::tempdir().unwrap();
+        fs::create_dir(sys_fs_btrfs.path().join("features")).unwrap();
+
+        // A two-device filesystem, with DUP metadata taking up twice its allocated space.
+        let fs_dir = sys_fs_btrfs
+            .path()
+            .join("0b9cbdc5-7bb8-4b5e-9b1c-1f2b3c4d5e6f");
+        write_chunk(&fs_dir, "data", 90 * GIB, 60 * GIB, 90 * GIB);
+        write_chunk(&fs_dir, "metadata", 2 * GIB, GIB, 4 * GIB);
+        write_chunk(&fs_dir, "system", 32 << 20, 16 << 10, 64 << 20);
+        write_device(&fs_dir, "sda2", 50 * GIB);
+        write_device(&fs_dir, "dm-0", 50 * GIB);
+
+        let allocations = read_btrfs_allocations(sys_fs_btrfs.path());
+        assert_eq!(allocations.len(), 2);
+
+        let allocation = allocations["sda2"];
+        assert_eq!(allocations["dm-0"], allocation);
+        assert_eq!(
+            allocation.data,
+            BtrfsChunkUsage {
+                total_bytes: 90 * GIB,
+                used_bytes: 60 * GIB,
+            }
+        );
+        assert_eq!(allocation.metadata.used_percent(), Some(50.0));
+        assert_eq!(allocation.system.total_bytes, 32 << 20);
+        assert_eq!(
+            allocation.unallocated_bytes,
+            Some(100 * GIB - 94 * GIB - (64 << 20))
+        );
+        assert!(!allocation.is_metadata_nearly_full());
+    }
+
+    #[test]
+    fn test_read_btrfs_allocation_without_disk_totals() {
+        let fs_dir = tempfile::tempdir().unwrap();
+        for chunk in ["data", "metadata", "system"] {
+            write_chunk(fs_dir.path(), chunk, GIB, GIB, 0);
+            fs::remove_file(
+                fs_dir
+                    .path()
+                    .join("allocation")
+                    .join(chunk)
+                    .join("disk_total"),
+            )
+            .unwrap();
+        }
+        write_device(fs_dir.path(), "sdb", 10 * GIB);
+
+        let allocation = read_btrfs_allocation(fs_dir.path()).unwrap();
+        assert_eq!(allocation.unallocated_bytes, None);
+        assert!(allocation.is_metadata_nearly_full());
+    }
+}
@@ -7,7 +7,7 @@ use std::{
     str::FromStr,
 };
 
-use crate::collection::disks::IoCounters;
+use crate::collection::disks::{IoCounters, IoDetails};
 
 /// Copied from the `psutil` sources:
 ///
@@ -52,15 +52,31 @@ impl FromStr for IoCounters {
 
         let name = next_part(&mut parts)?.to_string();
 
-        // Skip read count, read merged count.
-        let mut parts = parts.skip(2);
+        let reads = next_part_to_u64(&mut parts)?;
+
+        // Skip read merged count.
+        let mut parts = parts.skip(1);
         let read_bytes = next_part_to_u64(&mut parts)? * DISK_SECTOR_SIZE;
+        let read_time_ms = next_part_to_u64(&mut parts)?;
+        let writes = next_part_to_u64(&mut parts)?;
 
-        // Skip read time seconds, write count, and write merged count.
-        let mut parts = parts.skip(3);
+        // Skip write merged count.
+        let mut parts = parts.skip(1);
         let write_bytes = next_part_to_u64(&mut parts)? * DISK_SECTOR_SIZE;
-
-        Ok(IoCounters::new(name, read_bytes, write_bytes))
+        let write_time_ms = next_part_to_u64(&mut parts)?;
+        let in_flight = next_part_to_u64(&mut parts)?;
+        let io_ticks_ms = next_part_to_u64(&mut parts)?;
+
+        Ok(
+            IoCounters::new(name, read_bytes, write_bytes).with_details(IoDetails {
+                reads,
+                writes,
+                read_time_ms,
+                write_time_ms,
+                in_flight,
+                io_ticks_ms,
+            }),
+        )
     }
 }
 
@@ -95,3 +111,34 @@ pub fn io_stats() -> anyhow::Result<Vec<IoCounters>> {
 
     Ok(results)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_diskstats_line() {
+        let counters = IoCounters::from_str(
+            " 259       0 nvme0n1 183410 52075 13289710 41520 412008 210736 23655832 388226 3 243108 437032 0 0 0 0 18470 7285",
+        )
+        .unwrap();
+
+        assert_eq!(counters.device_name(), "nvme0n1");
+        assert_eq!(counters.read_bytes(), 13289710 * DISK_SECTOR_SIZE);
+        assert_eq!(counters.write_bytes(), 23655832 * DISK_SECTOR_SIZE);
+        assert_eq!(
+            counters.details(),
+            Some(IoDetails {
+                reads: 183410,
+                writes: 412008,
+                read_time_ms: 41520,
+                write_time_ms: 388226,
+                in_flight: 3,
+                io_ticks_ms: 243108,
+            })
+        );
+
+        // Lines that are too short are rejected.
+        assert!(IoCounters::from_str("   8       0 sda 1 2 3 4").is_err());
+    }
+}
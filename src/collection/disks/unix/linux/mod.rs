@@ -1,5 +1,9 @@
+mod btrfs;
 mod counters;
 mod partition;
+mod topology;
 
+pub use btrfs::*;
 pub use counters::*;
 pub(crate) use partition::*;
+pub use topology::*;
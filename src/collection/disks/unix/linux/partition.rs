@@ -19,6 +19,7 @@ pub(crate) struct Partition {
     device: Option<String>,
     mount_point: PathBuf,
     fs_type: FileSystem,
+    options: String,
 }
 
 impl Partition {
@@ -40,6 +41,12 @@ impl Partition {
         &self.fs_type
     }
 
+    /// Returns the mount options for this partition (e.g. `rw,noatime`).
+    #[inline]
+    pub fn options(&self) -> Option<&str> {
+        Some(self.options.as_str())
+    }
+
     /// Returns the device name for the partition.
     pub fn get_device_name(&self) -> String {
         if let Some(device) = self.device() {
@@ -143,11 +150,18 @@ impl FromStr for Partition {
                 bail!("missing filesystem type");
             }
         };
+        let options = match parts.next() {
+            Some(options) => options.to_string(),
+            None => {
+                bail!("missing mount options");
+            }
+        };
 
         Ok(Partition {
             device,
             mount_point,
             fs_type,
+            options,
         })
     }
 }
@@ -216,4 +230,17 @@ mod tests {
 
         assert_eq!(fix_mount_point(line), "/run/media/test/Samsung 980");
     }
+
+    #[test]
+    fn test_parse_partition() {
+        let partition =
+            Partition::from_str("/dev/sda3 /home ext4 rw,noatime,data=ordered 0 0").unwrap();
+
+        assert_eq!(partition.device(), Some("/dev/sda3"));
+        assert_eq!(partition.mount_point(), Path::new("/home"));
+        assert_eq!(partition.fs_type(), &FileSystem::Ext4);
+        assert_eq!(partition.options(), Some("rw,noatime,data=ordered"));
+
+        assert!(Partition::from_str("/dev/sda3 /home ext4").is_err());
+    }
 }
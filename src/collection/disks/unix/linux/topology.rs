@@ -0,0 +1,430 @@
+//! How block devices relate to each other, such as partitions of a disk,
+//! device-mapper targets (LVM, LUKS) and md RAID arrays, based on
+//! `/sys/block` and `/proc/mdstat`.
+
+use std::{fs, path::Path};
+
+use rustc_hash::FxHashMap as HashMap;
+
+/// The sync operation an md RAID array is running, and how far along it is.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RaidSync {
+    /// One of `resync`, `recovery`, `check`, or `reshape`.
+    pub action: String,
+    pub percent: f64,
+}
+
+/// The state of an md RAID array, as found in `/proc/mdstat`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RaidStatus {
+    pub active: bool,
+
+    /// The RAID level, such as `raid1`. Inactive arrays don't report one.
+    pub level: Option<String>,
+
+    /// Member devices, including failed and spare ones.
+    pub members: Vec<String>,
+
+    /// Member devices marked as failed.
+    pub failed: Vec<String>,
+
+    /// Which members are up (`U`) or down (`_`), such as `[UU_]`.
+    pub health: Option<String>,
+
+    pub sync: Option<RaidSync>,
+}
+
+impl RaidStatus {
+    /// Whether any member of the array is down.
+    pub fn is_degraded(&self) -> bool {
+        !self.failed.is_empty() || self.health.as_ref().is_some_and(|h| h.contains('_'))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum BlockDeviceKind {
+    /// A whole disk, or anything else without a more specific kind (e.g. loop devices).
+    Disk,
+    Partition,
+    Lvm {
+        vg: String,
+        lv: String,
+    },
+    Crypt {
+        luks: bool,
+    },
+    /// Any other device-mapper target.
+    Mapper,
+    Raid(RaidStatus),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlockDevice {
+    /// The kernel name, such as `sda1` or `dm-3`.
+    pub name: String,
+
+    /// The device-mapper name, such as `vg0-root`, if this is a device-mapper device.
+    pub mapper_name: Option<String>,
+
+    pub kind: BlockDeviceKind,
+
+    /// The kernel names of the devices this one is built on top of.
+    pub parents: Vec<String>,
+}
+
+impl BlockDevice {
+    /// Returns a human-readable description of the device.
+    pub fn label(&self) -> String {
+        let mapper_name = self.mapper_name.as_deref().unwrap_or(&self.name);
+
+        match &self.kind {
+            BlockDeviceKind::Disk | BlockDeviceKind::Partition => self.name.clone(),
+            BlockDeviceKind::Lvm { vg, lv } => format!("{vg}/{lv} (LVM)"),
+            BlockDeviceKind::Crypt { luks: true } => format!("{mapper_name} (LUKS)"),
+            BlockDeviceKind::Crypt { luks: false } => format!("{mapper_name} (dm-crypt)"),
+            BlockDeviceKind::Mapper => mapper_name.to_string(),
+            BlockDeviceKind::Raid(status) => {
+                let mut details = vec![];
+
+                if !status.active {
+                    details.push("inactive".to_string());
+                }
+                if let Some(level) = &status.level {
+                    details.push(level.clone());
+                }
+                if let Some(health) = &status.health {
+                    details.push(format!("[{health}]"));
+                }
+                if let Some(sync) = &status.sync {
+                    details.push(format!("{} {:.1}%", sync.action, sync.percent));
+                }
+
+                if details.is_empty() {
+                    self.name.clone()
+                } else {
+                    format!("{} ({})", self.name, details.join(", "))
+                }
+            }
+        }
+    }
+}
+
+/// Block devices, keyed by their kernel name.
+pub type BlockTopology = HashMap<String, BlockDevice>;
+
+/// Returns how the system's block devices relate to each other.
+pub fn get_block_topology() -> anyhow::Result<BlockTopology> {
+    let mdstat = fs::read_to_string("/proc/mdstat").unwrap_or_default();
+
+    read_block_topology(Path::new("/sys/block"), &mdstat)
+}
+
+fn read_trimmed(path: &Path) -> Option<String> {
+    fs::read_to_string(path)
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+fn dir_names(path: &Path) -> Vec<String> {
+    let mut names = fs::read_dir(path)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| entry.file_name().to_string_lossy().to_string())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    names.sort();
+
+    names
+}
+
+fn read_block_topology(sys_block: &Path, mdstat: &str) -> anyhow::Result<BlockTopology> {
+    let mut raid = parse_mdstat(mdstat);
+    let mut topology = BlockTopology::default();
+
+    for name in dir_names(sys_block) {
+        let device_path = sys_block.join(&name);
+        let parents = dir_names(&device_path.join("slaves"));
+        let mapper_name = read_trimmed(&device_path.join("dm").join("name"));
+
+        let kind = if let Some(status) = raid.remove(&name) {
+            BlockDeviceKind::Raid(status)
+        } else if device_path.join("md").is_dir() {
+            BlockDeviceKind::Raid(RaidStatus::default())
+        } else if let Some(mapper_name) = &mapper_name {
+            let uuid = read_trimmed(&device_path.join("dm").join("uuid")).unwrap_or_default();
+
+            if uuid.starts_with("LVM-") {
+                match split_lvm_name(mapper_name) {
+                    Some((vg, lv)) => BlockDeviceKind::Lvm { vg, lv },
+                    None => BlockDeviceKind::Mapper,
+                }
+            } else if uuid.starts_with("CRYPT-") {
+                BlockDeviceKind::Crypt {
+                    luks: uuid.starts_with("CRYPT-LUKS"),
+                }
+            } else {
+                BlockDeviceKind::Mapper
+            }
+        } else {
+            BlockDeviceKind::Disk
+        };
+
+        // Partitions show up as subdirectories of their disk with a `partition` file.
+        for partition in dir_names(&device_path) {
+            if device_path.join(&partition).join("partition").is_file() {
+                topology.insert(
+                    partition.clone(),
+                    BlockDevice {
+                        name: partition,
+                        mapper_name: None,
+                        kind: BlockDeviceKind::Partition,
+                        parents: vec![name.clone()],
+                    },
+                );
+            }
+        }
+
+        topology.insert(
+            name.clone(),
+            BlockDevice {
+                name,
+                mapper_name,
+                kind,
+                parents,
+            },
+        );
+    }
+
+    Ok(topology)
+}
+
+/// Splits a device-mapper name of an LVM logical volume into its volume group
+/// and logical volume names. Dashes within either name are doubled, so
+/// `vg--data-lv--root` is `vg-data` and `lv-root`.
+fn split_lvm_name(name: &str) -> Option<(String, String)> {
+    let mut vg = String::new();
+    let mut chars = name.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '-' {
+            if chars.peek() == Some(&'-') {
+                chars.next();
+                vg.push('-');
+            } else {
+                let lv = chars.collect::<String>().replace("--", "-");
+
+                return if vg.is_empty() || lv.is_empty() {
+                    None
+                } else {
+                    Some((vg, lv))
+                };
+            }
+        } else {
+            vg.push(c);
+        }
+    }
+
+    None
+}
+
+/// Parses the arrays in `/proc/mdstat`, keyed by their kernel name.
+fn parse_mdstat(mdstat: &str) -> HashMap<String, RaidStatus> {
+    const SYNC_ACTIONS: [&str; 4] = ["resync", "recovery", "check", "reshape"];
+
+    let mut arrays = HashMap::default();
+    let mut current: Option<(String, RaidStatus)> = None;
+
+    for line in mdstat.lines() {
+        if let Some((name, rest)) = line.split_once(" : ") {
+            let name = name.trim();
+
+            if !name.starts_with("md") {
+                continue;
+            }
+
+            if let Some((name, status)) = current.take() {
+                arrays.insert(name, status);
+            }
+
+            // Example: `md127 : active (auto-read-only) raid1 sdb1[1] sda1[0](F)`
+            let mut status = RaidStatus::default();
+            for (index, part) in rest.split_whitespace().enumerate() {
+                if index == 0 {
+                    status.active = part == "active";
+                } else if part.starts_with('(') {
+                    // A state such as `(read-only)`.
+                } else if let Some((member, flags)) = part.split_once('[') {
+                    status.members.push(member.to_string());
+                    if flags.ends_with("(F)") {
+                        status.failed.push(member.to_string());
+                    }
+                } else {
+                    status.level = Some(part.to_string());
+                }
+            }
+
+            current = Some((name.to_string(), status));
+        } else if let Some((_, status)) = &mut current {
+            let line = line.trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            // Example: `976630464 blocks super 1.2 [2/2] [UU]`
+            if let Some(health) = line
+                .split_whitespace()
+                .filter_map(|part| part.strip_prefix('[')?.strip_suffix(']'))
+                .find(|part| !part.is_empty() && part.chars().all(|c| c == 'U' || c == '_'))
+            {
+                status.health = Some(health.to_string());
+            }
+
+            // Example: `[==>.....]  resync = 12.6% (123/976) finish=80.1min speed=100000K/sec`
+            if let Some((before, after)) = line.split_once(" = ") {
+                if let Some(action) = before.split_whitespace().last() {
+                    if SYNC_ACTIONS.contains(&action) {
+                        if let Some(Ok(percent)) = after
+                            .split_whitespace()
+                            .next()
+                            .and_then(|p| p.strip_suffix('%'))
+                            .map(str::parse::<f64>)
+                        {
+                            status.sync = Some(RaidSync {
+                                action: action.to_string(),
+                                percent,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some((name, status)) = current {
+        arrays.insert(name, status);
+    }
+
+    arrays
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MDSTAT: &str = "\
+Personalities : [raid1] [raid6] [raid5] [raid4]
+md127 : active raid1 sdb1[1] sda1[0]
+      976630464 blocks super 1.2 [2/2] [UU]
+      [==>..................]  resync = 12.6% (123456/976630464) finish=80.1min speed=100000K/sec
+      bitmap: 0/8 pages [0KB], 65536KB chunk
+
+md1 : active (auto-read-only) raid5 sdd1[3](F) sdc1[1] sde1[0]
+      1953260544 blocks super 1.2 level 5, 512k chunk, algorithm 2 [3/2] [UU_]
+
+md0 : inactive sdf[0](S)
+      976630488 blocks super 1.2
+
+unused devices: <none>
+";
+
+    #[test]
+    fn test_parse_mdstat() {
+        let arrays = parse_mdstat(MDSTAT);
+        assert_eq!(arrays.len(), 3);
+
+        assert_eq!(
+            arrays["md127"],
+            RaidStatus {
+                active: true,
+                level: Some("raid1".to_string()),
+                members: vec!["sdb1".to_string(), "sda1".to_string()],
+                failed: vec![],
+                health: Some("UU".to_string()),
+                sync: Some(RaidSync {
+                    action: "resync".to_string(),
+                    percent: 12.6,
+                }),
+            }
+        );
+        assert!(!arrays["md127"].is_degraded());
+
+        let md1 = &arrays["md1"];
+        assert_eq!(md1.level.as_deref(), Some("raid5"));
+        assert_eq!(md1.failed, vec!["sdd1".to_string()]);
+        assert_eq!(md1.health.as_deref(), Some("UU_"));
+        assert!(md1.is_degraded());
+        assert!(md1.sync.is_none());
+
+        let md0 = &arrays["md0"];
+        assert!(!md0.active);
+        assert_eq!(md0.level, None);
+        assert_eq!(md0.members, vec!["sdf".to_string()]);
+    }
+
+    #[test]
+    fn test_split_lvm_name() {
+        assert_eq!(
+            split_lvm_name("vg0-root"),
+            Some(("vg0".to_string(), "root".to_string()))
+        );
+        assert_eq!(
+            split_lvm_name("vg--data-lv--home--1"),
+            Some(("vg-data".to_string(), "lv-home-1".to_string()))
+        );
+        assert_eq!(split_lvm_name("nodash"), None);
+        assert_eq!(split_lvm_name("-lv"), None);
+    }
+
+    #[test]
+    fn test_read_block_topology() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let write = |path: &str, contents: &str| {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        };
+        let mkdir = |path: &str| fs::create_dir_all(root.join(path)).unwrap();
+
+        // sda2 -> LUKS (dm-0) -> LVM (dm-1), and sda1 + sdb1 -> md127.
+        write("sda/sda1/partition", "1\n");
+        write("sda/sda2/partition", "2\n");
+        write("sdb/sdb1/partition", "1\n");
+        write("dm-0/dm/name", "luks-1234\n");
+        write("dm-0/dm/uuid", "CRYPT-LUKS2-1234-luks-1234\n");
+        mkdir("dm-0/slaves/sda2");
+        write("dm-1/dm/name", "vg--data-root\n");
+        write("dm-1/dm/uuid", "LVM-abcdef\n");
+        mkdir("dm-1/slaves/dm-0");
+        mkdir("md127/md");
+        mkdir("md127/slaves/sda1");
+        mkdir("md127/slaves/sdb1");
+
+        let topology = read_block_topology(root, MDSTAT).unwrap();
+        assert_eq!(topology.len(), 8);
+
+        assert_eq!(topology["sda"].kind, BlockDeviceKind::Disk);
+        assert_eq!(topology["sda2"].kind, BlockDeviceKind::Partition);
+        assert_eq!(topology["sda2"].parents, vec!["sda".to_string()]);
+
+        assert_eq!(topology["dm-0"].kind, BlockDeviceKind::Crypt { luks: true });
+        assert_eq!(topology["dm-0"].parents, vec!["sda2".to_string()]);
+        assert_eq!(topology["dm-0"].label(), "luks-1234 (LUKS)");
+
+        assert_eq!(topology["dm-1"].parents, vec!["dm-0".to_string()]);
+        assert_eq!(topology["dm-1"].label(), "vg-data/root (LVM)");
+
+        assert_eq!(
+            topology["md127"].parents,
+            vec!["sda1".to_string(), "sdb1".to_string()]
+        );
+        assert_eq!(
+            topology["md127"].label(),
+            "md127 (raid1, [UU], resync 12.6%)"
+        );
+    }
+}
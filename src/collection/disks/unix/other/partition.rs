@@ -29,6 +29,13 @@ impl Partition {
         &self.fs_type
     }
 
+    /// Returns the mount options for this partition. These aren't available here,
+    /// so callers fall back to [`Usage::is_read_only`].
+    #[inline]
+    pub fn options(&self) -> Option<&str> {
+        None
+    }
+
     /// Returns the usage stats for this partition.
     pub fn usage(&self) -> anyhow::Result<Usage> {
         let path = CString::new(self.mount_point().as_os_str().as_bytes())?;
@@ -32,4 +32,20 @@ impl Usage {
     pub fn free(&self) -> u64 {
         u64::from(self.0.f_bavail) * u64::from(self.0.f_frsize)
     }
+
+    /// Returns the total number of inodes. Some filesystems (e.g. btrfs) allocate
+    /// inodes dynamically and report zero here.
+    pub fn inodes_total(&self) -> u64 {
+        u64::from(self.0.f_files)
+    }
+
+    /// Returns the number of free inodes.
+    pub fn inodes_free(&self) -> u64 {
+        u64::from(self.0.f_ffree)
+    }
+
+    /// Returns whether the filesystem is mounted read-only.
+    pub fn is_read_only(&self) -> bool {
+        u64::from(self.0.f_flag) & u64::from(libc::ST_RDONLY) != 0
+    }
 }
@@ -60,90 +60,15 @@ pub fn zfs_io_stats() -> anyhow::Result<Vec<IoCounters>> {
 /// Returns zpool I/O stats. Pulls data from `/proc/spl/kstat/zfs/*/objset-*`.
 #[cfg(target_os = "linux")]
 pub fn zfs_io_stats() -> anyhow::Result<Vec<IoCounters>> {
-    if let Ok(zpools) = std::fs::read_dir("/proc/spl/kstat/zfs") {
-        let zpools_vec: Vec<std::path::PathBuf> = zpools
-            .filter_map(|e| {
-                e.ok().and_then(|d| {
-                    let p = d.path();
-                    if p.is_dir() { Some(p) } else { None }
-                })
-            })
-            .collect();
-        let results = zpools_vec
-            .iter()
-            .filter_map(|zpool| {
-                // go through each pool
-                if let Ok(datasets) = std::fs::read_dir(zpool) {
-                    let datasets_vec: Vec<std::path::PathBuf> =
-                        datasets // go through dataset
-                            .filter_map(|e| {
-                                e.ok().and_then(|d| {
-                                    let p = d.path();
-                                    if p.is_file() && p.to_str()?.contains("objset-") {
-                                        Some(p)
-                                    } else {
-                                        None
-                                    }
-                                })
-                            })
-                            .collect();
-                    let io_counters: Vec<IoCounters> = datasets_vec
-                        .iter()
-                        .filter_map(|ds| {
-                            // get io-counter from each dataset
-                            if let Ok(contents) = std::fs::read_to_string(ds) {
-                                let mut read = 0;
-                                let mut write = 0;
-                                let mut name = "";
-                                contents.lines().for_each(|line| {
-                                    if let Some((label, value)) = line.split_once(' ') {
-                                        match label {
-                                            "dataset_name" => {
-                                                if let Some((_type, val)) =
-                                                    value.trim_start().rsplit_once(' ')
-                                                {
-                                                    name = val;
-                                                }
-                                            }
-                                            "nwritten" => {
-                                                if let Some((_type, val)) =
-                                                    value.trim_start().rsplit_once(' ')
-                                                {
-                                                    if let Ok(number) = val.parse::<u64>() {
-                                                        write = number;
-                                                    }
-                                                }
-                                            }
-                                            "nread" => {
-                                                if let Some((_type, val)) =
-                                                    value.trim_start().rsplit_once(' ')
-                                                {
-                                                    if let Ok(number) = val.parse::<u64>() {
-                                                        read = number;
-                                                    }
-                                                }
-                                            }
-                                            _ => {}
-                                        }
-                                    }
-                                });
+    use super::zpools::{pool_dirs, pool_objsets};
 
-                                let counter = IoCounters::new(name.to_owned(), read, write);
-                                Some(counter)
-                            } else {
-                                None
-                            }
-                        })
-                        .collect();
-                    Some(io_counters)
-                } else {
-                    None
-                }
-            })
-            .flatten()
-            .collect(); // combine io-counters
-        Ok(results)
-    } else {
-        Err(anyhow::anyhow!("Unable to open zfs proc directory"))
+    if !std::path::Path::new("/proc/spl/kstat/zfs").is_dir() {
+        anyhow::bail!("Unable to open zfs proc directory");
     }
+
+    Ok(pool_dirs()
+        .iter()
+        .flat_map(|pool_dir| pool_objsets(pool_dir))
+        .map(|objset| IoCounters::new(objset.dataset_name, objset.read_bytes, objset.write_bytes))
+        .collect())
 }
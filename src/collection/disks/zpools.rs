@@ -0,0 +1,156 @@
+//! ZFS pool state and I/O on Linux, from `/proc/spl/kstat/zfs`.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+const ZFS_KSTAT_DIR: &str = "/proc/spl/kstat/zfs";
+
+/// The state and cumulative I/O of a ZFS pool.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ZpoolHarvest {
+    pub name: String,
+
+    /// The pool state, such as `ONLINE` or `DEGRADED`.
+    pub state: String,
+
+    /// Bytes read from all datasets in the pool.
+    pub read_bytes: u64,
+
+    /// Bytes written to all datasets in the pool.
+    pub write_bytes: u64,
+
+    /// The I/O of each dataset in the pool.
+    pub datasets: Vec<ObjsetCounters>,
+}
+
+impl ZpoolHarvest {
+    /// Whether the pool is in a state other than `ONLINE`.
+    pub fn is_unhealthy(&self) -> bool {
+        self.state != "ONLINE"
+    }
+}
+
+/// The counters of a single dataset, from an `objset-*` file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ObjsetCounters {
+    pub dataset_name: String,
+    pub read_bytes: u64,
+    pub write_bytes: u64,
+}
+
+/// Returns the kstat directory of each imported pool.
+pub(crate) fn pool_dirs() -> Vec<PathBuf> {
+    fs::read_dir(ZFS_KSTAT_DIR)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| path.is_dir())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Returns the counters of every dataset in a pool's kstat directory.
+pub(crate) fn pool_objsets(pool_dir: &Path) -> Vec<ObjsetCounters> {
+    fs::read_dir(pool_dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|entry| entry.file_name().to_string_lossy().starts_with("objset-"))
+                .filter_map(|entry| fs::read_to_string(entry.path()).ok())
+                .filter_map(|contents| parse_objset(&contents))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Parses an `objset-*` file, which looks like:
+///
+/// ```text
+/// 50 1 0x01 7 2160 5214787391 74150456813
+/// name                            type data
+/// dataset_name                    7    tank/home
+/// writes                          4    1234
+/// nwritten                        4    123456789
+/// reads                           4    5678
+/// nread                           4    987654321
+/// ```
+pub(crate) fn parse_objset(contents: &str) -> Option<ObjsetCounters> {
+    let mut dataset_name = None;
+    let mut read_bytes = 0;
+    let mut write_bytes = 0;
+
+    for line in contents.lines() {
+        let mut parts = line.split_whitespace();
+        let (Some(label), Some(_type), Some(value)) = (parts.next(), parts.next(), parts.next())
+        else {
+            continue;
+        };
+
+        match label {
+            "dataset_name" => dataset_name = Some(value.to_string()),
+            "nread" => read_bytes = value.parse().unwrap_or(0),
+            "nwritten" => write_bytes = value.parse().unwrap_or(0),
+            _ => {}
+        }
+    }
+
+    dataset_name.map(|dataset_name| ObjsetCounters {
+        dataset_name,
+        read_bytes,
+        write_bytes,
+    })
+}
+
+/// Returns the state and I/O of each imported pool.
+pub fn get_zpools() -> Vec<ZpoolHarvest> {
+    pool_dirs()
+        .into_iter()
+        .filter_map(|pool_dir| {
+            let name = pool_dir.file_name()?.to_string_lossy().to_string();
+            let state = fs::read_to_string(pool_dir.join("state")).ok()?;
+            let datasets = pool_objsets(&pool_dir);
+            let (read_bytes, write_bytes) =
+                datasets.iter().fold((0, 0), |(read, write), objset| {
+                    (read + objset.read_bytes, write + objset.write_bytes)
+                });
+
+            Some(ZpoolHarvest {
+                name,
+                state: state.trim().to_string(),
+                read_bytes,
+                write_bytes,
+                datasets,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_objset() {
+        let contents = "\
+50 1 0x01 7 2160 5214787391 74150456813
+name                            type data
+dataset_name                    7    tank/home
+writes                          4    1234
+nwritten                        4    123456789
+reads                           4    5678
+nread                           4    987654321
+nunlinks                        4    0
+";
+
+        let objset = parse_objset(contents).unwrap();
+        assert_eq!(objset.dataset_name, "tank/home");
+        assert_eq!(objset.read_bytes, 987654321);
+        assert_eq!(objset.write_bytes, 123456789);
+
+        assert!(parse_objset("name type data\nnread 4 1\n").is_none());
+    }
+}
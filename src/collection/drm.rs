@@ -0,0 +1,822 @@
+//! Per-process GPU usage based on DRM fdinfo, for any driver that reports the standard usage keys
+//! (e.g. `amdgpu`, `i915`, `xe`, `msm`, `panfrost` or `v3d`). This also covers accelerators such
+//! as NPUs that are exposed under `/dev/accel`.
+//!
+//! For more information, refer to the kernel's
+//! [DRM client usage stats](https://docs.kernel.org/gpu/drm-usage-stats.html) docs.
+
+use std::{
+    collections::BTreeMap,
+    fs::{self, read_to_string},
+    path::{Path, PathBuf},
+    sync::{LazyLock, Mutex},
+    time::{Duration, Instant},
+};
+
+use nohash::IntMap;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+use crate::{
+    app::layout_manager::UsedWidgets,
+    collection::{
+        gpu::{GpuEngineUtil, GpuProcUsage},
+        processes::Pid,
+    },
+};
+
+pub struct DrmGpuData {
+    pub procs: Option<(u64, Vec<IntMap<Pid, GpuProcUsage>>)>,
+}
+
+/// The [`GpuEngineUtil`] field that an engine counts towards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EngineClass {
+    Gfx,
+    Enc,
+    Dec,
+    Compute,
+}
+
+/// How to interpret the fdinfo of a specific driver.
+struct DriverInfo {
+    /// The `drm-driver` name.
+    name: &'static str,
+    /// The engines that count towards an [`EngineClass`]. Any other engine only counts towards
+    /// the overall utilization.
+    engines: &'static [(&'static str, EngineClass)],
+    /// The memory regions that count towards a process' memory usage, or [`None`] for all of them.
+    memory_regions: Option<&'static [&'static str]>,
+    /// Whether the total memory of the driver's devices is known, so process memory can also be
+    /// shown as a percentage.
+    has_total_memory: bool,
+}
+
+const DRIVERS: &[DriverInfo] = &[
+    DriverInfo {
+        name: "amdgpu",
+        engines: &[
+            ("gfx", EngineClass::Gfx),
+            ("compute", EngineClass::Compute),
+            ("enc", EngineClass::Enc),
+            ("enc_1", EngineClass::Enc),
+            ("dec", EngineClass::Dec),
+        ],
+        // Process memory is measured against the total VRAM.
+        memory_regions: Some(&["vram"]),
+        // The total VRAM is read by the AMD collector.
+        has_total_memory: true,
+    },
+    // The video engines of Intel GPUs handle both encoding and decoding, so they only count
+    // towards the overall utilization.
+    DriverInfo {
+        name: "i915",
+        engines: &[
+            ("render", EngineClass::Gfx),
+            ("compute", EngineClass::Compute),
+        ],
+        memory_regions: None,
+        has_total_memory: false,
+    },
+    DriverInfo {
+        name: "xe",
+        engines: &[("rcs", EngineClass::Gfx), ("ccs", EngineClass::Compute)],
+        memory_regions: None,
+        has_total_memory: false,
+    },
+    DriverInfo {
+        name: "msm",
+        engines: &[("gpu", EngineClass::Gfx)],
+        memory_regions: None,
+        has_total_memory: false,
+    },
+    DriverInfo {
+        name: "panfrost",
+        engines: &[
+            ("fragment", EngineClass::Gfx),
+            ("vertex-tiler", EngineClass::Gfx),
+        ],
+        memory_regions: None,
+        has_total_memory: false,
+    },
+    DriverInfo {
+        name: "v3d",
+        engines: &[
+            ("bin", EngineClass::Gfx),
+            ("render", EngineClass::Gfx),
+            ("csd", EngineClass::Compute),
+        ],
+        memory_regions: None,
+        has_total_memory: false,
+    },
+];
+
+/// Engine names commonly used by drivers that aren't in [`DRIVERS`].
+const GENERIC_ENGINES: &[(&str, EngineClass)] = &[
+    ("gfx", EngineClass::Gfx),
+    ("render", EngineClass::Gfx),
+    ("3d", EngineClass::Gfx),
+    ("compute", EngineClass::Compute),
+    ("enc", EngineClass::Enc),
+    ("encode", EngineClass::Enc),
+    ("dec", EngineClass::Dec),
+    ("decode", EngineClass::Dec),
+];
+
+/// Returns the [`EngineClass`] an engine of a driver counts towards, if any.
+fn engine_class(driver: &str, engine: &str) -> Option<EngineClass> {
+    let engines = DRIVERS
+        .iter()
+        .find(|info| info.name == driver)
+        .map_or(GENERIC_ENGINES, |info| info.engines);
+
+    engines
+        .iter()
+        .find_map(|(name, class)| (*name == engine).then_some(*class))
+}
+
+/// Returns whether the total memory of a driver's devices is known.
+fn has_total_memory(driver: &str) -> bool {
+    DRIVERS
+        .iter()
+        .find(|info| info.name == driver)
+        .is_some_and(|info| info.has_total_memory)
+}
+
+/// Returns whether a memory region of a driver counts towards a process' memory usage.
+fn is_counted_region(driver: &str, region: &str) -> bool {
+    DRIVERS
+        .iter()
+        .find(|info| info.name == driver)
+        .and_then(|info| info.memory_regions)
+        .is_none_or(|regions| regions.contains(&region))
+}
+
+/// A DRM device, identified by its driver and PCI address. Platform devices, which are common
+/// on SoCs, don't have a PCI address, so it is left empty.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+struct DrmDevice {
+    driver: String,
+    pdev: String,
+}
+
+/// The usage of a single engine class (e.g. `gfx` or `video`).
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+struct EngineUsage {
+    /// Busy time, in nanoseconds.
+    time: u64,
+    /// Busy GPU cycles.
+    cycles: Option<u64>,
+    /// Total GPU cycles, which busy cycles are measured against.
+    total_cycles: Option<u64>,
+    /// The number of engines of this class, which busy time is shared between.
+    capacity: u64,
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct DrmGpuProc {
+    engines: BTreeMap<String, EngineUsage>,
+    /// Memory used, in bytes.
+    memory: u64,
+}
+
+impl DrmGpuProc {
+    fn engine(&mut self, name: &str) -> &mut EngineUsage {
+        self.engines.entry(name.to_string()).or_default()
+    }
+}
+
+/// The usage of one DRM client, parsed from a file descriptor's fdinfo.
+#[derive(Debug, Default, Eq, PartialEq)]
+struct DrmClient {
+    id: u64,
+    device: DrmDevice,
+    usage: DrmGpuProc,
+}
+
+// needs previous state for usage calculation
+static PROC_DATA: LazyLock<Mutex<HashMap<DrmDevice, IntMap<Pid, DrmGpuProc>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::default()));
+
+// from amdgpu_top: https://github.com/Umio-Yasuno/amdgpu_top/blob/c961cf6625c4b6d63fda7f03348323048563c584/crates/libamdgpu_top/src/stat/fdinfo/proc_info.rs#L114
+fn diff_usage(pre: u64, cur: u64, interval: &Duration) -> u64 {
+    use std::ops::Mul;
+
+    let diff_ns = if pre == 0 || cur < pre {
+        return 0;
+    } else {
+        cur.saturating_sub(pre) as u128
+    };
+
+    diff_ns
+        .mul(100)
+        .checked_div(interval.as_nanos())
+        .unwrap_or(0) as u64
+}
+
+/// Parses a memory value such as `9 MiB`, or `0` with no unit, into bytes.
+fn parse_memory(value: &str) -> Option<u64> {
+    let mut parts = value.split_whitespace();
+    let amount = parts.next()?.parse::<u64>().ok()?;
+    let multiplier = match parts.next() {
+        None => 1,
+        Some("KiB") => 1 << 10,
+        Some("MiB") => 1 << 20,
+        Some("GiB") => 1 << 30,
+        Some(_) => return None,
+    };
+
+    Some(amount * multiplier)
+}
+
+/// The keys that memory usage is reported under, in order of preference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum MemoryKey {
+    /// `drm-resident-<region>`.
+    Resident,
+    /// `drm-total-<region>`, which also counts memory that has been swapped out.
+    Total,
+    /// `drm-memory-<region>`, which older drivers report instead.
+    Legacy,
+}
+
+/// Parses the fdinfo of a file descriptor, returning [`None`] if it isn't a DRM client.
+fn parse_fdinfo(fdinfo: &str) -> Option<DrmClient> {
+    let mut client = DrmClient::default();
+    let mut has_driver = false;
+    let mut memory = Vec::new();
+
+    for line in fdinfo.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+
+        if key == "drm-driver" {
+            client.device.driver = value.to_string();
+            has_driver = true;
+        } else if key == "drm-client-id" {
+            client.id = value.parse().ok()?;
+        } else if key == "drm-pdev" {
+            client.device.pdev = value.to_string();
+        } else if let Some(engine) = key.strip_prefix("drm-engine-capacity-") {
+            let capacity = value.parse().unwrap_or(1);
+            client.usage.engine(engine).capacity = capacity;
+        } else if let Some(engine) = key.strip_prefix("drm-engine-") {
+            // The time an engine class was busy, e.g. `25662044495 ns`.
+            if let Some(time) = value.strip_suffix(" ns").and_then(|ns| ns.parse().ok()) {
+                client.usage.engine(engine).time = time;
+            }
+        } else if let Some(engine) = key.strip_prefix("drm-total-cycles-") {
+            if let Ok(cycles) = value.parse() {
+                client.usage.engine(engine).total_cycles = Some(cycles);
+            }
+        } else if let Some(engine) = key.strip_prefix("drm-cycles-") {
+            if let Ok(cycles) = value.parse() {
+                client.usage.engine(engine).cycles = Some(cycles);
+            }
+        } else if let Some(region) = key.strip_prefix("drm-resident-") {
+            memory.push((MemoryKey::Resident, region, value));
+        } else if let Some(region) = key.strip_prefix("drm-total-") {
+            memory.push((MemoryKey::Total, region, value));
+        } else if let Some(region) = key.strip_prefix("drm-memory-") {
+            memory.push((MemoryKey::Legacy, region, value));
+        }
+    }
+
+    if !has_driver {
+        return None;
+    }
+
+    memory.retain(|(_, region, _)| is_counted_region(&client.device.driver, region));
+    if let Some(preferred) = memory.iter().map(|(key, _, _)| *key).min() {
+        client.usage.memory = memory
+            .iter()
+            .filter(|(key, _, _)| *key == preferred)
+            .filter_map(|(_, _, value)| parse_memory(value))
+            .sum();
+    }
+
+    for engine in client.usage.engines.values_mut() {
+        engine.capacity = engine.capacity.max(1);
+    }
+
+    Some(client)
+}
+
+/// Adds the usage of another DRM client of the same process.
+fn add_client(usage: &mut DrmGpuProc, client: DrmGpuProc) {
+    for (name, engine) in client.engines {
+        let total = usage.engines.entry(name).or_insert(EngineUsage {
+            capacity: engine.capacity,
+            ..Default::default()
+        });
+        total.time += engine.time;
+        if let Some(cycles) = engine.cycles {
+            *total.cycles.get_or_insert(0) += cycles;
+        }
+        // Total cycles are the same GPU timestamp for every client, so they shouldn't be summed.
+        total.total_cycles = total.total_cycles.max(engine.total_cycles);
+    }
+    usage.memory += client.memory;
+}
+
+/// Calculates the utilization of a single engine class between two readings.
+fn engine_util(prev: &EngineUsage, curr: &EngineUsage, interval: &Duration) -> u64 {
+    let util = match (
+        prev.cycles,
+        curr.cycles,
+        prev.total_cycles,
+        curr.total_cycles,
+    ) {
+        (Some(prev_cycles), Some(curr_cycles), Some(prev_total), Some(curr_total)) => {
+            let total = curr_total.saturating_sub(prev_total);
+            if prev_cycles == 0 || curr_cycles < prev_cycles || total == 0 {
+                0
+            } else {
+                (curr_cycles - prev_cycles).saturating_mul(100) / total
+            }
+        }
+        _ => diff_usage(prev.time, curr.time, interval),
+    };
+
+    util / curr.capacity
+}
+
+/// Calculates the GPU utilization of a process between two readings, summed across engines,
+/// along with the engines that map onto [`GpuEngineUtil`].
+fn gpu_util(
+    driver: &str, prev: &DrmGpuProc, curr: &DrmGpuProc, interval: &Duration,
+) -> (u32, GpuEngineUtil) {
+    let mut util = 0;
+    let mut engines = GpuEngineUtil::default();
+
+    for (name, curr) in &curr.engines {
+        let Some(prev) = prev.engines.get(name) else {
+            continue;
+        };
+        let engine = engine_util(prev, curr, interval);
+        util += engine;
+
+        let engine = engine.try_into().unwrap_or(0);
+        match engine_class(driver, name) {
+            Some(EngineClass::Gfx) => engines.gfx += engine,
+            Some(EngineClass::Enc) => engines.enc += engine,
+            Some(EngineClass::Dec) => engines.dec += engine,
+            Some(EngineClass::Compute) => engines.compute += engine,
+            None => {}
+        }
+    }
+
+    (util.try_into().unwrap_or(0), engines)
+}
+
+/// Whether a `/sys/class/drm` or `/sys/class/accel` entry is a device node, rather than
+/// something like the `version` file or a connector (e.g. `card0-HDMI-A-1`).
+fn is_drm_device(name: &str) -> bool {
+    ["card", "renderD", "accel"].into_iter().any(|prefix| {
+        name.strip_prefix(prefix)
+            .is_some_and(|id| !id.is_empty() && id.bytes().all(|b| b.is_ascii_digit()))
+    })
+}
+
+/// Whether there are any DRM or accel devices, as otherwise no process can have one open.
+fn has_drm_devices() -> bool {
+    ["/sys/class/drm", "/sys/class/accel"]
+        .into_iter()
+        .any(|class| {
+            fs::read_dir(class).is_ok_and(|entries| {
+                entries
+                    .flatten()
+                    .any(|entry| entry.file_name().to_str().is_some_and(is_drm_device))
+            })
+        })
+}
+
+/// Returns the sysfs runtime PM status file of the device behind a device node, e.g.
+/// `/sys/class/drm/renderD128/device/power/runtime_status` for `/dev/dri/renderD128`.
+fn runtime_status_path(node: &Path) -> Option<PathBuf> {
+    let class = if node.starts_with("/dev/dri") {
+        "drm"
+    } else if node.starts_with("/dev/accel") {
+        "accel"
+    } else {
+        return None;
+    };
+
+    let name = node.file_name()?.to_str()?;
+    Some(PathBuf::from(format!(
+        "/sys/class/{class}/{name}/device/power/runtime_status"
+    )))
+}
+
+/// Whether the device behind a device node is runtime-suspended. Devices without runtime PM are
+/// treated as awake.
+fn is_runtime_suspended(node: &Path) -> bool {
+    runtime_status_path(node)
+        .and_then(|path| read_to_string(path).ok())
+        .is_some_and(|status| status.trim() == "suspended")
+}
+
+/// Returns the GPU usage of every process, keyed by device.
+fn get_drm_fdinfo() -> Option<HashMap<DrmDevice, IntMap<Pid, DrmGpuProc>>> {
+    // Skip walking every process' file descriptors if there's nothing to find.
+    if !has_drm_devices() {
+        return None;
+    }
+
+    let mut devices: HashMap<DrmDevice, IntMap<Pid, DrmGpuProc>> = HashMap::default();
+    // Whether each device node is suspended, so sysfs is only read once per node.
+    let mut suspended: HashMap<PathBuf, bool> = HashMap::default();
+
+    let pids = fs::read_dir("/proc").ok()?.filter_map(|dir_entry| {
+        let pid = dir_entry.ok()?.file_name().to_str()?.parse::<Pid>().ok()?;
+
+        // skip init process
+        (pid != 1).then_some(pid)
+    });
+
+    for pid in pids {
+        let Ok(fd_list) = fs::read_dir(format!("/proc/{pid}/fd/")) else {
+            continue;
+        };
+
+        let mut observed_ids: HashSet<(DrmDevice, u64)> = HashSet::default();
+        for fd_link in fd_list.flatten() {
+            // e.g. "/dev/dri/renderD128", "/dev/dri/card0" or "/dev/accel/accel0"
+            let Ok(node) = fs::read_link(fd_link.path()) else {
+                continue;
+            };
+            if !(node.starts_with("/dev/dri") || node.starts_with("/dev/accel")) {
+                continue;
+            }
+
+            // Skip if asleep to avoid wakeups, as some drivers resume the device to report usage.
+            let is_suspended = *suspended
+                .entry(node)
+                .or_insert_with_key(|node| is_runtime_suspended(node));
+            if is_suspended {
+                continue;
+            }
+
+            let fdinfo_path = format!(
+                "/proc/{pid}/fdinfo/{}",
+                fd_link.file_name().to_string_lossy()
+            );
+            let Some(client) = read_to_string(fdinfo_path)
+                .ok()
+                .and_then(|fdinfo| parse_fdinfo(&fdinfo))
+            else {
+                continue;
+            };
+
+            // Several file descriptors can refer to the same client.
+            if !observed_ids.insert((client.device.clone(), client.id)) {
+                continue;
+            }
+
+            let usage = devices
+                .entry(client.device)
+                .or_default()
+                .entry(pid)
+                .or_default();
+            add_client(usage, client.usage);
+        }
+    }
+
+    Some(devices)
+}
+
+pub fn get_drm_vecs(widgets_to_harvest: &UsedWidgets, prev_time: Instant) -> Option<DrmGpuData> {
+    if !widgets_to_harvest.use_proc {
+        return None;
+    }
+
+    let devices = get_drm_fdinfo()?;
+    let interval = Instant::now().duration_since(prev_time);
+    let mut proc_vec = Vec::with_capacity(devices.len());
+    let mut proc_info = PROC_DATA.lock().expect("mutex is poisoned");
+
+    for (device, procs) in devices {
+        let prev_fdinfo = proc_info.get(&device);
+        let mem_without_total = !has_total_memory(&device.driver);
+
+        let mut procs_map = IntMap::default();
+        for (pid, usage) in &procs {
+            let (util, engines) = prev_fdinfo
+                .and_then(|prev| prev.get(pid))
+                .map_or_else(Default::default, |prev| {
+                    gpu_util(&device.driver, prev, usage, &interval)
+                });
+
+            if util > 0 || usage.memory > 0 {
+                procs_map.insert(
+                    *pid,
+                    GpuProcUsage {
+                        mem: usage.memory,
+                        mem_without_total,
+                        util,
+                        engines,
+                    },
+                );
+            }
+        }
+
+        if !procs_map.is_empty() {
+            proc_vec.push(procs_map);
+        }
+
+        // Replace rather than update the previous readings, so exited processes are dropped.
+        proc_info.insert(device, procs);
+    }
+
+    // The total memory of each device isn't part of fdinfo, so it is left to the vendor-specific
+    // collectors. Memory of devices without a known total is marked so it's only shown in bytes.
+    Some(DrmGpuData {
+        procs: (!proc_vec.is_empty()).then_some((0, proc_vec)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const I915_FDINFO: &str = "pos:\t0
+flags:\t02100002
+mnt_id:\t26
+ino:\t1008
+drm-driver:\ti915
+drm-client-id:\t7
+drm-pdev:\t0000:00:02.0
+drm-total-system0:\t9 MiB
+drm-shared-system0:\t0
+drm-active-system0:\t0
+drm-resident-system0:\t9 MiB
+drm-purgeable-system0:\t0
+drm-total-stolen-system0:\t0
+drm-shared-stolen-system0:\t0
+drm-active-stolen-system0:\t0
+drm-resident-stolen-system0:\t0
+drm-purgeable-stolen-system0:\t0
+drm-engine-render:\t25662044495 ns
+drm-engine-copy:\t0 ns
+drm-engine-video:\t3000000 ns
+drm-engine-capacity-video:\t2
+drm-engine-video-enhance:\t0 ns
+";
+
+    const XE_FDINFO: &str = "pos:\t0
+flags:\t0100002
+mnt_id:\t25
+ino:\t1133
+drm-driver:\txe
+drm-client-id:\t21
+drm-pdev:\t0000:03:00.0
+drm-total-system:\t0
+drm-shared-system:\t0
+drm-active-system:\t0
+drm-resident-system:\t0
+drm-purgeable-system:\t0
+drm-total-gtt:\t4 MiB
+drm-shared-gtt:\t0
+drm-active-gtt:\t0
+drm-resident-gtt:\t4 MiB
+drm-total-vram0:\t512 KiB
+drm-shared-vram0:\t0
+drm-active-vram0:\t0
+drm-resident-vram0:\t512 KiB
+drm-purgeable-vram0:\t0
+drm-cycles-rcs:\t28257900
+drm-total-cycles-rcs:\t7655183225
+drm-cycles-bcs:\t0
+drm-total-cycles-bcs:\t7655183225
+drm-cycles-vcs:\t0
+drm-total-cycles-vcs:\t7655183225
+drm-engine-capacity-vcs:\t2
+";
+
+    const AMDGPU_FDINFO: &str = "pos:\t0
+flags:\t02100002
+mnt_id:\t24
+ino:\t1063
+drm-driver:\tamdgpu
+drm-client-id:\t12
+drm-pdev:\t0000:03:00.0
+pasid:\t32770
+drm-memory-vram:\t8192 KiB
+drm-memory-gtt:\t2048 KiB
+drm-memory-cpu:\t0 KiB
+amd-memory-visible-vram:\t8192 KiB
+drm-engine-gfx:\t1000 ns
+drm-engine-compute:\t0 ns
+drm-engine-dec:\t0 ns
+drm-engine-enc:\t0 ns
+";
+
+    const PANFROST_FDINFO: &str = "pos:\t0
+flags:\t02100002
+drm-driver:\tpanfrost
+drm-client-id:\t3
+drm-engine-fragment:\t4000000 ns
+drm-cycles-fragment:\t3200000
+drm-maxfreq-fragment:\t800000000 Hz
+drm-curfreq-fragment:\t800000000 Hz
+drm-engine-vertex-tiler:\t1000000 ns
+drm-total-memory:\t32 MiB
+drm-resident-memory:\t16 MiB
+";
+
+    const UNKNOWN_FDINFO: &str = "pos:\t0
+flags:\t02100002
+drm-driver:\tnpu
+drm-client-id:\t1
+drm-pdev:\t0000:00:0b.0
+drm-engine-compute:\t0 ns
+drm-engine-dma:\t0 ns
+";
+
+    #[test]
+    fn test_is_drm_device() {
+        assert!(is_drm_device("card0"));
+        assert!(is_drm_device("renderD128"));
+        assert!(is_drm_device("accel0"));
+        assert!(!is_drm_device("version"));
+        assert!(!is_drm_device("card0-HDMI-A-1"));
+        assert!(!is_drm_device("card"));
+    }
+
+    #[test]
+    fn test_runtime_status_path() {
+        assert_eq!(
+            runtime_status_path(Path::new("/dev/dri/renderD128")),
+            Some(PathBuf::from(
+                "/sys/class/drm/renderD128/device/power/runtime_status"
+            ))
+        );
+        assert_eq!(
+            runtime_status_path(Path::new("/dev/accel/accel0")),
+            Some(PathBuf::from(
+                "/sys/class/accel/accel0/device/power/runtime_status"
+            ))
+        );
+        assert_eq!(runtime_status_path(Path::new("/dev/null")), None);
+    }
+
+    #[test]
+    fn test_parse_memory() {
+        assert_eq!(parse_memory("0"), Some(0));
+        assert_eq!(parse_memory("512 KiB"), Some(512 << 10));
+        assert_eq!(parse_memory("9 MiB"), Some(9 << 20));
+        assert_eq!(parse_memory("2 GiB"), Some(2 << 30));
+        assert_eq!(parse_memory("2 bogus"), None);
+    }
+
+    #[test]
+    fn test_has_total_memory() {
+        assert!(has_total_memory("amdgpu"));
+        assert!(!has_total_memory("i915"));
+        assert!(!has_total_memory("xe"));
+        assert!(!has_total_memory("some_npu"));
+    }
+
+    #[test]
+    fn test_parse_i915_fdinfo() {
+        let client = parse_fdinfo(I915_FDINFO).unwrap();
+        assert_eq!(client.id, 7);
+        assert_eq!(client.device.driver, "i915");
+        assert_eq!(client.device.pdev, "0000:00:02.0");
+        assert_eq!(client.usage.memory, 9 << 20);
+
+        let render = client.usage.engines["render"];
+        assert_eq!(render.time, 25_662_044_495);
+        assert_eq!(render.cycles, None);
+        assert_eq!(render.capacity, 1);
+        assert_eq!(client.usage.engines["video"].capacity, 2);
+        assert_eq!(client.usage.engines.len(), 4);
+    }
+
+    #[test]
+    fn test_parse_xe_fdinfo() {
+        let client = parse_fdinfo(XE_FDINFO).unwrap();
+        assert_eq!(client.id, 21);
+        assert_eq!(client.device.pdev, "0000:03:00.0");
+        assert_eq!(client.usage.memory, (4 << 20) + (512 << 10));
+
+        let rcs = client.usage.engines["rcs"];
+        assert_eq!(rcs.cycles, Some(28_257_900));
+        assert_eq!(rcs.total_cycles, Some(7_655_183_225));
+        assert_eq!(client.usage.engines["vcs"].capacity, 2);
+    }
+
+    #[test]
+    fn test_parse_amdgpu_fdinfo() {
+        let client = parse_fdinfo(AMDGPU_FDINFO).unwrap();
+        assert_eq!(client.id, 12);
+        assert_eq!(client.device.driver, "amdgpu");
+        assert_eq!(client.device.pdev, "0000:03:00.0");
+
+        // Only VRAM counts, as that is what the memory percentage is measured against.
+        assert_eq!(client.usage.memory, 8192 << 10);
+        assert_eq!(client.usage.engines["gfx"].time, 1000);
+        assert_eq!(client.usage.engines.len(), 4);
+    }
+
+    #[test]
+    fn test_parse_platform_fdinfo() {
+        let client = parse_fdinfo(PANFROST_FDINFO).unwrap();
+        assert_eq!(client.device.driver, "panfrost");
+        assert_eq!(client.device.pdev, "");
+        assert_eq!(client.usage.memory, 16 << 20);
+
+        // Without total cycles, busy cycles can't be turned into a percentage, so the time is used.
+        let fragment = client.usage.engines["fragment"];
+        assert_eq!(fragment.time, 4_000_000);
+        assert_eq!(fragment.cycles, Some(3_200_000));
+        assert_eq!(fragment.total_cycles, None);
+    }
+
+    #[test]
+    fn test_parse_non_drm() {
+        assert_eq!(parse_fdinfo("pos:\t0\nflags:\t02100002\n"), None);
+    }
+
+    #[test]
+    fn test_i915_gpu_util() {
+        let prev = parse_fdinfo(I915_FDINFO).unwrap().usage;
+        let mut curr = prev.clone();
+
+        // 250ms of render time and 200ms across both video engines over a second.
+        curr.engines.get_mut("render").unwrap().time += 250_000_000;
+        curr.engines.get_mut("video").unwrap().time += 200_000_000;
+
+        let (util, engines) = gpu_util("i915", &prev, &curr, &Duration::from_secs(1));
+        assert_eq!(util, 35);
+        assert_eq!(engines.gfx, 25);
+        assert_eq!(engines.compute, 0);
+        assert_eq!(engines.enc + engines.dec, 0);
+    }
+
+    #[test]
+    fn test_xe_gpu_util() {
+        let prev = parse_fdinfo(XE_FDINFO).unwrap().usage;
+        let mut curr = prev.clone();
+
+        for engine in curr.engines.values_mut() {
+            *engine.total_cycles.as_mut().unwrap() += 1_000_000;
+        }
+        *curr
+            .engines
+            .get_mut("rcs")
+            .unwrap()
+            .cycles
+            .as_mut()
+            .unwrap() += 400_000;
+
+        // The interval is ignored in favour of the elapsed GPU cycles.
+        let (util, engines) = gpu_util("xe", &prev, &curr, &Duration::from_secs(5));
+        assert_eq!(util, 40);
+        assert_eq!(engines.gfx, 40);
+    }
+
+    #[test]
+    fn test_amdgpu_gpu_util() {
+        let prev = parse_fdinfo(AMDGPU_FDINFO).unwrap().usage;
+        let mut curr = prev.clone();
+
+        curr.engines.get_mut("gfx").unwrap().time += 300_000_000;
+        // Engines that were idle in the previous reading have no baseline yet.
+        curr.engines.get_mut("enc").unwrap().time += 100_000_000;
+
+        let (util, engines) = gpu_util("amdgpu", &prev, &curr, &Duration::from_secs(1));
+        assert_eq!(util, 30);
+        assert_eq!(engines.gfx, 30);
+        assert_eq!(engines.enc, 0);
+    }
+
+    #[test]
+    fn test_unknown_driver_gpu_util() {
+        let mut prev = parse_fdinfo(UNKNOWN_FDINFO).unwrap().usage;
+        prev.engines.get_mut("compute").unwrap().time = 1;
+        prev.engines.get_mut("dma").unwrap().time = 1;
+        let mut curr = prev.clone();
+
+        curr.engines.get_mut("compute").unwrap().time += 600_000_000;
+        curr.engines.get_mut("dma").unwrap().time += 100_000_000;
+
+        // Unknown engines still count towards the overall utilization.
+        let (util, engines) = gpu_util("npu", &prev, &curr, &Duration::from_secs(1));
+        assert_eq!(util, 70);
+        assert_eq!(engines.compute, 60);
+        assert_eq!(engines.gfx, 0);
+    }
+
+    #[test]
+    fn test_add_client() {
+        let mut usage = parse_fdinfo(XE_FDINFO).unwrap().usage;
+        add_client(&mut usage, parse_fdinfo(XE_FDINFO).unwrap().usage);
+
+        let rcs = usage.engines["rcs"];
+        assert_eq!(rcs.cycles, Some(2 * 28_257_900));
+        assert_eq!(rcs.total_cycles, Some(7_655_183_225));
+        assert_eq!(usage.memory, 2 * ((4 << 20) + (512 << 10)));
+    }
+}
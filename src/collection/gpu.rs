@@ -0,0 +1,66 @@
+//! GPU activity, per device and per process, shared between the vendor-specific collectors.
+
+use std::ops::AddAssign;
+
+/// A snapshot of a GPU's activity. Fields are [`None`] if the device or its driver doesn't
+/// report them.
+#[derive(Debug, Clone, Default)]
+pub struct GpuStats {
+    /// A stable identifier for the device, such as its PCI bus ID. Unlike the name, this is
+    /// unique even with several of the same GPU.
+    pub id: String,
+    pub name: String,
+    /// Overall utilization, as a percentage.
+    pub utilization: Option<f64>,
+    /// Video encoder utilization, as a percentage.
+    pub encoder: Option<f64>,
+    /// Video decoder utilization, as a percentage.
+    pub decoder: Option<f64>,
+    /// Temperature, in Celsius.
+    pub temperature: Option<f32>,
+    /// Power draw, in watts.
+    pub power: Option<f64>,
+    /// Power limit, in watts.
+    pub power_limit: Option<f64>,
+    /// Core clock, in MHz.
+    pub core_clock: Option<u32>,
+    /// Memory clock, in MHz.
+    pub memory_clock: Option<u32>,
+    /// Fan speed, as a percentage of its maximum.
+    pub fan: Option<f64>,
+}
+
+/// The utilization of a process broken down by engine, as percentages. Engines that a driver
+/// doesn't report separately are left at zero.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GpuEngineUtil {
+    pub gfx: u32,
+    pub enc: u32,
+    pub dec: u32,
+    pub compute: u32,
+}
+
+impl AddAssign for GpuEngineUtil {
+    fn add_assign(&mut self, rhs: Self) {
+        self.gfx += rhs.gfx;
+        self.enc += rhs.enc;
+        self.dec += rhs.dec;
+        self.compute += rhs.compute;
+    }
+}
+
+/// The usage of a single GPU by a process.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GpuProcUsage {
+    /// GPU memory used, in bytes.
+    pub mem: u64,
+    /// Whether the GPU's total memory is unknown, in which case `mem` doesn't count towards the
+    /// process' GPU memory percentage.
+    #[cfg_attr(not(any(target_os = "linux", target_os = "windows")), allow(dead_code))]
+    pub mem_without_total: bool,
+    /// Utilization summed across all engines, as a percentage.
+    #[cfg_attr(not(any(target_os = "linux", target_os = "windows")), allow(dead_code))]
+    pub util: u32,
+    #[cfg_attr(not(any(target_os = "linux", target_os = "windows")), allow(dead_code))]
+    pub engines: GpuEngineUtil,
+}
@@ -18,6 +18,15 @@ cfg_if::cfg_if! {
 #[cfg(feature = "zfs")]
 pub mod arc;
 
+#[cfg(target_os = "linux")]
+pub mod zram;
+
+#[cfg(target_os = "linux")]
+pub mod zswap;
+
+#[cfg(target_os = "linux")]
+pub mod vmstat;
+
 #[derive(Debug, Clone)]
 pub struct MemData {
     pub used_bytes: u64,
@@ -34,3 +43,39 @@ impl MemData {
         used / total * 100.0
     }
 }
+
+/// Usage of a compressed in-memory store, such as zram or zswap.
+#[cfg(target_os = "linux")]
+#[derive(Debug, Clone)]
+pub struct CompressedMemData {
+    /// The uncompressed size of the stored data.
+    pub original_bytes: u64,
+
+    /// The compressed size of the stored data.
+    pub compressed_bytes: u64,
+
+    /// How much RAM the store actually takes up, including any overhead.
+    pub used_bytes: u64,
+
+    /// The total RAM of the system.
+    pub total_bytes: NonZeroU64,
+}
+
+#[cfg(target_os = "linux")]
+impl CompressedMemData {
+    /// Return how much of the system's RAM the store takes up, as a percentage.
+    #[inline]
+    pub fn percentage(&self) -> f64 {
+        let used = self.used_bytes as f64;
+        let total = self.total_bytes.get() as f64;
+
+        used / total * 100.0
+    }
+
+    /// Return the compression ratio, or [`None`] if nothing is stored.
+    #[inline]
+    pub fn compression_ratio(&self) -> Option<f64> {
+        (self.compressed_bytes > 0)
+            .then(|| self.original_bytes as f64 / self.compressed_bytes as f64)
+    }
+}
@@ -78,3 +78,130 @@ pub(crate) fn get_arc_usage() -> Option<(MemData, u64)> {
         )
     })
 }
+
+/// Cumulative ARC and L2ARC counters from `/proc/spl/kstat/zfs/arcstats`.
+#[cfg(all(feature = "zfs", target_os = "linux"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct ArcCounters {
+    hits: u64,
+    misses: u64,
+    l2_hits: u64,
+    l2_misses: u64,
+    l2_size: u64,
+}
+
+/// ARC and L2ARC efficiency over the last collection interval.
+#[cfg(all(feature = "zfs", target_os = "linux"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ArcDetail {
+    /// The percentage of ARC lookups that hit, or `None` if there were no lookups.
+    pub hit_percent: Option<f64>,
+
+    /// The percentage of L2ARC lookups that hit, or `None` if there were no lookups.
+    pub l2_hit_percent: Option<f64>,
+
+    /// The amount of data stored in the L2ARC, in bytes. Zero if there is no L2ARC.
+    pub l2_size: u64,
+}
+
+#[cfg(all(feature = "zfs", target_os = "linux"))]
+impl ArcCounters {
+    fn parse(arc_stats: &str) -> Self {
+        let mut counters = Self::default();
+
+        // Each line looks like `hits                            4    123456`.
+        for line in arc_stats.lines() {
+            let mut parts = line.split_whitespace();
+            let (Some(label), Some(_type), Some(value)) =
+                (parts.next(), parts.next(), parts.next())
+            else {
+                continue;
+            };
+
+            let to_write = match label {
+                "hits" => &mut counters.hits,
+                "misses" => &mut counters.misses,
+                "l2_hits" => &mut counters.l2_hits,
+                "l2_misses" => &mut counters.l2_misses,
+                "l2_size" => &mut counters.l2_size,
+                _ => continue,
+            };
+
+            if let Ok(value) = value.parse() {
+                *to_write = value;
+            }
+        }
+
+        counters
+    }
+
+    /// Calculates the hit ratios between a previous reading and this one.
+    pub(crate) fn detail(&self, prev: &ArcCounters) -> ArcDetail {
+        fn hit_percent(hits: u64, misses: u64) -> Option<f64> {
+            let lookups = hits + misses;
+            (lookups > 0).then(|| hits as f64 / lookups as f64 * 100.0)
+        }
+
+        ArcDetail {
+            hit_percent: hit_percent(
+                self.hits.saturating_sub(prev.hits),
+                self.misses.saturating_sub(prev.misses),
+            ),
+            l2_hit_percent: hit_percent(
+                self.l2_hits.saturating_sub(prev.l2_hits),
+                self.l2_misses.saturating_sub(prev.l2_misses),
+            ),
+            l2_size: self.l2_size,
+        }
+    }
+}
+
+/// Returns the cumulative ARC and L2ARC counters.
+#[cfg(all(feature = "zfs", target_os = "linux"))]
+pub(crate) fn get_arc_counters() -> Option<ArcCounters> {
+    std::fs::read_to_string("/proc/spl/kstat/zfs/arcstats")
+        .ok()
+        .map(|arc_stats| ArcCounters::parse(&arc_stats))
+}
+
+#[cfg(all(test, feature = "zfs", target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arc_detail() {
+        let arc_stats = "\
+13 1 0x01 123 33456 1234567890 9876543210
+name                            type data
+hits                            4    1000
+misses                          4    100
+demand_data_hits                4    600
+l2_hits                         4    30
+l2_misses                       4    70
+l2_size                         4    4294967296
+";
+        let prev = ArcCounters::parse(arc_stats);
+        assert_eq!(
+            prev,
+            ArcCounters {
+                hits: 1000,
+                misses: 100,
+                l2_hits: 30,
+                l2_misses: 70,
+                l2_size: 4294967296,
+            }
+        );
+
+        let curr = ArcCounters {
+            hits: 1900,
+            misses: 200,
+            l2_hits: 30,
+            l2_misses: 70,
+            ..prev
+        };
+        let detail = curr.detail(&prev);
+        assert_eq!(detail.hit_percent, Some(90.0));
+        assert_eq!(detail.l2_hit_percent, None);
+        assert_eq!(detail.l2_size, 4294967296);
+    }
+}
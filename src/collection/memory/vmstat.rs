@@ -0,0 +1,271 @@
+//! Virtual memory activity from `/proc/vmstat`. Linux-only.
+//!
+//! All counters in `/proc/vmstat` are cumulative since boot, so we keep the
+//! previous reading around and report per-second rates.
+
+use std::{
+    fs::{self, OpenOptions},
+    io::{ErrorKind, Read},
+    os::unix::fs::OpenOptionsExt,
+    time::Duration,
+};
+
+use crate::collection::processes::Pid;
+
+/// The raw cumulative counters we care about from `/proc/vmstat`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct VmStatCounters {
+    pgfault: u64,
+    pgmajfault: u64,
+    pswpin: u64,
+    pswpout: u64,
+    pgscan: u64,
+    pgsteal: u64,
+    oom_kill: u64,
+}
+
+/// Memory pressure rates, in events per second.
+#[derive(Debug, Default, Clone)]
+pub struct VmStatHarvest {
+    /// Page faults (minor and major).
+    pub pgfault: f64,
+
+    /// Major page faults, which required I/O.
+    pub pgmajfault: f64,
+
+    /// Pages swapped in.
+    pub pswpin: f64,
+
+    /// Pages swapped out.
+    pub pswpout: f64,
+
+    /// Pages scanned for reclaim, by both kswapd and direct reclaim.
+    pub pgscan: f64,
+
+    /// Pages reclaimed, by both kswapd and direct reclaim.
+    pub pgsteal: f64,
+
+    /// OOM kills.
+    pub oom_kill: f64,
+
+    /// How many OOM kills happened since the last reading.
+    pub new_oom_kills: u64,
+
+    /// The most recent OOM victim, if there were new OOM kills and the victim
+    /// could be found in the kernel log.
+    pub oom_victim: Option<OomVictim>,
+}
+
+/// A process killed by the OOM killer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OomVictim {
+    pub pid: Pid,
+    pub name: String,
+}
+
+/// Parses the contents of `/proc/vmstat`.
+///
+/// Scan and steal counters are split by who did the work (kswapd, direct
+/// reclaim, khugepaged), so we sum those up.
+fn parse_vmstat(contents: &str) -> VmStatCounters {
+    let mut counters = VmStatCounters::default();
+
+    for line in contents.lines() {
+        let Some((key, value)) = line.split_once(' ') else {
+            continue;
+        };
+
+        let Ok(value) = value.trim().parse::<u64>() else {
+            continue;
+        };
+
+        match key {
+            "pgfault" => counters.pgfault = value,
+            "pgmajfault" => counters.pgmajfault = value,
+            "pswpin" => counters.pswpin = value,
+            "pswpout" => counters.pswpout = value,
+            "oom_kill" => counters.oom_kill = value,
+            "pgscan_kswapd" | "pgscan_direct" | "pgscan_khugepaged" => counters.pgscan += value,
+            "pgsteal_kswapd" | "pgsteal_direct" | "pgsteal_khugepaged" => counters.pgsteal += value,
+            _ => {}
+        }
+    }
+
+    counters
+}
+
+/// Returns the current counters from `/proc/vmstat`.
+pub(crate) fn get_vmstat_counters() -> Option<VmStatCounters> {
+    fs::read_to_string("/proc/vmstat")
+        .ok()
+        .map(|contents| parse_vmstat(&contents))
+}
+
+impl VmStatCounters {
+    /// Calculates the rates between a previous reading and this one.
+    pub(crate) fn rates(&self, prev: &VmStatCounters, elapsed: Duration) -> VmStatHarvest {
+        let elapsed = elapsed.as_secs_f64();
+        let rate = |curr: u64, prev: u64| {
+            if elapsed > 0.0 {
+                curr.saturating_sub(prev) as f64 / elapsed
+            } else {
+                0.0
+            }
+        };
+
+        VmStatHarvest {
+            pgfault: rate(self.pgfault, prev.pgfault),
+            pgmajfault: rate(self.pgmajfault, prev.pgmajfault),
+            pswpin: rate(self.pswpin, prev.pswpin),
+            pswpout: rate(self.pswpout, prev.pswpout),
+            pgscan: rate(self.pgscan, prev.pgscan),
+            pgsteal: rate(self.pgsteal, prev.pgsteal),
+            oom_kill: rate(self.oom_kill, prev.oom_kill),
+            new_oom_kills: self.oom_kill.saturating_sub(prev.oom_kill),
+            oom_victim: None,
+        }
+    }
+}
+
+/// Parses an OOM killer message like `Out of memory: Killed process 1234 (foo)
+/// total-vm:...` from the kernel log.
+fn parse_oom_victim(line: &str) -> Option<OomVictim> {
+    let (_, rest) = line.split_once("Killed process ")?;
+    let (pid, rest) = rest.split_once(' ')?;
+    let pid = pid.parse().ok()?;
+    let name = rest.strip_prefix('(')?;
+    let name = match name.find(") ") {
+        Some(end) => &name[..end],
+        None => name.trim_end().strip_suffix(')')?,
+    };
+
+    Some(OomVictim {
+        pid,
+        name: name.to_string(),
+    })
+}
+
+/// Looks for the most recent OOM victim in the kernel log.
+///
+/// Reading `/dev/kmsg` usually requires privileges (see `dmesg_restrict`), so
+/// this will often return [`None`].
+pub(crate) fn get_latest_oom_victim() -> Option<OomVictim> {
+    let mut kmsg = OpenOptions::new()
+        .read(true)
+        .custom_flags(libc::O_NONBLOCK)
+        .open("/dev/kmsg")
+        .ok()?;
+
+    // Each read returns exactly one record.
+    let mut buffer = vec![0; 8192];
+    let mut victim = None;
+
+    loop {
+        match kmsg.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(read) => {
+                let record = String::from_utf8_lossy(&buffer[..read]);
+                if let Some(found) = parse_oom_victim(&record) {
+                    victim = Some(found);
+                }
+            }
+            // Records were overwritten while we were reading, just keep going.
+            Err(err) if err.kind() == ErrorKind::BrokenPipe => continue,
+            Err(_) => break,
+        }
+    }
+
+    victim
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_vmstat() {
+        let contents = "nr_free_pages 1405318\n\
+            pswpin 120\n\
+            pswpout 4500\n\
+            pgfault 987654321\n\
+            pgmajfault 4321\n\
+            pgsteal_kswapd 1000\n\
+            pgsteal_direct 200\n\
+            pgsteal_khugepaged 3\n\
+            pgscan_kswapd 2000\n\
+            pgscan_direct 400\n\
+            pgscan_khugepaged 5\n\
+            pgscan_anon 9999\n\
+            oom_kill 2\n";
+
+        assert_eq!(
+            parse_vmstat(contents),
+            VmStatCounters {
+                pgfault: 987654321,
+                pgmajfault: 4321,
+                pswpin: 120,
+                pswpout: 4500,
+                pgscan: 2405,
+                pgsteal: 1203,
+                oom_kill: 2,
+            }
+        );
+
+        assert_eq!(parse_vmstat(""), VmStatCounters::default());
+    }
+
+    #[test]
+    fn test_rates() {
+        let prev = VmStatCounters {
+            pgfault: 1000,
+            pswpout: 10,
+            oom_kill: 1,
+            ..Default::default()
+        };
+        let curr = VmStatCounters {
+            pgfault: 3000,
+            pswpout: 50,
+            oom_kill: 3,
+            ..Default::default()
+        };
+
+        let rates = curr.rates(&prev, Duration::from_secs(2));
+        assert_eq!(rates.pgfault, 1000.0);
+        assert_eq!(rates.pswpout, 20.0);
+        assert_eq!(rates.pswpin, 0.0);
+        assert_eq!(rates.oom_kill, 1.0);
+        assert_eq!(rates.new_oom_kills, 2);
+
+        // Counters shouldn't go backwards, but don't underflow if they somehow do.
+        let rates = prev.rates(&curr, Duration::from_secs(1));
+        assert_eq!(rates.pgfault, 0.0);
+        assert_eq!(rates.new_oom_kills, 0);
+    }
+
+    #[test]
+    fn test_parse_oom_victim() {
+        assert_eq!(
+            parse_oom_victim(
+                "3,1424,81930210,-;Out of memory: Killed process 4242 (stress-ng) total-vm:2097152kB, anon-rss:1048576kB, file-rss:0kB, shmem-rss:0kB, UID:1000 pgtables:2100kB oom_score_adj:0\n"
+            ),
+            Some(OomVictim {
+                pid: 4242,
+                name: "stress-ng".to_string(),
+            })
+        );
+
+        // Older kernels had a slightly different format.
+        assert_eq!(
+            parse_oom_victim("3,880,5000,-;Killed process 17 (Web Content)"),
+            Some(OomVictim {
+                pid: 17,
+                name: "Web Content".to_string(),
+            })
+        );
+
+        assert_eq!(
+            parse_oom_victim("6,1,0,-;oom-kill:constraint=CONSTRAINT_NONE"),
+            None
+        );
+    }
+}
@@ -0,0 +1,101 @@
+//! zram compressed swap device usage. Linux-only.
+//!
+//! See the [kernel documentation](https://docs.kernel.org/admin-guide/blockdev/zram.html#stats)
+//! for the layout of `mm_stat`.
+
+use std::{fs, num::NonZeroU64, path::Path};
+
+use super::CompressedMemData;
+
+/// The fields we care about from a zram device's `mm_stat` file.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct MmStat {
+    orig_data_size: u64,
+    compr_data_size: u64,
+    mem_used_total: u64,
+}
+
+/// Parses the contents of a `/sys/block/zram*/mm_stat` file. The first three
+/// fields are the original data size, the compressed data size, and the total
+/// memory used by the device (including allocator overhead), all in bytes.
+fn parse_mm_stat(contents: &str) -> Option<MmStat> {
+    let mut fields = contents.split_ascii_whitespace();
+
+    let orig_data_size = fields.next()?.parse().ok()?;
+    let compr_data_size = fields.next()?.parse().ok()?;
+    let mem_used_total = fields.next()?.parse().ok()?;
+
+    Some(MmStat {
+        orig_data_size,
+        compr_data_size,
+        mem_used_total,
+    })
+}
+
+/// Returns the combined usage of all zram devices, or [`None`] if there
+/// are no readable zram devices.
+///
+/// `total_bytes` should be the total amount of RAM on the system, as zram
+/// usage is reported as a fraction of RAM.
+pub(crate) fn get_zram_usage(total_bytes: u64) -> Option<CompressedMemData> {
+    let total_bytes = NonZeroU64::new(total_bytes)?;
+
+    let mut found = false;
+    let mut stats = MmStat::default();
+
+    for entry in Path::new("/sys/block").read_dir().ok()?.flatten() {
+        if !entry.file_name().to_string_lossy().starts_with("zram") {
+            continue;
+        }
+
+        if let Some(stat) = fs::read_to_string(entry.path().join("mm_stat"))
+            .ok()
+            .and_then(|contents| parse_mm_stat(&contents))
+        {
+            found = true;
+            stats.orig_data_size += stat.orig_data_size;
+            stats.compr_data_size += stat.compr_data_size;
+            stats.mem_used_total += stat.mem_used_total;
+        }
+    }
+
+    found.then_some(CompressedMemData {
+        original_bytes: stats.orig_data_size,
+        compressed_bytes: stats.compr_data_size,
+        used_bytes: stats.mem_used_total,
+        total_bytes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_mm_stat() {
+        let contents = "  1545019392    412844917    427597824            0    451698688    32184     2217      318      318\n";
+
+        assert_eq!(
+            parse_mm_stat(contents),
+            Some(MmStat {
+                orig_data_size: 1545019392,
+                compr_data_size: 412844917,
+                mem_used_total: 427597824,
+            })
+        );
+
+        // Older kernels have fewer fields, but the first three have always been the same.
+        assert_eq!(
+            parse_mm_stat("4096 74 12288 0 12288 0 0"),
+            Some(MmStat {
+                orig_data_size: 4096,
+                compr_data_size: 74,
+                mem_used_total: 12288,
+            })
+        );
+
+        assert_eq!(parse_mm_stat(""), None);
+        assert_eq!(parse_mm_stat("4096 74"), None);
+        assert_eq!(parse_mm_stat("a b c"), None);
+    }
+}
@@ -0,0 +1,123 @@
+//! zswap compressed swap cache usage. Linux-only.
+//!
+//! The detailed statistics live in debugfs (`/sys/kernel/debug/zswap`), which
+//! usually needs root. Newer kernels also expose the `Zswap` and `Zswapped`
+//! fields in `/proc/meminfo`, which we fall back to if debugfs isn't readable.
+
+use std::{fs, num::NonZeroU64, path::Path};
+
+use super::CompressedMemData;
+
+const ZSWAP_MODULE_PATH: &str = "/sys/module/zswap";
+const ZSWAP_DEBUGFS_PATH: &str = "/sys/kernel/debug/zswap";
+
+#[inline]
+fn read_u64(path: &Path) -> Option<u64> {
+    fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+/// Returns the original (uncompressed) and compressed sizes in bytes, read from
+/// debugfs.
+fn read_debugfs() -> Option<(u64, u64)> {
+    let debugfs = Path::new(ZSWAP_DEBUGFS_PATH);
+
+    let stored_pages = read_u64(&debugfs.join("stored_pages"))?;
+    let pool_total_size = read_u64(&debugfs.join("pool_total_size"))?;
+    let page_size = rustix::param::page_size() as u64;
+
+    Some((stored_pages * page_size, pool_total_size))
+}
+
+/// Parses the `Zswapped` (original) and `Zswap` (compressed) fields from the
+/// contents of `/proc/meminfo`, and returns them in bytes.
+fn parse_meminfo(contents: &str) -> Option<(u64, u64)> {
+    let mut original = None;
+    let mut compressed = None;
+
+    for line in contents.lines() {
+        let Some((label, value)) = line.split_once(':') else {
+            continue;
+        };
+
+        let to_write = match label {
+            "Zswapped" => &mut original,
+            "Zswap" => &mut compressed,
+            _ => continue,
+        };
+
+        // These values are in kibibytes.
+        *to_write = value
+            .trim()
+            .trim_end_matches("kB")
+            .trim_end()
+            .parse::<u64>()
+            .ok()
+            .map(|kib| kib * 1024);
+
+        if original.is_some() && compressed.is_some() {
+            break;
+        }
+    }
+
+    Some((original?, compressed?))
+}
+
+/// Returns zswap usage, or [`None`] if zswap isn't available or isn't in use.
+///
+/// `total_bytes` should be the total amount of RAM on the system, as the zswap
+/// pool lives in RAM.
+pub(crate) fn get_zswap_usage(total_bytes: u64) -> Option<CompressedMemData> {
+    let total_bytes = NonZeroU64::new(total_bytes)?;
+    let module = Path::new(ZSWAP_MODULE_PATH);
+
+    if !module.exists() {
+        return None;
+    }
+
+    let (original_bytes, compressed_bytes) = read_debugfs().or_else(|| {
+        fs::read_to_string("/proc/meminfo")
+            .ok()
+            .and_then(|contents| parse_meminfo(&contents))
+    })?;
+
+    // If zswap was turned off at runtime it can still hold pages, so only hide it
+    // if it's both disabled and empty.
+    let enabled = fs::read_to_string(module.join("parameters/enabled"))
+        .map(|enabled| enabled.trim() == "Y")
+        .unwrap_or(false);
+
+    if !enabled && original_bytes == 0 {
+        return None;
+    }
+
+    Some(CompressedMemData {
+        original_bytes,
+        compressed_bytes,
+        used_bytes: compressed_bytes,
+        total_bytes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_meminfo() {
+        let contents = "MemTotal:       32594176 kB\n\
+            MemFree:         1200300 kB\n\
+            SwapTotal:       8388604 kB\n\
+            SwapFree:        8000000 kB\n\
+            Zswap:             51200 kB\n\
+            Zswapped:         204800 kB\n\
+            Dirty:               320 kB\n";
+
+        assert_eq!(parse_meminfo(contents), Some((204800 * 1024, 51200 * 1024)));
+
+        // Kernels older than 5.19 don't report zswap at all.
+        assert_eq!(
+            parse_meminfo("MemTotal:       32594176 kB\nSwapTotal:       8388604 kB\n"),
+            None
+        );
+    }
+}
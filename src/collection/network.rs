@@ -1,8 +1,16 @@
 //! Data collection for network usage/IO.
 
+use std::ops::AddAssign;
+
 pub mod sysinfo;
 pub use self::sysinfo::*;
 
+#[cfg(target_os = "linux")]
+pub mod sysfs;
+
+#[cfg(target_os = "linux")]
+pub mod tcp;
+
 #[derive(Default, Clone, Debug)]
 /// All units in bits.
 pub struct NetworkHarvest {
@@ -10,11 +18,249 @@ pub struct NetworkHarvest {
     pub tx: u64,
     pub total_rx: u64,
     pub total_tx: u64,
+
+    /// The combined link speed of all interfaces with a known speed, in bits per second.
+    pub link_speed: Option<u64>,
+
+    /// RX and TX of interfaces with a known link speed, as a percentage of [`Self::link_speed`].
+    pub rx_percent: Option<f64>,
+    pub tx_percent: Option<f64>,
+
+    /// Packet, error and drop counts and rates, summed over all interfaces.
+    pub packet_totals: PacketCounters,
+    pub packet_rates: PacketRates,
+
+    /// Per-interface data, for every interface that passes the filter.
+    pub interfaces: Vec<InterfaceHarvest>,
 }
 
 impl NetworkHarvest {
     pub fn first_run_cleanup(&mut self) {
         self.rx = 0;
         self.tx = 0;
+        self.packet_rates = PacketRates::default();
+
+        for interface in &mut self.interfaces {
+            interface.rx = 0;
+            interface.tx = 0;
+            interface.packet_rates = PacketRates::default();
+        }
+
+        self.set_link_usage();
+    }
+
+    /// Computes RX and TX as a percentage of link capacity, for every interface with a known
+    /// link speed as well as for all of them combined.
+    pub(crate) fn set_link_usage(&mut self) {
+        let mut link_speed = 0;
+        let mut rx = 0;
+        let mut tx = 0;
+
+        for interface in &mut self.interfaces {
+            if let Some(speed) = interface.link_speed {
+                interface.rx_percent = Some(link_usage(interface.rx, speed));
+                interface.tx_percent = Some(link_usage(interface.tx, speed));
+
+                link_speed += speed;
+                rx += interface.rx;
+                tx += interface.tx;
+            } else {
+                interface.rx_percent = None;
+                interface.tx_percent = None;
+            }
+        }
+
+        if link_speed > 0 {
+            self.link_speed = Some(link_speed);
+            self.rx_percent = Some(link_usage(rx, link_speed));
+            self.tx_percent = Some(link_usage(tx, link_speed));
+        } else {
+            self.link_speed = None;
+            self.rx_percent = None;
+            self.tx_percent = None;
+        }
+    }
+}
+
+fn link_usage(rate: u64, link_speed: u64) -> f64 {
+    rate as f64 / link_speed as f64 * 100.0
+}
+
+/// Cumulative packet, error and drop counts.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PacketCounters {
+    pub rx_packets: u64,
+    pub tx_packets: u64,
+    pub rx_errors: u64,
+    pub tx_errors: u64,
+    pub rx_drops: u64,
+    pub tx_drops: u64,
+}
+
+impl PacketCounters {
+    /// Returns the per-second rates between a previous reading and this one. Counters that went
+    /// backwards (e.g. the interface was reset) are treated as zero.
+    pub(crate) fn rates(&self, prev: &PacketCounters, elapsed_time: f64) -> PacketRates {
+        if elapsed_time <= 0.0 {
+            return PacketRates::default();
+        }
+
+        let rate = |curr: u64, prev: u64| curr.saturating_sub(prev) as f64 / elapsed_time;
+
+        PacketRates {
+            rx_packets: rate(self.rx_packets, prev.rx_packets),
+            tx_packets: rate(self.tx_packets, prev.tx_packets),
+            rx_errors: rate(self.rx_errors, prev.rx_errors),
+            tx_errors: rate(self.tx_errors, prev.tx_errors),
+            rx_drops: rate(self.rx_drops, prev.rx_drops),
+            tx_drops: rate(self.tx_drops, prev.tx_drops),
+        }
+    }
+}
+
+impl AddAssign for PacketCounters {
+    fn add_assign(&mut self, other: Self) {
+        self.rx_packets += other.rx_packets;
+        self.tx_packets += other.tx_packets;
+        self.rx_errors += other.rx_errors;
+        self.tx_errors += other.tx_errors;
+        self.rx_drops += other.rx_drops;
+        self.tx_drops += other.tx_drops;
+    }
+}
+
+/// Packet, error and drop rates, per second.
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub struct PacketRates {
+    pub rx_packets: f64,
+    pub tx_packets: f64,
+    pub rx_errors: f64,
+    pub tx_errors: f64,
+    pub rx_drops: f64,
+    pub tx_drops: f64,
+}
+
+impl PacketRates {
+    /// Combined RX and TX errors per second.
+    pub fn errors(&self) -> f64 {
+        self.rx_errors + self.tx_errors
+    }
+
+    /// Combined RX and TX drops per second.
+    pub fn drops(&self) -> f64 {
+        self.rx_drops + self.tx_drops
+    }
+}
+
+impl AddAssign for PacketRates {
+    fn add_assign(&mut self, other: Self) {
+        self.rx_packets += other.rx_packets;
+        self.tx_packets += other.tx_packets;
+        self.rx_errors += other.rx_errors;
+        self.tx_errors += other.tx_errors;
+        self.rx_drops += other.rx_drops;
+        self.tx_drops += other.tx_drops;
+    }
+}
+
+/// Data for a single network interface. Rates and byte totals are in bits.
+#[derive(Default, Clone, Debug)]
+pub struct InterfaceHarvest {
+    pub name: String,
+    pub rx: u64,
+    pub tx: u64,
+    pub total_rx: u64,
+    pub total_tx: u64,
+    pub packet_totals: PacketCounters,
+    pub packet_rates: PacketRates,
+    pub mtu: u64,
+
+    /// The negotiated link speed in bits per second, if known.
+    pub link_speed: Option<u64>,
+
+    /// RX and TX as a percentage of the link speed, if known.
+    pub rx_percent: Option<f64>,
+    pub tx_percent: Option<f64>,
+
+    /// The interface's addresses, in CIDR notation.
+    pub addresses: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interface(name: &str, rx: u64, tx: u64, link_speed: Option<u64>) -> InterfaceHarvest {
+        InterfaceHarvest {
+            name: name.to_string(),
+            rx,
+            tx,
+            link_speed,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_link_usage() {
+        let mut harvest = NetworkHarvest {
+            interfaces: vec![
+                interface("eth0", 500_000_000, 100_000_000, Some(1_000_000_000)),
+                interface("eth1", 0, 900_000_000, Some(1_000_000_000)),
+                interface("lo", 123_456, 123_456, None),
+            ],
+            ..Default::default()
+        };
+        harvest.set_link_usage();
+
+        assert_eq!(harvest.interfaces[0].rx_percent, Some(50.0));
+        assert_eq!(harvest.interfaces[0].tx_percent, Some(10.0));
+        assert_eq!(harvest.interfaces[1].tx_percent, Some(90.0));
+        assert_eq!(harvest.interfaces[2].rx_percent, None);
+
+        // Interfaces without a known speed don't count towards the combined usage.
+        assert_eq!(harvest.link_speed, Some(2_000_000_000));
+        assert_eq!(harvest.rx_percent, Some(25.0));
+        assert_eq!(harvest.tx_percent, Some(50.0));
+    }
+
+    #[test]
+    fn test_packet_rates() {
+        let prev = PacketCounters {
+            rx_packets: 1000,
+            tx_packets: 500,
+            rx_errors: 2,
+            tx_errors: 0,
+            rx_drops: 10,
+            tx_drops: 0,
+        };
+        let curr = PacketCounters {
+            rx_packets: 3000,
+            tx_packets: 1500,
+            rx_errors: 6,
+            tx_errors: 0,
+            rx_drops: 0, // Counter was reset.
+            tx_drops: 2,
+        };
+
+        let rates = curr.rates(&prev, 2.0);
+        assert_eq!(rates.rx_packets, 1000.0);
+        assert_eq!(rates.tx_packets, 500.0);
+        assert_eq!(rates.errors(), 2.0);
+        assert_eq!(rates.drops(), 1.0);
+
+        assert_eq!(curr.rates(&prev, 0.0), PacketRates::default());
+    }
+
+    #[test]
+    fn test_link_usage_unknown_speed() {
+        let mut harvest = NetworkHarvest {
+            interfaces: vec![interface("wg0", 1000, 1000, None)],
+            ..Default::default()
+        };
+        harvest.set_link_usage();
+
+        assert_eq!(harvest.link_speed, None);
+        assert_eq!(harvest.rx_percent, None);
+        assert_eq!(harvest.tx_percent, None);
     }
 }
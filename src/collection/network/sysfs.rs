@@ -0,0 +1,51 @@
+//! Extra per-interface information from `/sys/class/net`. Linux-only.
+
+use std::{fs, path::Path};
+
+const NET_PATH: &str = "/sys/class/net";
+
+/// Parses a link speed file, which is in Mbit/s. Interfaces without a
+/// meaningful speed (e.g. virtual ones, or links that are down) report `-1`
+/// or fail to read entirely.
+fn parse_link_speed(contents: &str) -> Option<u64> {
+    let mbits: i64 = contents.trim().parse().ok()?;
+    if mbits > 0 {
+        Some(mbits as u64 * 1_000_000)
+    } else {
+        None
+    }
+}
+
+/// Returns the link speed of an interface in bits per second, if known.
+pub(crate) fn get_link_speed(interface: &str) -> Option<u64> {
+    let contents = fs::read_to_string(Path::new(NET_PATH).join(interface).join("speed")).ok()?;
+    parse_link_speed(&contents)
+}
+
+/// Returns the number of dropped received and transmitted packets of an
+/// interface. These aren't exposed by sysinfo.
+pub(crate) fn get_drops(interface: &str) -> (u64, u64) {
+    let statistics = Path::new(NET_PATH).join(interface).join("statistics");
+    let read = |file: &str| {
+        fs::read_to_string(statistics.join(file))
+            .ok()
+            .and_then(|contents| contents.trim().parse().ok())
+            .unwrap_or(0)
+    };
+
+    (read("rx_dropped"), read("tx_dropped"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_link_speed() {
+        assert_eq!(parse_link_speed("1000\n"), Some(1_000_000_000));
+        assert_eq!(parse_link_speed("25000"), Some(25_000_000_000));
+        assert_eq!(parse_link_speed("-1\n"), None);
+        assert_eq!(parse_link_speed("0"), None);
+        assert_eq!(parse_link_speed(""), None);
+    }
+}
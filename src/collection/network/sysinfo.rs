@@ -2,19 +2,57 @@
 
 use std::time::Instant;
 
+use rustc_hash::FxHashMap as HashMap;
 use sysinfo::Networks;
 
-use super::NetworkHarvest;
+use super::{InterfaceHarvest, NetworkHarvest, PacketCounters, PacketRates};
 use crate::app::filter::Filter;
 
-// TODO: Eventually make it so that this thing also takes individual usage into
-// account, so we can show per-interface!
+/// The cumulative counters of an interface from the previous collection, used to compute rates.
+#[derive(Clone, Copy, Debug)]
+pub struct PrevInterfaceCounters {
+    rx: u64,
+    tx: u64,
+    packets: PacketCounters,
+}
+
+/// Returns the rate in bits per second between two cumulative readings.
+fn rate(curr: u64, prev: u64, elapsed_time: f64) -> u64 {
+    if elapsed_time == 0.0 {
+        0
+    } else {
+        (curr.saturating_sub(prev) as f64 / elapsed_time) as u64
+    }
+}
+
+/// Returns the RX and TX rates, in bits per second, and the packet rates of an interface since
+/// its previous reading. An interface without a previous reading (e.g. one that just came up)
+/// has no rates yet.
+fn interface_rates(
+    curr: &PrevInterfaceCounters, prev: Option<&PrevInterfaceCounters>, elapsed_time: f64,
+) -> (u64, u64, PacketRates) {
+    let prev = prev.unwrap_or(curr);
+
+    (
+        rate(curr.rx, prev.rx, elapsed_time),
+        rate(curr.tx, prev.tx, elapsed_time),
+        curr.packets.rates(&prev.packets, elapsed_time),
+    )
+}
+
 pub fn get_network_data(
     networks: &Networks, prev_net_access_time: Instant, prev_net_rx: &mut u64,
-    prev_net_tx: &mut u64, curr_time: Instant, filter: &Option<Filter>,
+    prev_net_tx: &mut u64, prev_interfaces: &mut HashMap<String, PrevInterfaceCounters>,
+    curr_time: Instant, filter: &Option<Filter>,
 ) -> NetworkHarvest {
+    let elapsed_time = curr_time.duration_since(prev_net_access_time).as_secs_f64();
+
     let mut total_rx: u64 = 0;
     let mut total_tx: u64 = 0;
+    let mut packet_totals = PacketCounters::default();
+    let mut packet_rates = PacketRates::default();
+    let mut interfaces = Vec::new();
+    let mut new_prev_interfaces = HashMap::default();
 
     for (name, network) in networks {
         let to_keep = if let Some(filter) = filter {
@@ -24,28 +62,134 @@ pub fn get_network_data(
         };
 
         if to_keep {
-            total_rx += network.total_received() * 8;
-            total_tx += network.total_transmitted() * 8;
+            let interface_rx = network.total_received() * 8;
+            let interface_tx = network.total_transmitted() * 8;
+            total_rx += interface_rx;
+            total_tx += interface_tx;
+
+            cfg_if::cfg_if! {
+                if #[cfg(target_os = "linux")] {
+                    let (rx_drops, tx_drops) = super::sysfs::get_drops(name);
+                    let link_speed = super::sysfs::get_link_speed(name);
+                } else {
+                    let (rx_drops, tx_drops) = (0, 0);
+                    let link_speed = None;
+                }
+            }
+
+            let packets = PacketCounters {
+                rx_packets: network.total_packets_received(),
+                tx_packets: network.total_packets_transmitted(),
+                rx_errors: network.total_errors_on_received(),
+                tx_errors: network.total_errors_on_transmitted(),
+                rx_drops,
+                tx_drops,
+            };
+
+            let curr = PrevInterfaceCounters {
+                rx: interface_rx,
+                tx: interface_tx,
+                packets,
+            };
+            let (rx, tx, interface_packet_rates) =
+                interface_rates(&curr, prev_interfaces.get(name), elapsed_time);
+            new_prev_interfaces.insert(name.clone(), curr);
+
+            packet_totals += packets;
+            packet_rates += interface_packet_rates;
+
+            interfaces.push(InterfaceHarvest {
+                name: name.clone(),
+                rx,
+                tx,
+                total_rx: interface_rx,
+                total_tx: interface_tx,
+                packet_totals: packets,
+                packet_rates: interface_packet_rates,
+                mtu: network.mtu(),
+                link_speed,
+                rx_percent: None,
+                tx_percent: None,
+                addresses: network
+                    .ip_networks()
+                    .iter()
+                    .map(|network| network.to_string())
+                    .collect(),
+            });
         }
     }
 
-    let elapsed_time = curr_time.duration_since(prev_net_access_time).as_secs_f64();
+    interfaces.sort_unstable_by(|a, b| a.name.cmp(&b.name));
 
-    let (rx, tx) = if elapsed_time == 0.0 {
-        (0, 0)
-    } else {
-        (
-            ((total_rx.saturating_sub(*prev_net_rx)) as f64 / elapsed_time) as u64,
-            ((total_tx.saturating_sub(*prev_net_tx)) as f64 / elapsed_time) as u64,
-        )
-    };
+    let rx = rate(total_rx, *prev_net_rx, elapsed_time);
+    let tx = rate(total_tx, *prev_net_tx, elapsed_time);
 
     *prev_net_rx = total_rx;
     *prev_net_tx = total_tx;
-    NetworkHarvest {
+    *prev_interfaces = new_prev_interfaces;
+
+    let mut harvest = NetworkHarvest {
         rx,
         tx,
         total_rx,
         total_tx,
+        packet_totals,
+        packet_rates,
+        interfaces,
+        ..Default::default()
+    };
+    harvest.set_link_usage();
+
+    harvest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counters(rx: u64, tx: u64, rx_packets: u64) -> PrevInterfaceCounters {
+        PrevInterfaceCounters {
+            rx,
+            tx,
+            packets: PacketCounters {
+                rx_packets,
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_interface_rates() {
+        let prev = counters(8000, 1000, 10);
+        let curr = counters(24_000, 5000, 30);
+
+        let (rx, tx, packet_rates) = interface_rates(&curr, Some(&prev), 2.0);
+        assert_eq!(rx, 8000);
+        assert_eq!(tx, 2000);
+        assert_eq!(packet_rates.rx_packets, 10.0);
+    }
+
+    #[test]
+    fn test_interface_rates_new_interface() {
+        // An interface that just showed up has nothing to compare against yet.
+        let curr = counters(24_000, 5000, 30);
+
+        let (rx, tx, packet_rates) = interface_rates(&curr, None, 2.0);
+        assert_eq!((rx, tx), (0, 0));
+        assert_eq!(packet_rates, PacketRates::default());
+    }
+
+    #[test]
+    fn test_interface_rates_reset() {
+        // Counters that went backwards (e.g. the interface was recreated) are treated as zero.
+        let prev = counters(24_000, 5000, 30);
+        let curr = counters(800, 100, 1);
+
+        let (rx, tx, packet_rates) = interface_rates(&curr, Some(&prev), 2.0);
+        assert_eq!((rx, tx), (0, 0));
+        assert_eq!(packet_rates.rx_packets, 0.0);
+
+        // No time passing means no rates either.
+        assert_eq!(interface_rates(&prev, Some(&curr), 0.0).0, 0);
     }
 }
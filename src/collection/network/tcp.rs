@@ -0,0 +1,315 @@
+//! TCP and UDP protocol statistics from `/proc/net`. Linux-only.
+//!
+//! Counters in `/proc/net/snmp` and `/proc/net/netstat` are cumulative since
+//! boot, so we keep the previous reading around and report per-second rates.
+//! Connection states are instead counted from the socket tables in
+//! `/proc/net/tcp` and `/proc/net/tcp6` on every update.
+
+use std::{fs, time::Duration};
+
+/// The names of the TCP connection states, indexed by their value in
+/// `/proc/net/tcp` minus one.
+pub const TCP_STATE_NAMES: [&str; 11] = [
+    "ESTABLISHED",
+    "SYN_SENT",
+    "SYN_RECV",
+    "FIN_WAIT1",
+    "FIN_WAIT2",
+    "TIME_WAIT",
+    "CLOSE",
+    "CLOSE_WAIT",
+    "LAST_ACK",
+    "LISTEN",
+    "CLOSING",
+];
+
+/// The raw cumulative counters we care about from `/proc/net/snmp` and
+/// `/proc/net/netstat`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct TcpCounters {
+    active_opens: u64,
+    passive_opens: u64,
+    retrans_segs: u64,
+    out_segs: u64,
+    listen_overflows: u64,
+    udp_rcvbuf_errors: u64,
+
+    /// Not a counter, but the number of currently established connections.
+    curr_estab: u64,
+}
+
+/// The number of TCP sockets in each connection state, for both IPv4 and IPv6.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TcpStateCounts([u64; TCP_STATE_NAMES.len()]);
+
+impl TcpStateCounts {
+    /// Returns the number of sockets in the state with the given name.
+    pub fn get(&self, name: &str) -> u64 {
+        TCP_STATE_NAMES
+            .iter()
+            .position(|state| *state == name)
+            .map(|index| self.0[index])
+            .unwrap_or(0)
+    }
+
+    /// Returns each state's name along with its number of sockets.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, u64)> + '_ {
+        TCP_STATE_NAMES.iter().copied().zip(self.0.iter().copied())
+    }
+}
+
+/// TCP and UDP activity, with rates in events per second.
+#[derive(Debug, Default, Clone)]
+pub struct TcpHarvest {
+    /// Connections opened by this host (`connect`).
+    pub active_opens: f64,
+
+    /// Connections accepted by this host.
+    pub passive_opens: f64,
+
+    /// Retransmitted segments.
+    pub retrans_segs: f64,
+
+    /// Retransmitted segments as a percentage of all sent segments.
+    pub retrans_percent: f64,
+
+    /// Connections dropped because a listen queue was full.
+    pub listen_overflows: f64,
+
+    /// UDP datagrams dropped because a socket's receive buffer was full.
+    pub udp_rcvbuf_errors: f64,
+
+    /// Currently established connections, as reported by the kernel.
+    pub established: u64,
+
+    /// The number of sockets in each connection state.
+    pub states: TcpStateCounts,
+}
+
+/// Calls `f` with the protocol, name and value of each field in a file made of
+/// header and value line pairs, like `/proc/net/snmp`:
+///
+/// ```text
+/// Tcp: RtoAlgorithm RtoMin ActiveOpens
+/// Tcp: 1 200 5124
+/// ```
+fn for_each_field(contents: &str, mut f: impl FnMut(&str, &str, u64)) {
+    let mut lines = contents.lines();
+
+    while let (Some(header), Some(values)) = (lines.next(), lines.next()) {
+        let (Some((protocol, names)), Some((value_protocol, values))) =
+            (header.split_once(':'), values.split_once(':'))
+        else {
+            continue;
+        };
+
+        if protocol != value_protocol {
+            continue;
+        }
+
+        for (name, value) in names.split_whitespace().zip(values.split_whitespace()) {
+            // Some fields (like `Tcp: MaxConn`) can be negative, which we don't care about.
+            if let Ok(value) = value.parse() {
+                f(protocol, name, value);
+            }
+        }
+    }
+}
+
+/// Parses the contents of `/proc/net/snmp` and `/proc/net/netstat`.
+fn parse_counters(snmp: &str, netstat: &str) -> TcpCounters {
+    let mut counters = TcpCounters::default();
+
+    for_each_field(snmp, |protocol, name, value| match (protocol, name) {
+        ("Tcp", "ActiveOpens") => counters.active_opens = value,
+        ("Tcp", "PassiveOpens") => counters.passive_opens = value,
+        ("Tcp", "RetransSegs") => counters.retrans_segs = value,
+        ("Tcp", "OutSegs") => counters.out_segs = value,
+        ("Tcp", "CurrEstab") => counters.curr_estab = value,
+        ("Udp", "RcvbufErrors") => counters.udp_rcvbuf_errors = value,
+        _ => {}
+    });
+
+    for_each_field(netstat, |protocol, name, value| {
+        if let ("TcpExt", "ListenOverflows") = (protocol, name) {
+            counters.listen_overflows = value;
+        }
+    });
+
+    counters
+}
+
+/// Adds the connection states of a socket table like `/proc/net/tcp` to `counts`.
+fn count_states(contents: &str, counts: &mut TcpStateCounts) {
+    // Skip the header.
+    for line in contents.lines().skip(1) {
+        // The state is the fourth field, after the slot and the local and remote addresses.
+        let Some(state) = line.split_whitespace().nth(3) else {
+            continue;
+        };
+
+        if let Ok(state) = u8::from_str_radix(state, 16) {
+            if let Some(count) = (state as usize)
+                .checked_sub(1)
+                .and_then(|index| counts.0.get_mut(index))
+            {
+                *count += 1;
+            }
+        }
+    }
+}
+
+/// Returns the current counters from `/proc/net/snmp` and `/proc/net/netstat`.
+pub(crate) fn get_tcp_counters() -> Option<TcpCounters> {
+    let snmp = fs::read_to_string("/proc/net/snmp").ok()?;
+
+    // `/proc/net/netstat` only adds the listen overflows, so don't give up if it's missing.
+    let netstat = fs::read_to_string("/proc/net/netstat").unwrap_or_default();
+
+    Some(parse_counters(&snmp, &netstat))
+}
+
+/// Returns the number of sockets in each connection state.
+pub(crate) fn get_tcp_states() -> TcpStateCounts {
+    let mut counts = TcpStateCounts::default();
+
+    for path in ["/proc/net/tcp", "/proc/net/tcp6"] {
+        if let Ok(contents) = fs::read_to_string(path) {
+            count_states(&contents, &mut counts);
+        }
+    }
+
+    counts
+}
+
+impl TcpCounters {
+    /// Calculates the rates between a previous reading and this one.
+    pub(crate) fn rates(
+        &self, prev: &TcpCounters, elapsed: Duration, states: TcpStateCounts,
+    ) -> TcpHarvest {
+        let elapsed = elapsed.as_secs_f64();
+        let rate = |curr: u64, prev: u64| {
+            if elapsed > 0.0 {
+                curr.saturating_sub(prev) as f64 / elapsed
+            } else {
+                0.0
+            }
+        };
+
+        let out_segs = self.out_segs.saturating_sub(prev.out_segs);
+        let retrans_percent = if out_segs > 0 {
+            self.retrans_segs.saturating_sub(prev.retrans_segs) as f64 / out_segs as f64 * 100.0
+        } else {
+            0.0
+        };
+
+        TcpHarvest {
+            active_opens: rate(self.active_opens, prev.active_opens),
+            passive_opens: rate(self.passive_opens, prev.passive_opens),
+            retrans_segs: rate(self.retrans_segs, prev.retrans_segs),
+            retrans_percent,
+            listen_overflows: rate(self.listen_overflows, prev.listen_overflows),
+            udp_rcvbuf_errors: rate(self.udp_rcvbuf_errors, prev.udp_rcvbuf_errors),
+            established: self.curr_estab,
+            states,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SNMP: &str = "Ip: Forwarding DefaultTTL InReceives\n\
+        Ip: 1 64 123456\n\
+        Tcp: RtoAlgorithm RtoMin RtoMax MaxConn ActiveOpens PassiveOpens AttemptFails EstabResets CurrEstab InSegs OutSegs RetransSegs InErrs OutRsts InCsumErrors\n\
+        Tcp: 1 200 120000 -1 5124 310 12 40 23 998877 887766 1500 0 77 0\n\
+        Udp: InDatagrams NoPorts InErrors OutDatagrams RcvbufErrors SndbufErrors InCsumErrors IgnoredMulti MemErrors\n\
+        Udp: 4000 12 3 3900 7 0 0 0 0\n\
+        UdpLite: InDatagrams NoPorts InErrors OutDatagrams RcvbufErrors SndbufErrors InCsumErrors IgnoredMulti MemErrors\n\
+        UdpLite: 0 0 0 0 99 0 0 0 0\n";
+
+    const NETSTAT: &str = "TcpExt: SyncookiesSent SyncookiesRecv ListenOverflows ListenDrops\n\
+        TcpExt: 0 0 42 42\n\
+        IpExt: InNoRoutes InTruncatedPkts\n\
+        IpExt: 0 0\n";
+
+    #[test]
+    fn test_parse_counters() {
+        assert_eq!(
+            parse_counters(SNMP, NETSTAT),
+            TcpCounters {
+                active_opens: 5124,
+                passive_opens: 310,
+                retrans_segs: 1500,
+                out_segs: 887766,
+                listen_overflows: 42,
+                udp_rcvbuf_errors: 7,
+                curr_estab: 23,
+            }
+        );
+
+        assert_eq!(parse_counters("", ""), TcpCounters::default());
+    }
+
+    #[test]
+    fn test_count_states() {
+        let tcp = "  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode\n   \
+            0: 0100007F:0277 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 21571 1 0000000000000000 100 0 0 10 0\n   \
+            1: 0F02000A:0016 0202000A:C5B2 01 00000000:00000000 02:0007B2F6 00000000     0        0 44122 4 0000000000000000 20 4 31 10 -1\n   \
+            2: 0F02000A:A3F0 5DB8D822:01BB 06 00000000:00000000 03:00000F3C 00000000     0        0 0 3 0000000000000000\n   \
+            3: 0F02000A:A3F2 5DB8D822:01BB 08 00000000:00000000 00:00000000 00000000  1000        0 51234 1 0000000000000000 20 4 0 10 -1\n";
+        let tcp6 = "  sl  local_address                         remote_address                        st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode\n   \
+            0: 00000000000000000000000000000000:0016 00000000000000000000000000000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 21573 1 0000000000000000 100 0 0 10 0\n";
+
+        let mut counts = TcpStateCounts::default();
+        count_states(tcp, &mut counts);
+        count_states(tcp6, &mut counts);
+
+        assert_eq!(counts.get("LISTEN"), 2);
+        assert_eq!(counts.get("ESTABLISHED"), 1);
+        assert_eq!(counts.get("TIME_WAIT"), 1);
+        assert_eq!(counts.get("CLOSE_WAIT"), 1);
+        assert_eq!(counts.get("SYN_SENT"), 0);
+        assert_eq!(counts.iter().map(|(_, count)| count).sum::<u64>(), 5);
+
+        // Unknown states and malformed lines are skipped.
+        let mut counts = TcpStateCounts::default();
+        count_states(
+            "header\n   0: 0 0 00\n   1: 0 0 FF\n   2: garbage\n",
+            &mut counts,
+        );
+        assert_eq!(counts, TcpStateCounts::default());
+    }
+
+    #[test]
+    fn test_rates() {
+        let prev = TcpCounters {
+            active_opens: 100,
+            retrans_segs: 10,
+            out_segs: 1000,
+            listen_overflows: 5,
+            ..Default::default()
+        };
+        let curr = TcpCounters {
+            active_opens: 300,
+            retrans_segs: 30,
+            out_segs: 3000,
+            listen_overflows: 5,
+            curr_estab: 12,
+            ..Default::default()
+        };
+
+        let rates = curr.rates(&prev, Duration::from_secs(2), TcpStateCounts::default());
+        assert_eq!(rates.active_opens, 100.0);
+        assert_eq!(rates.retrans_segs, 10.0);
+        assert_eq!(rates.retrans_percent, 1.0);
+        assert_eq!(rates.listen_overflows, 0.0);
+        assert_eq!(rates.established, 12);
+
+        // Counters shouldn't go backwards, but don't underflow if they somehow do.
+        let rates = prev.rates(&curr, Duration::from_secs(1), TcpStateCounts::default());
+        assert_eq!(rates.active_opens, 0.0);
+        assert_eq!(rates.retrans_percent, 0.0);
+    }
+}
@@ -0,0 +1,307 @@
+//! NUMA topology and per-node memory usage. Linux-only.
+//!
+//! Node information comes from `/sys/devices/system/node/node*`, and
+//! per-process placement from `/proc/<pid>/numa_maps`.
+
+use std::{fs, num::NonZeroU64, path::Path};
+
+use super::{
+    cpu::{CpuData, CpuDataType, CpuHarvest},
+    memory::MemData,
+    processes::Pid,
+};
+
+const NODE_PATH: &str = "/sys/devices/system/node";
+
+/// A NUMA node and the CPUs that belong to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumaNode {
+    pub id: usize,
+    pub cpus: Vec<usize>,
+}
+
+/// Parses a CPU list like `0-3,8-11,16`.
+fn parse_cpulist(contents: &str) -> Vec<usize> {
+    let mut cpus = Vec::new();
+
+    for range in contents.trim().split(',').filter(|r| !r.is_empty()) {
+        match range.split_once('-') {
+            Some((start, end)) => {
+                if let (Ok(start), Ok(end)) = (start.parse::<usize>(), end.parse::<usize>()) {
+                    cpus.extend(start..=end);
+                }
+            }
+            None => {
+                if let Ok(cpu) = range.parse() {
+                    cpus.push(cpu);
+                }
+            }
+        }
+    }
+
+    cpus
+}
+
+/// Returns all NUMA nodes on the system, sorted by ID. This is empty if the
+/// kernel doesn't expose NUMA information.
+pub(crate) fn get_numa_nodes() -> Vec<NumaNode> {
+    let Ok(entries) = Path::new(NODE_PATH).read_dir() else {
+        return Vec::new();
+    };
+
+    let mut nodes: Vec<NumaNode> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let id = entry
+                .file_name()
+                .to_str()?
+                .strip_prefix("node")?
+                .parse()
+                .ok()?;
+            let cpus = fs::read_to_string(entry.path().join("cpulist"))
+                .map(|contents| parse_cpulist(&contents))
+                .unwrap_or_default();
+
+            Some(NumaNode { id, cpus })
+        })
+        .collect();
+
+    nodes.sort_unstable_by_key(|node| node.id);
+    nodes
+}
+
+/// Reorders CPU entries so that each node's CPUs follow an entry for that
+/// node's average usage. The overall average stays first, and any CPUs that
+/// don't belong to a node are left at the end.
+pub(crate) fn group_cpus_by_node(harvest: CpuHarvest, nodes: &[NumaNode]) -> CpuHarvest {
+    let mut grouped = Vec::with_capacity(harvest.len() + nodes.len());
+    let mut cpus = Vec::with_capacity(harvest.len());
+
+    for data in harvest {
+        match data.data_type {
+            CpuDataType::Cpu(index) => cpus.push((index, Some(data))),
+            _ => grouped.push(data),
+        }
+    }
+
+    for node in nodes {
+        let node_cpus: Vec<CpuData> = node
+            .cpus
+            .iter()
+            .filter_map(|cpu| {
+                cpus.iter_mut()
+                    .find(|(index, _)| index == cpu)
+                    .and_then(|(_, data)| data.take())
+            })
+            .collect();
+
+        if node_cpus.is_empty() {
+            continue;
+        }
+
+        let usage = node_cpus.iter().map(|data| data.usage).sum::<f32>() / node_cpus.len() as f32;
+        grouped.push(CpuData {
+            data_type: CpuDataType::Node(node.id),
+            usage,
+        });
+        grouped.extend(node_cpus);
+    }
+
+    grouped.extend(cpus.into_iter().filter_map(|(_, data)| data));
+    grouped
+}
+
+/// Parses a node's `meminfo` file. Used memory is calculated the same way as
+/// for the whole system, ignoring the page cache and reclaimable slab.
+fn parse_node_meminfo(contents: &str) -> Option<MemData> {
+    let mut total = None;
+    let mut free = None;
+    let mut file_pages = 0;
+    let mut shmem = 0;
+    let mut s_reclaimable = 0;
+
+    for line in contents.lines() {
+        // Lines look like `Node 0 MemTotal:       32594176 kB`.
+        let mut fields = line.split_whitespace().skip(2);
+        let (Some(label), Some(value)) = (fields.next(), fields.next()) else {
+            continue;
+        };
+        let Ok(kib) = value.parse::<u64>() else {
+            continue;
+        };
+        let bytes = kib * 1024;
+
+        match label {
+            "MemTotal:" => total = Some(bytes),
+            "MemFree:" => free = Some(bytes),
+            "FilePages:" => file_pages = bytes,
+            "Shmem:" => shmem = bytes,
+            "SReclaimable:" => s_reclaimable = bytes,
+            _ => {}
+        }
+    }
+
+    let total_bytes = NonZeroU64::new(total?)?;
+    let cached = (file_pages + s_reclaimable).saturating_sub(shmem);
+    let used_bytes = total_bytes
+        .get()
+        .saturating_sub(free?)
+        .saturating_sub(cached);
+
+    Some(MemData {
+        used_bytes,
+        total_bytes,
+    })
+}
+
+/// Returns the memory usage of each node, alongside the node's ID.
+pub(crate) fn get_numa_memory(nodes: &[NumaNode]) -> Vec<(usize, MemData)> {
+    nodes
+        .iter()
+        .filter_map(|node| {
+            let path = Path::new(NODE_PATH).join(format!("node{}/meminfo", node.id));
+            let contents = fs::read_to_string(path).ok()?;
+
+            Some((node.id, parse_node_meminfo(&contents)?))
+        })
+        .collect()
+}
+
+/// Parses a process' `numa_maps` file, and returns the node that holds the
+/// most of its memory.
+fn parse_numa_maps(contents: &str) -> Option<usize> {
+    let mut per_node: Vec<u64> = Vec::new();
+
+    for line in contents.lines() {
+        let mut page_kib = 4;
+        let mut pages: Vec<(usize, u64)> = Vec::new();
+
+        for field in line.split_ascii_whitespace() {
+            if let Some(size) = field.strip_prefix("kernelpagesize_kB=") {
+                page_kib = size.parse().unwrap_or(page_kib);
+            } else if let Some((node, count)) =
+                field.strip_prefix('N').and_then(|f| f.split_once('='))
+            {
+                if let (Ok(node), Ok(count)) = (node.parse(), count.parse()) {
+                    pages.push((node, count));
+                }
+            }
+        }
+
+        for (node, count) in pages {
+            if per_node.len() <= node {
+                per_node.resize(node + 1, 0);
+            }
+            per_node[node] += count * page_kib;
+        }
+    }
+
+    per_node
+        .iter()
+        .enumerate()
+        .filter(|(_, kib)| **kib > 0)
+        .max_by_key(|(_, kib)| **kib)
+        .map(|(node, _)| node)
+}
+
+/// Returns the node that holds the most of a process' memory, if it can be
+/// determined.
+pub(crate) fn get_process_numa_node(pid: Pid) -> Option<usize> {
+    let contents = fs::read_to_string(format!("/proc/{pid}/numa_maps")).ok()?;
+    parse_numa_maps(&contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cpulist() {
+        assert_eq!(parse_cpulist("0-3,8-11\n"), vec![0, 1, 2, 3, 8, 9, 10, 11]);
+        assert_eq!(parse_cpulist("0,2,4"), vec![0, 2, 4]);
+        assert_eq!(parse_cpulist("5"), vec![5]);
+
+        // Memory-only nodes have no CPUs.
+        assert_eq!(parse_cpulist("\n"), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_group_cpus_by_node() {
+        let harvest = std::iter::once(CpuData {
+            data_type: CpuDataType::Avg,
+            usage: 25.0,
+        })
+        .chain((0..5).map(|index| CpuData {
+            data_type: CpuDataType::Cpu(index),
+            usage: index as f32 * 10.0,
+        }))
+        .collect();
+        let nodes = [
+            NumaNode {
+                id: 0,
+                cpus: vec![0, 2],
+            },
+            NumaNode {
+                id: 1,
+                cpus: vec![1, 3],
+            },
+            NumaNode {
+                id: 2,
+                cpus: vec![],
+            },
+        ];
+
+        let grouped: Vec<_> = group_cpus_by_node(harvest, &nodes)
+            .into_iter()
+            .map(|data| (data.data_type, data.usage))
+            .collect();
+
+        assert!(matches!(grouped[0], (CpuDataType::Avg, 25.0)));
+        assert!(matches!(grouped[1], (CpuDataType::Node(0), 10.0)));
+        assert!(matches!(grouped[2], (CpuDataType::Cpu(0), _)));
+        assert!(matches!(grouped[3], (CpuDataType::Cpu(2), _)));
+        assert!(matches!(grouped[4], (CpuDataType::Node(1), 20.0)));
+        assert!(matches!(grouped[5], (CpuDataType::Cpu(1), _)));
+        assert!(matches!(grouped[6], (CpuDataType::Cpu(3), _)));
+        assert!(matches!(grouped[7], (CpuDataType::Cpu(4), _)));
+        assert_eq!(grouped.len(), 8);
+    }
+
+    #[test]
+    fn test_parse_node_meminfo() {
+        let contents = "Node 1 MemTotal:       16777216 kB\n\
+            Node 1 MemFree:         4194304 kB\n\
+            Node 1 MemUsed:        12582912 kB\n\
+            Node 1 FilePages:       2097152 kB\n\
+            Node 1 Shmem:            524288 kB\n\
+            Node 1 SReclaimable:     262144 kB\n";
+
+        let data = parse_node_meminfo(contents).unwrap();
+        assert_eq!(data.total_bytes.get(), 16777216 * 1024);
+        assert_eq!(
+            data.used_bytes,
+            (16777216 - 4194304 - (2097152 + 262144 - 524288)) * 1024
+        );
+
+        assert!(parse_node_meminfo("Node 0 MemFree: 100 kB\n").is_none());
+    }
+
+    #[test]
+    fn test_parse_numa_maps() {
+        let contents = "55d4c2a00000 default file=/usr/bin/postgres mapped=300 N0=200 N1=100 kernelpagesize_kB=4\n\
+            7f0000000000 default anon=1000 dirty=1000 N1=1000 kernelpagesize_kB=4\n\
+            7f2000000000 default file=/dev/hugepages/shm huge dirty=2 N0=2 kernelpagesize_kB=2048\n\
+            7ffd5c1e0000 default stack anon=3 dirty=3 N0=3 kernelpagesize_kB=4\n";
+
+        // Node 0 has 203 small pages and 2 huge pages, which outweighs node 1's 1100 small pages.
+        assert_eq!(parse_numa_maps(contents), Some(0));
+
+        assert_eq!(
+            parse_numa_maps("7f0000000000 default anon=10 N0=1 N1=9 kernelpagesize_kB=4\n"),
+            Some(1)
+        );
+
+        // Kernel threads have an empty numa_maps.
+        assert_eq!(parse_numa_maps(""), None);
+    }
+}
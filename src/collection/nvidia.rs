@@ -2,20 +2,29 @@ use std::{num::NonZeroU64, sync::OnceLock};
 
 use nohash::IntMap;
 use nvml_wrapper::{
-    Nvml, enum_wrappers::device::TemperatureSensor, enums::device::UsedGpuMemory, error::NvmlError,
+    Device, Nvml,
+    enum_wrappers::device::{Clock, TemperatureSensor, TemperatureThreshold},
+    enums::device::UsedGpuMemory,
+    error::NvmlError,
 };
 
 use crate::{
     app::{filter::Filter, layout_manager::UsedWidgets},
-    collection::{memory::MemData, processes::Pid, temperature::TempSensorData},
+    collection::{
+        gpu::{GpuEngineUtil, GpuProcUsage, GpuStats},
+        memory::MemData,
+        processes::Pid,
+        temperature::{SensorData, SensorKind},
+    },
 };
 
 pub static NVML_DATA: OnceLock<Result<Nvml, NvmlError>> = OnceLock::new();
 
 pub struct GpusData {
     pub memory: Option<Vec<(String, MemData)>>,
-    pub temperature: Option<Vec<TempSensorData>>,
-    pub procs: Option<(u64, Vec<IntMap<Pid, (u64, u32)>>)>,
+    pub temperature: Option<Vec<SensorData>>,
+    pub stats: Option<Vec<GpuStats>>,
+    pub procs: Option<(u64, Vec<IntMap<Pid, GpuProcUsage>>)>,
 }
 
 /// Wrapper around Nvml::init
@@ -41,6 +50,59 @@ fn init_nvml() -> Result<Nvml, NvmlError> {
     }
 }
 
+/// Returns the activity of a device.
+fn get_stats(device: &Device<'_>, name: String) -> GpuStats {
+    // Fans can't be read on passively cooled cards, in which case this just fails.
+    let fan = device
+        .num_fans()
+        .ok()
+        .filter(|fans| *fans > 0)
+        .and_then(|fans| {
+            let speeds = (0..fans)
+                .filter_map(|fan| device.fan_speed(fan).ok())
+                .collect::<Vec<_>>();
+
+            (!speeds.is_empty())
+                .then(|| f64::from(speeds.iter().sum::<u32>()) / speeds.len() as f64)
+        });
+
+    GpuStats {
+        id: device
+            .pci_info()
+            .map(|info| info.bus_id)
+            .unwrap_or_else(|_| name.clone()),
+        utilization: device
+            .utilization_rates()
+            .ok()
+            .map(|rates| f64::from(rates.gpu)),
+        encoder: device
+            .encoder_utilization()
+            .ok()
+            .map(|info| f64::from(info.utilization)),
+        decoder: device
+            .decoder_utilization()
+            .ok()
+            .map(|info| f64::from(info.utilization)),
+        temperature: device
+            .temperature(TemperatureSensor::Gpu)
+            .ok()
+            .map(|temp| temp as f32),
+        // Power is reported in milliwatts.
+        power: device
+            .power_usage()
+            .ok()
+            .map(|power| f64::from(power) / 1000.0),
+        power_limit: device
+            .enforced_power_limit()
+            .ok()
+            .map(|limit| f64::from(limit) / 1000.0),
+        core_clock: device.clock_info(Clock::Graphics).ok(),
+        memory_clock: device.clock_info(Clock::Memory).ok(),
+        fan,
+        name,
+    }
+}
+
 /// Returns the GPU data from NVIDIA cards.
 #[inline]
 pub fn get_nvidia_vecs(
@@ -50,6 +112,7 @@ pub fn get_nvidia_vecs(
         if let Ok(num_gpu) = nvml.device_count() {
             let mut temp_vec = Vec::with_capacity(num_gpu as usize);
             let mut mem_vec = Vec::with_capacity(num_gpu as usize);
+            let mut stats_vec = Vec::with_capacity(num_gpu as usize);
             let mut proc_vec = Vec::with_capacity(num_gpu as usize);
             let mut total_mem = 0;
 
@@ -70,20 +133,31 @@ pub fn get_nvidia_vecs(
                             }
                         }
 
+                        if widgets_to_harvest.use_gpu_stats {
+                            stats_vec.push(get_stats(&device, name.clone()));
+                        }
+
                         if widgets_to_harvest.use_temp
                             && Filter::optional_should_keep(filter, &name)
                         {
-                            if let Ok(temperature) = device.temperature(TemperatureSensor::Gpu) {
-                                temp_vec.push(TempSensorData {
-                                    name,
-                                    temperature: Some(temperature as f32),
-                                });
-                            } else {
-                                temp_vec.push(TempSensorData {
-                                    name,
-                                    temperature: None,
-                                });
-                            }
+                            let threshold = |threshold| {
+                                device
+                                    .temperature_threshold(threshold)
+                                    .ok()
+                                    .map(|temp| temp as f32)
+                            };
+
+                            temp_vec.push(SensorData {
+                                name,
+                                kind: SensorKind::Temperature,
+                                value: device
+                                    .temperature(TemperatureSensor::Gpu)
+                                    .ok()
+                                    .map(|temp| temp as f32),
+                                max: threshold(TemperatureThreshold::Slowdown),
+                                crit: threshold(TemperatureThreshold::Shutdown),
+                                crit_alarm: false,
+                            });
                         }
                     }
 
@@ -94,7 +168,22 @@ pub fn get_nvidia_vecs(
                             for proc in gpu_procs {
                                 let pid = proc.pid as Pid;
                                 let gpu_util = proc.sm_util + proc.enc_util + proc.dec_util;
-                                procs.insert(pid, (0, gpu_util));
+                                // NVML only breaks out the video engines; SM time covers both
+                                // graphics and compute work, so it can't be split between them.
+                                let engines = GpuEngineUtil {
+                                    enc: proc.enc_util,
+                                    dec: proc.dec_util,
+                                    ..Default::default()
+                                };
+                                procs.insert(
+                                    pid,
+                                    GpuProcUsage {
+                                        mem: 0,
+                                        mem_without_total: false,
+                                        util: gpu_util,
+                                        engines,
+                                    },
+                                );
                             }
                         }
 
@@ -105,11 +194,7 @@ pub fn get_nvidia_vecs(
                                     UsedGpuMemory::Used(val) => val,
                                     UsedGpuMemory::Unavailable => 0,
                                 };
-                                if let Some(prev) = procs.get(&pid) {
-                                    procs.insert(pid, (gpu_mem, prev.1));
-                                } else {
-                                    procs.insert(pid, (gpu_mem, 0));
-                                }
+                                procs.entry(pid).or_default().mem = gpu_mem;
                             }
                         }
 
@@ -121,11 +206,7 @@ pub fn get_nvidia_vecs(
                                     UsedGpuMemory::Used(val) => val,
                                     UsedGpuMemory::Unavailable => 0,
                                 };
-                                if let Some(prev) = procs.get(&pid) {
-                                    procs.insert(pid, (gpu_mem, prev.1));
-                                } else {
-                                    procs.insert(pid, (gpu_mem, 0));
-                                }
+                                procs.entry(pid).or_default().mem = gpu_mem;
                             }
                         }
 
@@ -136,11 +217,7 @@ pub fn get_nvidia_vecs(
                                     UsedGpuMemory::Used(val) => val,
                                     UsedGpuMemory::Unavailable => 0,
                                 };
-                                if let Some(prev) = procs.get(&pid) {
-                                    procs.insert(pid, (gpu_mem, prev.1));
-                                } else {
-                                    procs.insert(pid, (gpu_mem, 0));
-                                }
+                                procs.entry(pid).or_default().mem = gpu_mem;
                             }
                         }
 
@@ -167,6 +244,7 @@ pub fn get_nvidia_vecs(
                 } else {
                     None
                 },
+                stats: (!stats_vec.is_empty()).then_some(stats_vec),
                 procs: if !proc_vec.is_empty() {
                     Some((total_mem, proc_vec))
                 } else {
@@ -34,6 +34,8 @@ cfg_if! {
 
 use std::{sync::Arc, time::Duration};
 
+#[cfg(feature = "gpu")]
+use super::gpu::GpuEngineUtil;
 use super::{DataCollector, error::CollectionResult};
 
 cfg_if! {
@@ -144,6 +146,10 @@ pub struct ProcessHarvest {
     #[cfg(feature = "gpu")]
     pub gpu_util: u32,
 
+    /// Gpu utilization broken down by engine.
+    #[cfg(feature = "gpu")]
+    pub gpu_engines: GpuEngineUtil,
+
     /// The process entry "type".
     #[cfg(target_os = "linux")]
     pub process_type: ProcessType,
@@ -154,6 +160,10 @@ pub struct ProcessHarvest {
 
     /// The kernel scheduling priority.
     pub priority: i32,
+
+    /// The NUMA node holding most of the process' memory.
+    #[cfg(target_os = "linux")]
+    pub numa_node: Option<usize>,
     // TODO: Additional fields
     // pub rss_kb: u64,
     // pub virt_kb: u64,
@@ -15,7 +15,7 @@ use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use sysinfo::ProcessStatus;
 
 use super::{Pid, ProcessHarvest, UserTable, process_status_str};
-use crate::collection::{DataCollector, error::CollectionResult, processes::ProcessType};
+use crate::collection::{DataCollector, error::CollectionResult, numa, processes::ProcessType};
 
 /// Maximum character length of a `/proc/<PID>/stat` process name (the length is 16,
 /// but this includes a null terminator).
@@ -285,10 +285,13 @@ fn read_proc(
             gpu_mem_percent: 0.0,
             #[cfg(feature = "gpu")]
             gpu_util: 0,
+            #[cfg(feature = "gpu")]
+            gpu_engines: Default::default(),
             process_type,
             #[cfg(unix)]
             nice: stat.nice,
             priority: stat.priority,
+            numa_node: None,
         },
         new_process_times,
     ))
@@ -360,6 +363,7 @@ pub(crate) fn linux_process_data(
         unnormalized_cpu: collector.unnormalized_cpu,
         get_process_threads: collector.get_process_threads,
     };
+    let get_process_numa_node = collector.get_process_numa_node;
     let prev_process_details = &mut collector.prev_process_details;
     let user_table = &mut collector.user_table;
 
@@ -425,23 +429,32 @@ pub(crate) fn linux_process_data(
                 let pid = process.pid;
                 let prev_proc_details = prev_process_details.entry(pid).or_default();
 
-                #[cfg_attr(not(feature = "gpu"), expect(unused_mut))]
                 if let Ok((mut process_harvest, new_process_times)) =
                     read_proc(prev_proc_details, process, args, user_table, None)
                 {
+                    // Reading numa_maps walks every mapping of the process, so only do it if needed.
+                    if get_process_numa_node {
+                        process_harvest.numa_node = numa::get_process_numa_node(pid);
+                    }
+
                     #[cfg(feature = "gpu")]
                     if let Some(gpus) = &collector.gpu_pids {
+                        // Only memory on GPUs with a known total counts towards the percentage.
+                        let mut gpu_mem_with_total = 0;
                         gpus.iter().for_each(|gpu| {
                             // add mem/util for all gpus to pid
-                            if let Some((mem, util)) = gpu.get(&pid) {
-                                process_harvest.gpu_mem += mem;
-                                process_harvest.gpu_util += util;
+                            if let Some(usage) = gpu.get(&pid) {
+                                process_harvest.gpu_mem += usage.mem;
+                                process_harvest.gpu_util += usage.util;
+                                process_harvest.gpu_engines += usage.engines;
+                                if !usage.mem_without_total {
+                                    gpu_mem_with_total += usage.mem;
+                                }
                             }
                         });
                         if let Some(gpu_total_mem) = &collector.gpus_total_mem {
                             process_harvest.gpu_mem_percent =
-                                (process_harvest.gpu_mem as f64 / *gpu_total_mem as f64 * 100.0)
-                                    as f32;
+                                (gpu_mem_with_total as f64 / *gpu_total_mem as f64 * 100.0) as f32;
                         }
                     }
 
@@ -158,6 +158,8 @@ pub(crate) trait UnixProcessExt {
                 gpu_mem_percent: 0.0,
                 #[cfg(feature = "gpu")]
                 gpu_util: 0,
+                #[cfg(feature = "gpu")]
+                gpu_engines: Default::default(),
                 #[cfg(unix)]
                 nice,
                 priority,
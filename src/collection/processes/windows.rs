@@ -99,25 +99,33 @@ pub fn sysinfo_process_data(
         let process_state = (process_status_str(process.status()), 'R');
 
         #[cfg(feature = "gpu")]
-        let (gpu_mem, gpu_util, gpu_mem_percent) = {
+        let (gpu_mem, gpu_util, gpu_engines, gpu_mem_percent) = {
             let mut gpu_mem = 0;
             let mut gpu_util = 0;
+            let mut gpu_engines = crate::collection::gpu::GpuEngineUtil::default();
             let mut gpu_mem_percent = 0.0;
+            // Only memory on GPUs with a known total counts towards the percentage.
+            let mut gpu_mem_with_total = 0;
             if let Some(gpus) = &collector.gpu_pids {
                 use crate::collection::processes::Pid;
 
                 gpus.iter().for_each(|gpu| {
                     // add mem/util for all gpus to pid
-                    if let Some((mem, util)) = gpu.get(&(process.pid().as_u32() as Pid)) {
-                        gpu_mem += mem;
-                        gpu_util += util;
+                    if let Some(usage) = gpu.get(&(process.pid().as_u32() as Pid)) {
+                        gpu_mem += usage.mem;
+                        gpu_util += usage.util;
+                        gpu_engines += usage.engines;
+                        if !usage.mem_without_total {
+                            gpu_mem_with_total += usage.mem;
+                        }
                     }
                 });
             }
             if let Some(gpu_total_mem) = &collector.gpus_total_mem {
-                gpu_mem_percent = (gpu_mem as f64 / *gpu_total_mem as f64 * 100.0) as f32;
+                gpu_mem_percent =
+                    (gpu_mem_with_total as f64 / *gpu_total_mem as f64 * 100.0) as f32;
             }
-            (gpu_mem, gpu_util, gpu_mem_percent)
+            (gpu_mem, gpu_util, gpu_engines, gpu_mem_percent)
         };
 
         let pid = process.pid().as_u32();
@@ -159,6 +167,8 @@ pub fn sysinfo_process_data(
             #[cfg(feature = "gpu")]
             gpu_util,
             #[cfg(feature = "gpu")]
+            gpu_engines,
+            #[cfg(feature = "gpu")]
             gpu_mem_percent,
             priority, // TODO: Translate this to Windows priority names?
         });
@@ -3,6 +3,8 @@
 //! For Linux, this is handled by custom code.
 //! For everything else, this is handled by sysinfo.
 
+use serde::Deserialize;
+
 cfg_if::cfg_if! {
     if #[cfg(target_os = "linux")] {
         pub mod linux;
@@ -13,11 +15,74 @@ cfg_if::cfg_if! {
     }
 }
 
+/// The kind of reading a sensor reports. Kinds are ordered as declared, which is how
+/// readings of different kinds are grouped when sorted.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SensorKind {
+    /// In Celsius.
+    #[default]
+    Temperature,
+    /// In RPM.
+    Fan,
+    /// In volts.
+    Voltage,
+    /// In watts.
+    Power,
+    /// In amps.
+    Current,
+}
+
+impl SensorKind {
+    /// The unit readings of this kind are reported in.
+    pub fn unit(&self) -> &'static str {
+        match self {
+            SensorKind::Temperature => "°C",
+            SensorKind::Fan => "RPM",
+            SensorKind::Voltage => "V",
+            SensorKind::Power => "W",
+            SensorKind::Current => "A",
+        }
+    }
+}
+
+/// Parses the kinds of sensors to show from the config.
+impl<'de> Deserialize<'de> for SensorKind {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?.to_lowercase();
+        match value.as_str() {
+            "temperature" | "temp" => Ok(SensorKind::Temperature),
+            "fan" => Ok(SensorKind::Fan),
+            "voltage" | "in" => Ok(SensorKind::Voltage),
+            "power" => Ok(SensorKind::Power),
+            "current" | "curr" => Ok(SensorKind::Current),
+            _ => Err(serde::de::Error::custom(
+                "doesn't match any sensor type, use one of: [temperature, fan, voltage, power, current]",
+            )),
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone)]
-pub struct TempSensorData {
+pub struct SensorData {
     /// The name of the sensor.
     pub name: String,
 
-    /// The temperature in Celsius.
-    pub temperature: Option<f32>,
+    /// What the sensor measures.
+    pub kind: SensorKind,
+
+    /// The reading, in the kind's unit.
+    pub value: Option<f32>,
+
+    /// The value the sensor is rated for, if reported.
+    pub max: Option<f32>,
+
+    /// The critical value, if reported. For temperatures, hardware may throttle or shut
+    /// down past this point.
+    pub crit: Option<f32>,
+
+    /// Whether the kernel reports that the critical value has been reached.
+    pub crit_alarm: bool,
 }
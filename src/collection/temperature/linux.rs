@@ -8,7 +8,7 @@ use std::{
 use anyhow::Result;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
-use super::TempSensorData;
+use super::{SensorData, SensorKind};
 #[cfg(feature = "gpu")]
 use crate::collection::amd::get_amd_name;
 use crate::{app::filter::Filter, collection::linux::utils::is_device_awake};
@@ -18,14 +18,73 @@ const EMPTY_NAME: &str = "Unknown";
 /// Returned results from grabbing hwmon/coretemp temperature sensor
 /// values or names.
 struct HwmonResults {
-    temperatures: Vec<TempSensorData>,
+    temperatures: Vec<SensorData>,
     num_hwmon: usize,
 }
 
 /// Parses and reads temperatures that were in millidegree Celsius, and if
 /// successful, returns a temperature in Celsius.
 fn parse_temp(path: &Path) -> Result<f32> {
-    Ok(fs::read_to_string(path)?.trim_end().parse::<f32>()? / 1_000.0)
+    parse_reading(path, SensorKind::Temperature)
+}
+
+/// Parses an hwmon reading, and converts it from the fixed-point units hwmon uses to the
+/// kind's base unit. Temperatures, voltages and currents are reported in thousandths, power in
+/// microwatts, and fans in RPM.
+fn parse_reading(path: &Path, kind: SensorKind) -> Result<f32> {
+    let scale = match kind {
+        SensorKind::Temperature | SensorKind::Voltage | SensorKind::Current => 1_000.0,
+        SensorKind::Power => 1_000_000.0,
+        SensorKind::Fan => 1.0,
+    };
+
+    Ok(fs::read_to_string(path)?.trim_end().parse::<f32>()? / scale)
+}
+
+/// Reads the `max` and `crit` thresholds of an hwmon sensor (e.g. `temp1`), and whether its
+/// critical alarm is set. Thresholds that aren't positive are treated as missing, as some
+/// drivers report `0` for ones they don't support.
+fn read_thresholds(
+    hwmon_dir: &Path, sensor: &str, kind: SensorKind,
+) -> (Option<f32>, Option<f32>, bool) {
+    let threshold = |threshold: &str| {
+        parse_reading(&hwmon_dir.join(format!("{sensor}_{threshold}")), kind)
+            .ok()
+            .filter(|value| *value > 0.0)
+    };
+    let crit_alarm = read_to_string_lossy(hwmon_dir.join(format!("{sensor}_crit_alarm")))
+        .is_some_and(|alarm| alarm != "0");
+
+    (threshold("max"), threshold("crit"), crit_alarm)
+}
+
+/// Reads the `hot` and `critical` trip points of a thermal zone, which are the closest
+/// equivalents to hwmon's `max` and `crit` thresholds.
+fn read_trip_points(zone_dir: &Path) -> (Option<f32>, Option<f32>) {
+    let mut hot = None;
+    let mut critical = None;
+
+    for index in 0.. {
+        let Some(trip_type) =
+            read_to_string_lossy(zone_dir.join(format!("trip_point_{index}_type")))
+        else {
+            break;
+        };
+
+        let temp = || {
+            parse_temp(&zone_dir.join(format!("trip_point_{index}_temp")))
+                .ok()
+                .filter(|temp| *temp > 0.0)
+        };
+
+        match trip_type.as_str() {
+            "hot" => hot = temp(),
+            "critical" => critical = temp(),
+            _ => {}
+        }
+    }
+
+    (hot, critical)
 }
 
 /// Get all candidates from hwmon and coretemp. It will also return the number
@@ -181,6 +240,79 @@ fn finalize_name(
     counted_name(seen_names, candidate_name)
 }
 
+/// Returns the kind of sensor an hwmon file is the reading of, and the sensor's name (e.g.
+/// `fan1` for `fan1_input`). Power sensors report either an average or an instantaneous reading;
+/// the average is skipped if both exist.
+fn hwmon_sensor(hwmon_dir: &Path, file_name: &str) -> Option<(SensorKind, String)> {
+    if let Some(sensor) = file_name.strip_suffix("_average") {
+        return (sensor.starts_with("power")
+            && !hwmon_dir.join(format!("{sensor}_input")).exists())
+        .then(|| (SensorKind::Power, sensor.to_string()));
+    }
+
+    let sensor = file_name.strip_suffix("_input")?;
+    let kind = match sensor.trim_end_matches(|c: char| c.is_ascii_digit()) {
+        "temp" => SensorKind::Temperature,
+        "fan" => SensorKind::Fan,
+        "in" => SensorKind::Voltage,
+        "power" => SensorKind::Power,
+        "curr" => SensorKind::Current,
+        _ => return None,
+    };
+
+    Some((kind, sensor.to_string()))
+}
+
+/// Reads every sensor in an hwmon directory. Names are counted separately for each kind,
+/// as sensors of different kinds can share a label (e.g. a `CPU` temperature and fan).
+fn read_hwmon_sensors(
+    hwmon_dir: &Path, hwmon_name: Option<String>, sensor_name: &Option<String>,
+    filter: &Option<Filter>, seen_names: &mut HashMap<SensorKind, HashMap<String, u32>>,
+    out: &mut Vec<SensorData>,
+) {
+    let Ok(dir_entries) = hwmon_dir.read_dir() else {
+        return;
+    };
+
+    for file in dir_entries.flatten() {
+        let file_name = file.file_name();
+
+        // Skip anything that isn't a sensor reading early.
+        let Some((kind, sensor)) = hwmon_sensor(hwmon_dir, &file_name.to_string_lossy()) else {
+            continue;
+        };
+
+        // Unlike temperatures, other sensors usually aren't labelled, so fall back to their
+        // name (e.g. `fan1`) to tell them apart.
+        let sensor_label = read_to_string_lossy(hwmon_dir.join(format!("{sensor}_label")))
+            .or_else(|| (kind != SensorKind::Temperature).then(|| sensor.clone()));
+
+        let name = finalize_name(
+            hwmon_name.clone(),
+            sensor_label,
+            sensor_name,
+            seen_names.entry(kind).or_default(),
+        );
+
+        // TODO: It's possible we may want to move the filter check further up to avoid
+        // probing hwmon if not needed?
+        if Filter::optional_should_keep(filter, &name) {
+            if let Ok(value) = parse_reading(&file.path(), kind) {
+                let (max, crit, crit_alarm) = read_thresholds(hwmon_dir, &sensor, kind);
+
+                out.push(SensorData {
+                    name,
+                    kind,
+                    value: Some(value),
+                    max,
+                    crit,
+                    crit_alarm,
+                });
+            }
+        }
+    }
+}
+
 /// Get temperature sensors from the linux sysfs interface `/sys/class/hwmon`
 /// and `/sys/devices/platform/coretemp.*`. It returns all found temperature
 /// sensors, and the number of checked hwmon directories (not coretemp
@@ -201,8 +333,8 @@ fn finalize_name(
 /// once this happens, the device will be *kept* on through the sensor
 /// reading, and not be able to re-enter ACPI D3cold.
 fn hwmon_temperatures(filter: &Option<Filter>) -> HwmonResults {
-    let mut temperatures: Vec<TempSensorData> = vec![];
-    let mut seen_names: HashMap<String, u32> = HashMap::default();
+    let mut temperatures: Vec<SensorData> = vec![];
+    let mut seen_names: HashMap<SensorKind, HashMap<String, u32>> = HashMap::default();
 
     let (dirs, num_hwmon) = get_hwmon_candidates();
 
@@ -223,119 +355,99 @@ fn hwmon_temperatures(filter: &Option<Filter>) -> HwmonResults {
         let device = file_path.join("device");
 
         if !is_device_awake(&device) {
-            let name = finalize_name(None, None, &sensor_name, &mut seen_names);
-            temperatures.push(TempSensorData {
+            let name = finalize_name(
+                None,
+                None,
+                &sensor_name,
+                seen_names.entry(SensorKind::Temperature).or_default(),
+            );
+            temperatures.push(SensorData {
                 name,
-                temperature: None,
+                kind: SensorKind::Temperature,
+                value: None,
+                max: None,
+                crit: None,
+                crit_alarm: false,
             });
 
             continue;
         }
 
-        if let Ok(dir_entries) = file_path.read_dir() {
-            // Enumerate the devices temperature sensors
-            for file in dir_entries.flatten() {
-                let name = file.file_name();
-                let name = name.to_string_lossy();
-
-                // We only want temperature sensors, skip others early
-                if !(name.starts_with("temp") && name.ends_with("input")) {
-                    continue;
-                }
-
-                let temp_path = file.path();
-                let sensor_label_path = file_path.join(name.replace("input", "label"));
-                let sensor_label = read_to_string_lossy(sensor_label_path);
-
-                // Do some messing around to get a more sensible name for sensors:
-                // - For GPUs, this will use the kernel device name, ex `card0`
-                // - For nvme drives, this will also use the kernel name, ex `nvme0`. This is
-                //   found differently than for GPUs
-                // - For whatever acpitz is, on my machine this is now `thermal_zone0`.
-                // - For k10temp, this will still be k10temp, but it has to be handled special.
-                let hwmon_name = {
-                    let device = file_path.join("device");
-
-                    // This will exist for GPUs but not others, this is how we find their kernel
-                    // name.
-                    let drm = device.join("drm");
-                    if drm.exists() {
-                        // This should never actually be empty. If it is though, we'll fall back to
-                        // the sensor name later on.
-
-                        #[cfg(feature = "gpu")]
-                        {
-                            if let Some(amd_gpu_name) = get_amd_name(&device) {
-                                Some(amd_gpu_name)
-                            } else if let Ok(cards) = drm.read_dir() {
-                                cards.flatten().find_map(|card| {
-                                    card.file_name().to_str().and_then(|name| {
-                                        name.starts_with("card").then(|| {
-                                            humanize_name(
-                                                name.trim().to_string(),
-                                                sensor_name.as_ref(),
-                                            )
-                                        })
-                                    })
-                                })
-                            } else {
-                                None
-                            }
-                        }
-
-                        #[cfg(not(feature = "gpu"))]
-                        {
-                            if let Ok(cards) = drm.read_dir() {
-                                cards.flatten().find_map(|card| {
-                                    card.file_name().to_str().and_then(|name| {
-                                        name.starts_with("card").then(|| {
-                                            humanize_name(
-                                                name.trim().to_string(),
-                                                sensor_name.as_ref(),
-                                            )
-                                        })
-                                    })
+        // Do some messing around to get a more sensible name for sensors:
+        // - For GPUs, this will use the kernel device name, ex `card0`
+        // - For nvme drives, this will also use the kernel name, ex `nvme0`. This is
+        //   found differently than for GPUs
+        // - For whatever acpitz is, on my machine this is now `thermal_zone0`.
+        // - For k10temp, this will still be k10temp, but it has to be handled special.
+        let hwmon_name = {
+            // This will exist for GPUs but not others, this is how we find their kernel
+            // name.
+            let drm = device.join("drm");
+            if drm.exists() {
+                // This should never actually be empty. If it is though, we'll fall back to
+                // the sensor name later on.
+
+                #[cfg(feature = "gpu")]
+                {
+                    if let Some(amd_gpu_name) = get_amd_name(&device) {
+                        Some(amd_gpu_name)
+                    } else if let Ok(cards) = drm.read_dir() {
+                        cards.flatten().find_map(|card| {
+                            card.file_name().to_str().and_then(|name| {
+                                name.starts_with("card").then(|| {
+                                    humanize_name(name.trim().to_string(), sensor_name.as_ref())
                                 })
-                            } else {
-                                None
-                            }
-                        }
-                    } else {
-                        // This little mess is to account for stuff like k10temp. This is needed
-                        // because the `device` symlink points to `nvme*`
-                        // for nvme drives, but to PCI buses for anything
-                        // else. If the first character is alphabetic, it's an actual name like
-                        // k10temp or nvme0, not a PCI bus.
-                        fs::read_link(device).ok().and_then(|link| {
-                            let link = link
-                                .file_name()
-                                .and_then(|f| f.to_str())
-                                .map(|s| s.trim().to_owned());
-
-                            match link {
-                                Some(link) if link.as_bytes()[0].is_ascii_alphabetic() => {
-                                    Some(humanize_name(link, sensor_name.as_ref()))
-                                }
-                                _ => None,
-                            }
+                            })
                         })
+                    } else {
+                        None
                     }
-                };
-
-                let name = finalize_name(hwmon_name, sensor_label, &sensor_name, &mut seen_names);
+                }
 
-                // TODO: It's possible we may want to move the filter check further up to avoid
-                // probing hwmon if not needed?
-                if Filter::optional_should_keep(filter, &name) {
-                    if let Ok(temp_celsius) = parse_temp(&temp_path) {
-                        temperatures.push(TempSensorData {
-                            name,
-                            temperature: Some(temp_celsius),
-                        });
+                #[cfg(not(feature = "gpu"))]
+                {
+                    if let Ok(cards) = drm.read_dir() {
+                        cards.flatten().find_map(|card| {
+                            card.file_name().to_str().and_then(|name| {
+                                name.starts_with("card").then(|| {
+                                    humanize_name(name.trim().to_string(), sensor_name.as_ref())
+                                })
+                            })
+                        })
+                    } else {
+                        None
                     }
                 }
+            } else {
+                // This little mess is to account for stuff like k10temp. This is needed
+                // because the `device` symlink points to `nvme*`
+                // for nvme drives, but to PCI buses for anything
+                // else. If the first character is alphabetic, it's an actual name like
+                // k10temp or nvme0, not a PCI bus.
+                fs::read_link(device).ok().and_then(|link| {
+                    let link = link
+                        .file_name()
+                        .and_then(|f| f.to_str())
+                        .map(|s| s.trim().to_owned());
+
+                    match link {
+                        Some(link) if link.as_bytes()[0].is_ascii_alphabetic() => {
+                            Some(humanize_name(link, sensor_name.as_ref()))
+                        }
+                        _ => None,
+                    }
+                })
             }
-        }
+        };
+
+        read_hwmon_sensors(
+            &file_path,
+            hwmon_name,
+            &sensor_name,
+            filter,
+            &mut seen_names,
+            &mut temperatures,
+        );
     }
 
     HwmonResults {
@@ -350,7 +462,7 @@ fn hwmon_temperatures(filter: &Option<Filter>) -> HwmonResults {
 ///
 /// See [the Linux kernel documentation](https://www.kernel.org/doc/Documentation/ABI/testing/sysfs-class-thermal)
 /// for more details.
-fn add_thermal_zone_temperatures(temperatures: &mut Vec<TempSensorData>, filter: &Option<Filter>) {
+fn add_thermal_zone_temperatures(temperatures: &mut Vec<SensorData>, filter: &Option<Filter>) {
     let path = Path::new("/sys/class/thermal");
     let Ok(read_dir) = path.read_dir() else {
         return;
@@ -378,10 +490,15 @@ fn add_thermal_zone_temperatures(temperatures: &mut Vec<TempSensorData>, filter:
                     let temp_path = file_path.join("temp");
                     if let Ok(temp_celsius) = parse_temp(&temp_path) {
                         let name = counted_name(&mut seen_names, name);
+                        let (max, crit) = read_trip_points(&file_path);
 
-                        temperatures.push(TempSensorData {
+                        temperatures.push(SensorData {
                             name,
-                            temperature: Some(temp_celsius),
+                            kind: SensorKind::Temperature,
+                            value: Some(temp_celsius),
+                            max,
+                            crit,
+                            crit_alarm: false,
                         });
                     }
                 }
@@ -391,7 +508,7 @@ fn add_thermal_zone_temperatures(temperatures: &mut Vec<TempSensorData>, filter:
 }
 
 /// Gets temperature sensors and data.
-pub fn get_temperature_data(filter: &Option<Filter>) -> Result<Option<Vec<TempSensorData>>> {
+pub fn get_temperature_data(filter: &Option<Filter>) -> Result<Option<Vec<SensorData>>> {
     let mut results = hwmon_temperatures(filter);
 
     if results.num_hwmon == 0 {
@@ -403,9 +520,151 @@ pub fn get_temperature_data(filter: &Option<Filter>) -> Result<Option<Vec<TempSe
 
 #[cfg(test)]
 mod tests {
+    use std::fs;
+
     use rustc_hash::FxHashMap as HashMap;
 
-    use super::finalize_name;
+    use super::{SensorKind, finalize_name, read_hwmon_sensors, read_thresholds, read_trip_points};
+
+    #[test]
+    fn test_read_thresholds() {
+        let dir = tempfile::tempdir().unwrap();
+        let write =
+            |file: &str, contents: &str| fs::write(dir.path().join(file), contents).unwrap();
+
+        write("temp1_input", "45000\n");
+        write("temp1_max", "80000\n");
+        write("temp1_crit", "100000\n");
+        write("temp1_crit_alarm", "0\n");
+        assert_eq!(
+            read_thresholds(dir.path(), "temp1", SensorKind::Temperature),
+            (Some(80.0), Some(100.0), false)
+        );
+
+        // Missing and unsupported thresholds.
+        write("temp2_input", "105000\n");
+        write("temp2_max", "0\n");
+        write("temp2_crit_alarm", "1\n");
+        assert_eq!(
+            read_thresholds(dir.path(), "temp2", SensorKind::Temperature),
+            (None, None, true)
+        );
+
+        assert_eq!(
+            read_thresholds(dir.path(), "temp3", SensorKind::Temperature),
+            (None, None, false)
+        );
+    }
+
+    #[test]
+    fn test_read_hwmon_sensors() {
+        let dir = tempfile::tempdir().unwrap();
+        let write =
+            |file: &str, contents: &str| fs::write(dir.path().join(file), contents).unwrap();
+
+        write("name", "nct6775\n");
+        write("temp1_input", "42000\n");
+        write("temp1_label", "SYSTIN\n");
+        write("fan1_input", "1200\n");
+        write("fan1_max", "0\n");
+        write("fan2_input", "850\n");
+        write("fan2_label", "CPU Fan\n");
+        write("in0_input", "1216\n");
+        write("in0_crit", "1500\n");
+        write("curr1_input", "2500\n");
+        write("power1_input", "45500000\n");
+        write("power1_average", "40000000\n");
+        write("power2_average", "12250000\n");
+        write("pwm1", "128\n");
+
+        let mut sensors = vec![];
+        read_hwmon_sensors(
+            dir.path(),
+            None,
+            &Some("nct6775".to_string()),
+            &None,
+            &mut HashMap::default(),
+            &mut sensors,
+        );
+        sensors.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let readings = sensors
+            .iter()
+            .map(|sensor| (sensor.name.as_str(), sensor.kind, sensor.value.unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            readings,
+            vec![
+                ("nct6775: CPU Fan", SensorKind::Fan, 850.0),
+                ("nct6775: Curr1", SensorKind::Current, 2.5),
+                ("nct6775: Fan1", SensorKind::Fan, 1200.0),
+                ("nct6775: In0", SensorKind::Voltage, 1.216),
+                ("nct6775: Power1", SensorKind::Power, 45.5),
+                ("nct6775: Power2", SensorKind::Power, 12.25),
+                ("nct6775: SYSTIN", SensorKind::Temperature, 42.0),
+            ]
+        );
+
+        let voltage = sensors.iter().find(|s| s.name == "nct6775: In0").unwrap();
+        assert_eq!(voltage.crit, Some(1.5));
+        let fan = sensors.iter().find(|s| s.name == "nct6775: Fan1").unwrap();
+        assert_eq!(fan.max, None);
+    }
+
+    #[test]
+    fn test_read_hwmon_sensors_counts_names_per_kind() {
+        let dir = tempfile::tempdir().unwrap();
+        let write =
+            |file: &str, contents: &str| fs::write(dir.path().join(file), contents).unwrap();
+
+        write("temp1_input", "42000\n");
+        write("temp1_label", "CPU\n");
+        write("temp2_input", "40000\n");
+        write("temp2_label", "CPU\n");
+        write("fan1_input", "850\n");
+        write("fan1_label", "CPU\n");
+
+        let mut sensors = vec![];
+        read_hwmon_sensors(
+            dir.path(),
+            None,
+            &Some("nct6775".to_string()),
+            &None,
+            &mut HashMap::default(),
+            &mut sensors,
+        );
+        sensors.sort_by(|a, b| a.name.cmp(&b.name).then(a.kind.cmp(&b.kind)));
+
+        let names = sensors
+            .iter()
+            .map(|sensor| (sensor.name.as_str(), sensor.kind))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec![
+                ("nct6775: CPU", SensorKind::Temperature),
+                ("nct6775: CPU", SensorKind::Fan),
+                ("nct6775: CPU (1)", SensorKind::Temperature),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_trip_points() {
+        let dir = tempfile::tempdir().unwrap();
+        let write =
+            |file: &str, contents: &str| fs::write(dir.path().join(file), contents).unwrap();
+
+        write("trip_point_0_type", "passive\n");
+        write("trip_point_0_temp", "85000\n");
+        write("trip_point_1_type", "critical\n");
+        write("trip_point_1_temp", "105000\n");
+        assert_eq!(read_trip_points(dir.path()), (None, Some(105.0)));
+
+        write("trip_point_2_type", "hot\n");
+        write("trip_point_2_temp", "95000\n");
+        assert_eq!(read_trip_points(dir.path()), (Some(95.0), Some(105.0)));
+    }
 
     #[test]
     fn test_finalize_name() {
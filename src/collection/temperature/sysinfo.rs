@@ -2,21 +2,26 @@
 
 use anyhow::Result;
 
-use super::TempSensorData;
+use super::{SensorData, SensorKind};
 use crate::app::filter::Filter;
 
 pub fn get_temperature_data(
     components: &sysinfo::Components, filter: &Option<Filter>,
-) -> Result<Option<Vec<TempSensorData>>> {
-    let mut temperatures: Vec<TempSensorData> = Vec::new();
+) -> Result<Option<Vec<SensorData>>> {
+    let mut temperatures: Vec<SensorData> = Vec::new();
 
     for component in components {
         let name = component.label().to_string();
 
         if Filter::optional_should_keep(filter, &name) {
-            temperatures.push(TempSensorData {
+            temperatures.push(SensorData {
                 name,
-                temperature: component.temperature(),
+                kind: SensorKind::Temperature,
+                value: component.temperature(),
+                // sysinfo's `max` is the highest temperature seen, not a threshold.
+                max: None,
+                crit: component.critical(),
+                crit_alarm: false,
             });
         }
     }
@@ -32,9 +37,13 @@ pub fn get_temperature_data(
             for ctl in sysctl::CtlIter::below(root).flatten() {
                 if let (Ok(name), Ok(temp)) = (ctl.name(), ctl.value()) {
                     if let Some(temp) = temp.as_temperature() {
-                        temperatures.push(TempSensorData {
+                        temperatures.push(SensorData {
                             name,
-                            temperature: Some(temp.celsius()),
+                            kind: SensorKind::Temperature,
+                            value: Some(temp.celsius()),
+                            max: None,
+                            crit: None,
+                            crit_alarm: false,
                         });
                     }
                 }
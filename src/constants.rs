@@ -10,7 +10,7 @@ pub const DEFAULT_WIDGET_ID: u64 = 56709;
 pub const TABLE_GAP_HEIGHT_LIMIT: u16 = 7;
 
 // Help text
-const HELP_CONTENTS_TEXT: [&str; 10] = [
+const HELP_CONTENTS_TEXT: [&str; 11] = [
     "Either scroll or press the number key to go to the corresponding help menu section:",
     "1 - General",
     "2 - CPU widget",
@@ -21,6 +21,7 @@ const HELP_CONTENTS_TEXT: [&str; 10] = [
     "7 - Disk widget",
     "8 - Battery widget",
     "9 - Basic memory widget",
+    "10 - GPU widget (press 0)",
 ];
 
 // TODO [Help]: Search in help?
@@ -84,7 +85,7 @@ const PROCESS_HELP_TEXT: [&str; 20] = [
     "z                       Toggle the display of kernel threads",
 ];
 
-const SEARCH_HELP_TEXT: [&str; 51] = [
+const SEARCH_HELP_TEXT: [&str; 55] = [
     "4 - Process search widget",
     "Esc                  Close the search widget (retains the filter)",
     "Ctrl-a               Skip to the start of the search query",
@@ -115,6 +116,10 @@ const SEARCH_HELP_TEXT: [&str; 51] = [
     "gpu%                 ex: gpu% < 4.2",
     "gmem                 ex: gmem < 100 kb",
     "gmem%                ex: gmem% < 4.2",
+    "gfx%                 ex: gfx% > 10",
+    "enc%                 ex: enc% > 0",
+    "dec%                 ex: dec% > 0",
+    "compute%             ex: compute% >= 50",
     "",
     "Comparison operators:",
     "=                    ex: cpu = 1",
@@ -147,13 +152,14 @@ const SORT_HELP_TEXT: [&str; 6] = [
     "Enter                Sort by current selected column",
 ];
 
-const TEMP_HELP_WIDGET: [&str; 3] = [
+const TEMP_HELP_WIDGET: [&str; 4] = [
     "6 - Temperature widget",
     "'s'                  Sort by sensor name, press again to reverse",
     "'t'                  Sort by temperature, press again to reverse",
+    "Space                Toggle plotting the sensor in the temperature graph",
 ];
 
-const DISK_HELP_WIDGET: [&str; 9] = [
+const DISK_HELP_WIDGET: [&str; 11] = [
     "7 - Disk widget",
     "'d'                  Sort by disk name, press again to reverse",
     "'m'                  Sort by disk mount, press again to reverse",
@@ -163,6 +169,8 @@ const DISK_HELP_WIDGET: [&str; 9] = [
     "'p'                  Sort by disk usage percentage, press again to reverse",
     "'r'                  Sort by disk read activity, press again to reverse",
     "'w'                  Sort by disk write activity, press again to reverse",
+    "Space                Toggle plotting the disk in the disk I/O graph",
+    "'T'                  Toggle showing block devices as a tree (Linux-only)",
 ];
 
 const BATTERY_HELP_TEXT: [&str; 3] = [
@@ -176,6 +184,12 @@ const BASIC_MEM_HELP_TEXT: [&str; 2] = [
     "%, V, v              Toggle/force percent/value display for memory usage",
 ];
 
+const GPU_HELP_TEXT: [&str; 3] = [
+    "10 - GPU widget",
+    "Left, h              Show the previous metric",
+    "Right, l             Show the next metric",
+];
+
 pub(crate) const HELP_TEXT: [&[&str]; HELP_CONTENTS_TEXT.len()] = [
     &HELP_CONTENTS_TEXT,
     &GENERAL_HELP_TEXT,
@@ -187,6 +201,7 @@ pub(crate) const HELP_TEXT: [&[&str]; HELP_CONTENTS_TEXT.len()] = [
     &DISK_HELP_WIDGET,
     &BATTERY_HELP_TEXT,
     &BASIC_MEM_HELP_TEXT,
+    &GPU_HELP_TEXT,
 ];
 
 pub(crate) const DEFAULT_LAYOUT: &str = r#"
@@ -362,6 +377,9 @@ pub(crate) const CONFIG_TEXT: &str = r#"# This is a default config file for bott
 # Displays the network widget with a log scale.
 #network_use_log = false
 
+# Scales the network widget to the combined link capacity of interfaces with a known speed.
+#network_scale_to_link = false
+
 # Hides advanced options to stop a process on Unix-like systems.
 #disable_advanced_kill = false
 
@@ -371,6 +389,9 @@ pub(crate) const CONFIG_TEXT: &str = r#"# This is a default config file for bott
 # Hides the kernel threads
 #hide_k_threads = false
 
+# Groups CPU entries by NUMA node, with a per-node average. Only does anything on Linux.
+#group_cpus_by_numa = false
+
 # Hide GPU(s) information
 #disable_gpu = false
 
@@ -389,6 +410,21 @@ pub(crate) const CONFIG_TEXT: &str = r#"# This is a default config file for bott
 # Where to place the legend for the network widget. One of "none", "top-left", "top", "top-right", "left", "right", "bottom-left", "bottom", "bottom-right".
 #network_legend = "top-right"
 
+# Where to place the legend for the disk I/O widget. One of "none", "top-left", "top", "top-right", "left", "right", "bottom-left", "bottom", "bottom-right".
+#disk_io_legend = "top-right"
+
+# Where to place the legend for the TCP widget. One of "none", "top-left", "top", "top-right", "left", "right", "bottom-left", "bottom", "bottom-right".
+#tcp_legend = "top-right"
+
+# Where to place the legend for the power widget. One of "none", "top-left", "top", "top-right", "left", "right", "bottom-left", "bottom", "bottom-right".
+#power_legend = "top-right"
+
+# Where to place the legend for the GPU widget. One of "none", "top-left", "top", "top-right", "left", "right", "bottom-left", "bottom", "bottom-right".
+#gpu_legend = "top-right"
+
+# Whether to put the temperature graph legend to the left.
+#temperature_left_legend = false
+
 # Keyboard shortcut overrides (single characters).
 # Use 'space' for the space key.
 #[keybindings]
@@ -402,7 +438,8 @@ pub(crate) const CONFIG_TEXT: &str = r#"# This is a default config file for bott
 # Processes widget configuration
 #[processes]
 # The columns shown by the process widget. The following columns are supported (the GPU columns are only available if the GPU feature is enabled when built):
-# PID, Name, CPU%, Mem%, R/s, W/s, T.Read, T.Write, User, State, Time, GMem%, GPU%, Nice, Priority
+# PID, Name, CPU%, Mem%, R/s, W/s, T.Read, T.Write, User, State, Time, GMem%, GPU%, GPU gfx%, GPU enc%, GPU dec%,
+# GPU compute%, Nice, Priority, NUMA
 #columns = ["PID", "Name", "CPU%", "Mem%", "Virt", "R/s", "W/s", "T.Read", "T.Write", "User", "State", "GMem%", "GPU%", "Priority"]
 
 # Gather process child thread information
@@ -418,9 +455,17 @@ pub(crate) const CONFIG_TEXT: &str = r#"# This is a default config file for bott
 # Disk widget configuration
 #[disk]
 # The columns shown by the process widget. The following columns are supported:
-# Disk, Mount, Used, Free, Total, Used%, Free%, R/s, W/s
+# Disk, Mount, Used, Free, Total, Used%, Free%, R/s, W/s, R/IOPS, W/IOPS, Await, Util%, FS_Type, Options, IUsed,
+# IFree, ITotal, IUsed%, Data, Metadata, System, ETA_Full
 #columns = ["Disk", "Mount", "Used", "Free", "Total", "Used%", "R/s", "W/s"]
 
+# Whether to show block devices as a tree by default, from physical disks down to partitions, device-mapper
+# devices (LVM, LUKS), md RAID arrays and their mounted filesystems. Linux-only.
+#tree = false
+
+# How far back to look when estimating when each filesystem will fill up, for the ETA_Full column.
+#trend_window = "30m"
+
 # By default, there are no disk name filters enabled. These can be turned on to filter out specific data entries if you
 # don't want to see them. An example use case is provided below.
 #[disk.name_filter]
@@ -456,9 +501,30 @@ pub(crate) const CONFIG_TEXT: &str = r#"# This is a default config file for bott
 # Whether to be require matching the whole word. Defaults to false.
 #whole_word = false
 
+# By default, there are no filesystem type filters enabled. An example use case is provided below.
+#[disk.fs_type_filter]
+# Whether to ignore any matches. Defaults to true.
+#is_list_ignored = true
+
+# A list of filters to try and match.
+#list = ["squashfs", "vfat"]
+
+# Whether to use regex. Defaults to false.
+#regex = false
+
+# Whether to be case-sensitive. Defaults to false.
+#case_sensitive = false
+
+# Whether to be require matching the whole word. Defaults to false.
+#whole_word = false
+
 
 # Temperature widget configuration
 #[temperature]
+# The kinds of sensors to show. Any of "temperature", "fan", "voltage", "power" and "current". Defaults to
+# only temperatures.
+#sensor_types = ["temperature"]
+
 # By default, there are no temperature sensor filters enabled. An example use case is provided below.
 #[temperature.sensor_filter]
 # Whether to ignore any matches. Defaults to true.
@@ -479,6 +545,20 @@ pub(crate) const CONFIG_TEXT: &str = r#"# This is a default config file for bott
 
 # Network widget configuration
 #[network]
+# Interfaces to plot as separate lines in the network graph. These can also be toggled
+# with Space in the network table widget.
+#graph_interfaces = ["eth0", "wlan0"]
+
+# The percentage of link capacity at which a link is coloured as saturated. Defaults to 90.
+#saturation_threshold = 90
+
+# Extra series to chart below the network graph. Any of "packets", "errors" and "drops".
+#graph_series = ["packets", "errors", "drops"]
+
+# The errors or drops per second on any interface at which a warning is shown in the status line.
+# Defaults to 10, and 0 disables the warning.
+#error_rate_threshold = 10
+
 # By default, there are no network interface filters enabled. An example use case is provided below.
 #[network.interface_filter]
 # Whether to ignore any matches. Defaults to true.
@@ -522,19 +602,44 @@ pub(crate) const CONFIG_TEXT: &str = r#"# This is a default config file for bott
 #cache_color = "light red"
 #swap_color = "light yellow"
 #arc_color = "light cyan"
+#arc_hit_color = "light blue"
+#l2arc_color = "blue"
+#zram_color = "cyan"
+#zswap_color = "green"
+#numa_node_colors = ["light yellow", "light magenta", "light cyan", "light green"]
 #gpu_colors = ["light blue", "light red", "cyan", "green", "blue", "red"]
+#pressure_colors = ["light magenta", "light yellow", "light cyan", "light green", "light blue", "cyan", "green"]
 
 #[styles.network]
 #rx_color = "light magenta"
 #tx_color = "light yellow"
 #rx_total_color = "light cyan"
 #tx_total_color = "light green"
+#interface_colors = ["light green", "light blue", "light red", "cyan", "green", "blue"]
+#saturated_color = "red"
+#errors_color = "light red"
+#drops_color = "light blue"
+
+#[styles.disk_io]
+#disk_colors = ["light cyan", "light magenta", "light green", "light yellow", "cyan", "magenta"]
+
+#[styles.tcp]
+#rate_colors = ["light magenta", "light yellow", "light red", "light blue", "light cyan"]
+
+#[styles.power]
+#domain_colors = ["light red", "light yellow", "light magenta", "light cyan"]
 
 #[styles.battery]
 #high_battery_color = "green"
 #medium_battery_color = "yellow"
 #low_battery_color = "red"
 
+#[styles.temperature]
+#warm_color = "yellow"
+#hot_color = "light red"
+#critical_color = "red"
+#graph_colors = ["light red", "light yellow", "light magenta", "light cyan", "light green", "light blue"]
+
 #[styles.tables]
 #headers = {color = "light blue", bold = true}
 
@@ -553,6 +658,17 @@ pub(crate) const CONFIG_TEXT: &str = r#"# This is a default config file for bott
 # Only on Linux
 #thread_text = {color = "green"}
 
+# Alerts - each [[alerts]] entry fires when a metric crosses a threshold for long enough.
+# Metrics are one of "cpu", "core", "mem", "swap", "temp", "disk", "rx", "tx", or "processes".
+#[[alerts]]
+#name = "CPU busy"
+#metric = "cpu"
+#comparison = ">"
+#threshold = 90
+#for = "30s"
+#bell = true
+#command = "notify-send \"$BTM_ALERT_NAME\" \"$BTM_ALERT_VALUE\""
+
 # Layout - layouts follow a pattern like this:
 # [[row]] represents a row in the application.
 # [[row.child]] represents either a widget or a column.
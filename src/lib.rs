@@ -25,6 +25,7 @@ pub(crate) mod constants;
 pub(crate) mod event;
 pub(crate) mod localization;
 pub mod options;
+pub(crate) mod snapshot;
 pub mod widgets;
 
 use std::{
@@ -254,30 +255,71 @@ fn create_input_thread(
     })
 }
 
+/// The settings from [`AppConfigFields`] that the data collector uses.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct CollectorSettings {
+    pub(crate) use_current_cpu_total: bool,
+    pub(crate) unnormalized_cpu: bool,
+    pub(crate) show_average_cpu: bool,
+    pub(crate) get_process_threads: bool,
+    #[cfg(target_os = "linux")]
+    pub(crate) group_cpus_by_numa: bool,
+    #[cfg(target_os = "linux")]
+    pub(crate) get_process_numa_node: bool,
+    #[cfg(feature = "zfs")]
+    pub(crate) free_arc: bool,
+}
+
+impl CollectorSettings {
+    pub(crate) fn new(app_config_fields: &AppConfigFields) -> Self {
+        Self {
+            use_current_cpu_total: app_config_fields.use_current_cpu_total,
+            unnormalized_cpu: app_config_fields.unnormalized_cpu,
+            show_average_cpu: app_config_fields.show_average_cpu,
+            get_process_threads: app_config_fields.get_process_threads,
+            #[cfg(target_os = "linux")]
+            group_cpus_by_numa: app_config_fields.group_cpus_by_numa,
+            #[cfg(target_os = "linux")]
+            get_process_numa_node: app_config_fields.get_process_numa_node,
+            #[cfg(feature = "zfs")]
+            free_arc: app_config_fields.free_arc,
+        }
+    }
+}
+
+/// Create a [`collection::DataCollector`] set up with the given settings.
+pub(crate) fn create_data_collector(
+    settings: CollectorSettings, filters: DataFilters, used_widget_set: UsedWidgets,
+) -> collection::DataCollector {
+    let mut data_collector = collection::DataCollector::new(filters);
+
+    data_collector.set_collection(used_widget_set);
+    data_collector.set_use_current_cpu_total(settings.use_current_cpu_total);
+    data_collector.set_unnormalized_cpu(settings.unnormalized_cpu);
+    data_collector.set_show_average_cpu(settings.show_average_cpu);
+    data_collector.set_get_process_threads(settings.get_process_threads);
+    #[cfg(target_os = "linux")]
+    {
+        data_collector.set_group_cpus_by_numa(settings.group_cpus_by_numa);
+        data_collector.set_get_process_numa_node(settings.get_process_numa_node);
+    }
+    #[cfg(feature = "zfs")]
+    data_collector.set_free_arc_mem(settings.free_arc);
+
+    data_collector
+}
+
 /// Create a thread to handle data collection.
 fn create_collection_thread(
     sender: Sender<BottomEvent>, control_receiver: Receiver<CollectionThreadEvent>,
     cancellation_token: Arc<CancellationToken>, app_config_fields: &AppConfigFields,
     filters: DataFilters, used_widget_set: UsedWidgets,
 ) -> JoinHandle<()> {
-    let use_current_cpu_total = app_config_fields.use_current_cpu_total;
-    let unnormalized_cpu = app_config_fields.unnormalized_cpu;
-    let show_average_cpu = app_config_fields.show_average_cpu;
     let update_sleep = app_config_fields.update_rate;
-    let get_process_threads = app_config_fields.get_process_threads;
-    #[cfg(feature = "zfs")]
-    let get_arc_free = app_config_fields.free_arc;
+    let settings = CollectorSettings::new(app_config_fields);
 
     thread::spawn(move || {
-        let mut data_collector = collection::DataCollector::new(filters);
-
-        data_collector.set_collection(used_widget_set);
-        data_collector.set_use_current_cpu_total(use_current_cpu_total);
-        data_collector.set_unnormalized_cpu(unnormalized_cpu);
-        data_collector.set_show_average_cpu(show_average_cpu);
-        data_collector.set_get_process_threads(get_process_threads);
-        #[cfg(feature = "zfs")]
-        data_collector.set_free_arc_mem(get_arc_free);
+        let mut data_collector = create_data_collector(settings, filters, used_widget_set);
 
         data_collector.update_data();
         data_collector.data = Data::default();
@@ -332,6 +374,7 @@ pub fn start_bottom(enable_error_hook: &mut bool) -> anyhow::Result<()> {
     // let _profiler = dhat::Profiler::new_heap();
 
     let args = args::get_args();
+    let print_snapshot = args.other.json;
 
     #[cfg(feature = "logging")]
     {
@@ -349,6 +392,10 @@ pub fn start_bottom(enable_error_hook: &mut bool) -> anyhow::Result<()> {
     // Create the "app" and initialize a bunch of stuff.
     let (mut app, widget_layout, styling) = init_app(args, config)?;
 
+    if print_snapshot {
+        return snapshot::print_snapshot(&mut app);
+    }
+
     // Create painter and set colours.
     let mut painter = canvas::Painter::init(widget_layout, styling)?;
 
@@ -466,6 +513,7 @@ pub fn start_bottom(enable_error_hook: &mut bool) -> anyhow::Result<()> {
                 }
                 BottomEvent::Update(data) => {
                     app.data_store.eat_data(data, &app.app_config_fields);
+                    app.update_alerts();
 
                     // This thing is required as otherwise, some widgets can't draw correctly w/o
                     // some data (or they need to be re-drawn).
@@ -483,10 +531,21 @@ pub fn start_bottom(enable_error_hook: &mut bool) -> anyhow::Result<()> {
                             }
                         }
 
+                        if app.used_widgets.use_net {
+                            for net_table in app.states.net_table_state.widget_states.values_mut() {
+                                net_table.force_data_update();
+                            }
+                        }
+
                         if app.used_widgets.use_temp {
                             for temp in app.states.temp_state.widget_states.values_mut() {
                                 temp.force_data_update();
                             }
+
+                            for temp_graph in app.states.temp_graph_state.widget_states.values_mut()
+                            {
+                                temp_graph.force_data_update();
+                            }
                         }
 
                         if app.used_widgets.use_proc {
@@ -87,6 +87,15 @@ pub fn no_data() -> &'static str {
     }
 }
 
+#[inline]
+pub fn status_alerts() -> &'static str {
+    if is_japanese() {
+        "アラート"
+    } else {
+        "Alerts"
+    }
+}
+
 #[inline]
 pub fn status_frozen() -> &'static str {
     if is_japanese() {
@@ -96,6 +105,54 @@ pub fn status_frozen() -> &'static str {
     }
 }
 
+#[cfg(target_os = "linux")]
+#[inline]
+pub fn status_oom_killed() -> &'static str {
+    if is_japanese() {
+        "OOMキラーが発動"
+    } else {
+        "OOM killer invoked"
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[inline]
+pub fn status_rapl_unreadable() -> &'static str {
+    if is_japanese() {
+        "RAPLの読み取り権限なし"
+    } else {
+        "No permission to read RAPL"
+    }
+}
+
+#[inline]
+pub fn status_network_errors() -> &'static str {
+    if is_japanese() {
+        "ネットワークエラー"
+    } else {
+        "Network errors"
+    }
+}
+
+#[inline]
+pub fn status_btrfs_metadata_nearly_full() -> &'static str {
+    if is_japanese() {
+        "btrfsメタデータ残りわずか"
+    } else {
+        "btrfs metadata nearly full"
+    }
+}
+
+#[cfg(all(feature = "zfs", target_os = "linux"))]
+#[inline]
+pub fn status_unhealthy_zpools() -> &'static str {
+    if is_japanese() {
+        "ZFSプール異常"
+    } else {
+        "Unhealthy ZFS pools"
+    }
+}
+
 #[inline]
 pub fn environment_label() -> &'static str {
     if is_japanese() { "環境" } else { "Env" }
@@ -124,6 +181,16 @@ pub fn title_memory() -> &'static str {
     }
 }
 
+#[cfg(target_os = "linux")]
+#[inline]
+pub fn title_memory_pressure() -> &'static str {
+    if is_japanese() {
+        " メモリ負荷 "
+    } else {
+        " Memory Pressure "
+    }
+}
+
 #[inline]
 pub fn title_network() -> &'static str {
     if is_japanese() {
@@ -133,6 +200,39 @@ pub fn title_network() -> &'static str {
     }
 }
 
+#[inline]
+pub fn title_network_packets() -> &'static str {
+    if is_japanese() {
+        " パケット "
+    } else {
+        " Packets "
+    }
+}
+
+#[inline]
+pub fn title_disk_io() -> &'static str {
+    if is_japanese() {
+        " ディスクI/O "
+    } else {
+        " Disk I/O "
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[inline]
+pub fn title_tcp() -> &'static str {
+    " TCP "
+}
+
+#[inline]
+pub fn title_network_table() -> &'static str {
+    if is_japanese() {
+        " ネットワーク一覧 "
+    } else {
+        " Network Interfaces "
+    }
+}
+
 #[inline]
 pub fn title_disks() -> &'static str {
     if is_japanese() {
@@ -151,6 +251,40 @@ pub fn title_temperatures() -> &'static str {
     }
 }
 
+#[cfg(target_os = "linux")]
+#[inline]
+pub fn title_power() -> &'static str {
+    if is_japanese() {
+        " 消費電力 "
+    } else {
+        " Power "
+    }
+}
+
+#[cfg(feature = "gpu")]
+#[inline]
+pub fn title_gpu(metric: &str) -> String {
+    format!(" GPU ({metric}) ")
+}
+
+#[inline]
+pub fn title_temperature_graph() -> &'static str {
+    if is_japanese() {
+        " 温度推移 "
+    } else {
+        " Temperature History "
+    }
+}
+
+#[inline]
+pub fn title_sensors() -> &'static str {
+    if is_japanese() {
+        " センサー "
+    } else {
+        " Sensors "
+    }
+}
+
 #[cfg(feature = "battery")]
 #[inline]
 pub fn title_battery() -> &'static str {
@@ -161,6 +295,26 @@ pub fn title_battery() -> &'static str {
     }
 }
 
+#[cfg(feature = "battery")]
+#[inline]
+pub fn title_battery_charge() -> &'static str {
+    if is_japanese() {
+        " 充電量推移 "
+    } else {
+        " Charge History "
+    }
+}
+
+#[cfg(feature = "battery")]
+#[inline]
+pub fn title_battery_rate() -> &'static str {
+    if is_japanese() {
+        " 消費電力推移 "
+    } else {
+        " Rate History "
+    }
+}
+
 #[inline]
 pub fn sort_by_label() -> &'static str {
     if is_japanese() {
@@ -28,11 +28,22 @@ use starship_battery::Manager;
 
 use self::{
     args::BottomArgs,
-    config::{IgnoreList, StringOrNum, keybindings::KeyBindingsConfig, layout::Row},
+    config::{
+        IgnoreList, StringOrNum,
+        keybindings::KeyBindingsConfig,
+        layout::{LeftLegends, Row},
+        network::NetworkGraphSeries,
+    },
 };
 use crate::{
-    app::{filter::Filter, layout_manager::*, *},
+    app::{
+        alerts::{AlertComparison, AlertMetric, AlertRule, Alerts},
+        filter::Filter,
+        layout_manager::*,
+        *,
+    },
     canvas::components::time_graph::LegendPosition,
+    collection::temperature::SensorKind,
     constants::*,
     localization::{UiLanguage, set_ui_language},
     utils::{
@@ -40,7 +51,10 @@ use crate::{
         terminal::{is_wsl, should_auto_enable_dot_marker},
         text_width::TextWidthMode,
     },
-    widgets::*,
+    widgets::{
+        query::{QueryOptions, parse_query},
+        *,
+    },
 };
 
 macro_rules! is_flag_enabled {
@@ -255,16 +269,33 @@ pub(crate) fn init_app(args: BottomArgs, config: Config) -> Result<(App, BottomL
     let process_memory_as_value = is_flag_enabled!(process_memory_as_value, args.process, config);
     let is_default_tree_collapsed = is_flag_enabled!(tree_collapse, args.process, config);
 
+    let alerts = get_alerts(
+        config,
+        &QueryOptions {
+            whole_word: is_match_whole_word,
+            ignore_case: !is_case_sensitive,
+            use_regex: is_use_regex,
+        },
+        is_default_command,
+    )?;
+
     // For CPU
     let default_cpu_selection = get_default_cpu_selection(args, config);
 
     let mut widget_map = HashMap::default();
     let mut cpu_state_map: HashMap<u64, CpuWidgetState> = HashMap::default();
     let mut mem_state_map: HashMap<u64, MemWidgetState> = HashMap::default();
+    let mut mem_pressure_state_map: HashMap<u64, MemPressureWidgetState> = HashMap::default();
+    let mut tcp_state_map: HashMap<u64, TcpWidgetState> = HashMap::default();
+    let mut disk_io_state_map: HashMap<u64, DiskIoWidgetState> = HashMap::default();
+    let mut temp_graph_state_map: HashMap<u64, TempGraphWidgetState> = HashMap::default();
+    let mut power_state_map: HashMap<u64, PowerWidgetState> = HashMap::default();
+    let mut gpu_state_map: HashMap<u64, GpuWidgetState> = HashMap::default();
     let mut net_state_map: HashMap<u64, NetWidgetState> = HashMap::default();
     let mut proc_state_map: HashMap<u64, ProcWidgetState> = HashMap::default();
     let mut temp_state_map: HashMap<u64, TempWidgetState> = HashMap::default();
     let mut disk_state_map: HashMap<u64, DiskTableWidget> = HashMap::default();
+    let mut net_table_state_map: HashMap<u64, NetTableWidget> = HashMap::default();
     let mut battery_state_map: HashMap<u64, BatteryWidgetState> = HashMap::default();
 
     let autohide_timer = if autohide_time {
@@ -282,6 +313,9 @@ pub(crate) fn init_app(args: BottomArgs, config: Config) -> Result<(App, BottomL
     let network_scale_type = get_network_scale_type(args, config);
     let network_use_binary_prefix =
         is_flag_enabled!(network_use_binary_prefix, args.network, config);
+    let network_saturation_threshold = get_network_saturation_threshold(config)?;
+    let network_packet_series = get_network_packet_series(config);
+    let network_error_threshold = get_network_error_threshold(config);
 
     let proc_columns: Option<IndexSet<ProcWidgetColumn>> = {
         config.processes.as_ref().and_then(|cfg| {
@@ -298,6 +332,23 @@ pub(crate) fn init_app(args: BottomArgs, config: Config) -> Result<(App, BottomL
 
     let network_legend_position = get_network_legend_position(args, config)?;
     let memory_legend_position = get_memory_legend_position(args, config)?;
+    let flags = config.flags.as_ref();
+    let disk_io_legend_position = get_config_legend_position(
+        flags.and_then(|flags| flags.disk_io_legend.as_deref()),
+        "disk_io_legend",
+    )?;
+    let tcp_legend_position = get_config_legend_position(
+        flags.and_then(|flags| flags.tcp_legend.as_deref()),
+        "tcp_legend",
+    )?;
+    let power_legend_position = get_config_legend_position(
+        flags.and_then(|flags| flags.power_legend.as_deref()),
+        "power_legend",
+    )?;
+    let gpu_legend_position = get_config_legend_position(
+        flags.and_then(|flags| flags.gpu_legend.as_deref()),
+        "gpu_legend",
+    )?;
 
     // TODO: Can probably just reuse the options struct.
     let app_config_fields = AppConfigFields {
@@ -307,6 +358,7 @@ pub(crate) fn init_app(args: BottomArgs, config: Config) -> Result<(App, BottomL
         show_average_cpu: get_show_average_cpu(args, config),
         use_dot: get_use_dot_marker(args, config) || safe_terminal_mode,
         cpu_left_legend: is_flag_enabled!(cpu_left_legend, args.cpu, config),
+        temperature_left_legend: get_temperature_left_legend(config),
         use_current_cpu_total: is_flag_enabled!(current_usage, args.process, config),
         unnormalized_cpu: is_flag_enabled!(unnormalized_cpu, args.process, config),
         get_process_threads: is_flag_enabled_new!(get_threads, args.process, config.processes),
@@ -333,12 +385,26 @@ pub(crate) fn init_app(args: BottomArgs, config: Config) -> Result<(App, BottomL
         is_read_only,
         #[cfg(target_os = "linux")]
         hide_k_threads,
+        #[cfg(target_os = "linux")]
+        group_cpus_by_numa: is_flag_enabled!(group_cpus_by_numa, args.cpu, config),
+        #[cfg(target_os = "linux")]
+        get_process_numa_node: proc_columns
+            .as_ref()
+            .is_some_and(|columns| columns.contains(&ProcWidgetColumn::NumaNode)),
         memory_legend_position,
+        disk_io_legend_position,
+        tcp_legend_position,
+        power_legend_position,
+        gpu_legend_position,
         network_legend_position,
         network_scale_type,
         network_unit_type,
         network_use_binary_prefix,
+        network_saturation_threshold,
+        network_packet_series,
+        network_error_threshold,
         retention_ms,
+        disk_trend_window_ms: get_disk_trend_window(config)?,
         dedicated_average_row: get_dedicated_avg_row(config),
         default_tree_collapse: is_default_tree_collapsed,
         text_width_mode: get_text_width_mode(args, config)?,
@@ -414,6 +480,47 @@ pub(crate) fn init_app(args: BottomArgs, config: Config) -> Result<(App, BottomL
                                 MemWidgetState::init(default_time_value, autohide_timer),
                             );
                         }
+                        MemPressure => {
+                            mem_pressure_state_map.insert(
+                                widget.widget_id,
+                                MemPressureWidgetState::init(default_time_value, autohide_timer),
+                            );
+                        }
+                        Tcp => {
+                            tcp_state_map.insert(
+                                widget.widget_id,
+                                TcpWidgetState::init(default_time_value, autohide_timer),
+                            );
+                        }
+                        DiskIo => {
+                            disk_io_state_map.insert(
+                                widget.widget_id,
+                                DiskIoWidgetState::init(default_time_value, autohide_timer),
+                            );
+                        }
+                        Power => {
+                            power_state_map.insert(
+                                widget.widget_id,
+                                PowerWidgetState::init(default_time_value, autohide_timer),
+                            );
+                        }
+                        Gpu => {
+                            gpu_state_map.insert(
+                                widget.widget_id,
+                                GpuWidgetState::init(default_time_value, autohide_timer),
+                            );
+                        }
+                        TempGraph => {
+                            temp_graph_state_map.insert(
+                                widget.widget_id,
+                                TempGraphWidgetState::new(
+                                    &app_config_fields,
+                                    default_time_value,
+                                    autohide_timer,
+                                    &styling,
+                                ),
+                            );
+                        }
                         Net => {
                             net_state_map.insert(
                                 widget.widget_id,
@@ -447,18 +554,39 @@ pub(crate) fn init_app(args: BottomArgs, config: Config) -> Result<(App, BottomL
                                     &app_config_fields,
                                     &styling,
                                     config.disk.as_ref().and_then(|cfg| cfg.columns.as_deref()),
+                                    config
+                                        .disk
+                                        .as_ref()
+                                        .and_then(|cfg| cfg.tree)
+                                        .unwrap_or(false),
                                 ),
                             );
                         }
+                        NetTable => {
+                            net_table_state_map.insert(
+                                widget.widget_id,
+                                NetTableWidget::new(&app_config_fields, &styling),
+                            );
+                        }
                         Temp => {
                             temp_state_map.insert(
                                 widget.widget_id,
-                                TempWidgetState::new(&app_config_fields, &styling),
+                                TempWidgetState::new(
+                                    &app_config_fields,
+                                    &styling,
+                                    config
+                                        .temperature
+                                        .as_ref()
+                                        .and_then(|cfg| cfg.sensor_types.clone())
+                                        .unwrap_or_else(|| vec![SensorKind::Temperature]),
+                                ),
                             );
                         }
                         Battery => {
-                            battery_state_map
-                                .insert(widget.widget_id, BatteryWidgetState::default());
+                            battery_state_map.insert(
+                                widget.widget_id,
+                                BatteryWidgetState::init(default_time_value, autohide_timer),
+                            );
                         }
                         _ => {}
                     }
@@ -491,29 +619,50 @@ pub(crate) fn init_app(args: BottomArgs, config: Config) -> Result<(App, BottomL
     };
 
     let use_mem = used_widget_set.contains(&Mem) || used_widget_set.contains(&BasicMem);
-    let used_widgets = UsedWidgets {
+    let use_gpu = get_enable_gpu(args, config);
+    let mut used_widgets = UsedWidgets {
         use_cpu: used_widget_set.contains(&Cpu) || used_widget_set.contains(&BasicCpu),
         use_mem,
         use_cache: use_mem && get_enable_cache_memory(args, config),
-        use_gpu: get_enable_gpu(args, config),
-        use_net: used_widget_set.contains(&Net) || used_widget_set.contains(&BasicNet),
+        use_gpu,
+        use_net: used_widget_set.contains(&Net)
+            || used_widget_set.contains(&BasicNet)
+            || used_widget_set.contains(&NetTable),
         use_proc: used_widget_set.contains(&Proc),
-        use_disk: used_widget_set.contains(&Disk),
-        use_temp: used_widget_set.contains(&Temp),
+        use_disk: used_widget_set.contains(&Disk) || used_widget_set.contains(&DiskIo),
+        use_temp: used_widget_set.contains(&Temp) || used_widget_set.contains(&TempGraph),
         use_battery: used_widget_set.contains(&Battery),
+        use_vmstat: use_mem || used_widget_set.contains(&MemPressure),
+        use_tcp: used_widget_set.contains(&Tcp),
+        use_cpu_power: used_widget_set.contains(&Cpu) || used_widget_set.contains(&Power),
+        use_gpu_stats: use_gpu && used_widget_set.contains(&Gpu),
     };
 
-    let (disk_name_filter, disk_mount_filter) = {
+    // Alerts need their metrics collected even if no widget shows them.
+    for rule in &alerts {
+        match rule.metric {
+            AlertMetric::CpuAverage | AlertMetric::Core(_) => used_widgets.use_cpu = true,
+            AlertMetric::MemPercent | AlertMetric::SwapPercent => used_widgets.use_mem = true,
+            AlertMetric::Sensor(_) => used_widgets.use_temp = true,
+            AlertMetric::DiskUsedPercent(_) => used_widgets.use_disk = true,
+            AlertMetric::NetRx(_) | AlertMetric::NetTx(_) => used_widgets.use_net = true,
+            AlertMetric::ProcessCount { .. } => used_widgets.use_proc = true,
+        }
+    }
+
+    let (disk_name_filter, disk_mount_filter, disk_fs_type_filter) = {
         match &config.disk {
             Some(cfg) => {
                 let df = get_ignore_list(&cfg.name_filter)
                     .context("Update 'disk.name_filter' in your config file")?;
                 let mf = get_ignore_list(&cfg.mount_filter)
                     .context("Update 'disk.mount_filter' in your config file")?;
+                let ff = get_ignore_list(&cfg.fs_type_filter)
+                    .context("Update 'disk.fs_type_filter' in your config file")?;
 
-                (df, mf)
+                (df, mf, ff)
             }
-            None => (None, None),
+            None => (None, None, None),
         }
     };
     let temp_sensor_filter = match &config.temperature {
@@ -530,10 +679,24 @@ pub(crate) fn init_app(args: BottomArgs, config: Config) -> Result<(App, BottomL
     let states = AppWidgetStates {
         cpu_state: CpuState::init(cpu_state_map),
         mem_state: MemState::init(mem_state_map),
-        net_state: NetState::init(net_state_map),
+        mem_pressure_state: MemPressureState::init(mem_pressure_state_map),
+        tcp_state: TcpState::init(tcp_state_map),
+        net_state: NetState::init(
+            net_state_map,
+            config
+                .network
+                .as_ref()
+                .and_then(|cfg| cfg.graph_interfaces.clone())
+                .unwrap_or_default(),
+        ),
+        net_table_state: NetTableState::init(net_table_state_map),
         proc_state: ProcState::init(proc_state_map),
         temp_state: TempState::init(temp_state_map),
+        temp_graph_state: TempGraphState::init(temp_graph_state_map),
+        power_state: PowerState::init(power_state_map),
+        gpu_state: GpuState::init(gpu_state_map),
         disk_state: DiskState::init(disk_state_map),
+        disk_io_state: DiskIoState::init(disk_io_state_map),
         battery_state: AppBatteryState::init(battery_state_map),
         basic_table_widget_state,
     };
@@ -545,6 +708,7 @@ pub(crate) fn init_app(args: BottomArgs, config: Config) -> Result<(App, BottomL
     let filters = DataFilters {
         disk_filter: disk_name_filter,
         mount_filter: disk_mount_filter,
+        fs_type_filter: disk_fs_type_filter,
         temp_filter: temp_sensor_filter,
         net_filter: net_interface_filter,
     };
@@ -558,6 +722,7 @@ pub(crate) fn init_app(args: BottomArgs, config: Config) -> Result<(App, BottomL
             current_widget,
             used_widgets,
             filters,
+            Alerts::new(alerts),
             is_expanded,
         ),
         widget_layout,
@@ -568,7 +733,10 @@ pub(crate) fn init_app(args: BottomArgs, config: Config) -> Result<(App, BottomL
 fn get_widget_layout(
     args: &BottomArgs, config: &Config,
 ) -> OptionResult<(BottomLayout, u64, Option<BottomWidgetType>)> {
-    let cpu_left_legend = is_flag_enabled!(cpu_left_legend, args.cpu, config);
+    let left_legends = LeftLegends {
+        cpu: is_flag_enabled!(cpu_left_legend, args.cpu, config),
+        temperature: get_temperature_left_legend(config),
+    };
 
     let (default_widget_type, mut default_widget_count) =
         get_default_widget_and_count(args, config)?;
@@ -607,7 +775,7 @@ fn get_widget_layout(
                         &mut default_widget_id,
                         &default_widget_type,
                         &mut default_widget_count,
-                        cpu_left_legend,
+                        left_legends,
                     )
                     .map_err(|err| OptionError::config(err.to_string()))
                 })
@@ -925,6 +1093,14 @@ fn get_dedicated_avg_row(config: &Config) -> bool {
         .unwrap_or(false)
 }
 
+fn get_temperature_left_legend(config: &Config) -> bool {
+    config
+        .flags
+        .as_ref()
+        .and_then(|flags| flags.temperature_left_legend)
+        .unwrap_or(false)
+}
+
 #[inline]
 fn get_default_time_value(
     args: &BottomArgs, config: &Config, retention_ms: u64,
@@ -1136,7 +1312,57 @@ fn get_network_scale_type(args: &BottomArgs, config: &Config) -> AxisScaling {
         }
     }
 
-    AxisScaling::Linear
+    if is_flag_enabled!(network_scale_to_link, args.network, config) {
+        AxisScaling::Link
+    } else {
+        AxisScaling::Linear
+    }
+}
+
+fn get_network_packet_series(config: &Config) -> PacketGraphSeries {
+    let mut packet_series = PacketGraphSeries::default();
+
+    if let Some(series) = config
+        .network
+        .as_ref()
+        .and_then(|cfg| cfg.graph_series.as_ref())
+    {
+        for series in series {
+            match series {
+                NetworkGraphSeries::Packets => packet_series.packets = true,
+                NetworkGraphSeries::Errors => packet_series.errors = true,
+                NetworkGraphSeries::Drops => packet_series.drops = true,
+            }
+        }
+    }
+
+    packet_series
+}
+
+fn get_network_saturation_threshold(config: &Config) -> OptionResult<u8> {
+    const DEFAULT_SATURATION_THRESHOLD: u8 = 90;
+
+    match config
+        .network
+        .as_ref()
+        .and_then(|cfg| cfg.saturation_threshold)
+    {
+        Some(threshold) if (1..=100).contains(&threshold) => Ok(threshold),
+        Some(_) => Err(OptionError::config(
+            "'network.saturation_threshold' must be a percentage between 1 and 100.",
+        )),
+        None => Ok(DEFAULT_SATURATION_THRESHOLD),
+    }
+}
+
+fn get_network_error_threshold(config: &Config) -> u32 {
+    const DEFAULT_ERROR_THRESHOLD: u32 = 10;
+
+    config
+        .network
+        .as_ref()
+        .and_then(|cfg| cfg.error_rate_threshold)
+        .unwrap_or(DEFAULT_ERROR_THRESHOLD)
 }
 
 fn get_retention(args: &BottomArgs, config: &Config) -> OptionResult<u64> {
@@ -1155,6 +1381,155 @@ fn get_retention(args: &BottomArgs, config: &Config) -> OptionResult<u64> {
     )
 }
 
+/// How far back to look when estimating when each disk will fill up.
+fn get_disk_trend_window(config: &Config) -> OptionResult<u64> {
+    const DEFAULT_DISK_TREND_WINDOW_MS: u64 = 30 * 60 * 1000;
+
+    parse_ms_option!(
+        &None::<String>,
+        config
+            .disk
+            .as_ref()
+            .and_then(|disk| disk.trend_window.as_ref()),
+        DEFAULT_DISK_TREND_WINDOW_MS,
+        "trend_window",
+        Some(60 * 1000),
+        None,
+    )
+}
+
+/// Parses an alert threshold, which may have a decimal (e.g. "1.5M") or binary (e.g. "2Gi") unit
+/// prefix.
+fn parse_alert_threshold(threshold: &StringOrNum) -> Result<f64, ()> {
+    const PREFIXES: [(&str, f64); 8] = [
+        ("Ki", 1024.0),
+        ("Mi", 1024.0 * 1024.0),
+        ("Gi", 1024.0 * 1024.0 * 1024.0),
+        ("Ti", 1024.0 * 1024.0 * 1024.0 * 1024.0),
+        ("K", 1e3),
+        ("M", 1e6),
+        ("G", 1e9),
+        ("T", 1e12),
+    ];
+
+    match threshold {
+        StringOrNum::Num(num) => Ok(*num as f64),
+        StringOrNum::String(s) => {
+            let s = s.trim();
+            let (number, multiplier) = PREFIXES
+                .iter()
+                .find_map(|(prefix, multiplier)| {
+                    s.strip_suffix(prefix)
+                        .or_else(|| s.strip_suffix(&prefix.to_lowercase()))
+                        .map(|number| (number, *multiplier))
+                })
+                .unwrap_or((s, 1.0));
+
+            match number.trim().parse::<f64>() {
+                Ok(value) if value.is_finite() => Ok(value * multiplier),
+                _ => Err(()),
+            }
+        }
+    }
+}
+
+fn get_alerts(
+    config: &Config, query_options: &QueryOptions, is_using_command: bool,
+) -> OptionResult<Vec<AlertRule>> {
+    let Some(alerts) = &config.alerts else {
+        return Ok(vec![]);
+    };
+
+    alerts
+        .iter()
+        .enumerate()
+        .map(|(index, alert)| {
+            let context = |msg: &str| {
+                let name = match &alert.name {
+                    Some(name) => format!("'{name}'"),
+                    None => format!("#{}", index + 1),
+                };
+
+                OptionError::config(format!("alert {name}: {msg}"))
+            };
+
+            let target = alert
+                .target
+                .as_deref()
+                .map(str::trim)
+                .filter(|target| !target.is_empty());
+
+            let metric = match alert.metric.trim().to_lowercase().as_str() {
+                "cpu" => AlertMetric::CpuAverage,
+                "core" => match target.map(str::parse) {
+                    Some(Ok(core)) => AlertMetric::Core(core),
+                    _ => return Err(context("'core' alerts need a core number as the 'target'.")),
+                },
+                "mem" | "memory" => AlertMetric::MemPercent,
+                "swap" => AlertMetric::SwapPercent,
+                "temp" | "temperature" => AlertMetric::Sensor(target.map(str::to_string)),
+                "disk" => AlertMetric::DiskUsedPercent(target.map(str::to_string)),
+                "rx" => AlertMetric::NetRx(target.map(str::to_string)),
+                "tx" => AlertMetric::NetTx(target.map(str::to_string)),
+                "processes" => match target {
+                    Some(target) => AlertMetric::ProcessCount {
+                        query: parse_query(target, query_options)
+                            .map_err(|err| context(&format!("invalid process query: {err}")))?,
+                        is_using_command,
+                    },
+                    None => {
+                        return Err(context(
+                            "'processes' alerts need a search query as the 'target'.",
+                        ));
+                    }
+                },
+                other => return Err(context(&format!("'{other}' is not a valid metric."))),
+            };
+
+            let comparison = match &alert.comparison {
+                Some(comparison) => comparison
+                    .parse()
+                    .map_err(|_| context(&format!("'{comparison}' is not a valid comparison.")))?,
+                None => AlertComparison::default(),
+            };
+
+            let threshold = parse_alert_threshold(&alert.threshold)
+                .map_err(|_| context("'threshold' is not a valid number."))?;
+
+            let duration = match &alert.duration {
+                Some(StringOrNum::Num(ms)) => Duration::from_millis(*ms),
+                Some(StringOrNum::String(s)) => Duration::from_millis(
+                    try_parse_ms(s).map_err(|_| context("'for' is not a valid duration."))?,
+                ),
+                None => Duration::ZERO,
+            };
+
+            let name = match &alert.name {
+                Some(name) => name.clone(),
+                None => {
+                    let threshold = match &alert.threshold {
+                        StringOrNum::String(s) => s.trim().to_string(),
+                        StringOrNum::Num(num) => num.to_string(),
+                    };
+
+                    AlertRule::describe(metric.config_name(), target, comparison, &threshold)
+                }
+            };
+
+            Ok(AlertRule {
+                name,
+                metric,
+                target: target.map(str::to_string),
+                comparison,
+                threshold,
+                duration,
+                bell: alert.bell.unwrap_or(false),
+                command: alert.command.clone(),
+            })
+        })
+        .collect()
+}
+
 fn get_network_legend_position(
     args: &BottomArgs, config: &Config,
 ) -> OptionResult<Option<LegendPosition>> {
@@ -1203,6 +1578,25 @@ fn get_memory_legend_position(
     Ok(result)
 }
 
+/// Parses the legend position of a widget that only has a config file option, named `setting`.
+fn get_config_legend_position(
+    legend: Option<&str>, setting: &str,
+) -> OptionResult<Option<LegendPosition>> {
+    let result = match legend {
+        Some(s) => match s.to_ascii_lowercase().trim() {
+            "none" => None,
+            position => Some(
+                position
+                    .parse()
+                    .map_err(|_| OptionError::invalid_config_value(setting))?,
+            ),
+        },
+        None => Some(LegendPosition::default()),
+    };
+
+    Ok(result)
+}
+
 #[cfg(test)]
 mod test {
     use clap::Parser;
@@ -1611,8 +2005,15 @@ mod test {
         let default_app = create_app(BottomArgs::parse_from(["btm"]));
 
         // Skip battery since it's tricky to test depending on the platform/features
-        // we're testing with.
-        let skip = ["help", "version", "celsius", "battery", "generate_schema"];
+        // we're testing with. JSON snapshots are handled before the app is used.
+        let skip = [
+            "help",
+            "version",
+            "celsius",
+            "battery",
+            "generate_schema",
+            "json",
+        ];
 
         for arg in app.get_arguments().collect::<Vec<_>>() {
             let arg_name = arg
@@ -1645,6 +2046,68 @@ mod test {
         }
     }
 
+    #[test]
+    fn verify_alert_thresholds() {
+        use super::{StringOrNum, parse_alert_threshold};
+
+        let parse = |s: &str| parse_alert_threshold(&StringOrNum::String(s.to_string()));
+
+        assert_eq!(parse_alert_threshold(&StringOrNum::Num(90)), Ok(90.0));
+        assert_eq!(parse("87.5"), Ok(87.5));
+        assert_eq!(parse(" 10M "), Ok(10_000_000.0));
+        assert_eq!(parse("1.5k"), Ok(1500.0));
+        assert_eq!(parse("2Gi"), Ok(2.0 * 1024.0 * 1024.0 * 1024.0));
+        assert!(parse("fast").is_err());
+        assert!(parse("10X").is_err());
+        assert!(parse("").is_err());
+    }
+
+    #[test]
+    fn verify_get_alerts() {
+        use std::time::Duration;
+
+        use super::get_alerts;
+        use crate::{app::alerts::AlertComparison, widgets::query::QueryOptions};
+
+        let parse = |s: &str| {
+            let config: Config = toml_edit::de::from_str(s).unwrap();
+            get_alerts(&config, &QueryOptions::default(), false)
+        };
+
+        let alerts = parse(
+            r#"
+            [[alerts]]
+            metric = "cpu"
+            threshold = 90
+            for = "30s"
+
+            [[alerts]]
+            name = "Hot"
+            metric = "temp"
+            target = "Tctl"
+            comparison = ">="
+            threshold = "85.5"
+            bell = true
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(alerts.len(), 2);
+        assert_eq!(alerts[0].name, "cpu > 90");
+        assert_eq!(alerts[0].duration, Duration::from_secs(30));
+        assert_eq!(alerts[1].name, "Hot");
+        assert_eq!(alerts[1].target.as_deref(), Some("Tctl"));
+        assert_eq!(alerts[1].comparison, AlertComparison::GreaterOrEqual);
+        assert_eq!(alerts[1].threshold, 85.5);
+        assert!(alerts[1].bell);
+
+        assert!(parse("[[alerts]]\nmetric = \"load\"\nthreshold = 1").is_err());
+        assert!(parse("[[alerts]]\nmetric = \"core\"\nthreshold = 1").is_err());
+        assert!(parse("[[alerts]]\nmetric = \"processes\"\nthreshold = 1").is_err());
+        assert!(parse("[[alerts]]\nmetric = \"mem\"\ncomparison = \"!\"\nthreshold = 1").is_err());
+        assert!(parse("[[alerts]]\nmetric = \"mem\"\nthreshold = 1\nfor = \"soon\"").is_err());
+    }
+
     /// This one has slightly more complex behaviour due to `dirs` not respecting XDG on macOS, so we manually
     /// handle it. However, to ensure backwards-compatibility, we also have to do some special cases.
     #[cfg(target_os = "macos")]
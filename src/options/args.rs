@@ -183,6 +183,8 @@ pub struct GeneralArgs {
             "mem",
             "net",
             "network",
+            "net_table",
+            "network_table",
             "proc",
             "process",
             "processes",
@@ -193,6 +195,20 @@ pub struct GeneralArgs {
             "batt",
             #[cfg(feature = "battery")]
             "battery",
+            #[cfg(target_os = "linux")]
+            "mem_pressure",
+            #[cfg(target_os = "linux")]
+            "vmstat",
+            #[cfg(target_os = "linux")]
+            "tcp",
+            #[cfg(target_os = "linux")]
+            "power",
+            #[cfg(feature = "gpu")]
+            "gpu",
+            "disk_io",
+            "io",
+            "temp_graph",
+            "temperature_graph",
         ],
         alias = "default-widget-type"
     )]
@@ -536,6 +552,15 @@ pub struct CpuArgs {
         alias = "hide-avg-cpu"
     )]
     pub hide_avg_cpu: bool,
+
+    #[cfg(target_os = "linux")]
+    #[arg(
+        long,
+        action = ArgAction::SetTrue,
+        help = "Groups CPU entries by NUMA node, with a per-node average.",
+        alias = "group-cpus-by-numa"
+    )]
+    pub group_cpus_by_numa: bool,
 }
 
 /// Memory argument/config options.
@@ -614,6 +639,17 @@ pub struct NetworkArgs {
     )]
     pub network_use_log: bool,
 
+    #[arg(
+        long,
+        action = ArgAction::SetTrue,
+        help = "Scales the network widget to the link capacity of the network interfaces.",
+        long_help = "Scales the network widget to the combined link capacity of all network interfaces with a known \
+                    speed. Falls back to a non-log scale if no interface speed is known. Ignored if the log scale \
+                    is enabled.",
+        alias = "network-scale-to-link"
+    )]
+    pub network_scale_to_link: bool,
+
     #[arg(
         long,
         action = ArgAction::SetTrue,
@@ -645,7 +681,7 @@ pub struct BatteryArgs {
 #[derive(Args, Clone, Debug, Default)]
 #[command(next_help_heading = "GPU Options", rename_all = "snake_case")]
 pub struct GpuArgs {
-    #[arg(long, action = ArgAction::SetTrue, help = "Disable collecting and displaying NVIDIA and AMD GPU information.", alias = "disable-gpu")]
+    #[arg(long, action = ArgAction::SetTrue, help = "Disable collecting and displaying NVIDIA, AMD and Intel GPU information.", alias = "disable-gpu")]
     pub disable_gpu: bool,
 }
 
@@ -691,6 +727,16 @@ pub struct OtherArgs {
 
     #[arg(short = 'V', long, action = ArgAction::Version, help = "Prints version information.")]
     version: (),
+
+    #[arg(
+        long,
+        action = ArgAction::SetTrue,
+        help = "Prints a JSON snapshot of the system and exits.",
+        long_help = "Collects data twice, one update interval apart, then prints a JSON snapshot of CPU, memory, \
+                network, disk, sensor, battery, GPU, and process data to stdout and exits, without starting the \
+                interface."
+    )]
+    pub json: bool,
 }
 
 /// Parse arguments and return a [`BottomArgs`]. If this fails it will exit the program.
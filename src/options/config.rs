@@ -1,3 +1,4 @@
+pub mod alert;
 pub mod cpu;
 pub mod disk;
 pub mod flags;
@@ -9,6 +10,7 @@ pub mod process;
 pub mod style;
 pub mod temperature;
 
+use alert::AlertConfig;
 use disk::DiskConfig;
 use flags::GeneralConfig;
 use keybindings::KeyBindingsConfig;
@@ -34,6 +36,7 @@ pub struct Config {
     pub(crate) temperature: Option<TempConfig>,
     pub(crate) network: Option<NetworkConfig>,
     pub(crate) cpu: Option<CpuConfig>,
+    pub(crate) alerts: Option<Vec<AlertConfig>>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
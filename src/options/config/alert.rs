@@ -0,0 +1,40 @@
+use serde::Deserialize;
+
+use super::StringOrNum;
+
+/// A single alert rule, from an `[[alerts]]` entry.
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "generate_schema", derive(schemars::JsonSchema))]
+#[cfg_attr(test, serde(deny_unknown_fields), derive(PartialEq, Eq))]
+pub(crate) struct AlertConfig {
+    /// The name shown when the alert fires. Defaults to a description of the rule.
+    pub(crate) name: Option<String>,
+
+    /// The metric to watch. One of "cpu", "core", "mem", "swap", "temp", "disk", "rx", "tx", or
+    /// "processes".
+    pub(crate) metric: String,
+
+    /// What the metric applies to: the core index for "core", the sensor name for "temp", the
+    /// mount point for "disk", the interface for "rx"/"tx", or the search query for "processes".
+    pub(crate) target: Option<String>,
+
+    /// How the value is compared against the threshold. One of ">", ">=", "<", "<=", or "=".
+    /// Defaults to ">".
+    pub(crate) comparison: Option<String>,
+
+    /// The threshold to compare against. Decimals and unit prefixes (e.g. "1.5M") must be given
+    /// as a string.
+    pub(crate) threshold: StringOrNum,
+
+    /// How long the comparison must hold before the alert fires, as a human duration or in
+    /// milliseconds. Defaults to firing immediately.
+    #[serde(rename = "for")]
+    pub(crate) duration: Option<StringOrNum>,
+
+    /// Whether to ring the terminal bell when the alert fires.
+    pub(crate) bell: Option<bool>,
+
+    /// A command to run when the alert fires. The alert is passed in `BTM_ALERT_*` environment
+    /// variables.
+    pub(crate) command: Option<String>,
+}
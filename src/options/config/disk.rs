@@ -1,6 +1,6 @@
 use serde::Deserialize;
 
-use super::IgnoreList;
+use super::{IgnoreList, StringOrNum};
 use crate::options::DiskColumn;
 
 /// Disk configuration.
@@ -14,6 +14,15 @@ pub(crate) struct DiskConfig {
     /// A filter over the mount names.
     pub(crate) mount_filter: Option<IgnoreList>,
 
+    /// A filter over the filesystem types.
+    pub(crate) fs_type_filter: Option<IgnoreList>,
+
+    /// Whether to show block devices as a tree by default. Linux-only.
+    pub(crate) tree: Option<bool>,
+
+    /// How far back to look when estimating when each filesystem will fill up.
+    pub(crate) trend_window: Option<StringOrNum>,
+
     /// A list of disk widget columns.
     #[serde(default)]
     pub(crate) columns: Option<Vec<DiskColumn>>, // TODO: make this more composable(?) in the future, we might need to rethink how it's done for custom widgets
@@ -41,6 +50,12 @@ mod test {
     fn valid_disk_column_settings() {
         let config = r#"columns = ["disk", "mount", "used", "free", "total", "used%", "free%", "r/s", "w/s"]"#;
         toml_edit::de::from_str::<DiskConfig>(config).expect("Should succeed!");
+
+        let config = r#"columns = ["fs_type", "options", "iused", "ifree", "itotal", "iused%"]"#;
+        toml_edit::de::from_str::<DiskConfig>(config).expect("Should succeed!");
+
+        let config = r#"columns = ["data", "metadata", "system", "eta_full"]"#;
+        toml_edit::de::from_str::<DiskConfig>(config).expect("Should succeed!");
     }
 
     #[test]
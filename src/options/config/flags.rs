@@ -37,6 +37,11 @@ pub(crate) struct GeneralConfig {
     pub(crate) no_write: Option<bool>,
     pub(crate) network_legend: Option<String>,
     pub(crate) memory_legend: Option<String>,
+    pub(crate) disk_io_legend: Option<String>,
+    pub(crate) tcp_legend: Option<String>,
+    pub(crate) power_legend: Option<String>,
+    pub(crate) gpu_legend: Option<String>,
+    pub(crate) temperature_left_legend: Option<bool>,
     pub(crate) process_memory_as_value: Option<bool>,
     pub(crate) tree: Option<bool>,
     pub(crate) show_table_scroll_position: Option<bool>,
@@ -46,10 +51,13 @@ pub(crate) struct GeneralConfig {
     pub(crate) read_only: Option<bool>,
     // #[cfg(target_os = "linux")]
     pub(crate) hide_k_threads: Option<bool>,
+    // #[cfg(target_os = "linux")]
+    pub(crate) group_cpus_by_numa: Option<bool>,
     // #[cfg(feature = "zfs")]
     pub(crate) free_arc: Option<bool>,
     pub(crate) network_use_bytes: Option<bool>,
     pub(crate) network_use_log: Option<bool>,
+    pub(crate) network_scale_to_link: Option<bool>,
     pub(crate) network_use_binary_prefix: Option<bool>,
     pub(crate) disable_gpu: Option<bool>,
     pub(crate) enable_cache_memory: Option<bool>,
@@ -13,31 +13,62 @@ pub struct Row {
     pub child: Option<Vec<RowChildren>>,
 }
 
-fn new_cpu(cpu_left_legend: bool, iter_id: &mut u64) -> BottomColRow {
-    let cpu_id = *iter_id;
+/// Which graphs have their legend drawn on the left rather than the right.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LeftLegends {
+    pub cpu: bool,
+    pub temperature: bool,
+}
+
+/// Creates a graph and its legend, which takes up `legend_ratio` out of 20 of the width.
+fn new_graph_with_legend(
+    graph_type: BottomWidgetType, legend_type: BottomWidgetType, left_legend: bool,
+    legend_ratio: u16, iter_id: &mut u64,
+) -> BottomColRow {
+    let graph_id = *iter_id;
     *iter_id += 1;
     let legend_id = *iter_id;
 
-    if cpu_left_legend {
+    if left_legend {
         BottomColRow::new(vec![
-            BottomWidget::new(BottomWidgetType::CpuLegend, legend_id)
+            BottomWidget::new(legend_type, legend_id)
                 .canvas_handled()
-                .with_ratio_override(3)
+                .with_ratio_override(legend_ratio)
                 .parent_reflector(Some((WidgetDirection::Right, 1))),
-            BottomWidget::new(BottomWidgetType::Cpu, cpu_id).grow(Some(17)),
+            BottomWidget::new(graph_type, graph_id).grow(Some(20 - legend_ratio)),
         ])
     } else {
         BottomColRow::new(vec![
-            BottomWidget::new(BottomWidgetType::Cpu, cpu_id).grow(Some(17)),
-            BottomWidget::new(BottomWidgetType::CpuLegend, legend_id)
+            BottomWidget::new(graph_type, graph_id).grow(Some(20 - legend_ratio)),
+            BottomWidget::new(legend_type, legend_id)
                 .canvas_handled()
-                .with_ratio_override(3)
+                .with_ratio_override(legend_ratio)
                 .parent_reflector(Some((WidgetDirection::Left, 1))),
         ])
     }
     .total_widget_ratio(20)
 }
 
+fn new_cpu(cpu_left_legend: bool, iter_id: &mut u64) -> BottomColRow {
+    new_graph_with_legend(
+        BottomWidgetType::Cpu,
+        BottomWidgetType::CpuLegend,
+        cpu_left_legend,
+        3,
+        iter_id,
+    )
+}
+
+fn new_temp_graph(temperature_left_legend: bool, iter_id: &mut u64) -> BottomColRow {
+    new_graph_with_legend(
+        BottomWidgetType::TempGraph,
+        BottomWidgetType::TempGraphLegend,
+        temperature_left_legend,
+        5,
+        iter_id,
+    )
+}
+
 fn new_proc_sort(sort_id: u64) -> BottomWidget {
     BottomWidget::new(BottomWidgetType::ProcSort, sort_id)
         .canvas_handled()
@@ -57,7 +88,7 @@ impl Row {
     pub fn convert_row_to_bottom_row(
         &self, iter_id: &mut u64, total_height_ratio: &mut u16, default_widget_id: &mut u64,
         default_widget_type: &Option<BottomWidgetType>, default_widget_count: &mut u64,
-        cpu_left_legend: bool,
+        left_legends: LeftLegends,
     ) -> OptionResult<BottomRow> {
         // TODO: In the future we want to also add percentages.
         // But for MVP, we aren't going to bother.
@@ -95,9 +126,14 @@ impl Row {
 
                         children.push(match widget_type {
                             BottomWidgetType::Cpu => {
-                                BottomCol::new(vec![new_cpu(cpu_left_legend, iter_id)])
+                                BottomCol::new(vec![new_cpu(left_legends.cpu, iter_id)])
                                     .ratio(width_ratio)
                             }
+                            BottomWidgetType::TempGraph => BottomCol::new(vec![new_temp_graph(
+                                left_legends.temperature,
+                                iter_id,
+                            )])
+                            .ratio(width_ratio),
                             BottomWidgetType::Proc => {
                                 let proc_id = *iter_id;
                                 let proc_search_id = *iter_id + 1;
@@ -157,7 +193,16 @@ impl Row {
                                     total_col_row_ratio += col_row_height_ratio;
 
                                     col_row_children.push(
-                                        new_cpu(cpu_left_legend, iter_id)
+                                        new_cpu(left_legends.cpu, iter_id)
+                                            .ratio(col_row_height_ratio),
+                                    );
+                                }
+                                BottomWidgetType::TempGraph => {
+                                    let col_row_height_ratio = widget.ratio.unwrap_or(1);
+                                    total_col_row_ratio += col_row_height_ratio;
+
+                                    col_row_children.push(
+                                        new_temp_graph(left_legends.temperature, iter_id)
                                             .ratio(col_row_height_ratio),
                                     );
                                 }
@@ -285,7 +330,10 @@ mod test {
                         &mut default_widget_id,
                         &default_widget_type,
                         &mut default_widget_count,
-                        left_legend,
+                        LeftLegends {
+                            cpu: left_legend,
+                            ..Default::default()
+                        },
                     )
                 })
                 .collect::<OptionResult<Vec<_>>>()
@@ -486,7 +534,7 @@ mod test {
         let mut default_widget_count = 1;
         let mut default_widget_id = DEFAULT_WIDGET_ID;
         let default_widget_type = None;
-        let cpu_left_legend = false;
+        let left_legends = LeftLegends::default();
 
         let mut ret_bottom_layout = BottomLayout {
             rows: rows
@@ -498,7 +546,7 @@ mod test {
                         &mut default_widget_id,
                         &default_widget_type,
                         &mut default_widget_count,
-                        cpu_left_legend,
+                        left_legends,
                     )
                 })
                 .collect::<OptionResult<Vec<_>>>()
@@ -519,7 +567,7 @@ mod test {
         let mut default_widget_count = 3;
         let mut default_widget_id = DEFAULT_WIDGET_ID;
         let default_widget_type = Some(BottomWidgetType::Proc);
-        let cpu_left_legend = false;
+        let left_legends = LeftLegends::default();
 
         let mut ret_bottom_layout = BottomLayout {
             rows: rows
@@ -531,7 +579,7 @@ mod test {
                         &mut default_widget_id,
                         &default_widget_type,
                         &mut default_widget_count,
-                        cpu_left_legend,
+                        left_legends,
                     )
                 })
                 .collect::<OptionResult<Vec<_>>>()
@@ -710,4 +758,47 @@ mod test {
             Some(13)
         );
     }
+
+    #[test]
+    /// Tests that temperature graphs get a legend, on the side that was asked for.
+    fn test_temp_graph_legend() {
+        let rows = from_str::<Config>(
+            r#"
+    [[row]]
+        [[row.child]]
+            type="temp_graph"
+    "#,
+        )
+        .unwrap()
+        .row
+        .unwrap();
+
+        for temperature in [false, true] {
+            let mut iter_id = 0;
+            let mut default_widget_id = DEFAULT_WIDGET_ID;
+            let row = rows[0]
+                .convert_row_to_bottom_row(
+                    &mut iter_id,
+                    &mut 0,
+                    &mut default_widget_id,
+                    &None,
+                    &mut 1,
+                    LeftLegends {
+                        temperature,
+                        ..Default::default()
+                    },
+                )
+                .unwrap();
+
+            let widgets = &row.children[0].children[0].children;
+            let (graph, legend) = if temperature {
+                (&widgets[1], &widgets[0])
+            } else {
+                (&widgets[0], &widgets[1])
+            };
+            assert_eq!(graph.widget_type, BottomWidgetType::TempGraph);
+            assert_eq!(legend.widget_type, BottomWidgetType::TempGraphLegend);
+            assert_eq!(legend.widget_id, graph.widget_id + 1);
+        }
+    }
 }
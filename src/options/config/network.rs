@@ -9,4 +9,27 @@ use super::IgnoreList;
 pub(crate) struct NetworkConfig {
     /// A filter over the network interface names.
     pub(crate) interface_filter: Option<IgnoreList>,
+
+    /// Interfaces to plot as separate lines in the network graph.
+    pub(crate) graph_interfaces: Option<Vec<String>>,
+
+    /// The percentage of link capacity at which a link is considered saturated. Defaults to 90.
+    pub(crate) saturation_threshold: Option<u8>,
+
+    /// Extra series to chart below the network graph.
+    pub(crate) graph_series: Option<Vec<NetworkGraphSeries>>,
+
+    /// The errors or drops per second on any interface at which a warning is shown. Defaults to
+    /// 10, and 0 disables the warning.
+    pub(crate) error_rate_threshold: Option<u32>,
+}
+
+/// Extra series that can be charted below the network graph.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "generate_schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum NetworkGraphSeries {
+    Packets,
+    Errors,
+    Drops,
 }
@@ -3,10 +3,14 @@
 mod battery;
 mod borders;
 mod cpu;
+mod disk_io;
 mod graphs;
 mod memory;
 mod network;
+mod power;
 mod tables;
+mod tcp;
+mod temperature;
 mod themes;
 mod utils;
 mod widgets;
@@ -15,17 +19,24 @@ use std::borrow::Cow;
 
 use battery::BatteryStyle;
 use cpu::CpuStyle;
+use disk_io::DiskIoStyle;
 use graphs::GraphStyle;
 use memory::MemoryStyle;
 use network::NetworkStyle;
+use power::PowerStyle;
 use serde::{Deserialize, Serialize};
 use tables::TableStyle;
+use tcp::TcpStyle;
+use temperature::TemperatureStyle;
 use tui::{style::Style, widgets::BorderType};
 use utils::{opt, set_colour, set_colour_list, set_style};
 use widgets::WidgetStyle;
 
 use super::Config;
-use crate::options::{OptionError, OptionResult, args::BottomArgs};
+use crate::{
+    collection::cpu::CpuDataType,
+    options::{OptionError, OptionResult, args::BottomArgs},
+};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[cfg_attr(feature = "generate_schema", derive(schemars::JsonSchema))]
@@ -80,9 +91,21 @@ pub(crate) struct StyleConfig {
     /// Styling for the network widget.
     pub(crate) network: Option<NetworkStyle>,
 
+    /// Styling for the disk I/O widget.
+    pub(crate) disk_io: Option<DiskIoStyle>,
+
+    /// Styling for the TCP widget.
+    pub(crate) tcp: Option<TcpStyle>,
+
+    /// Styling for the power widget.
+    pub(crate) power: Option<PowerStyle>,
+
     /// Styling for the battery widget.
     pub(crate) battery: Option<BatteryStyle>,
 
+    /// Styling for the temperature table and graph widgets.
+    pub(crate) temperature: Option<TemperatureStyle>,
+
     /// Styling for table widgets.
     pub(crate) tables: Option<TableStyle>,
 
@@ -102,12 +125,31 @@ pub struct Styles {
     pub(crate) swap_style: Style,
     #[cfg(feature = "zfs")]
     pub(crate) arc_style: Style,
+    #[cfg(all(feature = "zfs", target_os = "linux"))]
+    pub(crate) arc_hit_style: Style,
+    #[cfg(all(feature = "zfs", target_os = "linux"))]
+    pub(crate) l2arc_style: Style,
+    #[cfg(target_os = "linux")]
+    pub(crate) zram_style: Style,
+    #[cfg(target_os = "linux")]
+    pub(crate) zswap_style: Style,
+    #[cfg(target_os = "linux")]
+    pub(crate) numa_colours: Vec<Style>,
     #[cfg(feature = "gpu")]
     pub(crate) gpu_colours: Vec<Style>,
+    pub(crate) mem_pressure_colours: Vec<Style>,
     pub(crate) rx_style: Style,
     pub(crate) tx_style: Style,
     pub(crate) total_rx_style: Style,
     pub(crate) total_tx_style: Style,
+    pub(crate) net_interface_colours: Vec<Style>,
+    pub(crate) saturated_link_style: Style,
+    pub(crate) net_errors_style: Style,
+    pub(crate) net_drops_style: Style,
+    pub(crate) disk_io_colours: Vec<Style>,
+    pub(crate) tcp_colours: Vec<Style>,
+    pub(crate) temp_graph_colours: Vec<Style>,
+    pub(crate) power_colours: Vec<Style>,
     pub(crate) all_cpu_colour: Style,
     pub(crate) avg_cpu_colour: Style,
     pub(crate) cpu_colour_styles: Vec<Style>,
@@ -122,6 +164,9 @@ pub struct Styles {
     pub(crate) high_battery: Style,
     pub(crate) medium_battery: Style,
     pub(crate) low_battery: Style,
+    pub(crate) warm_temp_style: Style,
+    pub(crate) hot_temp_style: Style,
+    pub(crate) critical_temp_style: Style,
     pub(crate) invalid_query_style: Style,
     pub(crate) disabled_text_style: Style,
     #[cfg(target_os = "linux")]
@@ -169,6 +214,52 @@ impl Styles {
         }
     }
 
+    /// Returns the `index`-th style of a colour list, wrapping around if needed. Falls back to
+    /// the text style if the list is empty, as the config allows that.
+    pub(crate) fn list_style(&self, colours: &[Style], index: usize) -> Style {
+        if colours.is_empty() {
+            self.text_style
+        } else {
+            colours[index % colours.len()]
+        }
+    }
+
+    /// Returns the style of a CPU entry's label and graph line.
+    pub(crate) fn cpu_style(&self, data_type: CpuDataType) -> Style {
+        match data_type {
+            CpuDataType::Avg => self.avg_cpu_colour,
+            CpuDataType::Cpu(index) => self.list_style(&self.cpu_colour_styles, index),
+            #[cfg(target_os = "linux")]
+            CpuDataType::Node(node) => self.list_style(&self.numa_colours, node),
+        }
+    }
+
+    /// Returns the RX and TX styles of the `index`-th interface plotted in the network graph.
+    pub(crate) fn net_interface_style(&self, index: usize) -> (Style, Style) {
+        let colours = &self.net_interface_colours;
+        if colours.is_empty() {
+            return (self.rx_style, self.tx_style);
+        }
+
+        (
+            colours[(index * 2) % colours.len()],
+            colours[(index * 2 + 1) % colours.len()],
+        )
+    }
+
+    /// Returns the read and write styles of the `index`-th disk plotted in the disk I/O graph.
+    pub(crate) fn disk_io_style(&self, index: usize) -> (Style, Style) {
+        let colours = &self.disk_io_colours;
+        if colours.is_empty() {
+            return (self.text_style, self.text_style);
+        }
+
+        (
+            colours[(index * 2) % colours.len()],
+            colours[(index * 2 + 1) % colours.len()],
+        )
+    }
+
     fn set_styles_from_config(&mut self, config: &StyleConfig) -> OptionResult<()> {
         // CPU
         set_colour!(self.avg_cpu_colour, config.cpu, avg_entry_color);
@@ -185,20 +276,54 @@ impl Styles {
         #[cfg(feature = "zfs")]
         set_colour!(self.arc_style, config.memory, arc_color);
 
+        #[cfg(all(feature = "zfs", target_os = "linux"))]
+        {
+            set_colour!(self.arc_hit_style, config.memory, arc_hit_color);
+            set_colour!(self.l2arc_style, config.memory, l2arc_color);
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            set_colour!(self.zram_style, config.memory, zram_color);
+            set_colour!(self.zswap_style, config.memory, zswap_color);
+            set_colour_list!(self.numa_colours, config.memory, numa_node_colors);
+        }
+
         #[cfg(feature = "gpu")]
         set_colour_list!(self.gpu_colours, config.memory, gpu_colors);
 
+        set_colour_list!(self.mem_pressure_colours, config.memory, pressure_colors);
+
         // Network
         set_colour!(self.rx_style, config.network, rx_color);
         set_colour!(self.tx_style, config.network, tx_color);
         set_colour!(self.total_rx_style, config.network, rx_total_color);
         set_colour!(self.total_tx_style, config.network, tx_total_color);
+        set_colour_list!(self.net_interface_colours, config.network, interface_colors);
+        set_colour!(self.saturated_link_style, config.network, saturated_color);
+        set_colour!(self.net_errors_style, config.network, errors_color);
+        set_colour!(self.net_drops_style, config.network, drops_color);
+
+        // Disk I/O
+        set_colour_list!(self.disk_io_colours, config.disk_io, disk_colors);
+
+        // TCP
+        set_colour_list!(self.tcp_colours, config.tcp, rate_colors);
+
+        // Power
+        set_colour_list!(self.power_colours, config.power, domain_colors);
 
         // Battery
         set_colour!(self.high_battery, config.battery, high_battery_color);
         set_colour!(self.medium_battery, config.battery, medium_battery_color);
         set_colour!(self.low_battery, config.battery, low_battery_color);
 
+        // Temperature
+        set_colour!(self.warm_temp_style, config.temperature, warm_color);
+        set_colour!(self.hot_temp_style, config.temperature, hot_color);
+        set_colour!(self.critical_temp_style, config.temperature, critical_color);
+        set_colour_list!(self.temp_graph_colours, config.temperature, graph_colors);
+
         // Tables
         set_style!(self.table_header_style, config.tables, headers);
 
@@ -268,6 +393,29 @@ mod test {
         );
     }
 
+    #[test]
+    fn empty_colour_lists_fall_back() {
+        use crate::collection::cpu::CpuDataType;
+
+        let mut styles = Styles {
+            cpu_colour_styles: vec![],
+            ..Default::default()
+        };
+        assert_eq!(styles.cpu_style(CpuDataType::Cpu(3)), styles.text_style);
+
+        #[cfg(target_os = "linux")]
+        {
+            styles.numa_colours = vec![];
+            assert_eq!(styles.cpu_style(CpuDataType::Node(1)), styles.text_style);
+        }
+
+        styles.cpu_colour_styles = vec![Style::default().fg(Color::Red)];
+        assert_eq!(
+            styles.cpu_style(CpuDataType::Cpu(3)),
+            Style::default().fg(Color::Red)
+        );
+    }
+
     #[test]
     fn built_in_colour_schemes_work() {
         Styles::from_theme("default").unwrap();
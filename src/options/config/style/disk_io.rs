@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+use super::ColorStr;
+
+/// Styling specific to the disk I/O widget.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "generate_schema", derive(schemars::JsonSchema))]
+#[cfg_attr(test, serde(deny_unknown_fields), derive(PartialEq, Eq))]
+pub(crate) struct DiskIoStyle {
+    /// Colours of each plotted disk's graph lines. Read in pairs, as the read and write colours
+    /// of each disk in turn.
+    #[serde(alias = "disk_colours")]
+    pub(crate) disk_colors: Option<Vec<ColorStr>>,
+}
@@ -25,7 +25,38 @@ pub(crate) struct MemoryStyle {
     #[serde(alias = "arc_colour")]
     pub(crate) arc_color: Option<ColorStr>,
 
-    /// Colour of each GPU's memory label and graph line. Read in order.
+    /// The colour of the ARC hit ratio label and graph line. Only does anything on Linux.
+    #[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+    #[serde(alias = "arc_hit_colour")]
+    pub(crate) arc_hit_color: Option<ColorStr>,
+
+    /// The colour of the L2ARC hit ratio label and graph line. Only does anything on Linux.
+    #[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+    #[serde(alias = "l2arc_colour")]
+    pub(crate) l2arc_color: Option<ColorStr>,
+
+    /// The colour of the zram label and graph line. Only does anything on Linux.
+    #[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+    #[serde(alias = "zram_colour")]
+    pub(crate) zram_color: Option<ColorStr>,
+
+    /// The colour of the zswap label and graph line. Only does anything on Linux.
+    #[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+    #[serde(alias = "zswap_colour")]
+    pub(crate) zswap_color: Option<ColorStr>,
+
+    /// Colour of each NUMA node's label and graph line, in both the memory and
+    /// CPU widgets. Read in order. Only does anything on Linux.
+    #[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+    #[serde(alias = "numa_node_colours")]
+    pub(crate) numa_node_colors: Option<Vec<ColorStr>>,
+
+    /// Colour of each GPU's memory label and graph line, and its line in the GPU widget. Read in
+    /// order.
     #[serde(alias = "gpu_colours")]
     pub(crate) gpu_colors: Option<Vec<ColorStr>>,
+
+    /// Colour of each rate's label and graph line in the memory pressure widget. Read in order.
+    #[serde(alias = "pressure_colours")]
+    pub(crate) pressure_colors: Option<Vec<ColorStr>>,
 }
@@ -22,4 +22,22 @@ pub(crate) struct NetworkStyle {
     /// The colour of the total TX (upload) label in basic mode.
     #[serde(alias = "tx_total_colour")]
     pub(crate) tx_total_color: Option<ColorStr>,
+
+    /// Colours of the graph lines of individually plotted interfaces. Read in pairs, as the RX
+    /// and TX colours of each interface in turn.
+    #[serde(alias = "interface_colours")]
+    pub(crate) interface_colors: Option<Vec<ColorStr>>,
+
+    /// The colour of the label and graph line of a link whose usage is at or above
+    /// `network.saturation_threshold`.
+    #[serde(alias = "saturated_colour")]
+    pub(crate) saturated_color: Option<ColorStr>,
+
+    /// The colour of the errors per second line in the packet graph.
+    #[serde(alias = "errors_colour")]
+    pub(crate) errors_color: Option<ColorStr>,
+
+    /// The colour of the drops per second line in the packet graph.
+    #[serde(alias = "drops_colour")]
+    pub(crate) drops_color: Option<ColorStr>,
 }
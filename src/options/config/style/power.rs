@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+use super::ColorStr;
+
+/// Styling specific to the power widget.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "generate_schema", derive(schemars::JsonSchema))]
+#[cfg_attr(test, serde(deny_unknown_fields), derive(PartialEq, Eq))]
+pub(crate) struct PowerStyle {
+    /// Colour of each power domain's label and graph line. Read in the order package, core,
+    /// uncore, then DRAM.
+    #[serde(alias = "domain_colours")]
+    pub(crate) domain_colors: Option<Vec<ColorStr>>,
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+use super::ColorStr;
+
+/// Styling specific to the TCP widget.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "generate_schema", derive(schemars::JsonSchema))]
+#[cfg_attr(test, serde(deny_unknown_fields), derive(PartialEq, Eq))]
+pub(crate) struct TcpStyle {
+    /// Colour of each rate's label and graph line. Read in order.
+    #[serde(alias = "rate_colours")]
+    pub(crate) rate_colors: Option<Vec<ColorStr>>,
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+use super::ColorStr;
+
+/// Styling specific to the temperature table and graph widgets.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[cfg_attr(feature = "generate_schema", derive(schemars::JsonSchema))]
+#[cfg_attr(test, serde(deny_unknown_fields), derive(PartialEq, Eq))]
+pub(crate) struct TemperatureStyle {
+    /// The colour of a reading within 10°C of its sensor's next threshold.
+    #[serde(alias = "warm_colour")]
+    pub(crate) warm_color: Option<ColorStr>,
+
+    /// The colour of a reading at or above its sensor's max threshold.
+    #[serde(alias = "hot_colour")]
+    pub(crate) hot_color: Option<ColorStr>,
+
+    /// The colour of a reading at or above its sensor's critical threshold, or with a
+    /// critical alarm set.
+    #[serde(alias = "critical_colour")]
+    pub(crate) critical_color: Option<ColorStr>,
+
+    /// Colour of each sensor's line in the temperature graph, and its legend entry. Read in
+    /// order.
+    #[serde(alias = "graph_colours")]
+    pub(crate) graph_colors: Option<Vec<ColorStr>>,
+}
@@ -27,6 +27,21 @@ impl Styles {
             swap_style: color!(SECOND_COLOUR),
             #[cfg(feature = "zfs")]
             arc_style: color!(THIRD_COLOUR),
+            #[cfg(all(feature = "zfs", target_os = "linux"))]
+            arc_hit_style: color!(Color::LightBlue),
+            #[cfg(all(feature = "zfs", target_os = "linux"))]
+            l2arc_style: color!(Color::Blue),
+            #[cfg(target_os = "linux")]
+            zram_style: color!(Color::Cyan),
+            #[cfg(target_os = "linux")]
+            zswap_style: color!(Color::Green),
+            #[cfg(target_os = "linux")]
+            numa_colours: vec![
+                color!(Color::LightYellow),
+                color!(Color::LightMagenta),
+                color!(Color::LightCyan),
+                color!(Color::LightGreen),
+            ],
             #[cfg(feature = "gpu")]
             gpu_colours: vec![
                 color!(FOURTH_COLOUR),
@@ -37,12 +52,61 @@ impl Styles {
                 color!(Color::Blue),
                 color!(Color::Red),
             ],
+            mem_pressure_colours: vec![
+                color!(Color::LightMagenta),
+                color!(Color::LightYellow),
+                color!(Color::LightCyan),
+                color!(Color::LightGreen),
+                color!(Color::LightBlue),
+                color!(Color::Cyan),
+                color!(Color::Green),
+            ],
             rx_style: color!(FIRST_COLOUR),
             tx_style: color!(SECOND_COLOUR),
             total_rx_style: color!(THIRD_COLOUR),
             total_tx_style: color!(FOURTH_COLOUR),
+            net_interface_colours: vec![
+                color!(Color::LightGreen),
+                color!(Color::LightBlue),
+                color!(Color::LightRed),
+                color!(Color::Cyan),
+                color!(Color::Green),
+                color!(Color::Blue),
+            ],
+            saturated_link_style: color!(Color::Red),
+            net_errors_style: color!(Color::LightRed),
+            net_drops_style: color!(Color::LightBlue),
+            disk_io_colours: vec![
+                color!(Color::LightCyan),
+                color!(Color::LightMagenta),
+                color!(Color::LightGreen),
+                color!(Color::LightYellow),
+                color!(Color::Cyan),
+                color!(Color::Magenta),
+            ],
             all_cpu_colour: color!(ALL_COLOUR),
             avg_cpu_colour: color!(AVG_COLOUR),
+            tcp_colours: vec![
+                color!(Color::LightMagenta),
+                color!(Color::LightYellow),
+                color!(Color::LightRed),
+                color!(Color::LightBlue),
+                color!(Color::LightCyan),
+            ],
+            temp_graph_colours: vec![
+                color!(Color::LightRed),
+                color!(Color::LightYellow),
+                color!(Color::LightMagenta),
+                color!(Color::LightCyan),
+                color!(Color::LightGreen),
+                color!(Color::LightBlue),
+            ],
+            power_colours: vec![
+                color!(Color::LightRed),
+                color!(Color::LightYellow),
+                color!(Color::LightMagenta),
+                color!(Color::LightCyan),
+            ],
             cpu_colour_styles: vec![
                 color!(Color::LightMagenta),
                 color!(Color::LightYellow),
@@ -64,6 +128,9 @@ impl Styles {
             high_battery: color!(Color::Green),
             medium_battery: color!(Color::Yellow),
             low_battery: color!(Color::Red),
+            warm_temp_style: color!(Color::Yellow),
+            hot_temp_style: color!(Color::LightRed),
+            critical_temp_style: color!(Color::Red).add_modifier(Modifier::BOLD),
             invalid_query_style: color!(Color::Red),
             disabled_text_style: color!(Color::DarkGray),
             border_type: BorderType::Plain,
@@ -80,6 +147,21 @@ impl Styles {
             swap_style: color!(Color::Red),
             #[cfg(feature = "zfs")]
             arc_style: color!(Color::LightBlue),
+            #[cfg(all(feature = "zfs", target_os = "linux"))]
+            arc_hit_style: color!(Color::Blue),
+            #[cfg(all(feature = "zfs", target_os = "linux"))]
+            l2arc_style: color!(Color::Magenta),
+            #[cfg(target_os = "linux")]
+            zram_style: color!(Color::Cyan),
+            #[cfg(target_os = "linux")]
+            zswap_style: color!(Color::Green),
+            #[cfg(target_os = "linux")]
+            numa_colours: vec![
+                color!(Color::Blue),
+                color!(Color::Magenta),
+                color!(Color::Red),
+                color!(Color::Green),
+            ],
             #[cfg(feature = "gpu")]
             gpu_colours: vec![
                 color!(Color::LightGreen),
@@ -90,10 +172,59 @@ impl Styles {
                 color!(Color::Blue),
                 color!(Color::Red),
             ],
+            mem_pressure_colours: vec![
+                color!(Color::LightMagenta),
+                color!(Color::LightBlue),
+                color!(Color::LightRed),
+                color!(Color::Cyan),
+                color!(Color::Green),
+                color!(Color::Blue),
+                color!(Color::Red),
+            ],
             rx_style: color!(Color::Blue),
             tx_style: color!(Color::Red),
             total_rx_style: color!(Color::LightBlue),
             total_tx_style: color!(Color::LightRed),
+            net_interface_colours: vec![
+                color!(Color::Green),
+                color!(Color::Magenta),
+                color!(Color::Cyan),
+                color!(Color::LightMagenta),
+                color!(Color::LightGreen),
+                color!(Color::LightCyan),
+            ],
+            saturated_link_style: color!(Color::LightRed),
+            net_errors_style: color!(Color::Magenta),
+            net_drops_style: color!(Color::Cyan),
+            disk_io_colours: vec![
+                color!(Color::Blue),
+                color!(Color::Red),
+                color!(Color::Green),
+                color!(Color::Magenta),
+                color!(Color::Cyan),
+                color!(Color::LightRed),
+            ],
+            tcp_colours: vec![
+                color!(Color::LightMagenta),
+                color!(Color::LightBlue),
+                color!(Color::LightRed),
+                color!(Color::Cyan),
+                color!(Color::Green),
+            ],
+            temp_graph_colours: vec![
+                color!(Color::Red),
+                color!(Color::Yellow),
+                color!(Color::Magenta),
+                color!(Color::Blue),
+                color!(Color::Green),
+                color!(Color::Cyan),
+            ],
+            power_colours: vec![
+                color!(Color::Red),
+                color!(Color::Yellow),
+                color!(Color::Magenta),
+                color!(Color::Blue),
+            ],
             cpu_colour_styles: vec![
                 color!(Color::LightMagenta),
                 color!(Color::LightBlue),
@@ -15,6 +15,21 @@ impl Styles {
             swap_style: hex!("#fabd2f"),
             #[cfg(feature = "zfs")]
             arc_style: hex!("#689d6a"),
+            #[cfg(all(feature = "zfs", target_os = "linux"))]
+            arc_hit_style: hex!("#8ec07c"),
+            #[cfg(all(feature = "zfs", target_os = "linux"))]
+            l2arc_style: hex!("#458588"),
+            #[cfg(target_os = "linux")]
+            zram_style: hex!("#83a598"),
+            #[cfg(target_os = "linux")]
+            zswap_style: hex!("#d3869b"),
+            #[cfg(target_os = "linux")]
+            numa_colours: vec![
+                hex!("#fabd2f"),
+                hex!("#d3869b"),
+                hex!("#83a598"),
+                hex!("#b8bb26"),
+            ],
             #[cfg(feature = "gpu")]
             gpu_colours: vec![
                 hex!("#d79921"),
@@ -25,12 +40,61 @@ impl Styles {
                 hex!("#cc241d"),
                 hex!("#98971a"),
             ],
+            mem_pressure_colours: vec![
+                hex!("#cc241d"),
+                hex!("#98971a"),
+                hex!("#d79921"),
+                hex!("#458588"),
+                hex!("#b16286"),
+                hex!("#689d6a"),
+                hex!("#fe8019"),
+            ],
             rx_style: hex!("#8ec07c"),
             tx_style: hex!("#fabd2f"),
             total_rx_style: hex!("#689d6a"),
             total_tx_style: hex!("#d79921"),
+            net_interface_colours: vec![
+                hex!("#b8bb26"),
+                hex!("#d3869b"),
+                hex!("#83a598"),
+                hex!("#fe8019"),
+                hex!("#98971a"),
+                hex!("#b16286"),
+            ],
+            saturated_link_style: hex!("#fb4934"),
+            net_errors_style: hex!("#fe8019"),
+            net_drops_style: hex!("#83a598"),
+            disk_io_colours: vec![
+                hex!("#83a598"),
+                hex!("#fe8019"),
+                hex!("#b8bb26"),
+                hex!("#d3869b"),
+                hex!("#8ec07c"),
+                hex!("#fabd2f"),
+            ],
             all_cpu_colour: hex!("#8ec07c"),
             avg_cpu_colour: hex!("#fb4934"),
+            tcp_colours: vec![
+                hex!("#98971a"),
+                hex!("#d79921"),
+                hex!("#cc241d"),
+                hex!("#458588"),
+                hex!("#b16286"),
+            ],
+            temp_graph_colours: vec![
+                hex!("#fb4934"),
+                hex!("#fabd2f"),
+                hex!("#fe8019"),
+                hex!("#d3869b"),
+                hex!("#8ec07c"),
+                hex!("#83a598"),
+            ],
+            power_colours: vec![
+                hex!("#fb4934"),
+                hex!("#fabd2f"),
+                hex!("#d3869b"),
+                hex!("#83a598"),
+            ],
             cpu_colour_styles: vec![
                 hex!("#cc241d"),
                 hex!("#98971a"),
@@ -64,6 +128,9 @@ impl Styles {
             high_battery: hex!("#98971a"),
             medium_battery: hex!("#fabd2f"),
             low_battery: hex!("#fb4934"),
+            warm_temp_style: hex!("#fabd2f"),
+            hot_temp_style: hex!("#fe8019"),
+            critical_temp_style: hex!("#fb4934").add_modifier(Modifier::BOLD),
             invalid_query_style: color!(Color::Red),
             disabled_text_style: hex!("#665c54"),
             border_type: BorderType::Plain,
@@ -80,6 +147,21 @@ impl Styles {
             swap_style: hex!("#cc241d"),
             #[cfg(feature = "zfs")]
             arc_style: hex!("#689d6a"),
+            #[cfg(all(feature = "zfs", target_os = "linux"))]
+            arc_hit_style: hex!("#427b58"),
+            #[cfg(all(feature = "zfs", target_os = "linux"))]
+            l2arc_style: hex!("#076678"),
+            #[cfg(target_os = "linux")]
+            zram_style: hex!("#076678"),
+            #[cfg(target_os = "linux")]
+            zswap_style: hex!("#8f3f71"),
+            #[cfg(target_os = "linux")]
+            numa_colours: vec![
+                hex!("#b57614"),
+                hex!("#8f3f71"),
+                hex!("#076678"),
+                hex!("#79740e"),
+            ],
             #[cfg(feature = "gpu")]
             gpu_colours: vec![
                 hex!("#9d0006"),
@@ -90,12 +172,61 @@ impl Styles {
                 hex!("#fe8019"),
                 hex!("#b8bb26"),
             ],
+            mem_pressure_colours: vec![
+                hex!("#cc241d"),
+                hex!("#98971a"),
+                hex!("#d79921"),
+                hex!("#458588"),
+                hex!("#b16286"),
+                hex!("#689d6a"),
+                hex!("#fe8019"),
+            ],
             rx_style: hex!("#427b58"),
             tx_style: hex!("#cc241d"),
             total_rx_style: hex!("#689d6a"),
             total_tx_style: hex!("#d79921"),
+            net_interface_colours: vec![
+                hex!("#79740e"),
+                hex!("#8f3f71"),
+                hex!("#076678"),
+                hex!("#af3a03"),
+                hex!("#98971a"),
+                hex!("#b16286"),
+            ],
+            saturated_link_style: hex!("#9d0006"),
+            net_errors_style: hex!("#af3a03"),
+            net_drops_style: hex!("#076678"),
+            disk_io_colours: vec![
+                hex!("#076678"),
+                hex!("#af3a03"),
+                hex!("#79740e"),
+                hex!("#8f3f71"),
+                hex!("#427b58"),
+                hex!("#b57614"),
+            ],
             all_cpu_colour: hex!("#8ec07c"),
             avg_cpu_colour: hex!("#fb4934"),
+            tcp_colours: vec![
+                hex!("#98971a"),
+                hex!("#d79921"),
+                hex!("#cc241d"),
+                hex!("#458588"),
+                hex!("#b16286"),
+            ],
+            temp_graph_colours: vec![
+                hex!("#9d0006"),
+                hex!("#b57614"),
+                hex!("#af3a03"),
+                hex!("#8f3f71"),
+                hex!("#427b58"),
+                hex!("#076678"),
+            ],
+            power_colours: vec![
+                hex!("#9d0006"),
+                hex!("#b57614"),
+                hex!("#8f3f71"),
+                hex!("#076678"),
+            ],
             cpu_colour_styles: vec![
                 hex!("#cc241d"),
                 hex!("#98971a"),
@@ -129,6 +260,9 @@ impl Styles {
             high_battery: hex!("#98971a"),
             medium_battery: hex!("#d79921"),
             low_battery: hex!("#cc241d"),
+            warm_temp_style: hex!("#d79921"),
+            hot_temp_style: hex!("#d65d0e"),
+            critical_temp_style: hex!("#cc241d").add_modifier(Modifier::BOLD),
             invalid_query_style: color!(Color::Red),
             disabled_text_style: hex!("#d5c4a1"),
             border_type: BorderType::Plain,
@@ -15,6 +15,21 @@ impl Styles {
             swap_style: hex!("#d08770"),
             #[cfg(feature = "zfs")]
             arc_style: hex!("#5e81ac"),
+            #[cfg(all(feature = "zfs", target_os = "linux"))]
+            arc_hit_style: hex!("#81a1c1"),
+            #[cfg(all(feature = "zfs", target_os = "linux"))]
+            l2arc_style: hex!("#b48ead"),
+            #[cfg(target_os = "linux")]
+            zram_style: hex!("#8fbcbb"),
+            #[cfg(target_os = "linux")]
+            zswap_style: hex!("#b48ead"),
+            #[cfg(target_os = "linux")]
+            numa_colours: vec![
+                hex!("#ebcb8b"),
+                hex!("#b48ead"),
+                hex!("#88c0d0"),
+                hex!("#a3be8c"),
+            ],
             #[cfg(feature = "gpu")]
             gpu_colours: vec![
                 hex!("#8fbcbb"),
@@ -25,12 +40,61 @@ impl Styles {
                 hex!("#ebcb8b"),
                 hex!("#bf616a"),
             ],
+            mem_pressure_colours: vec![
+                hex!("#5e81ac"),
+                hex!("#81a1c1"),
+                hex!("#d8dee9"),
+                hex!("#b48ead"),
+                hex!("#a3be8c"),
+                hex!("#ebcb8b"),
+                hex!("#d08770"),
+            ],
             rx_style: hex!("#88c0d0"),
             tx_style: hex!("#d08770"),
             total_rx_style: hex!("#5e81ac"),
             total_tx_style: hex!("#8fbcbb"),
+            net_interface_colours: vec![
+                hex!("#a3be8c"),
+                hex!("#b48ead"),
+                hex!("#81a1c1"),
+                hex!("#ebcb8b"),
+                hex!("#bf616a"),
+                hex!("#8fbcbb"),
+            ],
+            saturated_link_style: hex!("#bf616a"),
+            net_errors_style: hex!("#d08770"),
+            net_drops_style: hex!("#b48ead"),
+            disk_io_colours: vec![
+                hex!("#88c0d0"),
+                hex!("#d08770"),
+                hex!("#a3be8c"),
+                hex!("#b48ead"),
+                hex!("#81a1c1"),
+                hex!("#ebcb8b"),
+            ],
             all_cpu_colour: hex!("#88c0d0"),
             avg_cpu_colour: hex!("#8fbcbb"),
+            tcp_colours: vec![
+                hex!("#81a1c1"),
+                hex!("#ebcb8b"),
+                hex!("#bf616a"),
+                hex!("#b48ead"),
+                hex!("#a3be8c"),
+            ],
+            temp_graph_colours: vec![
+                hex!("#bf616a"),
+                hex!("#ebcb8b"),
+                hex!("#d08770"),
+                hex!("#b48ead"),
+                hex!("#a3be8c"),
+                hex!("#88c0d0"),
+            ],
+            power_colours: vec![
+                hex!("#bf616a"),
+                hex!("#ebcb8b"),
+                hex!("#b48ead"),
+                hex!("#88c0d0"),
+            ],
             cpu_colour_styles: vec![
                 hex!("#5e81ac"),
                 hex!("#81a1c1"),
@@ -52,6 +116,9 @@ impl Styles {
             high_battery: hex!("#a3be8c"),
             medium_battery: hex!("#ebcb8b"),
             low_battery: hex!("#bf616a"),
+            warm_temp_style: hex!("#ebcb8b"),
+            hot_temp_style: hex!("#d08770"),
+            critical_temp_style: hex!("#bf616a").add_modifier(Modifier::BOLD),
             invalid_query_style: color!(Color::Red),
             disabled_text_style: hex!("#4c566a"),
             border_type: BorderType::Plain,
@@ -68,6 +135,21 @@ impl Styles {
             swap_style: hex!("#d08770"),
             #[cfg(feature = "zfs")]
             arc_style: hex!("#5e81ac"),
+            #[cfg(all(feature = "zfs", target_os = "linux"))]
+            arc_hit_style: hex!("#81a1c1"),
+            #[cfg(all(feature = "zfs", target_os = "linux"))]
+            l2arc_style: hex!("#b48ead"),
+            #[cfg(target_os = "linux")]
+            zram_style: hex!("#88c0d0"),
+            #[cfg(target_os = "linux")]
+            zswap_style: hex!("#b48ead"),
+            #[cfg(target_os = "linux")]
+            numa_colours: vec![
+                hex!("#d08770"),
+                hex!("#b48ead"),
+                hex!("#5e81ac"),
+                hex!("#a3be8c"),
+            ],
             #[cfg(feature = "gpu")]
             gpu_colours: vec![
                 hex!("#8fbcbb"),
@@ -78,12 +160,61 @@ impl Styles {
                 hex!("#ebcb8b"),
                 hex!("#bf616a"),
             ],
+            mem_pressure_colours: vec![
+                hex!("#5e81ac"),
+                hex!("#88c0d0"),
+                hex!("#4c566a"),
+                hex!("#b48ead"),
+                hex!("#a3be8c"),
+                hex!("#ebcb8b"),
+                hex!("#d08770"),
+            ],
             rx_style: hex!("#81a1c1"),
             tx_style: hex!("#d08770"),
             total_rx_style: hex!("#5e81ac"),
             total_tx_style: hex!("#8fbcbb"),
+            net_interface_colours: vec![
+                hex!("#a3be8c"),
+                hex!("#b48ead"),
+                hex!("#5e81ac"),
+                hex!("#ebcb8b"),
+                hex!("#bf616a"),
+                hex!("#88c0d0"),
+            ],
+            saturated_link_style: hex!("#bf616a"),
+            net_errors_style: hex!("#d08770"),
+            net_drops_style: hex!("#b48ead"),
+            disk_io_colours: vec![
+                hex!("#5e81ac"),
+                hex!("#d08770"),
+                hex!("#a3be8c"),
+                hex!("#b48ead"),
+                hex!("#81a1c1"),
+                hex!("#ebcb8b"),
+            ],
             all_cpu_colour: hex!("#81a1c1"),
             avg_cpu_colour: hex!("#8fbcbb"),
+            tcp_colours: vec![
+                hex!("#88c0d0"),
+                hex!("#ebcb8b"),
+                hex!("#bf616a"),
+                hex!("#b48ead"),
+                hex!("#a3be8c"),
+            ],
+            temp_graph_colours: vec![
+                hex!("#bf616a"),
+                hex!("#d08770"),
+                hex!("#ebcb8b"),
+                hex!("#b48ead"),
+                hex!("#a3be8c"),
+                hex!("#5e81ac"),
+            ],
+            power_colours: vec![
+                hex!("#bf616a"),
+                hex!("#d08770"),
+                hex!("#b48ead"),
+                hex!("#5e81ac"),
+            ],
             cpu_colour_styles: vec![
                 hex!("#5e81ac"),
                 hex!("#88c0d0"),
@@ -105,6 +236,9 @@ impl Styles {
             high_battery: hex!("#a3be8c"),
             medium_battery: hex!("#ebcb8b"),
             low_battery: hex!("#bf616a"),
+            warm_temp_style: hex!("#ebcb8b"),
+            hot_temp_style: hex!("#d08770"),
+            critical_temp_style: hex!("#bf616a").add_modifier(Modifier::BOLD),
             invalid_query_style: color!(Color::Red),
             disabled_text_style: hex!("#d8dee9"),
             border_type: BorderType::Plain,
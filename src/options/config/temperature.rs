@@ -1,6 +1,7 @@
 use serde::Deserialize;
 
 use super::IgnoreList;
+use crate::collection::temperature::SensorKind;
 
 /// Temperature configuration.
 #[derive(Clone, Debug, Default, Deserialize)]
@@ -9,4 +10,36 @@ use super::IgnoreList;
 pub(crate) struct TempConfig {
     /// A filter over the sensor names.
     pub(crate) sensor_filter: Option<IgnoreList>,
+
+    /// The kinds of sensors to show. Defaults to only temperatures.
+    #[cfg_attr(feature = "generate_schema", schemars(with = "Option<Vec<String>>"))]
+    pub(crate) sensor_types: Option<Vec<SensorKind>>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::TempConfig;
+    use crate::collection::temperature::SensorKind;
+
+    #[test]
+    fn sensor_type_settings() {
+        let generated: TempConfig = toml_edit::de::from_str("").unwrap();
+        assert!(generated.sensor_types.is_none());
+
+        let config = r#"sensor_types = ["temp", "fan", "in", "power", "curr"]"#;
+        let generated: TempConfig = toml_edit::de::from_str(config).unwrap();
+        assert_eq!(
+            generated.sensor_types.unwrap(),
+            vec![
+                SensorKind::Temperature,
+                SensorKind::Fan,
+                SensorKind::Voltage,
+                SensorKind::Power,
+                SensorKind::Current,
+            ]
+        );
+
+        let config = r#"sensor_types = ["humidity"]"#;
+        toml_edit::de::from_str::<TempConfig>(config).expect_err("Should error out!");
+    }
 }
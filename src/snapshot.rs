@@ -0,0 +1,570 @@
+//! A one-off JSON snapshot of the system, printed by `btm --json` instead of starting the
+//! interface.
+//!
+//! The layout of the output is versioned by [`SNAPSHOT_VERSION`], which must be bumped whenever
+//! a field is removed, renamed, or changes meaning. Adding fields does not need a new version.
+
+use std::{
+    io::{Write, stdout},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::Serialize;
+
+use crate::{
+    CollectorSettings,
+    app::{
+        App,
+        data::{SensorValue, StoredData, TypedTemperature},
+    },
+    collection::{cpu::CpuDataType, memory::MemData, temperature::SensorKind},
+    create_data_collector,
+};
+
+/// The version of the snapshot layout.
+pub(crate) const SNAPSHOT_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct Snapshot<'a> {
+    version: u32,
+    /// When the snapshot was taken, in seconds since the Unix epoch.
+    timestamp: u64,
+    /// The time between the two collections that rates are calculated over.
+    interval_ms: u64,
+    cpu: Cpu,
+    memory: Memory,
+    network: Network<'a>,
+    disks: Vec<Disk<'a>>,
+    sensors: Vec<Sensor<'a>>,
+    batteries: Vec<Battery<'a>>,
+    gpus: Vec<Gpu<'a>>,
+    processes: Vec<Process<'a>>,
+}
+
+#[derive(Serialize)]
+struct Cpu {
+    average: Option<f32>,
+    cores: Vec<Core>,
+    load_average: [f32; 3],
+}
+
+#[derive(Serialize)]
+struct Core {
+    core: usize,
+    usage_percent: f32,
+}
+
+#[derive(Serialize)]
+struct Memory {
+    ram: Option<MemUsage>,
+    swap: Option<MemUsage>,
+    cache: Option<MemUsage>,
+    arc: Option<MemUsage>,
+}
+
+#[derive(Serialize)]
+struct MemUsage {
+    used_bytes: u64,
+    total_bytes: u64,
+    used_percent: f64,
+}
+
+impl From<&MemData> for MemUsage {
+    fn from(mem: &MemData) -> Self {
+        Self {
+            used_bytes: mem.used_bytes,
+            total_bytes: mem.total_bytes.get(),
+            used_percent: mem.percentage(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Network<'a> {
+    rx_bits_per_sec: u64,
+    tx_bits_per_sec: u64,
+    total_rx_bytes: u64,
+    total_tx_bytes: u64,
+    interfaces: Vec<Interface<'a>>,
+}
+
+#[derive(Serialize)]
+struct Interface<'a> {
+    name: &'a str,
+    rx_bits_per_sec: u64,
+    tx_bits_per_sec: u64,
+    total_rx_bytes: u64,
+    total_tx_bytes: u64,
+    link_speed_bits_per_sec: Option<u64>,
+}
+
+#[derive(Serialize)]
+struct Disk<'a> {
+    name: &'a str,
+    mount_point: &'a str,
+    fs_type: Option<&'a str>,
+    used_bytes: Option<u64>,
+    free_bytes: Option<u64>,
+    total_bytes: Option<u64>,
+    used_percent: Option<f64>,
+    read_bytes_per_sec: Option<u64>,
+    write_bytes_per_sec: Option<u64>,
+}
+
+#[derive(Serialize)]
+struct Sensor<'a> {
+    name: &'a str,
+    kind: &'static str,
+    value: Option<f64>,
+    unit: &'static str,
+}
+
+#[derive(Serialize)]
+struct Battery<'a> {
+    charge_percent: f64,
+    power_watts: f64,
+    health_percent: f64,
+    state: &'static str,
+    time_to_full_secs: Option<u32>,
+    time_to_empty_secs: Option<u32>,
+    cycle_count: Option<u32>,
+    vendor: Option<&'a str>,
+    model: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct Gpu<'a> {
+    id: Option<&'a str>,
+    name: &'a str,
+    utilization_percent: Option<f64>,
+    encoder_percent: Option<f64>,
+    decoder_percent: Option<f64>,
+    temperature_celsius: Option<f32>,
+    power_watts: Option<f64>,
+    power_limit_watts: Option<f64>,
+    core_clock_mhz: Option<u32>,
+    memory_clock_mhz: Option<u32>,
+    fan_percent: Option<f64>,
+    memory: Option<MemUsage>,
+}
+
+#[derive(Serialize)]
+struct Process<'a> {
+    pid: i64,
+    parent_pid: Option<i64>,
+    name: &'a str,
+    command: &'a str,
+    user: Option<&'a str>,
+    state: &'static str,
+    cpu_percent: f32,
+    mem_percent: f32,
+    mem_bytes: u64,
+    virtual_mem_bytes: u64,
+    read_bytes_per_sec: u64,
+    write_bytes_per_sec: u64,
+    total_read_bytes: u64,
+    total_write_bytes: u64,
+    time_secs: u64,
+    priority: i32,
+    nice: Option<i32>,
+    gpu_mem_bytes: Option<u64>,
+    gpu_util_percent: Option<u32>,
+}
+
+impl<'a> Snapshot<'a> {
+    fn new(data: &'a StoredData, interval_ms: u64) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_secs())
+            .unwrap_or_default();
+
+        Self {
+            version: SNAPSHOT_VERSION,
+            timestamp,
+            interval_ms,
+            cpu: cpu(data),
+            memory: memory(data),
+            network: network(data),
+            disks: disks(data),
+            sensors: sensors(data),
+            batteries: batteries(data),
+            gpus: gpus(data),
+            processes: processes(data),
+        }
+    }
+}
+
+fn cpu(data: &StoredData) -> Cpu {
+    let mut average = None;
+    let mut cores = vec![];
+
+    for cpu in &data.cpu_harvest {
+        match cpu.data_type {
+            CpuDataType::Avg => average = Some(cpu.usage),
+            CpuDataType::Cpu(core) => cores.push(Core {
+                core,
+                usage_percent: cpu.usage,
+            }),
+            #[cfg(target_os = "linux")]
+            CpuDataType::Node(_) => {}
+        }
+    }
+
+    Cpu {
+        average,
+        cores,
+        load_average: data.load_avg_harvest,
+    }
+}
+
+fn memory(data: &StoredData) -> Memory {
+    #[cfg(not(target_os = "windows"))]
+    let cache = data.cache_harvest.as_ref().map(MemUsage::from);
+    #[cfg(target_os = "windows")]
+    let cache = None;
+
+    #[cfg(feature = "zfs")]
+    let arc = data.arc_harvest.as_ref().map(MemUsage::from);
+    #[cfg(not(feature = "zfs"))]
+    let arc = None;
+
+    Memory {
+        ram: data.ram_harvest.as_ref().map(MemUsage::from),
+        swap: data.swap_harvest.as_ref().map(MemUsage::from),
+        cache,
+        arc,
+    }
+}
+
+fn network(data: &StoredData) -> Network<'_> {
+    let network = &data.network_harvest;
+
+    // Totals are collected in bits.
+    Network {
+        rx_bits_per_sec: network.rx,
+        tx_bits_per_sec: network.tx,
+        total_rx_bytes: network.total_rx / 8,
+        total_tx_bytes: network.total_tx / 8,
+        interfaces: network
+            .interfaces
+            .iter()
+            .map(|interface| Interface {
+                name: &interface.name,
+                rx_bits_per_sec: interface.rx,
+                tx_bits_per_sec: interface.tx,
+                total_rx_bytes: interface.total_rx / 8,
+                total_tx_bytes: interface.total_tx / 8,
+                link_speed_bits_per_sec: interface.link_speed,
+            })
+            .collect(),
+    }
+}
+
+fn disks(data: &StoredData) -> Vec<Disk<'_>> {
+    data.disk_harvest
+        .iter()
+        .map(|disk| Disk {
+            name: &disk.name,
+            mount_point: &disk.mount_point,
+            fs_type: disk.fs_type.as_deref(),
+            used_bytes: disk.used_bytes,
+            free_bytes: disk.free_bytes,
+            total_bytes: disk.total_bytes,
+            used_percent: disk.used_percent(),
+            read_bytes_per_sec: disk.io_read_rate_bytes,
+            write_bytes_per_sec: disk.io_write_rate_bytes,
+        })
+        .collect()
+}
+
+fn sensors(data: &StoredData) -> Vec<Sensor<'_>> {
+    data.temp_data
+        .iter()
+        .map(|sensor| {
+            let (value, unit) = match &sensor.value {
+                Some(value) => {
+                    let (value, unit) = sensor_value(value);
+                    (Some(value), unit)
+                }
+                None => (None, sensor.kind.unit()),
+            };
+
+            Sensor {
+                name: &sensor.sensor,
+                kind: sensor_kind(sensor.kind),
+                value,
+                unit,
+            }
+        })
+        .collect()
+}
+
+fn sensor_kind(kind: SensorKind) -> &'static str {
+    match kind {
+        SensorKind::Temperature => "temperature",
+        SensorKind::Fan => "fan",
+        SensorKind::Voltage => "voltage",
+        SensorKind::Power => "power",
+        SensorKind::Current => "current",
+    }
+}
+
+/// Returns a sensor reading and its unit. Temperatures are in the configured unit.
+fn sensor_value(value: &SensorValue) -> (f64, &'static str) {
+    match value {
+        SensorValue::Temperature(TypedTemperature::Celsius(val)) => (*val as f64, "°C"),
+        SensorValue::Temperature(TypedTemperature::Kelvin(val)) => (*val as f64, "K"),
+        SensorValue::Temperature(TypedTemperature::Fahrenheit(val)) => (*val as f64, "°F"),
+        SensorValue::Fan(rpm) => (*rpm as f64, "RPM"),
+        SensorValue::Voltage(val) => (*val as f64, "V"),
+        SensorValue::Power(val) => (*val as f64, "W"),
+        SensorValue::Current(val) => (*val as f64, "A"),
+    }
+}
+
+#[cfg(feature = "battery")]
+fn batteries(data: &StoredData) -> Vec<Battery<'_>> {
+    use crate::collection::batteries::BatteryState;
+
+    data.battery_harvest
+        .iter()
+        .map(|battery| {
+            let (state, time_to_full_secs, time_to_empty_secs) = match battery.state {
+                BatteryState::Charging { time_to_full } => ("charging", time_to_full, None),
+                BatteryState::Discharging { time_to_empty } => ("discharging", None, time_to_empty),
+                BatteryState::Empty => ("empty", None, None),
+                BatteryState::Full => ("full", None, None),
+                BatteryState::Unknown => ("unknown", None, None),
+            };
+
+            Battery {
+                charge_percent: battery.charge_percent,
+                power_watts: battery.power_consumption,
+                health_percent: battery.health_percent,
+                state,
+                time_to_full_secs,
+                time_to_empty_secs,
+                cycle_count: battery.cycle_count,
+                vendor: battery.vendor.as_deref(),
+                model: battery.model.as_deref(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(not(feature = "battery"))]
+fn batteries(_data: &StoredData) -> Vec<Battery<'_>> {
+    vec![]
+}
+
+/// GPU stats and memory are collected separately, so they are matched up by name, in order.
+#[cfg(feature = "gpu")]
+fn gpus(data: &StoredData) -> Vec<Gpu<'_>> {
+    let mut memory = data
+        .gpu_harvest
+        .iter()
+        .map(|(name, mem)| Some((name.as_str(), mem)))
+        .collect::<Vec<_>>();
+
+    let mut gpus = data
+        .gpu_stats_harvest
+        .iter()
+        .map(|stats| {
+            let mem = memory
+                .iter_mut()
+                .find(|entry| matches!(entry, Some((name, _)) if *name == stats.name))
+                .and_then(Option::take)
+                .map(|(_, mem)| MemUsage::from(mem));
+
+            Gpu {
+                id: Some(&stats.id),
+                name: &stats.name,
+                utilization_percent: stats.utilization,
+                encoder_percent: stats.encoder,
+                decoder_percent: stats.decoder,
+                temperature_celsius: stats.temperature,
+                power_watts: stats.power,
+                power_limit_watts: stats.power_limit,
+                core_clock_mhz: stats.core_clock,
+                memory_clock_mhz: stats.memory_clock,
+                fan_percent: stats.fan,
+                memory: mem,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    gpus.extend(memory.into_iter().flatten().map(|(name, mem)| Gpu {
+        id: None,
+        name,
+        utilization_percent: None,
+        encoder_percent: None,
+        decoder_percent: None,
+        temperature_celsius: None,
+        power_watts: None,
+        power_limit_watts: None,
+        core_clock_mhz: None,
+        memory_clock_mhz: None,
+        fan_percent: None,
+        memory: Some(MemUsage::from(mem)),
+    }));
+
+    gpus
+}
+
+#[cfg(not(feature = "gpu"))]
+fn gpus(_data: &StoredData) -> Vec<Gpu<'_>> {
+    vec![]
+}
+
+fn processes(data: &StoredData) -> Vec<Process<'_>> {
+    data.process_data
+        .process_harvest
+        .values()
+        .map(|process| {
+            #[cfg(unix)]
+            let nice = Some(process.nice);
+            #[cfg(not(unix))]
+            let nice = None;
+
+            #[cfg(feature = "gpu")]
+            let (gpu_mem_bytes, gpu_util_percent) = (Some(process.gpu_mem), Some(process.gpu_util));
+            #[cfg(not(feature = "gpu"))]
+            let (gpu_mem_bytes, gpu_util_percent) = (None, None);
+
+            Process {
+                pid: process.pid as i64,
+                parent_pid: process.parent_pid.map(|pid| pid as i64),
+                name: &process.name,
+                command: &process.command,
+                user: process.user.as_deref(),
+                state: process.process_state.0,
+                cpu_percent: process.cpu_usage_percent,
+                mem_percent: process.mem_usage_percent,
+                mem_bytes: process.mem_usage,
+                virtual_mem_bytes: process.virtual_mem,
+                read_bytes_per_sec: process.read_per_sec,
+                write_bytes_per_sec: process.write_per_sec,
+                total_read_bytes: process.total_read,
+                total_write_bytes: process.total_write,
+                time_secs: process.time.as_secs(),
+                priority: process.priority,
+                nice,
+                gpu_mem_bytes,
+                gpu_util_percent,
+            }
+        })
+        .collect()
+}
+
+/// Collects data twice, one update interval apart so that rates can be calculated, then
+/// prints a snapshot of it as JSON.
+pub(crate) fn print_snapshot(app: &mut App) -> anyhow::Result<()> {
+    let used_widgets = app.used_widgets;
+    let settings = CollectorSettings {
+        show_average_cpu: true,
+        ..CollectorSettings::new(&app.app_config_fields)
+    };
+    let mut collector = create_data_collector(
+        settings,
+        app.filters.clone(),
+        crate::app::layout_manager::UsedWidgets {
+            use_cpu: true,
+            use_mem: true,
+            use_net: true,
+            use_proc: true,
+            use_disk: true,
+            use_temp: true,
+            use_battery: cfg!(feature = "battery"),
+            use_gpu_stats: used_widgets.use_gpu,
+            ..used_widgets
+        },
+    );
+
+    let interval_ms = app.app_config_fields.update_rate;
+
+    collector.update_data();
+    app.data_store.eat_data(
+        Box::new(std::mem::take(&mut collector.data)),
+        &app.app_config_fields,
+    );
+
+    thread::sleep(Duration::from_millis(interval_ms));
+
+    collector.update_data();
+    app.data_store.eat_data(
+        Box::new(std::mem::take(&mut collector.data)),
+        &app.app_config_fields,
+    );
+
+    let snapshot = Snapshot::new(app.data_store.get_data(), interval_ms);
+
+    let mut out = stdout().lock();
+    serde_json::to_writer_pretty(&mut out, &snapshot)?;
+    writeln!(out)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU64;
+
+    use super::*;
+    use crate::collection::cpu::CpuData;
+
+    #[test]
+    fn empty_snapshot() {
+        let data = StoredData::default();
+        let json = serde_json::to_value(Snapshot::new(&data, 1000)).unwrap();
+
+        assert_eq!(json["version"], SNAPSHOT_VERSION);
+        assert_eq!(json["interval_ms"], 1000);
+        assert!(json["cpu"]["average"].is_null());
+        assert!(json["memory"]["ram"].is_null());
+        assert_eq!(json["network"]["interfaces"], serde_json::json!([]));
+
+        for key in ["disks", "sensors", "batteries", "gpus", "processes"] {
+            assert_eq!(json[key], serde_json::json!([]), "{key} should be empty");
+        }
+    }
+
+    #[test]
+    fn cpu_and_memory() {
+        let data = StoredData {
+            cpu_harvest: vec![
+                CpuData {
+                    data_type: CpuDataType::Avg,
+                    usage: 50.0,
+                },
+                CpuData {
+                    data_type: CpuDataType::Cpu(0),
+                    usage: 25.0,
+                },
+                CpuData {
+                    data_type: CpuDataType::Cpu(1),
+                    usage: 75.0,
+                },
+            ],
+            ram_harvest: Some(MemData {
+                used_bytes: 256,
+                total_bytes: NonZeroU64::new(1024).unwrap(),
+            }),
+            ..Default::default()
+        };
+        let json = serde_json::to_value(Snapshot::new(&data, 1000)).unwrap();
+
+        assert_eq!(json["cpu"]["average"], 50.0);
+        assert_eq!(
+            json["cpu"]["cores"],
+            serde_json::json!([
+                { "core": 0, "usage_percent": 25.0 },
+                { "core": 1, "usage_percent": 75.0 },
+            ])
+        );
+        assert_eq!(
+            json["memory"]["ram"],
+            serde_json::json!({ "used_bytes": 256, "total_bytes": 1024, "used_percent": 25.0 })
+        );
+    }
+}
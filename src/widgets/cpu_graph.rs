@@ -12,7 +12,7 @@ use crate::{
             DataToCell,
         },
     },
-    collection::cpu::{CpuData, CpuDataType},
+    collection::cpu::{CpuData, CpuDataType, CpuPower},
     localization::is_japanese,
     options::config::{cpu::CpuDefault, style::Styles},
 };
@@ -39,7 +39,13 @@ impl ColumnHeader for CpuWidgetColumn {
 
 pub enum CpuWidgetTableData {
     All,
-    Entry { data_type: CpuDataType, usage: f32 },
+    Entry {
+        data_type: CpuDataType,
+        usage: f32,
+    },
+    /// Power draw, listed after the CPUs. These don't have their own graph line, so they can't
+    /// be selected.
+    Power(CpuPower),
 }
 
 impl CpuWidgetTableData {
@@ -77,6 +83,10 @@ impl DataToCell<CpuWidgetColumn> for CpuWidgetTableData {
                 }
                 CpuWidgetColumn::Use => None,
             },
+            CpuWidgetTableData::Power(power) => match column {
+                CpuWidgetColumn::Cpu => Some(power.domain.short_name().into()),
+                CpuWidgetColumn::Use => Some(format!("{:.0}W", power.watts).into()),
+            },
             CpuWidgetTableData::Entry {
                 data_type,
                 usage: last_entry,
@@ -101,6 +111,17 @@ impl DataToCell<CpuWidgetColumn> for CpuWidgetTableData {
                                     concat_string!("CPU", index_str).into()
                                 };
 
+                                Some(text)
+                            }
+                            #[cfg(target_os = "linux")]
+                            CpuDataType::Node(node) => {
+                                let node_str = node.to_string();
+                                let text = if calculated_width < CPU_TRUNCATE_BREAKPOINT {
+                                    concat_string!("N", node_str).into()
+                                } else {
+                                    concat_string!("Node", node_str).into()
+                                };
+
                                 Some(text)
                             }
                         },
@@ -118,12 +139,8 @@ impl DataToCell<CpuWidgetColumn> for CpuWidgetTableData {
             CpuWidgetTableData::Entry {
                 data_type,
                 usage: _,
-            } => match data_type {
-                CpuDataType::Avg => painter.styles.avg_cpu_colour,
-                CpuDataType::Cpu(index) => {
-                    painter.styles.cpu_colour_styles[index % painter.styles.cpu_colour_styles.len()]
-                }
-            },
+            } => painter.styles.cpu_style(*data_type),
+            CpuWidgetTableData::Power(_) => painter.styles.text_style,
         };
 
         row.style(style)
@@ -145,6 +162,10 @@ pub struct CpuWidgetState {
     pub autohide_timer: Option<Instant>,
     pub table: DataTable<CpuWidgetTableData, CpuWidgetColumn>,
     pub force_update_data: bool,
+
+    /// How many legend entries can be selected. The power entries come last and can't be, as
+    /// they have no line in the graph.
+    num_selectable: usize,
 }
 
 impl CpuWidgetState {
@@ -182,6 +203,7 @@ impl CpuWidgetState {
             autohide_timer,
             table,
             force_update_data: false,
+            num_selectable: 1,
         }
     }
 
@@ -191,12 +213,75 @@ impl CpuWidgetState {
         self.force_update_data = true;
     }
 
-    pub fn set_legend_data(&mut self, data: &[CpuData]) {
+    pub fn set_legend_data(&mut self, data: &[CpuData], power: &[CpuPower]) {
         self.table.set_data(
             std::iter::once(CpuWidgetTableData::All)
                 .chain(data.iter().map(CpuWidgetTableData::from_cpu_data))
+                .chain(power.iter().copied().map(CpuWidgetTableData::Power))
                 .collect(),
         );
+        self.num_selectable = 1 + data.len();
+        self.clamp_selection();
         self.force_update_data = false;
     }
+
+    /// Moves the legend selection by `change`, stopping before the power entries.
+    pub fn increment_legend_position(&mut self, change: i64) {
+        self.table.increment_position(change);
+        self.clamp_selection();
+    }
+
+    /// Moves the legend selection to the last entry that isn't a power entry.
+    pub fn scroll_legend_to_last(&mut self) {
+        self.table.scroll_to_last();
+        self.clamp_selection();
+    }
+
+    fn clamp_selection(&mut self) {
+        if self.table.current_index() >= self.num_selectable {
+            self.table.set_position(self.num_selectable - 1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::collection::cpu::PowerDomain;
+
+    #[test]
+    fn power_entries_are_not_selectable() {
+        let mut state = CpuWidgetState::new(
+            &AppConfigFields::default(),
+            CpuDefault::All,
+            60_000,
+            None,
+            &Styles::default(),
+        );
+        let cpus = [
+            CpuData {
+                data_type: CpuDataType::Cpu(0),
+                usage: 10.0,
+            },
+            CpuData {
+                data_type: CpuDataType::Cpu(1),
+                usage: 20.0,
+            },
+        ];
+        let power = [CpuPower {
+            domain: PowerDomain::Package,
+            watts: 15.0,
+        }];
+        state.set_legend_data(&cpus, &power);
+
+        state.increment_legend_position(10);
+        assert_eq!(state.table.current_index(), 2);
+
+        state.scroll_legend_to_last();
+        assert_eq!(state.table.current_index(), 2);
+
+        // The selection also moves back if the CPU entries go away.
+        state.set_legend_data(&cpus[..1], &power);
+        assert_eq!(state.table.current_index(), 1);
+    }
 }
@@ -1,4 +1,4 @@
-use std::{borrow::Cow, cmp::max, num::NonZeroU16};
+use std::{borrow::Cow, cmp::max, num::NonZeroU16, time::Duration};
 
 use serde::Deserialize;
 
@@ -8,11 +8,12 @@ use crate::{
         ColumnHeader, DataTableColumn, DataTableProps, DataTableStyling, DataToCell, SortColumn,
         SortDataTable, SortDataTableProps, SortOrder, SortsRow,
     },
+    collection::disks::{BtrfsAllocation, BtrfsChunkUsage, IoDetailRates},
     localization::{is_japanese, title_disks},
     options::config::style::Styles,
     utils::{
         conversion::dec_bytes_per_second_string,
-        data_units::get_decimal_bytes,
+        data_units::{get_decimal_bytes, get_unit_prefix},
         general::sort_partial_fn,
         text_width::{TextWidthMode, display_width},
     },
@@ -28,9 +29,33 @@ pub struct DiskWidgetData {
     pub summed_total_bytes: Option<u64>,
     pub io_read_rate_bytes: Option<u64>,
     pub io_write_rate_bytes: Option<u64>,
+    pub io_detail_rates: Option<IoDetailRates>,
+    pub fs_type: Option<String>,
+    pub mount_options: Option<String>,
+    pub inodes_free: Option<u64>,
+    pub inodes_used: Option<u64>,
+    pub inodes_total: Option<u64>,
+    pub btrfs: Option<BtrfsAllocation>,
+
+    /// How long until the filesystem fills up at its current rate of growth, if it's growing.
+    pub eta_full: Option<Duration>,
+
+    /// The name shown in tree mode, including the tree branches.
+    pub tree_name: Option<String>,
+
+    /// The state of a ZFS pool (e.g. `DEGRADED`), shown in place of the mount point.
+    pub pool_state: Option<String>,
 }
 
 impl DiskWidgetData {
+    /// The text of the mount column.
+    fn mount_text(&self) -> &str {
+        match &self.pool_state {
+            Some(state) if self.mount_point.is_empty() => state,
+            _ => &self.mount_point,
+        }
+    }
+
     fn total_space(&self) -> Cow<'static, str> {
         if let Some(total_bytes) = self.total_bytes {
             let converted_total_space = get_decimal_bytes(total_bytes);
@@ -78,7 +103,7 @@ impl DiskWidgetData {
         }
     }
 
-    fn used_percent(&self) -> Option<f64> {
+    pub(crate) fn used_percent(&self) -> Option<f64> {
         if let (Some(used_bytes), Some(summed_total_bytes)) =
             (self.used_bytes, self.summed_total_bytes)
         {
@@ -117,6 +142,42 @@ impl DiskWidgetData {
             |w_rate| dec_bytes_per_second_string(w_rate).into(),
         )
     }
+
+    fn read_ops(&self) -> Option<f64> {
+        self.io_detail_rates.map(|rates| rates.read_ops)
+    }
+
+    fn write_ops(&self) -> Option<f64> {
+        self.io_detail_rates.map(|rates| rates.write_ops)
+    }
+
+    fn await_ms(&self) -> Option<f64> {
+        self.io_detail_rates.map(|rates| rates.await_ms)
+    }
+
+    fn util_percent(&self) -> Option<f64> {
+        self.io_detail_rates.map(|rates| rates.util_percent)
+    }
+
+    fn btrfs_chunk(
+        &self, chunk: fn(&BtrfsAllocation) -> BtrfsChunkUsage,
+    ) -> Option<BtrfsChunkUsage> {
+        self.btrfs.as_ref().map(chunk)
+    }
+
+    /// Whether this is a btrfs filesystem whose metadata space is nearly exhausted.
+    pub fn is_btrfs_metadata_nearly_full(&self) -> bool {
+        self.btrfs
+            .as_ref()
+            .is_some_and(BtrfsAllocation::is_metadata_nearly_full)
+    }
+
+    fn inodes_used_percent(&self) -> Option<f64> {
+        match (self.inodes_used, self.inodes_total) {
+            (Some(used), Some(total)) if total > 0 => Some(used as f64 / total as f64 * 100_f64),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -135,6 +196,20 @@ pub enum DiskColumn {
     FreePercent,
     IoRead,
     IoWrite,
+    ReadIops,
+    WriteIops,
+    Await,
+    UtilPercent,
+    FsType,
+    MountOptions,
+    InodesUsed,
+    InodesFree,
+    InodesTotal,
+    InodesUsedPercent,
+    BtrfsData,
+    BtrfsMetadata,
+    BtrfsSystem,
+    EtaFull,
 }
 
 impl<'de> Deserialize<'de> for DiskColumn {
@@ -153,6 +228,20 @@ impl<'de> Deserialize<'de> for DiskColumn {
             "freepercent" | "free%" => Ok(DiskColumn::FreePercent),
             "r/s" => Ok(DiskColumn::IoRead),
             "w/s" => Ok(DiskColumn::IoWrite),
+            "r/iops" => Ok(DiskColumn::ReadIops),
+            "w/iops" => Ok(DiskColumn::WriteIops),
+            "await" => Ok(DiskColumn::Await),
+            "util%" | "util" => Ok(DiskColumn::UtilPercent),
+            "fs_type" | "fs" => Ok(DiskColumn::FsType),
+            "options" | "mount_options" => Ok(DiskColumn::MountOptions),
+            "iused" => Ok(DiskColumn::InodesUsed),
+            "ifree" => Ok(DiskColumn::InodesFree),
+            "itotal" => Ok(DiskColumn::InodesTotal),
+            "iused%" => Ok(DiskColumn::InodesUsedPercent),
+            "data" => Ok(DiskColumn::BtrfsData),
+            "metadata" | "meta" => Ok(DiskColumn::BtrfsMetadata),
+            "system" | "sys" => Ok(DiskColumn::BtrfsSystem),
+            "eta_full" | "eta full" | "eta" => Ok(DiskColumn::EtaFull),
             _ => Err(serde::de::Error::custom(
                 "doesn't match any disk column name",
             )),
@@ -174,6 +263,20 @@ impl DiskColumn {
             DiskColumn::FreePercent => &["Free%"],
             DiskColumn::IoRead => &["R/s", "Read", "Rps"],
             DiskColumn::IoWrite => &["W/s", "Write", "Wps"],
+            DiskColumn::ReadIops => &["R/IOPS"],
+            DiskColumn::WriteIops => &["W/IOPS"],
+            DiskColumn::Await => &["Await"],
+            DiskColumn::UtilPercent => &["Util%", "Util"],
+            DiskColumn::FsType => &["FS_Type", "FS"],
+            DiskColumn::MountOptions => &["Options", "Mount_Options"],
+            DiskColumn::InodesUsed => &["IUsed"],
+            DiskColumn::InodesFree => &["IFree"],
+            DiskColumn::InodesTotal => &["ITotal"],
+            DiskColumn::InodesUsedPercent => &["IUsed%"],
+            DiskColumn::BtrfsData => &["Data"],
+            DiskColumn::BtrfsMetadata => &["Metadata", "Meta"],
+            DiskColumn::BtrfsSystem => &["System", "Sys"],
+            DiskColumn::EtaFull => &["ETA_Full", "ETA"],
         }
     }
 }
@@ -232,6 +335,92 @@ impl ColumnHeader for DiskColumn {
             }
             DiskColumn::IoRead => "R/s(r)",
             DiskColumn::IoWrite => "W/s(w)",
+            DiskColumn::ReadIops => "R/IOPS",
+            DiskColumn::WriteIops => "W/IOPS",
+            DiskColumn::Await => {
+                if is_japanese() {
+                    "待ち時間"
+                } else {
+                    "Await"
+                }
+            }
+            DiskColumn::UtilPercent => {
+                if is_japanese() {
+                    "稼働率%"
+                } else {
+                    "Util%"
+                }
+            }
+            DiskColumn::FsType => {
+                if is_japanese() {
+                    "FS種別"
+                } else {
+                    "FS Type"
+                }
+            }
+            DiskColumn::MountOptions => {
+                if is_japanese() {
+                    "オプション"
+                } else {
+                    "Options"
+                }
+            }
+            DiskColumn::InodesUsed => {
+                if is_japanese() {
+                    "使用inode"
+                } else {
+                    "IUsed"
+                }
+            }
+            DiskColumn::InodesFree => {
+                if is_japanese() {
+                    "空きinode"
+                } else {
+                    "IFree"
+                }
+            }
+            DiskColumn::InodesTotal => {
+                if is_japanese() {
+                    "合計inode"
+                } else {
+                    "ITotal"
+                }
+            }
+            DiskColumn::InodesUsedPercent => {
+                if is_japanese() {
+                    "inode使用%"
+                } else {
+                    "IUsed%"
+                }
+            }
+            DiskColumn::BtrfsData => {
+                if is_japanese() {
+                    "データ"
+                } else {
+                    "Data"
+                }
+            }
+            DiskColumn::BtrfsMetadata => {
+                if is_japanese() {
+                    "メタデータ"
+                } else {
+                    "Metadata"
+                }
+            }
+            DiskColumn::BtrfsSystem => {
+                if is_japanese() {
+                    "システム"
+                } else {
+                    "System"
+                }
+            }
+            DiskColumn::EtaFull => {
+                if is_japanese() {
+                    "満杯予測"
+                } else {
+                    "ETA Full"
+                }
+            }
         }
         .into()
     }
@@ -255,9 +444,123 @@ impl DataToCell<DiskColumn> for DiskWidgetData {
             }
         }
 
+        fn ops_string(value: Option<f64>) -> Cow<'static, str> {
+            match value {
+                Some(val) => format!("{val:.1}").into(),
+                None => {
+                    if is_japanese() {
+                        "該当なし".into()
+                    } else {
+                        "N/A".into()
+                    }
+                }
+            }
+        }
+
+        fn ms_string(value: Option<f64>) -> Cow<'static, str> {
+            match value {
+                Some(val) => format!("{val:.2}ms").into(),
+                None => {
+                    if is_japanese() {
+                        "該当なし".into()
+                    } else {
+                        "N/A".into()
+                    }
+                }
+            }
+        }
+
+        fn count_string(value: Option<u64>) -> Cow<'static, str> {
+            match value {
+                Some(val) => {
+                    let (val, unit) = get_unit_prefix(val, false);
+                    if unit.is_empty() {
+                        format!("{val:.0}").into()
+                    } else {
+                        format!("{val:.1}{unit}").into()
+                    }
+                }
+                None => {
+                    if is_japanese() {
+                        "該当なし".into()
+                    } else {
+                        "N/A".into()
+                    }
+                }
+            }
+        }
+
+        /// Shows used space out of allocated space, e.g. `12G/20G`.
+        fn chunk_string(value: Option<BtrfsChunkUsage>, warn: bool) -> Cow<'static, str> {
+            match value {
+                Some(chunk) => {
+                    let (used, used_unit) = get_decimal_bytes(chunk.used_bytes);
+                    let (total, total_unit) = get_decimal_bytes(chunk.total_bytes);
+                    let marker = if warn { "!" } else { "" };
+                    format!("{used:.0}{used_unit}/{total:.0}{total_unit}{marker}").into()
+                }
+                None => {
+                    if is_japanese() {
+                        "該当なし".into()
+                    } else {
+                        "N/A".into()
+                    }
+                }
+            }
+        }
+
+        fn eta_string(value: Option<Duration>) -> Cow<'static, str> {
+            const SECS_PER_MINUTE: u64 = 60;
+            const SECS_PER_HOUR: u64 = 60 * SECS_PER_MINUTE;
+            const SECS_PER_DAY: u64 = 24 * SECS_PER_HOUR;
+
+            match value {
+                Some(eta) => {
+                    let secs = eta.as_secs();
+                    let (days, hours, minutes) = (
+                        secs / SECS_PER_DAY,
+                        secs % SECS_PER_DAY / SECS_PER_HOUR,
+                        secs % SECS_PER_HOUR / SECS_PER_MINUTE,
+                    );
+
+                    if days >= 365 {
+                        ">365d".into()
+                    } else if days > 0 {
+                        format!("{days}d{hours}h").into()
+                    } else if hours > 0 {
+                        format!("{hours}h{minutes}m").into()
+                    } else if minutes > 0 {
+                        format!("{minutes}m").into()
+                    } else {
+                        "<1m".into()
+                    }
+                }
+                None => {
+                    if is_japanese() {
+                        "該当なし".into()
+                    } else {
+                        "N/A".into()
+                    }
+                }
+            }
+        }
+
+        fn text_string(value: &Option<String>) -> Cow<'static, str> {
+            match value {
+                Some(val) => val.clone().into(),
+                None => {
+                    if is_japanese() {
+                        "該当なし".into()
+                    } else {
+                        "N/A".into()
+                    }
+                }
+            }
+        }
+
         let text = match column {
-            DiskColumn::Disk => self.name.clone().into(),
-            DiskColumn::Mount => self.mount_point.clone().into(),
+            DiskColumn::Disk => self.tree_name.as_ref().unwrap_or(&self.name).clone().into(),
+            DiskColumn::Mount => self.mount_text().to_string().into(),
             DiskColumn::Used => self.used_space(),
             DiskColumn::Free => self.free_space(),
             DiskColumn::UsedPercent => percent_string(self.used_percent()),
@@ -265,6 +568,23 @@ impl DataToCell<DiskColumn> for DiskWidgetData {
             DiskColumn::Total => self.total_space(),
             DiskColumn::IoRead => self.io_read(),
             DiskColumn::IoWrite => self.io_write(),
+            DiskColumn::ReadIops => ops_string(self.read_ops()),
+            DiskColumn::WriteIops => ops_string(self.write_ops()),
+            DiskColumn::Await => ms_string(self.await_ms()),
+            DiskColumn::UtilPercent => percent_string(self.util_percent()),
+            DiskColumn::FsType => text_string(&self.fs_type),
+            DiskColumn::MountOptions => text_string(&self.mount_options),
+            DiskColumn::InodesUsed => count_string(self.inodes_used),
+            DiskColumn::InodesFree => count_string(self.inodes_free),
+            DiskColumn::InodesTotal => count_string(self.inodes_total),
+            DiskColumn::InodesUsedPercent => percent_string(self.inodes_used_percent()),
+            DiskColumn::BtrfsData => chunk_string(self.btrfs_chunk(|btrfs| btrfs.data), false),
+            DiskColumn::BtrfsMetadata => chunk_string(
+                self.btrfs_chunk(|btrfs| btrfs.metadata),
+                self.is_btrfs_metadata_nearly_full(),
+            ),
+            DiskColumn::BtrfsSystem => chunk_string(self.btrfs_chunk(|btrfs| btrfs.system), false),
+            DiskColumn::EtaFull => eta_string(self.eta_full),
         };
 
         Some(text)
@@ -279,10 +599,11 @@ impl DataToCell<DiskColumn> for DiskWidgetData {
         let mut widths = vec![0; 7];
 
         data.iter().for_each(|row| {
-            widths[0] = max(widths[0], display_width(&row.name, width_mode) as u16);
+            let name = row.tree_name.as_ref().unwrap_or(&row.name);
+            widths[0] = max(widths[0], display_width(name, width_mode) as u16);
             widths[1] = max(
                 widths[1],
-                display_width(&row.mount_point, width_mode) as u16,
+                display_width(row.mount_text(), width_mode) as u16,
             );
         });
 
@@ -293,6 +614,10 @@ impl DataToCell<DiskColumn> for DiskWidgetData {
 pub struct DiskTableWidget {
     pub table: SortDataTable<DiskWidgetData, DiskColumn>,
     pub force_update_data: bool,
+
+    /// Whether to show block devices as a tree, from physical disks down to
+    /// their mounted filesystems. Currently Linux-only.
+    pub tree: bool,
 }
 
 impl SortsRow for DiskColumn {
@@ -335,6 +660,58 @@ impl SortsRow for DiskColumn {
                     sort_partial_fn(descending)(&a.io_write_rate_bytes, &b.io_write_rate_bytes)
                 });
             }
+            DiskColumn::ReadIops => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(&a.read_ops(), &b.read_ops()));
+            }
+            DiskColumn::WriteIops => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(&a.write_ops(), &b.write_ops()));
+            }
+            DiskColumn::Await => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(&a.await_ms(), &b.await_ms()));
+            }
+            DiskColumn::UtilPercent => {
+                data.sort_by(|a, b| {
+                    sort_partial_fn(descending)(&a.util_percent(), &b.util_percent())
+                });
+            }
+            DiskColumn::FsType => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(&a.fs_type, &b.fs_type));
+            }
+            DiskColumn::MountOptions => {
+                data.sort_by(|a, b| {
+                    sort_partial_fn(descending)(&a.mount_options, &b.mount_options)
+                });
+            }
+            DiskColumn::InodesUsed => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(&a.inodes_used, &b.inodes_used));
+            }
+            DiskColumn::InodesFree => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(&a.inodes_free, &b.inodes_free));
+            }
+            DiskColumn::InodesTotal => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(&a.inodes_total, &b.inodes_total));
+            }
+            DiskColumn::InodesUsedPercent => {
+                data.sort_by(|a, b| {
+                    sort_partial_fn(descending)(&a.inodes_used_percent(), &b.inodes_used_percent())
+                });
+            }
+            DiskColumn::BtrfsData | DiskColumn::BtrfsMetadata | DiskColumn::BtrfsSystem => {
+                let chunk: fn(&BtrfsAllocation) -> BtrfsChunkUsage = match self {
+                    DiskColumn::BtrfsData => |btrfs| btrfs.data,
+                    DiskColumn::BtrfsMetadata => |btrfs| btrfs.metadata,
+                    _ => |btrfs| btrfs.system,
+                };
+                let used_percent =
+                    |row: &DiskWidgetData| row.btrfs_chunk(chunk).and_then(|c| c.used_percent());
+
+                data.sort_by(|a, b| {
+                    sort_partial_fn(descending)(&used_percent(a), &used_percent(b))
+                });
+            }
+            DiskColumn::EtaFull => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(&a.eta_full, &b.eta_full));
+            }
         }
     }
 }
@@ -354,6 +731,30 @@ const fn create_column(column_type: &DiskColumn) -> SortColumn<DiskColumn> {
         }
         DiskColumn::IoRead => SortColumn::hard(DiskColumn::IoRead, 10).default_descending(),
         DiskColumn::IoWrite => SortColumn::hard(DiskColumn::IoWrite, 11).default_descending(),
+        DiskColumn::ReadIops => SortColumn::hard(DiskColumn::ReadIops, 8).default_descending(),
+        DiskColumn::WriteIops => SortColumn::hard(DiskColumn::WriteIops, 8).default_descending(),
+        DiskColumn::Await => SortColumn::hard(DiskColumn::Await, 10).default_descending(),
+        DiskColumn::UtilPercent => {
+            SortColumn::hard(DiskColumn::UtilPercent, 8).default_descending()
+        }
+        DiskColumn::FsType => SortColumn::hard(DiskColumn::FsType, 9),
+        DiskColumn::MountOptions => SortColumn::soft(DiskColumn::MountOptions, Some(0.2)),
+        DiskColumn::InodesUsed => SortColumn::hard(DiskColumn::InodesUsed, 9).default_descending(),
+        DiskColumn::InodesFree => SortColumn::hard(DiskColumn::InodesFree, 9).default_descending(),
+        DiskColumn::InodesTotal => {
+            SortColumn::hard(DiskColumn::InodesTotal, 9).default_descending()
+        }
+        DiskColumn::InodesUsedPercent => {
+            SortColumn::hard(DiskColumn::InodesUsedPercent, 10).default_descending()
+        }
+        DiskColumn::BtrfsData => SortColumn::hard(DiskColumn::BtrfsData, 12).default_descending(),
+        DiskColumn::BtrfsMetadata => {
+            SortColumn::hard(DiskColumn::BtrfsMetadata, 12).default_descending()
+        }
+        DiskColumn::BtrfsSystem => {
+            SortColumn::hard(DiskColumn::BtrfsSystem, 12).default_descending()
+        }
+        DiskColumn::EtaFull => SortColumn::hard(DiskColumn::EtaFull, 9),
     }
 }
 
@@ -371,7 +772,9 @@ const fn default_disk_columns() -> [SortColumn<DiskColumn>; 8] {
 }
 
 impl DiskTableWidget {
-    pub fn new(config: &AppConfigFields, palette: &Styles, columns: Option<&[DiskColumn]>) -> Self {
+    pub fn new(
+        config: &AppConfigFields, palette: &Styles, columns: Option<&[DiskColumn]>, tree: bool,
+    ) -> Self {
         let props = SortDataTableProps {
             inner: DataTableProps {
                 title: Some(title_disks().into()),
@@ -393,11 +796,13 @@ impl DiskTableWidget {
                 Self {
                     table: SortDataTable::new_sortable(columns, props, styling),
                     force_update_data: false,
+                    tree,
                 }
             }
             None => Self {
                 table: SortDataTable::new_sortable(default_disk_columns(), props, styling),
                 force_update_data: false,
+                tree,
             },
         }
     }
@@ -408,8 +813,22 @@ impl DiskTableWidget {
         self.force_update_data = true;
     }
 
+    /// Toggles between showing mounted filesystems and a tree of block devices.
+    pub fn toggle_tree(&mut self) {
+        self.tree = !self.tree;
+        self.force_data_update();
+    }
+
     /// Update the current table data.
     pub fn set_table_data(&mut self, data: &StoredData) {
+        #[cfg(target_os = "linux")]
+        if self.tree {
+            let data = self.tree_data(data);
+            self.table.set_data(data);
+            self.force_update_data = false;
+            return;
+        }
+
         let mut data = data.disk_harvest.clone();
 
         if let Some(column) = self.table.columns.get(self.table.sort_index()) {
@@ -419,8 +838,300 @@ impl DiskTableWidget {
         self.force_update_data = false;
     }
 
+    /// Builds the rows for tree mode. Only devices that a shown filesystem is
+    /// mounted on, along with the devices they're built on top of, are included.
+    /// Siblings are sorted by the current sort column.
+    #[cfg(target_os = "linux")]
+    fn tree_data(&self, data: &StoredData) -> Vec<DiskWidgetData> {
+        use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+        const BRANCH_END: char = '└';
+        const BRANCH_SPLIT: char = '├';
+        const BRANCH_HORIZONTAL: char = '─';
+        const SPACED_BRANCH_VERTICAL: &str = "│  ";
+
+        /// Returns the kernel name of a device, e.g. `dm-0` for `/dev/dm-0`.
+        fn kernel_name(name: &str) -> &str {
+            name.rsplit('/').next().unwrap_or(name)
+        }
+
+        let topology = &data.block_topology;
+
+        // Group the mounted filesystems by their device's kernel name.
+        let mut mounts: HashMap<&str, Vec<&DiskWidgetData>> = HashMap::default();
+        for disk in &data.disk_harvest {
+            mounts
+                .entry(kernel_name(&disk.name))
+                .or_default()
+                .push(disk);
+        }
+
+        // Find every device that's needed to reach a mounted filesystem.
+        let mut shown: HashSet<&str> = HashSet::default();
+        let mut stack = mounts.keys().copied().collect::<Vec<_>>();
+        while let Some(name) = stack.pop() {
+            if shown.insert(name) {
+                if let Some(device) = topology.get(name) {
+                    stack.extend(device.parents.iter().map(String::as_str));
+                }
+            }
+        }
+
+        let mut children: HashMap<&str, Vec<&str>> = HashMap::default();
+        let mut roots = vec![];
+        for &name in &shown {
+            let parents = topology
+                .get(name)
+                .map(|device| {
+                    device
+                        .parents
+                        .iter()
+                        .filter(|parent| shown.contains(parent.as_str()))
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+
+            if parents.is_empty() {
+                roots.push(name);
+            } else {
+                for parent in parents {
+                    children.entry(parent.as_str()).or_default().push(name);
+                }
+            }
+        }
+
+        let row = |name: &str| -> DiskWidgetData {
+            let mut row = match mounts.get(name) {
+                Some(disks) => {
+                    // Filesystems mounted in several places share the same usage.
+                    let mut row = disks[0].clone();
+                    row.mount_point = disks
+                        .iter()
+                        .map(|disk| disk.mount_point.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    row
+                }
+                None => DiskWidgetData {
+                    name: format!("/dev/{name}"),
+                    mount_point: String::default(),
+                    free_bytes: None,
+                    used_bytes: None,
+                    total_bytes: None,
+                    summed_total_bytes: None,
+                    io_read_rate_bytes: None,
+                    io_write_rate_bytes: None,
+                    io_detail_rates: None,
+                    fs_type: None,
+                    mount_options: None,
+                    inodes_free: None,
+                    inodes_used: None,
+                    inodes_total: None,
+                    btrfs: None,
+                    eta_full: None,
+                    tree_name: None,
+                    pool_state: None,
+                },
+            };
+
+            // A whole disk's counters include everything on it, so this is where I/O
+            // is aggregated at the physical level.
+            if let Some((read, write)) = data.block_io_rates.get(name) {
+                row.io_read_rate_bytes = Some(*read);
+                row.io_write_rate_bytes = Some(*write);
+            }
+
+            row.tree_name = Some(
+                topology
+                    .get(name)
+                    .map(|device| device.label())
+                    .unwrap_or_else(|| row.name.clone()),
+            );
+
+            row
+        };
+
+        let column = self.table.columns.get(self.table.sort_index());
+        let sorted = |names: &[&str]| {
+            let mut rows = names.iter().map(|name| row(name)).collect::<Vec<_>>();
+            if let Some(column) = column {
+                column.sort_by(&mut rows, self.table.order());
+            }
+
+            rows
+        };
+
+        let mut output = vec![];
+        let mut prefixes: Vec<&str> = vec![];
+        let mut path: Vec<String> = vec![];
+        let mut stack = sorted(&roots);
+        stack.reverse();
+        let mut length_stack = vec![stack.len()];
+
+        while let (Some(mut row), Some(siblings_left)) = (stack.pop(), length_stack.last_mut()) {
+            let name = kernel_name(&row.name).to_string();
+            *siblings_left -= 1;
+            let is_last = *siblings_left == 0;
+
+            let prefix = if prefixes.is_empty() {
+                String::default()
+            } else {
+                format!(
+                    "{}{}{} ",
+                    prefixes.join(""),
+                    if is_last { BRANCH_END } else { BRANCH_SPLIT },
+                    BRANCH_HORIZONTAL
+                )
+            };
+            row.tree_name = Some(format!(
+                "{prefix}{}",
+                row.tree_name.take().unwrap_or_default()
+            ));
+            output.push(row);
+
+            // Guard against cycles, which shouldn't happen, but sysfs is outside our control.
+            let node_children = children
+                .get(name.as_str())
+                .map(|names| {
+                    names
+                        .iter()
+                        .copied()
+                        .filter(|child| !path.iter().any(|ancestor| ancestor == child))
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+
+            if node_children.is_empty() {
+                while let Some(children_left) = length_stack.last() {
+                    if *children_left == 0 {
+                        length_stack.pop();
+                        prefixes.pop();
+                        path.pop();
+                    } else {
+                        break;
+                    }
+                }
+            } else {
+                prefixes.push(if length_stack.len() == 1 {
+                    ""
+                } else if is_last {
+                    "   "
+                } else {
+                    SPACED_BRANCH_VERTICAL
+                });
+                path.push(name);
+
+                let mut node_children = sorted(&node_children);
+                node_children.reverse();
+                length_stack.push(node_children.len());
+                stack.extend(node_children);
+            }
+        }
+
+        output
+    }
+
     pub fn set_index(&mut self, index: usize) {
         self.table.set_sort_index(index);
         self.force_data_update();
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_tree_data() {
+        use crate::collection::disks::{BlockDevice, BlockDeviceKind};
+
+        fn mounted(name: &str, mount_point: &str) -> DiskWidgetData {
+            DiskWidgetData {
+                name: name.to_string(),
+                mount_point: mount_point.to_string(),
+                free_bytes: Some(1),
+                used_bytes: Some(1),
+                total_bytes: Some(2),
+                summed_total_bytes: Some(2),
+                io_read_rate_bytes: Some(1),
+                io_write_rate_bytes: Some(1),
+                io_detail_rates: None,
+                fs_type: None,
+                mount_options: None,
+                inodes_free: None,
+                inodes_used: None,
+                inodes_total: None,
+                btrfs: None,
+                eta_full: None,
+                tree_name: None,
+                pool_state: None,
+            }
+        }
+
+        fn device(name: &str, kind: BlockDeviceKind, parents: &[&str]) -> (String, BlockDevice) {
+            (
+                name.to_string(),
+                BlockDevice {
+                    name: name.to_string(),
+                    mapper_name: Some("luks".to_string()),
+                    kind,
+                    parents: parents.iter().map(|p| p.to_string()).collect(),
+                },
+            )
+        }
+
+        let block_topology = [
+            device("sda", BlockDeviceKind::Disk, &[]),
+            device("sdb", BlockDeviceKind::Disk, &[]),
+            device("sda1", BlockDeviceKind::Partition, &["sda"]),
+            device("sda2", BlockDeviceKind::Partition, &["sda"]),
+            device("dm-0", BlockDeviceKind::Crypt { luks: true }, &["sda2"]),
+            device(
+                "dm-1",
+                BlockDeviceKind::Lvm {
+                    vg: "vg".to_string(),
+                    lv: "root".to_string(),
+                },
+                &["dm-0"],
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        let data = StoredData {
+            disk_harvest: vec![
+                mounted("/dev/dm-1", "/"),
+                mounted("/dev/sda1", "/boot"),
+                mounted("/dev/dm-1", "/home"),
+            ],
+            block_topology,
+            block_io_rates: [("sda".to_string(), (100, 200))].into_iter().collect(),
+            ..Default::default()
+        };
+
+        let widget =
+            DiskTableWidget::new(&AppConfigFields::default(), &Styles::default(), None, true);
+        let rows = widget.tree_data(&data);
+
+        // Unused devices like `sdb` aren't shown.
+        assert_eq!(
+            rows.iter()
+                .map(|row| row.tree_name.as_deref().unwrap())
+                .collect::<Vec<_>>(),
+            vec![
+                "sda",
+                "├─ sda1",
+                "└─ sda2",
+                "   └─ luks (LUKS)",
+                "      └─ vg/root (LVM)",
+            ]
+        );
+
+        assert_eq!(rows[0].io_read_rate_bytes, Some(100));
+        assert_eq!(rows[0].io_write_rate_bytes, Some(200));
+        assert_eq!(rows[0].total_bytes, None);
+        assert_eq!(rows[1].mount_point, "/boot");
+        assert_eq!(rows[4].mount_point, "/, /home");
+    }
+}
@@ -0,0 +1,98 @@
+use std::time::Instant;
+
+use crate::localization::is_japanese;
+
+/// The metric plotted in a GPU widget.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GpuMetric {
+    #[default]
+    Utilization,
+    Encoder,
+    Decoder,
+    Temperature,
+    Power,
+    Clocks,
+    Fan,
+}
+
+impl GpuMetric {
+    const ALL: [GpuMetric; 7] = [
+        GpuMetric::Utilization,
+        GpuMetric::Encoder,
+        GpuMetric::Decoder,
+        GpuMetric::Temperature,
+        GpuMetric::Power,
+        GpuMetric::Clocks,
+        GpuMetric::Fan,
+    ];
+
+    fn index(self) -> usize {
+        Self::ALL
+            .iter()
+            .position(|metric| *metric == self)
+            .unwrap_or_default()
+    }
+
+    /// The metric after this one, wrapping around.
+    pub fn next(self) -> Self {
+        Self::ALL[(self.index() + 1) % Self::ALL.len()]
+    }
+
+    /// The metric before this one, wrapping around.
+    pub fn prev(self) -> Self {
+        Self::ALL[(self.index() + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+
+    pub fn name(self) -> &'static str {
+        if is_japanese() {
+            match self {
+                GpuMetric::Utilization => "使用率",
+                GpuMetric::Encoder => "エンコーダー",
+                GpuMetric::Decoder => "デコーダー",
+                GpuMetric::Temperature => "温度",
+                GpuMetric::Power => "消費電力",
+                GpuMetric::Clocks => "クロック",
+                GpuMetric::Fan => "ファン",
+            }
+        } else {
+            match self {
+                GpuMetric::Utilization => "Utilization",
+                GpuMetric::Encoder => "Encoder",
+                GpuMetric::Decoder => "Decoder",
+                GpuMetric::Temperature => "Temperature",
+                GpuMetric::Power => "Power",
+                GpuMetric::Clocks => "Clocks",
+                GpuMetric::Fan => "Fan",
+            }
+        }
+    }
+}
+
+pub struct GpuWidgetState {
+    pub current_display_time: u64,
+    pub autohide_timer: Option<Instant>,
+    pub metric: GpuMetric,
+}
+
+impl GpuWidgetState {
+    pub fn init(current_display_time: u64, autohide_timer: Option<Instant>) -> Self {
+        GpuWidgetState {
+            current_display_time,
+            autohide_timer,
+            metric: GpuMetric::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metric_cycling() {
+        assert_eq!(GpuMetric::Utilization.next(), GpuMetric::Encoder);
+        assert_eq!(GpuMetric::Fan.next(), GpuMetric::Utilization);
+        assert_eq!(GpuMetric::Utilization.prev(), GpuMetric::Fan);
+        assert_eq!(GpuMetric::Clocks.prev(), GpuMetric::Power);
+    }
+}
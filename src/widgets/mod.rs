@@ -1,15 +1,29 @@
 pub mod battery_info;
 pub mod cpu_graph;
+pub mod disk_io_graph;
 pub mod disk_table;
+pub mod gpu_graph;
 pub mod mem_graph;
+pub mod mem_pressure_graph;
+pub mod net_table;
 pub mod network_graph;
+pub mod power_graph;
 pub mod process_table;
+pub mod tcp_graph;
+pub mod temperature_graph;
 pub mod temperature_table;
 
 pub use battery_info::*;
 pub use cpu_graph::*;
+pub use disk_io_graph::*;
 pub use disk_table::*;
+pub use gpu_graph::*;
 pub use mem_graph::*;
+pub use mem_pressure_graph::*;
+pub use net_table::*;
 pub use network_graph::*;
+pub use power_graph::*;
 pub use process_table::*;
+pub use tcp_graph::*;
+pub use temperature_graph::*;
 pub use temperature_table::*;
@@ -0,0 +1,544 @@
+use std::{borrow::Cow, cmp::max, num::NonZeroU16};
+
+use tui::widgets::Row;
+
+use crate::{
+    app::{AppConfigFields, data::StoredData},
+    canvas::{
+        Painter,
+        components::data_table::{
+            ColumnHeader, DataTableColumn, DataTableProps, DataTableStyling, DataToCell,
+            SortColumn, SortDataTable, SortDataTableProps, SortOrder, SortsRow,
+        },
+    },
+    collection::network::{InterfaceHarvest, PacketCounters, PacketRates},
+    localization::{is_japanese, title_network_table},
+    options::config::style::Styles,
+    utils::{
+        data_units::{DataUnit, convert_bits, get_unit_prefix},
+        general::sort_partial_fn,
+        text_width::{TextWidthMode, display_width},
+    },
+};
+
+#[derive(Clone, Debug)]
+pub struct NetWidgetData {
+    pub name: String,
+    pub rx: u64,
+    pub tx: u64,
+    pub total_rx: u64,
+    pub total_tx: u64,
+    pub packet_totals: PacketCounters,
+    pub packet_rates: PacketRates,
+    pub mtu: u64,
+    pub link_speed: Option<u64>,
+    pub addresses: String,
+
+    /// The index of this interface in the network graph, if it is plotted.
+    pub graph_index: Option<usize>,
+
+    /// Whether RX or TX is at or above the link saturation threshold.
+    pub saturated: bool,
+
+    unit_type: DataUnit,
+    use_binary_prefix: bool,
+}
+
+impl NetWidgetData {
+    fn new(
+        interface: &InterfaceHarvest, graph_index: Option<usize>, saturation_threshold: f64,
+        unit_type: DataUnit, use_binary_prefix: bool,
+    ) -> Self {
+        let saturated = [interface.rx_percent, interface.tx_percent]
+            .into_iter()
+            .flatten()
+            .any(|usage| usage >= saturation_threshold);
+
+        Self {
+            name: interface.name.clone(),
+            rx: interface.rx,
+            tx: interface.tx,
+            total_rx: interface.total_rx,
+            total_tx: interface.total_tx,
+            packet_totals: interface.packet_totals,
+            packet_rates: interface.packet_rates,
+            mtu: interface.mtu,
+            link_speed: interface.link_speed,
+            addresses: interface.addresses.join(", "),
+            graph_index,
+            saturated,
+            unit_type,
+            use_binary_prefix,
+        }
+    }
+
+    fn rate(&self, value: u64) -> Cow<'static, str> {
+        let unit = match self.unit_type {
+            DataUnit::Byte => "B/s",
+            DataUnit::Bit => "b/s",
+        };
+        let (value, prefix) = get_unit_prefix(value, self.use_binary_prefix);
+
+        format!("{value:.1}{prefix}{unit}").into()
+    }
+
+    fn total(&self, bits: u64) -> Cow<'static, str> {
+        let (value, unit) = convert_bits(bits, self.use_binary_prefix);
+
+        format!("{value:.1}{unit}").into()
+    }
+
+    fn link_speed(&self) -> Cow<'static, str> {
+        match self.link_speed {
+            Some(speed) => {
+                let (value, prefix) = get_unit_prefix(speed, false);
+                format!("{value:.0}{prefix}b/s").into()
+            }
+            None => {
+                if is_japanese() {
+                    "該当なし".into()
+                } else {
+                    "N/A".into()
+                }
+            }
+        }
+    }
+}
+
+/// Formats a received/transmitted pair of counts compactly, e.g. `1.2M/340.0K`.
+fn count_pair(rx: u64, tx: u64) -> Cow<'static, str> {
+    fn count(value: u64) -> String {
+        match get_unit_prefix(value, false) {
+            (value, "") => format!("{value:.0}"),
+            (value, prefix) => format!("{value:.1}{prefix}"),
+        }
+    }
+
+    format!("{}/{}", count(rx), count(tx)).into()
+}
+
+/// Formats a received/transmitted pair of per-second rates compactly.
+fn rate_pair(rx: f64, tx: f64) -> Cow<'static, str> {
+    count_pair(rx.round() as u64, tx.round() as u64)
+}
+
+#[derive(Debug, Clone)]
+pub enum NetColumn {
+    Interface,
+    Rx,
+    Tx,
+    TotalRx,
+    TotalTx,
+    Packets,
+    PacketRate,
+    Errors,
+    Drops,
+    Mtu,
+    Speed,
+    Addresses,
+}
+
+impl ColumnHeader for NetColumn {
+    fn text(&self) -> Cow<'static, str> {
+        let japanese = is_japanese();
+
+        match self {
+            NetColumn::Interface => {
+                if japanese {
+                    "インターフェース"
+                } else {
+                    "Interface"
+                }
+            }
+            NetColumn::Rx => {
+                if japanese {
+                    "受信"
+                } else {
+                    "RX"
+                }
+            }
+            NetColumn::Tx => {
+                if japanese {
+                    "送信"
+                } else {
+                    "TX"
+                }
+            }
+            NetColumn::TotalRx => {
+                if japanese {
+                    "累積受信"
+                } else {
+                    "Total RX"
+                }
+            }
+            NetColumn::TotalTx => {
+                if japanese {
+                    "累積送信"
+                } else {
+                    "Total TX"
+                }
+            }
+            NetColumn::Packets => {
+                if japanese {
+                    "パケット"
+                } else {
+                    "Packets"
+                }
+            }
+            NetColumn::PacketRate => {
+                if japanese {
+                    "パケット/s"
+                } else {
+                    "Pkt/s"
+                }
+            }
+            NetColumn::Errors => {
+                if japanese {
+                    "エラー"
+                } else {
+                    "Errors"
+                }
+            }
+            NetColumn::Drops => {
+                if japanese {
+                    "破棄"
+                } else {
+                    "Drops"
+                }
+            }
+            NetColumn::Mtu => "MTU",
+            NetColumn::Speed => {
+                if japanese {
+                    "速度"
+                } else {
+                    "Speed"
+                }
+            }
+            NetColumn::Addresses => {
+                if japanese {
+                    "アドレス"
+                } else {
+                    "Addresses"
+                }
+            }
+        }
+        .into()
+    }
+}
+
+impl DataToCell<NetColumn> for NetWidgetData {
+    fn to_cell_text(
+        &self, column: &NetColumn, _calculated_width: NonZeroU16,
+    ) -> Option<Cow<'static, str>> {
+        let text = match column {
+            NetColumn::Interface => self.name.clone().into(),
+            NetColumn::Rx => self.rate(self.rx),
+            NetColumn::Tx => self.rate(self.tx),
+            NetColumn::TotalRx => self.total(self.total_rx),
+            NetColumn::TotalTx => self.total(self.total_tx),
+            NetColumn::Packets => {
+                count_pair(self.packet_totals.rx_packets, self.packet_totals.tx_packets)
+            }
+            NetColumn::PacketRate => {
+                rate_pair(self.packet_rates.rx_packets, self.packet_rates.tx_packets)
+            }
+            NetColumn::Errors => {
+                count_pair(self.packet_totals.rx_errors, self.packet_totals.tx_errors)
+            }
+            NetColumn::Drops => {
+                count_pair(self.packet_totals.rx_drops, self.packet_totals.tx_drops)
+            }
+            NetColumn::Mtu => self.mtu.to_string().into(),
+            NetColumn::Speed => self.link_speed(),
+            NetColumn::Addresses => self.addresses.clone().into(),
+        };
+
+        Some(text)
+    }
+
+    #[inline(always)]
+    fn style_row<'a>(&self, row: Row<'a>, painter: &Painter) -> Row<'a> {
+        if self.saturated {
+            row.style(painter.styles.saturated_link_style)
+        } else if let Some(index) = self.graph_index {
+            row.style(painter.styles.net_interface_style(index).0)
+        } else {
+            row
+        }
+    }
+
+    fn column_widths<C: DataTableColumn<NetColumn>>(
+        data: &[Self], _columns: &[C], width_mode: TextWidthMode,
+    ) -> Vec<u16>
+    where
+        Self: Sized,
+    {
+        let mut widths = vec![0; 12];
+
+        data.iter().for_each(|row| {
+            widths[0] = max(widths[0], display_width(&row.name, width_mode) as u16);
+            widths[11] = max(widths[11], display_width(&row.addresses, width_mode) as u16);
+        });
+
+        widths
+    }
+}
+
+pub struct NetTableWidget {
+    pub table: SortDataTable<NetWidgetData, NetColumn>,
+    pub force_update_data: bool,
+    saturation_threshold: f64,
+    unit_type: DataUnit,
+    use_binary_prefix: bool,
+}
+
+impl SortsRow for NetColumn {
+    type DataType = NetWidgetData;
+
+    fn sort_data(&self, data: &mut [Self::DataType], descending: bool) {
+        match self {
+            NetColumn::Interface => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(&a.name, &b.name));
+            }
+            NetColumn::Rx => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.rx, b.rx));
+            }
+            NetColumn::Tx => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.tx, b.tx));
+            }
+            NetColumn::TotalRx => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.total_rx, b.total_rx));
+            }
+            NetColumn::TotalTx => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.total_tx, b.total_tx));
+            }
+            NetColumn::Packets => {
+                let packets = |row: &NetWidgetData| {
+                    row.packet_totals.rx_packets + row.packet_totals.tx_packets
+                };
+                data.sort_by(|a, b| sort_partial_fn(descending)(packets(a), packets(b)));
+            }
+            NetColumn::PacketRate => {
+                let packet_rate =
+                    |row: &NetWidgetData| row.packet_rates.rx_packets + row.packet_rates.tx_packets;
+                data.sort_by(|a, b| sort_partial_fn(descending)(packet_rate(a), packet_rate(b)));
+            }
+            NetColumn::Errors => {
+                let errors =
+                    |row: &NetWidgetData| row.packet_totals.rx_errors + row.packet_totals.tx_errors;
+                data.sort_by(|a, b| sort_partial_fn(descending)(errors(a), errors(b)));
+            }
+            NetColumn::Drops => {
+                let drops =
+                    |row: &NetWidgetData| row.packet_totals.rx_drops + row.packet_totals.tx_drops;
+                data.sort_by(|a, b| sort_partial_fn(descending)(drops(a), drops(b)));
+            }
+            NetColumn::Mtu => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.mtu, b.mtu));
+            }
+            NetColumn::Speed => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.link_speed, b.link_speed));
+            }
+            NetColumn::Addresses => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(&a.addresses, &b.addresses));
+            }
+        }
+    }
+}
+
+const fn create_column(column_type: &NetColumn) -> SortColumn<NetColumn> {
+    match column_type {
+        NetColumn::Interface => SortColumn::soft(NetColumn::Interface, Some(0.2)),
+        NetColumn::Rx => SortColumn::hard(NetColumn::Rx, 10).default_descending(),
+        NetColumn::Tx => SortColumn::hard(NetColumn::Tx, 10).default_descending(),
+        NetColumn::TotalRx => SortColumn::hard(NetColumn::TotalRx, 9).default_descending(),
+        NetColumn::TotalTx => SortColumn::hard(NetColumn::TotalTx, 9).default_descending(),
+        NetColumn::Packets => SortColumn::hard(NetColumn::Packets, 13).default_descending(),
+        NetColumn::PacketRate => SortColumn::hard(NetColumn::PacketRate, 11).default_descending(),
+        NetColumn::Errors => SortColumn::hard(NetColumn::Errors, 9).default_descending(),
+        NetColumn::Drops => SortColumn::hard(NetColumn::Drops, 9).default_descending(),
+        NetColumn::Mtu => SortColumn::hard(NetColumn::Mtu, 6).default_descending(),
+        NetColumn::Speed => SortColumn::hard(NetColumn::Speed, 8).default_descending(),
+        NetColumn::Addresses => SortColumn::soft(NetColumn::Addresses, Some(0.3)),
+    }
+}
+
+const fn default_net_columns() -> [SortColumn<NetColumn>; 12] {
+    [
+        create_column(&NetColumn::Interface),
+        create_column(&NetColumn::Rx),
+        create_column(&NetColumn::Tx),
+        create_column(&NetColumn::TotalRx),
+        create_column(&NetColumn::TotalTx),
+        create_column(&NetColumn::Packets),
+        create_column(&NetColumn::PacketRate),
+        create_column(&NetColumn::Errors),
+        create_column(&NetColumn::Drops),
+        create_column(&NetColumn::Mtu),
+        create_column(&NetColumn::Speed),
+        create_column(&NetColumn::Addresses),
+    ]
+}
+
+impl NetTableWidget {
+    pub fn new(config: &AppConfigFields, palette: &Styles) -> Self {
+        let props = SortDataTableProps {
+            inner: DataTableProps {
+                title: Some(title_network_table().into()),
+                table_gap: config.table_gap,
+                left_to_right: true,
+                is_basic: config.use_basic_mode,
+                show_table_scroll_position: config.show_table_scroll_position,
+                show_current_entry_when_unfocused: false,
+            },
+            sort_index: 0,
+            order: SortOrder::Ascending,
+        };
+
+        let styling = DataTableStyling::from_palette(palette);
+
+        Self {
+            table: SortDataTable::new_sortable(default_net_columns(), props, styling),
+            force_update_data: false,
+            saturation_threshold: f64::from(config.network_saturation_threshold),
+            unit_type: config.network_unit_type,
+            use_binary_prefix: config.network_use_binary_prefix,
+        }
+    }
+
+    /// Forces an update of the data stored.
+    #[inline]
+    pub fn force_data_update(&mut self) {
+        self.force_update_data = true;
+    }
+
+    /// Update the current table data. `graph_interfaces` are the interfaces currently plotted
+    /// in the network graph.
+    pub fn set_table_data(&mut self, data: &StoredData, graph_interfaces: &[String]) {
+        let mut data = data
+            .network_harvest
+            .interfaces
+            .iter()
+            .map(|interface| {
+                let graph_index = graph_interfaces.iter().position(|n| *n == interface.name);
+                NetWidgetData::new(
+                    interface,
+                    graph_index,
+                    self.saturation_threshold,
+                    self.unit_type,
+                    self.use_binary_prefix,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(column) = self.table.columns.get(self.table.sort_index()) {
+            column.sort_by(&mut data, self.table.order());
+        }
+        self.table.set_data(data);
+        self.force_update_data = false;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn interface(name: &str, rx: u64, tx: u64) -> InterfaceHarvest {
+        InterfaceHarvest {
+            name: name.to_string(),
+            rx,
+            tx,
+            ..Default::default()
+        }
+    }
+
+    fn row(interface: &InterfaceHarvest) -> NetWidgetData {
+        NetWidgetData::new(interface, None, 90.0, DataUnit::Bit, false)
+    }
+
+    fn cell(data: &NetWidgetData, column: NetColumn) -> String {
+        data.to_cell_text(&column, NonZeroU16::new(20).unwrap())
+            .unwrap()
+            .into_owned()
+    }
+
+    #[test]
+    fn test_count_pair() {
+        assert_eq!(count_pair(0, 999), "0/999");
+        assert_eq!(count_pair(1_234_567, 340_000), "1.2M/340.0K");
+        assert_eq!(rate_pair(2.4, 1499.6), "2/1.5K");
+    }
+
+    #[test]
+    fn test_cell_text() {
+        let data = NetWidgetData::new(
+            &InterfaceHarvest {
+                total_rx: 8_000_000,
+                mtu: 1500,
+                link_speed: Some(1_000_000_000),
+                addresses: vec!["10.0.0.2/24".to_string(), "fe80::1/64".to_string()],
+                ..interface("eth0", 1_500_000, 800)
+            },
+            None,
+            90.0,
+            DataUnit::Bit,
+            false,
+        );
+
+        assert_eq!(cell(&data, NetColumn::Interface), "eth0");
+        assert_eq!(cell(&data, NetColumn::Rx), "1.5Mb/s");
+        assert_eq!(cell(&data, NetColumn::Tx), "800.0b/s");
+        assert_eq!(cell(&data, NetColumn::TotalRx), "1.0MB");
+        assert_eq!(cell(&data, NetColumn::Mtu), "1500");
+        assert_eq!(cell(&data, NetColumn::Speed), "1Gb/s");
+        assert_eq!(cell(&data, NetColumn::Addresses), "10.0.0.2/24, fe80::1/64");
+
+        let data = NetWidgetData::new(&interface("wg0", 2048, 0), None, 90.0, DataUnit::Byte, true);
+        assert_eq!(cell(&data, NetColumn::Rx), "2.0KiB/s");
+    }
+
+    #[test]
+    fn test_saturation() {
+        let saturated = |rx_percent, tx_percent| {
+            let interface = InterfaceHarvest {
+                rx_percent,
+                tx_percent,
+                ..interface("eth0", 0, 0)
+            };
+            row(&interface).saturated
+        };
+
+        assert!(!saturated(None, None));
+        assert!(!saturated(Some(89.9), Some(10.0)));
+        assert!(saturated(Some(10.0), Some(90.0)));
+        assert!(saturated(Some(100.0), None));
+    }
+
+    #[test]
+    fn test_sort_packets() {
+        let packets = |name, rx_packets, tx_packets| {
+            row(&InterfaceHarvest {
+                packet_totals: PacketCounters {
+                    rx_packets,
+                    tx_packets,
+                    ..Default::default()
+                },
+                ..interface(name, 0, 0)
+            })
+        };
+        let mut data = vec![
+            packets("a", 10, 10),
+            packets("b", 5, 30),
+            packets("c", 1, 1),
+        ];
+
+        // Packets are sorted by RX and TX combined.
+        NetColumn::Packets.sort_data(&mut data, true);
+        let names = data.iter().map(|row| row.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["b", "a", "c"]);
+
+        NetColumn::Packets.sort_data(&mut data, false);
+        let names = data.iter().map(|row| row.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["c", "a", "b"]);
+    }
+}
@@ -0,0 +1,15 @@
+use std::time::Instant;
+
+pub struct PowerWidgetState {
+    pub current_display_time: u64,
+    pub autohide_timer: Option<Instant>,
+}
+
+impl PowerWidgetState {
+    pub fn init(current_display_time: u64, autohide_timer: Option<Instant>) -> Self {
+        PowerWidgetState {
+            current_display_time,
+            autohide_timer,
+        }
+    }
+}
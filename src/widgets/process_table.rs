@@ -159,12 +159,22 @@ fn make_column(column: ProcColumn) -> SortColumn<ProcColumn> {
         Priority => SortColumn::new(Priority).default_descending(),
         #[cfg(unix)]
         Nice => SortColumn::new(Nice),
+        #[cfg(target_os = "linux")]
+        NumaNode => SortColumn::new(NumaNode),
         #[cfg(feature = "gpu")]
         GpuMemValue => SortColumn::new(GpuMemValue).default_descending(),
         #[cfg(feature = "gpu")]
         GpuMemPercent => SortColumn::new(GpuMemPercent).default_descending(),
         #[cfg(feature = "gpu")]
         GpuUtilPercent => SortColumn::new(GpuUtilPercent).default_descending(),
+        #[cfg(feature = "gpu")]
+        GpuGfxPercent => SortColumn::new(GpuGfxPercent).default_descending(),
+        #[cfg(feature = "gpu")]
+        GpuEncPercent => SortColumn::new(GpuEncPercent).default_descending(),
+        #[cfg(feature = "gpu")]
+        GpuDecPercent => SortColumn::new(GpuDecPercent).default_descending(),
+        #[cfg(feature = "gpu")]
+        GpuComputePercent => SortColumn::new(GpuComputePercent).default_descending(),
     }
 }
 
@@ -195,10 +205,20 @@ pub enum ProcWidgetColumn {
     Priority,
     #[cfg(unix)]
     Nice,
+    #[cfg(target_os = "linux")]
+    NumaNode,
     #[cfg(feature = "gpu")]
     GpuMem,
     #[cfg(feature = "gpu")]
     GpuUtil,
+    #[cfg(feature = "gpu")]
+    GpuGfx,
+    #[cfg(feature = "gpu")]
+    GpuEnc,
+    #[cfg(feature = "gpu")]
+    GpuDec,
+    #[cfg(feature = "gpu")]
+    GpuCompute,
 }
 
 // This is temporary. Switch back to `ProcColumn` later!
@@ -344,6 +364,8 @@ impl ProcWidgetState {
                             ProcWidgetColumn::Priority => Priority,
                             #[cfg(unix)]
                             ProcWidgetColumn::Nice => Nice,
+                            #[cfg(target_os = "linux")]
+                            ProcWidgetColumn::NumaNode => NumaNode,
                             #[cfg(feature = "gpu")]
                             ProcWidgetColumn::GpuMem => {
                                 if mem_as_values {
@@ -354,6 +376,14 @@ impl ProcWidgetState {
                             }
                             #[cfg(feature = "gpu")]
                             ProcWidgetColumn::GpuUtil => GpuUtilPercent,
+                            #[cfg(feature = "gpu")]
+                            ProcWidgetColumn::GpuGfx => GpuGfxPercent,
+                            #[cfg(feature = "gpu")]
+                            ProcWidgetColumn::GpuEnc => GpuEncPercent,
+                            #[cfg(feature = "gpu")]
+                            ProcWidgetColumn::GpuDec => GpuDecPercent,
+                            #[cfg(feature = "gpu")]
+                            ProcWidgetColumn::GpuCompute => GpuComputePercent,
                         };
 
                         make_column(col)
@@ -402,10 +432,20 @@ impl ProcWidgetState {
                     Priority => ProcWidgetColumn::Priority,
                     #[cfg(unix)]
                     Nice => ProcWidgetColumn::Nice,
+                    #[cfg(target_os = "linux")]
+                    NumaNode => ProcWidgetColumn::NumaNode,
                     #[cfg(feature = "gpu")]
                     GpuMemValue | GpuMemPercent => ProcWidgetColumn::GpuMem,
                     #[cfg(feature = "gpu")]
                     GpuUtilPercent => ProcWidgetColumn::GpuUtil,
+                    #[cfg(feature = "gpu")]
+                    GpuGfxPercent => ProcWidgetColumn::GpuGfx,
+                    #[cfg(feature = "gpu")]
+                    GpuEncPercent => ProcWidgetColumn::GpuEnc,
+                    #[cfg(feature = "gpu")]
+                    GpuDecPercent => ProcWidgetColumn::GpuDec,
+                    #[cfg(feature = "gpu")]
+                    GpuComputePercent => ProcWidgetColumn::GpuCompute,
                 }
             })
             .collect::<IndexSet<_>>();
@@ -831,6 +871,7 @@ impl ProcWidgetState {
                     #[cfg(feature = "gpu")]
                     {
                         pwd.gpu_usage += process.gpu_util;
+                        pwd.gpu_engines += process.gpu_engines;
                         match &mut pwd.gpu_mem_usage {
                             MemUsage::Percent(usage) => {
                                 *usage += process.gpu_mem_percent;
@@ -1211,11 +1252,15 @@ mod test {
             gpu_mem_usage: MemUsage::Percent(1.1),
             #[cfg(feature = "gpu")]
             gpu_usage: 0,
+            #[cfg(feature = "gpu")]
+            gpu_engines: Default::default(),
             #[cfg(target_os = "linux")]
             process_type: crate::collection::processes::ProcessType::Regular,
             #[cfg(unix)]
             nice: 0,
             priority: -20,
+            #[cfg(target_os = "linux")]
+            numa_node: None,
         };
 
         let b = ProcWidgetData {
@@ -34,12 +34,22 @@ pub enum ProcColumn {
     #[cfg(unix)]
     Nice,
     Priority,
+    #[cfg(target_os = "linux")]
+    NumaNode,
     #[cfg(feature = "gpu")]
     GpuMemValue,
     #[cfg(feature = "gpu")]
     GpuMemPercent,
     #[cfg(feature = "gpu")]
     GpuUtilPercent,
+    #[cfg(feature = "gpu")]
+    GpuGfxPercent,
+    #[cfg(feature = "gpu")]
+    GpuEncPercent,
+    #[cfg(feature = "gpu")]
+    GpuDecPercent,
+    #[cfg(feature = "gpu")]
+    GpuComputePercent,
 }
 
 impl ProcColumn {
@@ -67,9 +77,19 @@ impl ProcColumn {
             ProcColumn::GpuMemValue | ProcColumn::GpuMemPercent => &["GMem", "GMem%"],
             #[cfg(feature = "gpu")]
             ProcColumn::GpuUtilPercent => &["GPU%"],
+            #[cfg(feature = "gpu")]
+            ProcColumn::GpuGfxPercent => &["GPU gfx%", "gfx%"],
+            #[cfg(feature = "gpu")]
+            ProcColumn::GpuEncPercent => &["GPU enc%", "enc%"],
+            #[cfg(feature = "gpu")]
+            ProcColumn::GpuDecPercent => &["GPU dec%", "dec%"],
+            #[cfg(feature = "gpu")]
+            ProcColumn::GpuComputePercent => &["GPU compute%", "compute%"],
             #[cfg(unix)]
             ProcColumn::Nice => &["Nice"],
             ProcColumn::Priority => &["Priority"],
+            #[cfg(target_os = "linux")]
+            ProcColumn::NumaNode => &["NUMA"],
         }
     }
 }
@@ -155,12 +175,22 @@ impl ColumnHeader for ProcColumn {
                     "Priority"
                 }
             }
+            #[cfg(target_os = "linux")]
+            ProcColumn::NumaNode => "NUMA",
             #[cfg(feature = "gpu")]
             ProcColumn::GpuMemValue => "GMem",
             #[cfg(feature = "gpu")]
             ProcColumn::GpuMemPercent => "GMem%",
             #[cfg(feature = "gpu")]
             ProcColumn::GpuUtilPercent => "GPU%",
+            #[cfg(feature = "gpu")]
+            ProcColumn::GpuGfxPercent => "GPU gfx%",
+            #[cfg(feature = "gpu")]
+            ProcColumn::GpuEncPercent => "GPU enc%",
+            #[cfg(feature = "gpu")]
+            ProcColumn::GpuDecPercent => "GPU dec%",
+            #[cfg(feature = "gpu")]
+            ProcColumn::GpuComputePercent => "GPU compute%",
         }
         .into()
     }
@@ -277,6 +307,10 @@ impl SortsRow for ProcColumn {
             ProcColumn::Nice => {
                 data.sort_by(|a, b| sort_partial_fn(descending)(a.nice, b.nice));
             }
+            #[cfg(target_os = "linux")]
+            ProcColumn::NumaNode => {
+                data.sort_by(|a, b| sort_partial_fn(descending)(a.numa_node, b.numa_node));
+            }
             #[cfg(feature = "gpu")]
             ProcColumn::GpuMemValue | ProcColumn::GpuMemPercent => {
                 data.sort_by(|a, b| {
@@ -287,6 +321,30 @@ impl SortsRow for ProcColumn {
             ProcColumn::GpuUtilPercent => {
                 data.sort_by(|a, b| sort_partial_fn(descending)(a.gpu_usage, b.gpu_usage));
             }
+            #[cfg(feature = "gpu")]
+            ProcColumn::GpuGfxPercent => {
+                data.sort_by(|a, b| {
+                    sort_partial_fn(descending)(a.gpu_engines.gfx, b.gpu_engines.gfx)
+                });
+            }
+            #[cfg(feature = "gpu")]
+            ProcColumn::GpuEncPercent => {
+                data.sort_by(|a, b| {
+                    sort_partial_fn(descending)(a.gpu_engines.enc, b.gpu_engines.enc)
+                });
+            }
+            #[cfg(feature = "gpu")]
+            ProcColumn::GpuDecPercent => {
+                data.sort_by(|a, b| {
+                    sort_partial_fn(descending)(a.gpu_engines.dec, b.gpu_engines.dec)
+                });
+            }
+            #[cfg(feature = "gpu")]
+            ProcColumn::GpuComputePercent => {
+                data.sort_by(|a, b| {
+                    sort_partial_fn(descending)(a.gpu_engines.compute, b.gpu_engines.compute)
+                });
+            }
         }
     }
 }
@@ -315,10 +373,20 @@ impl<'de> Deserialize<'de> for ProcColumn {
             #[cfg(unix)]
             "nice" => Ok(ProcColumn::Nice),
             "priority" => Ok(ProcColumn::Priority),
+            #[cfg(target_os = "linux")]
+            "numa" => Ok(ProcColumn::NumaNode),
             #[cfg(feature = "gpu")]
             "gmem" | "gmem%" => Ok(ProcColumn::GpuMemPercent),
             #[cfg(feature = "gpu")]
             "gpu%" => Ok(ProcColumn::GpuUtilPercent),
+            #[cfg(feature = "gpu")]
+            "gpu gfx%" | "gfx%" => Ok(ProcColumn::GpuGfxPercent),
+            #[cfg(feature = "gpu")]
+            "gpu enc%" | "enc%" => Ok(ProcColumn::GpuEncPercent),
+            #[cfg(feature = "gpu")]
+            "gpu dec%" | "dec%" => Ok(ProcColumn::GpuDecPercent),
+            #[cfg(feature = "gpu")]
+            "gpu compute%" | "compute%" => Ok(ProcColumn::GpuComputePercent),
             _ => Err(serde::de::Error::custom(
                 "doesn't match any process column name",
             )),
@@ -344,10 +412,20 @@ impl From<&ProcColumn> for ProcWidgetColumn {
             ProcColumn::Priority => ProcWidgetColumn::Priority,
             #[cfg(unix)]
             ProcColumn::Nice => ProcWidgetColumn::Nice,
+            #[cfg(target_os = "linux")]
+            ProcColumn::NumaNode => ProcWidgetColumn::NumaNode,
             #[cfg(feature = "gpu")]
             ProcColumn::GpuMemPercent | ProcColumn::GpuMemValue => ProcWidgetColumn::GpuMem,
             #[cfg(feature = "gpu")]
             ProcColumn::GpuUtilPercent => ProcWidgetColumn::GpuUtil,
+            #[cfg(feature = "gpu")]
+            ProcColumn::GpuGfxPercent => ProcWidgetColumn::GpuGfx,
+            #[cfg(feature = "gpu")]
+            ProcColumn::GpuEncPercent => ProcWidgetColumn::GpuEnc,
+            #[cfg(feature = "gpu")]
+            ProcColumn::GpuDecPercent => ProcWidgetColumn::GpuDec,
+            #[cfg(feature = "gpu")]
+            ProcColumn::GpuComputePercent => ProcWidgetColumn::GpuCompute,
         }
     }
 }
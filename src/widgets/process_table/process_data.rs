@@ -11,6 +11,8 @@ use concat_string::concat_string;
 use tui::widgets::Row;
 
 use super::process_columns::ProcColumn;
+#[cfg(feature = "gpu")]
+use crate::collection::gpu::GpuEngineUtil;
 use crate::{
     canvas::{
         Painter,
@@ -218,12 +220,16 @@ pub struct ProcWidgetData {
     pub gpu_mem_usage: MemUsage,
     #[cfg(feature = "gpu")]
     pub gpu_usage: u32,
+    #[cfg(feature = "gpu")]
+    pub gpu_engines: GpuEngineUtil,
     /// The process "type". Used to color things.
     #[cfg(target_os = "linux")]
     pub process_type: crate::collection::processes::ProcessType,
     #[cfg(unix)]
     pub nice: i32,
     pub priority: i32,
+    #[cfg(target_os = "linux")]
+    pub numa_node: Option<usize>,
 }
 
 impl ProcWidgetData {
@@ -268,11 +274,15 @@ impl ProcWidgetData {
             },
             #[cfg(feature = "gpu")]
             gpu_usage: process.gpu_util,
+            #[cfg(feature = "gpu")]
+            gpu_engines: process.gpu_engines,
             #[cfg(target_os = "linux")]
             process_type: process.process_type,
             #[cfg(unix)]
             nice: process.nice,
             priority: process.priority,
+            #[cfg(target_os = "linux")]
+            numa_node: process.numa_node,
         }
     }
 
@@ -312,14 +322,24 @@ impl ProcWidgetData {
                 }
             };
             self.gpu_usage += other.gpu_usage;
+            self.gpu_engines += other.gpu_engines;
         }
     }
 
+    #[cfg(target_os = "linux")]
+    fn numa_node_string(&self) -> String {
+        self.numa_node
+            .map(|node| node.to_string())
+            .unwrap_or_else(|| "N/A".to_string())
+    }
+
     fn to_string(&self, column: &ProcColumn) -> String {
         match column {
             &ProcColumn::Priority => self.priority.to_string(),
             #[cfg(unix)]
             ProcColumn::Nice => self.nice.to_string(),
+            #[cfg(target_os = "linux")]
+            ProcColumn::NumaNode => self.numa_node_string(),
             ProcColumn::CpuPercent => format!("{:.1}%", self.cpu_usage_percent),
             ProcColumn::MemValue | ProcColumn::MemPercent => self.mem_usage.to_string(),
             ProcColumn::VirtualMem => binary_byte_string(self.virtual_mem),
@@ -341,6 +361,14 @@ impl ProcWidgetData {
             ProcColumn::GpuMemValue | ProcColumn::GpuMemPercent => self.gpu_mem_usage.to_string(),
             #[cfg(feature = "gpu")]
             ProcColumn::GpuUtilPercent => format!("{:.1}%", self.gpu_usage),
+            #[cfg(feature = "gpu")]
+            ProcColumn::GpuGfxPercent => format!("{:.1}%", self.gpu_engines.gfx),
+            #[cfg(feature = "gpu")]
+            ProcColumn::GpuEncPercent => format!("{:.1}%", self.gpu_engines.enc),
+            #[cfg(feature = "gpu")]
+            ProcColumn::GpuDecPercent => format!("{:.1}%", self.gpu_engines.dec),
+            #[cfg(feature = "gpu")]
+            ProcColumn::GpuComputePercent => format!("{:.1}%", self.gpu_engines.compute),
         }
     }
 }
@@ -355,6 +383,8 @@ impl DataToCell<ProcColumn> for ProcWidgetData {
         Some(match column {
             #[cfg(unix)]
             ProcColumn::Nice => self.nice.to_string().into(),
+            #[cfg(target_os = "linux")]
+            ProcColumn::NumaNode => self.numa_node_string().into(),
             &ProcColumn::Priority => self.priority.to_string().into(),
             ProcColumn::CpuPercent => format!("{:.1}%", self.cpu_usage_percent).into(),
             ProcColumn::MemValue | ProcColumn::MemPercent => self.mem_usage.to_string().into(),
@@ -385,6 +415,14 @@ impl DataToCell<ProcColumn> for ProcWidgetData {
             }
             #[cfg(feature = "gpu")]
             ProcColumn::GpuUtilPercent => format!("{:.1}%", self.gpu_usage).into(),
+            #[cfg(feature = "gpu")]
+            ProcColumn::GpuGfxPercent => format!("{:.1}%", self.gpu_engines.gfx).into(),
+            #[cfg(feature = "gpu")]
+            ProcColumn::GpuEncPercent => format!("{:.1}%", self.gpu_engines.enc).into(),
+            #[cfg(feature = "gpu")]
+            ProcColumn::GpuDecPercent => format!("{:.1}%", self.gpu_engines.dec).into(),
+            #[cfg(feature = "gpu")]
+            ProcColumn::GpuComputePercent => format!("{:.1}%", self.gpu_engines.compute).into(),
         })
     }
 
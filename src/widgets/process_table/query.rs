@@ -181,6 +181,14 @@ enum PrefixType {
     GpuMemoryBytes,
     #[cfg(feature = "gpu")]
     GpuMemoryPercentage,
+    #[cfg(feature = "gpu")]
+    GpuGfxPercentage,
+    #[cfg(feature = "gpu")]
+    GpuEncPercentage,
+    #[cfg(feature = "gpu")]
+    GpuDecPercentage,
+    #[cfg(feature = "gpu")]
+    GpuComputePercentage,
 }
 
 impl std::str::FromStr for PrefixType {
@@ -231,6 +239,14 @@ impl std::str::FromStr for PrefixType {
                 result = GpuMemoryPercentage;
             } else if multi_eq_ignore_ascii_case!(s, "gpu%") {
                 result = GpuPercentage;
+            } else if multi_eq_ignore_ascii_case!(s, "gfx%") {
+                result = GpuGfxPercentage;
+            } else if multi_eq_ignore_ascii_case!(s, "enc%") {
+                result = GpuEncPercentage;
+            } else if multi_eq_ignore_ascii_case!(s, "dec%") {
+                result = GpuDecPercentage;
+            } else if multi_eq_ignore_ascii_case!(s, "compute%") {
+                result = GpuComputePercentage;
             }
         }
         Ok(result)
@@ -919,6 +935,28 @@ mod tests {
         assert!(!use_percent.check(&process_b, false));
     }
 
+    #[cfg(feature = "gpu")]
+    #[test]
+    fn test_gpu_engine_queries() {
+        let gfx = parse_query_no_options("gfx% > 20").unwrap();
+        let enc = parse_query_no_options("enc% >= 5").unwrap();
+        let dec = parse_query_no_options("dec% = 0").unwrap();
+        let compute = parse_query_no_options("compute% < 10").unwrap();
+
+        let mut process = simple_process("test");
+        process.gpu_engines.gfx = 30;
+        process.gpu_engines.enc = 5;
+        process.gpu_engines.compute = 15;
+
+        assert!(gfx.check(&process, false));
+        assert!(enc.check(&process, false));
+        assert!(dec.check(&process, false));
+        assert!(!compute.check(&process, false));
+
+        let both = parse_query_no_options("gfx% > 20 and enc% > 5").unwrap();
+        assert!(!both.check(&process, false));
+    }
+
     /// Test GPU queries that involve invalid string comparisons.
     #[cfg(feature = "gpu")]
     #[test]
@@ -926,6 +964,8 @@ mod tests {
         parse_query_no_options("gmem = \"what\"").unwrap_err();
         parse_query_no_options("gmem% = \"the\"").unwrap_err();
         parse_query_no_options("gpu% = \"heck\"").unwrap_err();
+        parse_query_no_options("gfx% = \"oh\"").unwrap_err();
+        parse_query_no_options("compute% = \"no\"").unwrap_err();
     }
 
     // TODO: Test all attribute keywords (e.g. cpu, mem, etc.)
@@ -92,6 +92,14 @@ pub(super) enum ProcessAttribute {
     GpuMemoryPercentage(NumericalQuery),
     #[cfg(feature = "gpu")]
     GpuMemoryBytes(NumericalQuery),
+    #[cfg(feature = "gpu")]
+    GpuGfxPercentage(NumericalQuery),
+    #[cfg(feature = "gpu")]
+    GpuEncPercentage(NumericalQuery),
+    #[cfg(feature = "gpu")]
+    GpuDecPercentage(NumericalQuery),
+    #[cfg(feature = "gpu")]
+    GpuComputePercentage(NumericalQuery),
 }
 
 impl ProcessAttribute {
@@ -127,6 +135,16 @@ impl ProcessAttribute {
             ProcessAttribute::GpuMemoryPercentage(cmp) => cmp.check(process.gpu_mem_percent as f64),
             #[cfg(feature = "gpu")]
             ProcessAttribute::GpuMemoryBytes(cmp) => cmp.check(process.gpu_mem as f64),
+            #[cfg(feature = "gpu")]
+            ProcessAttribute::GpuGfxPercentage(cmp) => cmp.check(process.gpu_engines.gfx as f64),
+            #[cfg(feature = "gpu")]
+            ProcessAttribute::GpuEncPercentage(cmp) => cmp.check(process.gpu_engines.enc as f64),
+            #[cfg(feature = "gpu")]
+            ProcessAttribute::GpuDecPercentage(cmp) => cmp.check(process.gpu_engines.dec as f64),
+            #[cfg(feature = "gpu")]
+            ProcessAttribute::GpuComputePercentage(cmp) => {
+                cmp.check(process.gpu_engines.compute as f64)
+            }
         }
     }
 }
@@ -186,6 +204,14 @@ pub(super) fn new_numerical_attribute(
         PrefixType::GpuMemoryBytes => Ok(ProcessAttribute::GpuMemoryBytes(query)),
         #[cfg(feature = "gpu")]
         PrefixType::GpuMemoryPercentage => Ok(ProcessAttribute::GpuMemoryPercentage(query)),
+        #[cfg(feature = "gpu")]
+        PrefixType::GpuGfxPercentage => Ok(ProcessAttribute::GpuGfxPercentage(query)),
+        #[cfg(feature = "gpu")]
+        PrefixType::GpuEncPercentage => Ok(ProcessAttribute::GpuEncPercentage(query)),
+        #[cfg(feature = "gpu")]
+        PrefixType::GpuDecPercentage => Ok(ProcessAttribute::GpuDecPercentage(query)),
+        #[cfg(feature = "gpu")]
+        PrefixType::GpuComputePercentage => Ok(ProcessAttribute::GpuComputePercentage(query)),
         _ => Err(QueryError::new(format!(
             "process attribute type {prefix_type:?} is not a supported numerical attribute"
         ))),
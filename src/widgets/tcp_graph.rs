@@ -0,0 +1,15 @@
+use std::time::Instant;
+
+pub struct TcpWidgetState {
+    pub current_display_time: u64,
+    pub autohide_timer: Option<Instant>,
+}
+
+impl TcpWidgetState {
+    pub fn init(current_display_time: u64, autohide_timer: Option<Instant>) -> Self {
+        TcpWidgetState {
+            current_display_time,
+            autohide_timer,
+        }
+    }
+}
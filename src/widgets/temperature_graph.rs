@@ -0,0 +1,200 @@
+use std::{borrow::Cow, num::NonZeroU16, time::Instant};
+
+use rustc_hash::FxHashMap as HashMap;
+use tui::widgets::Row;
+
+use crate::{
+    app::{
+        AppConfigFields,
+        data::{StoredData, Values},
+    },
+    canvas::{
+        Painter,
+        components::data_table::{
+            Column, ColumnHeader, DataTable, DataTableColumn, DataTableProps, DataTableStyling,
+            DataToCell,
+        },
+    },
+    collection::temperature::SensorKind,
+    localization::is_japanese,
+    options::config::style::Styles,
+};
+
+/// Returns the sensors plotted in the temperature graphs, in the order they were selected, or
+/// every sensor if none are.
+pub fn plotted_sensors<'a>(
+    graph_sensors: &[String], temperatures: &'a HashMap<String, Values>,
+) -> Vec<(&'a String, &'a Values)> {
+    let mut sensors = if graph_sensors.is_empty() {
+        let mut sensors = temperatures.iter().collect::<Vec<_>>();
+        sensors.sort_by_key(|(name, _)| *name);
+        sensors
+    } else {
+        graph_sensors
+            .iter()
+            .filter_map(|name| temperatures.get_key_value(name))
+            .collect()
+    };
+    sensors.dedup_by(|(a, _), (b, _)| a == b);
+
+    sensors
+}
+
+pub enum TempGraphWidgetColumn {
+    Sensor,
+    Temp,
+}
+
+impl ColumnHeader for TempGraphWidgetColumn {
+    fn text(&self) -> Cow<'static, str> {
+        match self {
+            TempGraphWidgetColumn::Sensor => {
+                if is_japanese() {
+                    "センサー".into()
+                } else {
+                    "Sensor".into()
+                }
+            }
+            TempGraphWidgetColumn::Temp => {
+                if is_japanese() {
+                    "温度".into()
+                } else {
+                    "Temp".into()
+                }
+            }
+        }
+    }
+}
+
+pub enum TempGraphWidgetTableData {
+    All,
+    Entry {
+        name: String,
+        /// The latest reading, if the sensor is still being reported.
+        reading: Option<String>,
+        /// The sensor's position in the graph, which decides its colour.
+        index: usize,
+    },
+}
+
+impl DataToCell<TempGraphWidgetColumn> for TempGraphWidgetTableData {
+    fn to_cell_text(
+        &self, column: &TempGraphWidgetColumn, calculated_width: NonZeroU16,
+    ) -> Option<Cow<'static, str>> {
+        match self {
+            TempGraphWidgetTableData::All => match column {
+                TempGraphWidgetColumn::Sensor => {
+                    if is_japanese() {
+                        Some("全体".into())
+                    } else {
+                        Some("All".into())
+                    }
+                }
+                TempGraphWidgetColumn::Temp => None,
+            },
+            TempGraphWidgetTableData::Entry { name, reading, .. } => match column {
+                TempGraphWidgetColumn::Sensor => Some(name.clone().into()),
+                TempGraphWidgetColumn::Temp => {
+                    let reading = match reading {
+                        Some(reading) => reading.clone(),
+                        None if is_japanese() => "該当なし".to_string(),
+                        None => "N/A".to_string(),
+                    };
+
+                    // Always show the reading, but hide it rather than cutting it off.
+                    (reading.len() <= usize::from(calculated_width.get())).then(|| reading.into())
+                }
+            },
+        }
+    }
+
+    #[inline(always)]
+    fn style_row<'a>(&self, row: Row<'a>, painter: &Painter) -> Row<'a> {
+        let style = match self {
+            TempGraphWidgetTableData::All => painter.styles.text_style,
+            TempGraphWidgetTableData::Entry { index, .. } => painter
+                .styles
+                .list_style(&painter.styles.temp_graph_colours, *index),
+        };
+
+        row.style(style)
+    }
+
+    fn column_widths<C: DataTableColumn<TempGraphWidgetColumn>>(
+        _data: &[Self], _columns: &[C], _width_mode: crate::utils::text_width::TextWidthMode,
+    ) -> Vec<u16>
+    where
+        Self: Sized,
+    {
+        vec![1, 3]
+    }
+}
+
+pub struct TempGraphWidgetState {
+    pub current_display_time: u64,
+    pub is_legend_hidden: bool,
+    pub autohide_timer: Option<Instant>,
+    pub table: DataTable<TempGraphWidgetTableData, TempGraphWidgetColumn>,
+    pub force_update_data: bool,
+}
+
+impl TempGraphWidgetState {
+    pub(crate) fn new(
+        config: &AppConfigFields, current_display_time: u64, autohide_timer: Option<Instant>,
+        colours: &Styles,
+    ) -> Self {
+        const COLUMNS: [Column<TempGraphWidgetColumn>; 2] = [
+            Column::soft(TempGraphWidgetColumn::Sensor, Some(0.7)),
+            Column::soft(TempGraphWidgetColumn::Temp, Some(0.3)),
+        ];
+
+        let props = DataTableProps {
+            title: None,
+            table_gap: config.table_gap,
+            left_to_right: false,
+            is_basic: false,
+            show_table_scroll_position: false,
+            show_current_entry_when_unfocused: true,
+        };
+
+        let styling = DataTableStyling::from_palette(colours);
+
+        TempGraphWidgetState {
+            current_display_time,
+            is_legend_hidden: false,
+            autohide_timer,
+            table: DataTable::new(COLUMNS, props, styling),
+            force_update_data: false,
+        }
+    }
+
+    /// Forces an update of the data stored.
+    #[inline]
+    pub fn force_data_update(&mut self) {
+        self.force_update_data = true;
+    }
+
+    /// Sets the legend to the plotted sensors, along with their latest readings.
+    pub fn set_legend_data(&mut self, graph_sensors: &[String], data: &StoredData) {
+        let sensors = plotted_sensors(graph_sensors, &data.timeseries_data.temperatures);
+        let reading = |name: &str| {
+            data.temp_data
+                .iter()
+                .find(|temp| temp.kind == SensorKind::Temperature && temp.sensor == name)
+                .map(|temp| temp.reading().into_owned())
+        };
+
+        self.table.set_data(
+            std::iter::once(TempGraphWidgetTableData::All)
+                .chain(sensors.iter().enumerate().map(|(index, (name, _))| {
+                    TempGraphWidgetTableData::Entry {
+                        name: name.to_string(),
+                        reading: reading(name),
+                        index,
+                    }
+                }))
+                .collect(),
+        );
+        self.force_update_data = false;
+    }
+}
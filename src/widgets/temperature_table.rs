@@ -1,12 +1,19 @@
 use std::{borrow::Cow, cmp::max, num::NonZeroU16};
 
 use crate::{
-    app::{AppConfigFields, data::TypedTemperature},
-    canvas::components::data_table::{
-        ColumnHeader, DataTableColumn, DataTableProps, DataTableStyling, DataToCell, SortColumn,
-        SortDataTable, SortDataTableProps, SortOrder, SortsRow,
+    app::{
+        AppConfigFields,
+        data::{SensorValue, TempLevel},
     },
-    localization::{is_japanese, title_temperatures},
+    canvas::{
+        Painter,
+        components::data_table::{
+            ColumnHeader, DataTableColumn, DataTableProps, DataTableStyling, DataToCell,
+            SortColumn, SortDataTable, SortDataTableProps, SortOrder, SortsRow,
+        },
+    },
+    collection::temperature::SensorKind,
+    localization::{is_japanese, title_sensors, title_temperatures},
     options::config::style::Styles,
     utils::{
         general::sort_partial_fn,
@@ -17,12 +24,21 @@ use crate::{
 #[derive(Clone, Debug)]
 pub struct TempWidgetData {
     pub sensor: String,
-    pub temperature: Option<TypedTemperature>,
+    pub kind: SensorKind,
+    pub value: Option<SensorValue>,
+    pub max: Option<SensorValue>,
+    pub crit: Option<SensorValue>,
+    pub crit_alarm: bool,
+    pub level: TempLevel,
 }
 
 pub enum TempWidgetColumn {
     Sensor,
     Temp,
+    /// Used in place of [`TempWidgetColumn::Temp`] if other kinds of sensors are shown.
+    Reading,
+    Max,
+    Crit,
 }
 
 impl ColumnHeader for TempWidgetColumn {
@@ -42,48 +58,104 @@ impl ColumnHeader for TempWidgetColumn {
                     "Temp(t)".into()
                 }
             }
+            TempWidgetColumn::Reading => {
+                if is_japanese() {
+                    "測定値(t)".into()
+                } else {
+                    "Reading(t)".into()
+                }
+            }
+            TempWidgetColumn::Max => {
+                if is_japanese() {
+                    "上限".into()
+                } else {
+                    "Max".into()
+                }
+            }
+            TempWidgetColumn::Crit => {
+                if is_japanese() {
+                    "臨界".into()
+                } else {
+                    "Crit".into()
+                }
+            }
         }
     }
 }
 
-impl TempWidgetData {
-    pub fn temperature(&self) -> Cow<'static, str> {
-        match &self.temperature {
-            Some(temp) => temp.to_string().into(),
-            None => {
-                if is_japanese() {
-                    "該当なし".into()
-                } else {
-                    "N/A".into()
-                }
+fn value_string(value: &Option<SensorValue>) -> Cow<'static, str> {
+    match value {
+        Some(value) => value.to_string().into(),
+        None => {
+            if is_japanese() {
+                "該当なし".into()
+            } else {
+                "N/A".into()
             }
         }
     }
 }
 
+impl TempWidgetData {
+    /// The current reading, marked with a `!` if the kernel reports a critical alarm.
+    pub fn reading(&self) -> Cow<'static, str> {
+        let reading = value_string(&self.value);
+
+        if self.crit_alarm {
+            format!("{reading}!").into()
+        } else {
+            reading
+        }
+    }
+}
+
 impl DataToCell<TempWidgetColumn> for TempWidgetData {
     fn to_cell_text(
         &self, column: &TempWidgetColumn, _calculated_width: NonZeroU16,
     ) -> Option<Cow<'static, str>> {
         Some(match column {
             TempWidgetColumn::Sensor => self.sensor.clone().into(),
-            TempWidgetColumn::Temp => self.temperature(),
+            TempWidgetColumn::Temp | TempWidgetColumn::Reading => self.reading(),
+            TempWidgetColumn::Max => value_string(&self.max),
+            TempWidgetColumn::Crit => value_string(&self.crit),
         })
     }
 
+    fn style_cell(
+        &self, column: &TempWidgetColumn, painter: &Painter,
+    ) -> Option<tui::style::Style> {
+        match column {
+            TempWidgetColumn::Temp | TempWidgetColumn::Reading => match self.level {
+                TempLevel::Normal => None,
+                TempLevel::Warm => Some(painter.styles.warm_temp_style),
+                TempLevel::Hot => Some(painter.styles.hot_temp_style),
+                TempLevel::Critical => Some(painter.styles.critical_temp_style),
+            },
+            _ => None,
+        }
+    }
+
     fn column_widths<C: DataTableColumn<TempWidgetColumn>>(
         data: &[TempWidgetData], _columns: &[C], width_mode: TextWidthMode,
     ) -> Vec<u16>
     where
         Self: Sized,
     {
-        let mut widths = vec![0; 2];
+        let mut widths = vec![0; 4];
 
         data.iter().for_each(|row| {
             widths[0] = max(widths[0], display_width(&row.sensor, width_mode) as u16);
             widths[1] = max(
                 widths[1],
-                display_width(row.temperature().as_ref(), width_mode) as u16,
+                display_width(row.reading().as_ref(), width_mode) as u16,
+            );
+            widths[2] = max(
+                widths[2],
+                display_width(value_string(&row.max).as_ref(), width_mode) as u16,
+            );
+            widths[3] = max(
+                widths[3],
+                display_width(value_string(&row.crit).as_ref(), width_mode) as u16,
             );
         });
 
@@ -99,8 +171,27 @@ impl SortsRow for TempWidgetColumn {
             TempWidgetColumn::Sensor => {
                 data.sort_by(move |a, b| sort_partial_fn(descending)(&a.sensor, &b.sensor));
             }
-            TempWidgetColumn::Temp => {
-                data.sort_by(|a, b| sort_partial_fn(descending)(&a.temperature, &b.temperature));
+            // Readings of different kinds can't be compared, so group them by kind first.
+            TempWidgetColumn::Temp | TempWidgetColumn::Reading => {
+                data.sort_by(|a, b| {
+                    a.kind
+                        .cmp(&b.kind)
+                        .then_with(|| sort_partial_fn(descending)(&a.value, &b.value))
+                });
+            }
+            TempWidgetColumn::Max => {
+                data.sort_by(|a, b| {
+                    a.kind
+                        .cmp(&b.kind)
+                        .then_with(|| sort_partial_fn(descending)(&a.max, &b.max))
+                });
+            }
+            TempWidgetColumn::Crit => {
+                data.sort_by(|a, b| {
+                    a.kind
+                        .cmp(&b.kind)
+                        .then_with(|| sort_partial_fn(descending)(&a.crit, &b.crit))
+                });
             }
         }
     }
@@ -109,18 +200,35 @@ impl SortsRow for TempWidgetColumn {
 pub struct TempWidgetState {
     pub table: SortDataTable<TempWidgetData, TempWidgetColumn>,
     pub force_update_data: bool,
+
+    /// The kinds of sensors to show.
+    sensor_types: Vec<SensorKind>,
 }
 
 impl TempWidgetState {
-    pub(crate) fn new(config: &AppConfigFields, palette: &Styles) -> Self {
+    pub(crate) fn new(
+        config: &AppConfigFields, palette: &Styles, sensor_types: Vec<SensorKind>,
+    ) -> Self {
+        // Only call it a temperature widget if that's all it shows.
+        let only_temperatures = sensor_types
+            .iter()
+            .all(|kind| *kind == SensorKind::Temperature);
+        let (reading_column, title) = if only_temperatures {
+            (TempWidgetColumn::Temp, title_temperatures())
+        } else {
+            (TempWidgetColumn::Reading, title_sensors())
+        };
+
         let columns = [
-            SortColumn::soft(TempWidgetColumn::Sensor, Some(0.8)),
-            SortColumn::soft(TempWidgetColumn::Temp, None).default_descending(),
+            SortColumn::soft(TempWidgetColumn::Sensor, Some(0.6)),
+            SortColumn::soft(reading_column, None).default_descending(),
+            SortColumn::soft(TempWidgetColumn::Max, None).default_descending(),
+            SortColumn::soft(TempWidgetColumn::Crit, None).default_descending(),
         ];
 
         let props = SortDataTableProps {
             inner: DataTableProps {
-                title: Some(title_temperatures().into()),
+                title: Some(title.into()),
                 table_gap: config.table_gap,
                 left_to_right: false,
                 is_basic: config.use_basic_mode,
@@ -136,6 +244,7 @@ impl TempWidgetState {
         Self {
             table: SortDataTable::new_sortable(columns, props, styling),
             force_update_data: false,
+            sensor_types,
         }
     }
 
@@ -147,7 +256,11 @@ impl TempWidgetState {
 
     /// Update the current table data.
     pub fn set_table_data(&mut self, data: &[TempWidgetData]) {
-        let mut data = data.to_vec();
+        let mut data = data
+            .iter()
+            .filter(|row| self.sensor_types.contains(&row.kind))
+            .cloned()
+            .collect::<Vec<_>>();
         if let Some(column) = self.table.columns.get(self.table.sort_index()) {
             column.sort_by(&mut data, self.table.order());
         }
@@ -155,3 +268,37 @@ impl TempWidgetState {
         self.force_update_data = false;
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::app::data::TemperatureType;
+
+    #[test]
+    fn test_sort_readings_by_kind() {
+        let sensor = |name: &str, kind, value| TempWidgetData {
+            sensor: name.to_string(),
+            kind,
+            value: Some(SensorValue::new(kind, value, TemperatureType::Celsius)),
+            max: None,
+            crit: None,
+            crit_alarm: false,
+            level: TempLevel::Normal,
+        };
+        let mut data = vec![
+            sensor("Fan", SensorKind::Fan, 1200.0),
+            sensor("GPU", SensorKind::Temperature, 60.0),
+            sensor("Vcore", SensorKind::Voltage, 1.2),
+            sensor("CPU", SensorKind::Temperature, 45.0),
+            sensor("Pump", SensorKind::Fan, 2400.0),
+        ];
+
+        TempWidgetColumn::Reading.sort_data(&mut data, true);
+
+        let names = data
+            .iter()
+            .map(|sensor| sensor.sensor.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["GPU", "CPU", "Pump", "Fan", "Vcore"]);
+    }
+}
@@ -6,6 +6,22 @@ use predicates::prelude::*;
 
 use crate::util::{btm_command, no_cfg_btm_command};
 
+#[test]
+fn test_json_snapshot() {
+    let output = btm_command(&["-C", "./tests/valid_configs/empty_config.toml"])
+        .arg("--json")
+        .arg("-r")
+        .arg("250")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+
+    let snapshot: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(snapshot["version"], 1);
+    assert!(snapshot["processes"].is_array());
+}
+
 #[test]
 fn test_small_rate() {
     btm_command(&["-C", "./tests/valid_configs/empty_config.toml"])
@@ -190,6 +190,11 @@ fn test_proc_columns() {
     run_and_kill(&["-C", "./tests/valid_configs/proc_columns.toml"]);
 }
 
+#[test]
+fn test_alerts() {
+    run_and_kill(&["-C", "./tests/valid_configs/alerts.toml"]);
+}
+
 #[cfg(target_os = "linux")]
 #[test]
 fn test_linux_only() {